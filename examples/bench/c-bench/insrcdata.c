@@ -652,6 +652,11 @@ bench_iter_t  bench_byte_range( uint32_t start, uint32_t stop) {
     bench_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  bench_byte_count( uint32_t start, uint32_t stop) {
+    bench_iter_t iter = bench_byte_range(start, stop);
+    return iter.end - iter.ptr;
+}
 bench_iter_t  bench_short_range( uint32_t start, uint32_t stop) {
     uint16_t* lo = BENCH_SHORT_INDEX;
     uint16_t*  hi = BENCH_SHORT_INDEX + BENCH_SHORT_INDEX_COUNT;
@@ -678,6 +683,11 @@ bench_iter_t  bench_short_range( uint32_t start, uint32_t stop) {
     bench_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  bench_short_count( uint32_t start, uint32_t stop) {
+    bench_iter_t iter = bench_short_range(start, stop);
+    return iter.end - iter.ptr;
+}
 bench_iter_t  bench_int_range( uint32_t start, uint32_t stop) {
     uint16_t* lo = BENCH_INT_INDEX;
     uint16_t*  hi = BENCH_INT_INDEX + BENCH_INT_INDEX_COUNT;
@@ -704,6 +714,11 @@ bench_iter_t  bench_int_range( uint32_t start, uint32_t stop) {
     bench_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  bench_int_count( uint32_t start, uint32_t stop) {
+    bench_iter_t iter = bench_int_range(start, stop);
+    return iter.end - iter.ptr;
+}
 bench_iter_t  bench_str_range( const char* start, const char* stop) {
     uint16_t* lo = BENCH_STR_INDEX;
     uint16_t*  hi = BENCH_STR_INDEX + BENCH_STR_INDEX_COUNT;
@@ -730,3 +745,8 @@ bench_iter_t  bench_str_range( const char* start, const char* stop) {
     bench_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  bench_str_count( const char* start, const char* stop) {
+    bench_iter_t iter = bench_str_range(start, stop);
+    return iter.end - iter.ptr;
+}
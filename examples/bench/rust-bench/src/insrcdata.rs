@@ -1,5 +1,22 @@
 // generated by insrcdata version 0.3.0
 
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
 pub struct Bench {
     byte_ : u8,
     short_ : u16,
@@ -8,18 +25,18 @@ pub struct Bench {
 }
 impl PartialEq<Self> for Bench {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Bench {}
-impl std::hash::Hash for Bench {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Bench {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         bench::index_of(self).hash(state);
     }
 }
 
 impl Bench {
-    pub fn byte(&self) -> u32 { self.byte_ as u32 }
+    pub const fn byte(&self) -> u32 { self.byte_ as u32 }
     pub fn byte_range(start:u32, stop:u32) -> bench::IndexIter {
         let mut lo = 0;
         let mut hi = bench::BYTE_INDEX.len();
@@ -43,10 +60,35 @@ impl Bench {
             }
         }
         bench::IndexIter {
-            indexes: Box::new(bench::BYTE_INDEX[begin..lo].iter()),
+            indexes: bench::BYTE_INDEX[begin..lo].iter(),
         }
     }
-    pub fn short(&self) -> u32 { self.short_ as u32 }
+    /// Number of rows returned by byte_range
+    pub fn byte_count(start:u32, stop:u32) -> usize {
+        let mut lo = 0;
+        let mut hi = bench::BYTE_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > bench::TABLE[bench::BYTE_INDEX[mid] as usize].byte_ as u32 {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = bench::BYTE_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < bench::TABLE[bench::BYTE_INDEX[mid] as usize].byte_ as u32 {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo - begin
+    }
+    pub const fn short(&self) -> u32 { self.short_ as u32 }
     pub fn short_range(start:u32, stop:u32) -> bench::IndexIter {
         let mut lo = 0;
         let mut hi = bench::SHORT_INDEX.len();
@@ -70,10 +112,35 @@ impl Bench {
             }
         }
         bench::IndexIter {
-            indexes: Box::new(bench::SHORT_INDEX[begin..lo].iter()),
+            indexes: bench::SHORT_INDEX[begin..lo].iter(),
         }
     }
-    pub fn int(&self) -> u32 { self.int_ }
+    /// Number of rows returned by short_range
+    pub fn short_count(start:u32, stop:u32) -> usize {
+        let mut lo = 0;
+        let mut hi = bench::SHORT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > bench::TABLE[bench::SHORT_INDEX[mid] as usize].short_ as u32 {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = bench::SHORT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < bench::TABLE[bench::SHORT_INDEX[mid] as usize].short_ as u32 {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo - begin
+    }
+    pub const fn int(&self) -> u32 { self.int_ }
     pub fn int_range(start:u32, stop:u32) -> bench::IndexIter {
         let mut lo = 0;
         let mut hi = bench::INT_INDEX.len();
@@ -97,10 +164,35 @@ impl Bench {
             }
         }
         bench::IndexIter {
-            indexes: Box::new(bench::INT_INDEX[begin..lo].iter()),
+            indexes: bench::INT_INDEX[begin..lo].iter(),
         }
     }
-    pub fn str(&self) -> &'static str { self.str_ }
+    /// Number of rows returned by int_range
+    pub fn int_count(start:u32, stop:u32) -> usize {
+        let mut lo = 0;
+        let mut hi = bench::INT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > bench::TABLE[bench::INT_INDEX[mid] as usize].int_ {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = bench::INT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < bench::TABLE[bench::INT_INDEX[mid] as usize].int_ {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo - begin
+    }
+    pub const fn str(&self) -> &'static str { self.str_ }
     pub fn str_range(start:& str, stop:& str) -> bench::IndexIter {
         let mut lo = 0;
         let mut hi = bench::STR_INDEX.len();
@@ -124,22 +216,71 @@ impl Bench {
             }
         }
         bench::IndexIter {
-            indexes: Box::new(bench::STR_INDEX[begin..lo].iter()),
+            indexes: bench::STR_INDEX[begin..lo].iter(),
+        }
+    }
+    /// Number of rows returned by str_range
+    pub fn str_count(start:& str, stop:& str) -> usize {
+        let mut lo = 0;
+        let mut hi = bench::STR_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > bench::TABLE[bench::STR_INDEX[mid] as usize].str_ {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = bench::STR_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < bench::TABLE[bench::STR_INDEX[mid] as usize].str_ {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
         }
+        lo - begin
+    }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 4] = ["byte", "short", "int", "str"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 4] {
+        [
+            ("byte", FieldValue::Uint(self.byte() as u64)),
+            ("short", FieldValue::Uint(self.short() as u64)),
+            ("int", FieldValue::Uint(self.int() as u64)),
+            ("str", FieldValue::Str(self.str())),
+        ]
     }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 500 }
     /// Reference to the table containing all the values
     pub fn array() -> &'static [Bench; 500] { &bench::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { bench::index_of(self) }
 }
 
+impl core::fmt::Debug for Bench {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Bench")
+            .field("byte", &self.byte())
+            .field("short", &self.short())
+            .field("int", &self.int())
+            .field("str", &self.str())
+            .finish()
+    }
+}
+
 mod bench {use super::*;
 
 pub fn index_of(fic:&Bench) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Bench>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Bench>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u16>>,
+    pub indexes : core::slice::Iter<'static, u16>,
 }
 
 impl Iterator for IndexIter {
@@ -152,8 +293,14 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
 
 const fn r(byte:u8, short:u16, int:u32, str:&'static str, ) -> Bench {
     Bench{byte_:byte, short_:short, int_:int, str_:str, }
@@ -20,14 +20,24 @@ extern const bench_t* bench_next(bench_iter_t* idx);
 
 
 // ------    
+// Number of rows in the table
+static inline size_t bench_len(void) { return 500; }
 static inline uint32_t bench_byte(const bench_t* s) { return s->byte_; }
 extern bench_iter_t  bench_byte_range( uint32_t start, uint32_t stop);
+// Number of rows returned by bench_byte_range
+extern size_t  bench_byte_count( uint32_t start, uint32_t stop);
 static inline uint32_t bench_short(const bench_t* s) { return s->short_; }
 extern bench_iter_t  bench_short_range( uint32_t start, uint32_t stop);
+// Number of rows returned by bench_short_range
+extern size_t  bench_short_count( uint32_t start, uint32_t stop);
 static inline uint32_t bench_int(const bench_t* s) { return s->int_; }
 extern bench_iter_t  bench_int_range( uint32_t start, uint32_t stop);
+// Number of rows returned by bench_int_range
+extern size_t  bench_int_count( uint32_t start, uint32_t stop);
 static inline const char* bench_str(const bench_t* s) { return s->str_; }
 extern bench_iter_t  bench_str_range( const char* start, const char* stop);
+// Number of rows returned by bench_str_range
+extern size_t  bench_str_count( const char* start, const char* stop);
 
 // swift bindings
 static inline const bench_t* BENCH_TABLE_PTR() { return BENCH_TABLE; }
@@ -10,6 +10,7 @@ public struct Bench : Hashable {
     init(index:Int){
         cstruct = BENCH_TABLE_PTR()+index
     }
+    static var len : Int { Int(bench_len()) }
     var byte: UInt32 {  bench_byte(cstruct) }
     static func byteRange(start:UInt32, stop:UInt32) -> bench_iter_t {
         bench_byte_range(start, stop) 
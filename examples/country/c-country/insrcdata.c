@@ -5,6 +5,50 @@
 #include <stdio.h>
 #include <stdlib.h>
 
+static uint32_t insrcdata_utf8_next(const char** s) {
+    const unsigned char* p = (const unsigned char*)*s;
+    uint32_t c = *p++;
+    int extra = c >= 0xF0 ? 3 : c >= 0xE0 ? 2 : c >= 0xC0 ? 1 : 0;
+    if( extra ) {
+        c &= 0x3F >> extra;
+    }
+    while( extra-- && ( *p & 0xC0 ) == 0x80 ) {
+        c = ( c << 6 ) | ( *p++ & 0x3F );
+    }
+    *s = (const char*)p;
+    return c;
+}
+
+static unsigned insrcdata_levenshtein(const char* query, const char* s, unsigned* row) {
+    unsigned len = 0;
+    for( const char* p = s; *p; insrcdata_utf8_next(&p) ) {
+        row[len] = len;
+        len++;
+    }
+    row[len] = len;
+
+    unsigned i = 0;
+    while( *query ) {
+        uint32_t cq = insrcdata_utf8_next(&query);
+        unsigned diag = row[0];
+        row[0] = ++i;
+        const char* p = s;
+        for( unsigned j = 0; j < len; j++ ) {
+            uint32_t cs = insrcdata_utf8_next(&p);
+            unsigned above = row[j+1];
+            unsigned best = diag;
+            if( cq != cs ) {
+                best = diag < above ? diag : above;
+                best = row[j] < best ? row[j] : best;
+                best++;
+            }
+            row[j+1] = best;
+            diag = above;
+        }
+    }
+    return row[len];
+}
+
 static unsigned const REGION_TABLE_COUNT = 5;
 static const region_t REGION_TABLE[REGION_TABLE_COUNT] = {
    {"Asia", 142, },
@@ -298,6 +342,55 @@ const country_t COUNTRY_TABLE[COUNTRY_TABLE_COUNT] = {
 
 const country_t* country_next(country_iter_t* idx) { return idx->ptr<idx->end ? &COUNTRY_TABLE[*idx->ptr++] : NULL; }
 
+static unsigned const COUNTRY_NAME_FUZZY_COUNT  =  249;
+static const uint8_t COUNTRY_NAME_FUZZY_NODES[249] = {
+      0, 168,   2,   3,   6,   5,   1,   4,  46,  54,   9,  67,  63, 187, 145,  28,  42, 190,  72, 189, 
+     79,  32,  26,  97,  51, 122, 118, 234, 238, 115, 228,  11,  10,   7,  15,  25, 121,  14,  13,  17, 
+    132, 103,  12,   8,  29,  19,  18, 139,  94,  41,  52,  30, 156, 158,  66,  36,  21,  61, 179,  77, 
+     86,  37, 130, 245, 195, 157, 164,  78, 244, 172, 183,  33, 185, 119, 225, 170, 216, 233, 194, 213, 
+    165, 242,  47, 201, 217, 235, 105, 219,  27, 191, 144, 240, 236, 207, 186, 218, 226, 182,  84, 129, 
+    220,  69,  95,  40,  34,  55,  96,  16, 181, 124,  62, 176, 192,  82,  99, 147,  59,  20,  80, 177, 
+     75,  31,  39,  70,  87, 140,  53, 108, 208, 128,  73, 163, 215, 193, 135,  91, 100, 101,  60, 241, 
+    149,  22,  24,  50,  49, 117, 148,  35,  74, 143, 151, 199, 188, 150, 174,  98, 198,  89, 206, 204, 
+    138, 243, 229, 231, 161, 137, 171, 184,  45, 120, 153, 111, 104, 112,  23, 134, 203,  85, 224, 114, 
+    126, 197,  81,  71,  88,  48,  38, 127,  93, 102,  83,  76,  43, 196, 159,  44,  65,  92, 109, 106, 
+    146, 110, 214, 141, 175,  58, 113,  56, 212, 248, 178, 200, 162, 222, 160, 221, 227, 205, 180, 152, 
+    239, 202, 247, 123, 142, 211, 116, 107, 167, 136, 131,  57, 232, 173, 210, 223, 154, 155,  90, 125, 
+    230, 166,  64, 209, 169, 133, 246, 237,  68, 
+};
+
+static const uint8_t COUNTRY_NAME_FUZZY_DISTANCES[249] = {
+      0,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15,  16,  17,  18,  19,  20,  21,  22,  23, 
+     24,  25,  27,  28,  29,  30,  36,  46,   7,   8,   9,   3,   4,   5,   6,   7,   8,   5,   6,   7, 
+      8,   4,   5,   6,   7,   8,   9,  10,  11,  12,   5,   7,   8,   9,  10,  11,  12,  13,  10,  11, 
+     12,  13,  14,  12,  14,  15,  12,  14,  15,  14,  16,  17,  15,  10,  14,  17,  17,  18,  17,  21, 
+     22,  19,  21,  18,  20,  22,  23,  24,  26,  30,  29,  23,  31,  36,  39,   4,   6,   7,   8,   6, 
+      9,   4,   5,   6,   5,   6,   7,   8,   9,   6,   7,   9,   4,   5,   6,   7,   7,   8,   9,   5, 
+      6,   7,   8,   8,   9,   9,  10,  11,  10,  13,   4,  10,   6,   8,   9,  10,  11,   6,   7,   8, 
+     10,   3,   5,   6,   7,   8,   9,  10,   6,   7,   9,   9,   9,  12,   5,   7,   8,   9,  10,  12, 
+     13,  17,  12,   6,   5,   5,   2,   3,   4,   5,   4,   5,   8,   5,   6,   7,   5,   8,   4,   5, 
+      7,   4,   5,   6,   6,   7,   8,   4,   5,   3,   5,   4,   5,   6,   7,   7,   8,   8,   7,   6, 
+      5,   6,   7,   9,  11,   5,   6,   6,   8,   6,   7,   8,   3,   5,   6,  10,   8,   4,   6,   5, 
+      6,   6,   1,   3,   7,   5,   4,   1,   4,   5,   4,   5,   6,   8,   9,   6,   4,   5,   6,   7, 
+      6,   5,   6,   5,   3,   6,   5,   6,   6, 
+};
+
+static const uint8_t COUNTRY_NAME_FUZZY_CHILDREN[250] = {
+      1,  28,  28,  31,  37,  41,  50,  58,  63,  66,  69,  72,  73,  76,  78,  81,  83,  83,  85,  86, 
+     86,  88,  88,  90,  91,  91,  93,  95,  95,  95,  96,  96,  96,  96,  98,  99, 100, 101, 104, 109, 
+    111, 112, 112, 116, 119, 123, 125, 128, 129, 129, 130, 131, 131, 132, 134, 137, 141, 148, 151, 152, 
+    154, 157, 161, 161, 161, 161, 161, 161, 161, 161, 161, 161, 161, 161, 161, 161, 161, 162, 162, 162, 
+    162, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 163, 164, 164, 164, 
+    164, 164, 165, 166, 169, 169, 170, 173, 175, 175, 176, 178, 178, 179, 180, 181, 181, 184, 187, 189, 
+    189, 191, 195, 197, 198, 198, 198, 198, 198, 198, 198, 198, 198, 198, 198, 198, 199, 199, 200, 204, 
+    204, 205, 205, 205, 207, 209, 212, 212, 212, 213, 215, 216, 216, 216, 216, 216, 216, 216, 217, 217, 
+    217, 217, 217, 217, 217, 217, 218, 218, 218, 218, 218, 219, 219, 219, 220, 221, 222, 222, 222, 222, 
+    222, 222, 222, 224, 224, 224, 225, 225, 226, 227, 228, 229, 230, 233, 233, 233, 234, 234, 235, 235, 
+    235, 235, 235, 236, 236, 236, 240, 241, 243, 243, 243, 243, 243, 243, 243, 243, 243, 243, 243, 244, 
+    244, 244, 244, 244, 244, 244, 244, 244, 244, 244, 244, 245, 246, 246, 246, 246, 246, 246, 246, 247, 
+    247, 248, 248, 249, 249, 249, 249, 249, 249, 249, 
+};
+
 static unsigned const COUNTRY_ALPHA3_INDEX_COUNT  =  249;
 static uint8_t COUNTRY_ALPHA3_INDEX   [COUNTRY_ALPHA3_INDEX_COUNT] = {
      12,   0,   6,   7,   1,   2,   5, 233,  10,  11,   4,   8,  79,   9,  13,  14,  15,  36,  21,  23, 
@@ -421,6 +514,42 @@ country_iter_t subregion_countries(const subregion_t* s) {
     return res;
 }
 
+size_t country_name_fuzzy(const char* query, unsigned max_distance, country_fuzzy_t* results, size_t capacity) {
+    unsigned row[53];
+    uint8_t stack[49];
+    size_t top = 0;
+    size_t count = 0;
+    if( COUNTRY_NAME_FUZZY_COUNT ) {
+        stack[top++] = 0;
+    }
+    while( top ) {
+        uint8_t node = stack[--top];
+        const country_t* s = &COUNTRY_TABLE[COUNTRY_NAME_FUZZY_NODES[node]];
+        unsigned distance = insrcdata_levenshtein(query, s->name_, row);
+        if( distance <= max_distance ) {
+            // insert in ranked results
+            size_t i = count < capacity ? count++ : capacity;
+            while( i > 0 && ( results[i-1].distance > distance || ( results[i-1].distance == distance && results[i-1].row > s ))) {
+                if( i < capacity ) {
+                    results[i] = results[i-1];
+                }
+                i--;
+            }
+            if( i < capacity ) {
+                results[i].row = s;
+                results[i].distance = distance;
+            }
+        }
+        for( uint8_t child = COUNTRY_NAME_FUZZY_CHILDREN[node]; child < COUNTRY_NAME_FUZZY_CHILDREN[node+1]; child++ ) {
+            unsigned edge = COUNTRY_NAME_FUZZY_DISTANCES[child];
+            if( edge + max_distance >= distance && edge <= distance + max_distance ) {
+                stack[top++] = child;
+            }
+        }
+    }
+    return count;
+}
+
 country_iter_t  country_alpha3_range( const char* start, const char* stop) {
     uint8_t* lo = COUNTRY_ALPHA3_INDEX;
     uint8_t*  hi = COUNTRY_ALPHA3_INDEX + COUNTRY_ALPHA3_INDEX_COUNT;
@@ -447,6 +576,11 @@ country_iter_t  country_alpha3_range( const char* start, const char* stop) {
     country_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  country_alpha3_count( const char* start, const char* stop) {
+    country_iter_t iter = country_alpha3_range(start, stop);
+    return iter.end - iter.ptr;
+}
 country_iter_t  country_code_range( uint16_t start, uint16_t stop) {
     uint8_t* lo = COUNTRY_CODE_INDEX;
     uint8_t*  hi = COUNTRY_CODE_INDEX + COUNTRY_CODE_INDEX_COUNT;
@@ -473,6 +607,11 @@ country_iter_t  country_code_range( uint16_t start, uint16_t stop) {
     country_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  country_code_count( uint16_t start, uint16_t stop) {
+    country_iter_t iter = country_code_range(start, stop);
+    return iter.end - iter.ptr;
+}
 bool country_subregion(const country_t* s, const subregion_t** ptr) {
     if( s->subregion_) {
         *ptr = &SUBREGION_TABLE[s->subregion_-1];
@@ -80,6 +80,13 @@ static void demo() {
             printf("%s ( code : %d )\n", subregion_name(subregion), subregion_code(subregion));
       }
       
+      printf("\n  countries with a name close to 'Grece'\n");
+      country_fuzzy_t found[10];
+      size_t count = country_name_fuzzy("Grece", 2, found, 10);
+      for( size_t i=0; i<count; i++) {
+          printf("%s ( distance : %u )\n", country_name(found[i].row), found[i].distance);
+      }
+
       printf("\n  the first 5 countries of the table\n");
       assert(COUNTRY_TABLE_COUNT>5);
       for( int i=0; i<5; i++) {
@@ -183,6 +190,22 @@ static void test(void) {
       test_sdn_sgp("SDM", "SGP");
       test_sdn_sgp("SDN", "SGQ");
       test_sdn_sgp("SDM", "SGQ");
+
+      // count and aggregates
+      assert( country_len()==COUNTRY_TABLE_COUNT );
+      assert( country_code_count(136, 136)==1 );
+      assert( country_code_count(0, 3)==0 );
+      assert( COUNTRY_CODE_MIN==4 && COUNTRY_CODE_MAX==894 );
+
+      // approximate lookup
+      country_fuzzy_t found[3];
+      size_t count = country_name_fuzzy("Belgum", 1, found, 3);
+      assert( count==1 && found[0].row==belgium && found[0].distance==1 );
+      count = country_name_fuzzy("France", 0, found, 3);
+      assert( count==1 && found[0].row==france && found[0].distance==0 );
+      assert( country_name_fuzzy("Xyzzyx", 1, found, 3)==0 );
+      assert( country_name_fuzzy("Gr", 4, found, 3)==3 );
+      assert( found[0].distance<=found[1].distance && found[1].distance<=found[2].distance );
 }
 
 int main(){
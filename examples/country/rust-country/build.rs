@@ -62,6 +62,8 @@ help = "Country according to ISO/UN"
 [[table.col]]
 name = 'name'
 help = "English short name"
+fuzzy = true
+fuzzy_help = "Search country by approximate English short name"
 
 [[table.col]]
 name = 'alpha2'
@@ -82,6 +84,7 @@ src = 'country-code'
 range = true
 help = "UN M49 code"
 range_help = "Search country by UN M49 code"
+aggregates = ["min", "max"]
 
 [[table.join]]
 name = 'subregion'
@@ -81,6 +81,11 @@ pub fn demo() {
         println!("{} ( code : {} )", subregion.name(), subregion.code());
     }
 
+    println!("\n  countries with a name close to 'Grece'");
+    for (country, distance) in db::Country::name_fuzzy("Grece", 2) {
+        println!("{} ( distance : {} )", country.name(), distance);
+    }
+
     println!("\n  the first 5 countries of the table");
     for country in &db::Country::array()[0..5] {
         print_country(country);
@@ -160,6 +165,21 @@ pub fn test() {
     test_sdn_sgp("SDM", "SGP");
     test_sdn_sgp("SDN", "SGQ");
     test_sdn_sgp("SDM", "SGQ");
+
+    // count and aggregates
+    assert!(db::Country::len() == db::Country::array().len());
+    assert!(db::Country::code_count(100, 112) == db::Country::code_range(100, 112).count());
+    assert!(db::Country::code_count(136, 136) == 1);
+    assert!(db::Country::code_count(0, 3) == 0);
+    assert_eq!(db::Country::CODE_MIN, 4);
+    assert_eq!(db::Country::CODE_MAX, 894);
+
+    // approximate lookup
+    let found = db::Country::name_fuzzy("Belgum", 1);
+    assert!(found.len() == 1 && found[0].0 == db::Countries::Belgium && found[0].1 == 1);
+    let found = db::Country::name_fuzzy("France", 0);
+    assert!(found.len() == 1 && found[0].1 == 0);
+    assert!(db::Country::name_fuzzy("Xyzzyx", 1).is_empty());
 }
 
 fn main() {
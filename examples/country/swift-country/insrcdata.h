@@ -36,10 +36,13 @@ static unsigned const COUNTRY_TABLE_COUNT = 249;
 extern const country_t COUNTRY_TABLE[COUNTRY_TABLE_COUNT];
 typedef struct { uint8_t* ptr; uint8_t* end; } country_iter_t;
 extern const country_t* country_next(country_iter_t* idx);
+typedef struct { const country_t* row; unsigned distance; } country_fuzzy_t;
 
 
 
 // ------    
+// Number of rows in the table
+static inline size_t region_len(void) { return 5; }
 //English short name
 static inline const char* region_name(const region_t* s) { return s->name_; }
 //UN M49 code
@@ -49,6 +52,8 @@ extern subregion_iter_t region_subregions(const region_t* s);
 
 
 // ------    
+// Number of rows in the table
+static inline size_t subregion_len(void) { return 17; }
 //English short name
 static inline const char* subregion_name(const subregion_t* s) { return s->name_; }
 //UN M49 code
@@ -68,18 +73,30 @@ typedef enum {
 const country_t* country_from_countries(countries_t label);
 countries_t country_countries(const country_t *s);
             
+// Number of rows in the table
+static inline size_t country_len(void) { return 249; }
 //English short name
 static inline const char* country_name(const country_t* s) { return s->name_; }
+//Search country by approximate English short name
+extern size_t country_name_fuzzy(const char* query, unsigned max_distance, country_fuzzy_t* results, size_t capacity);
 //Two-letter country codes defined in ISO 3166-1
 static inline const char* country_alpha2(const country_t* s) { return s->alpha2_; }
 //Three-letter country codes defined in ISO 3166-1
 static inline const char* country_alpha3(const country_t* s) { return s->alpha3_; }
 //Search country by ISO 3166-1 three-letter code
 extern country_iter_t  country_alpha3_range( const char* start, const char* stop);
+// Number of rows returned by country_alpha3_range
+extern size_t  country_alpha3_count( const char* start, const char* stop);
 //UN M49 code
 static inline uint16_t country_code(const country_t* s) { return s->code_; }
 //Search country by UN M49 code
 extern country_iter_t  country_code_range( uint16_t start, uint16_t stop);
+// Number of rows returned by country_code_range
+extern size_t  country_code_count( uint16_t start, uint16_t stop);
+// min of code column values
+static uint16_t const COUNTRY_CODE_MIN = 4;
+// max of code column values
+static uint16_t const COUNTRY_CODE_MAX = 894;
 //Subregion containing the country
 extern bool country_subregion(const country_t* s, const subregion_t** ptr);
 
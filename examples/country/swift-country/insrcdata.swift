@@ -6,6 +6,7 @@ import Foundation
 public struct Region : Hashable {
         var cstruct : UnsafePointer<region_t>
     
+    static var len : Int { Int(region_len()) }
     /// English short name
     var name: String { String(cString:  region_name(cstruct)) }
     /// UN M49 code
@@ -30,6 +31,7 @@ extension region_iter_t : Sequence, IteratorProtocol {
 public struct Subregion : Hashable {
         var cstruct : UnsafePointer<subregion_t>
     
+    static var len : Int { Int(subregion_len()) }
     /// English short name
     var name: String { String(cString:  subregion_name(cstruct)) }
     /// UN M49 code
@@ -66,6 +68,7 @@ public struct Country : Hashable {
     init(index:Int){
         cstruct = COUNTRY_TABLE_PTR()+index
     }
+    static var len : Int { Int(country_len()) }
     /// English short name
     var name: String { String(cString:  country_name(cstruct)) }
     /// Two-letter country codes defined in ISO 3166-1
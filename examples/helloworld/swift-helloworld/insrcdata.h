@@ -15,6 +15,8 @@ extern const hello_world_t HELLO_WORLD_TABLE[HELLO_WORLD_TABLE_COUNT];
 
 
 // ------    
+// Number of rows in the table
+static inline size_t hello_world_len(void) { return 2; }
 static inline const char* hello_world_sentence(const hello_world_t* s) { return s->sentence_; }
 
 // swift bindings
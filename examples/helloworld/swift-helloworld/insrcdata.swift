@@ -10,6 +10,7 @@ public struct HelloWorld : Hashable {
     init(index:Int){
         cstruct = HELLO_WORLD_TABLE_PTR()+index
     }
+    static var len : Int { Int(hello_world_len()) }
     var sentence: String { String(cString:  hello_world_sentence(cstruct)) }
 
 } // struct HelloWorld
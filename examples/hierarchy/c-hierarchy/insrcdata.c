@@ -46,6 +46,22 @@ static uint8_t CHAPTER_PARENT_INDEX   [CHAPTER_PARENT_INDEX_COUNT] = {
     0, 1, 4, 2, 3, 5, 6, 
 };
 
+static const uint8_t CHAPTER_PARENT_ENTER[7] = {
+    0, 1, 2, 3, 4, 5, 6, 
+};
+
+static const uint8_t CHAPTER_PARENT_EXIT[7] = {
+    7, 4, 3, 4, 7, 6, 7, 
+};
+
+static const uint8_t CHAPTER_PARENT_PREORDER[7] = {
+    0, 1, 2, 3, 4, 5, 6, 
+};
+
+static const uint8_t CHAPTER_PARENT_DEPTH[7] = {
+    0, 1, 2, 2, 1, 2, 2, 
+};
+
 const chapter_t* leave_chapter(const leave_t* s) { return &CHAPTER_TABLE[s->chapter_];}
 chapter_iter_t  chapter_code_range( const char* start, const char* stop) {
     uint8_t* lo = CHAPTER_CODE_INDEX;
@@ -73,7 +89,24 @@ chapter_iter_t  chapter_code_range( const char* start, const char* stop) {
     chapter_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  chapter_code_count( const char* start, const char* stop) {
+    chapter_iter_t iter = chapter_code_range(start, stop);
+    return iter.end - iter.ptr;
+}
 const chapter_t* chapter_parent(const chapter_t* s) { return &CHAPTER_TABLE[s->parent_];}
+chapter_descendants_t chapter_parent_descendants(const chapter_t* s) {
+    size_t row = s - CHAPTER_TABLE;
+    chapter_descendants_t res = { CHAPTER_PARENT_PREORDER + CHAPTER_PARENT_ENTER[row] + 1, CHAPTER_PARENT_PREORDER + CHAPTER_PARENT_EXIT[row], CHAPTER_PARENT_DEPTH, CHAPTER_PARENT_DEPTH[row] };
+    return res;
+}
+
+bool chapter_parent_is_ancestor_of(const chapter_t* s, const chapter_t* other) {
+    size_t row = s - CHAPTER_TABLE;
+    size_t orow = other - CHAPTER_TABLE;
+    return CHAPTER_PARENT_ENTER[row] < CHAPTER_PARENT_ENTER[orow] && CHAPTER_PARENT_ENTER[orow] < CHAPTER_PARENT_EXIT[row];
+}
+
 leave_iter_t chapter_leaves(const chapter_t* s) {
     long cons = s - CHAPTER_TABLE;
 
@@ -106,3 +139,11 @@ leave_iter_t chapter_leaves(const chapter_t* s) {
     return res;
 }
 
+const chapter_t* chapter_descendants_next(chapter_descendants_t* iter, unsigned* depth) {
+    if( iter->ptr >= iter->end ) {
+        return NULL;
+    }
+    *depth = iter->depths[*iter->ptr] - iter->base;
+    return &CHAPTER_TABLE[*iter->ptr++];
+}
+
@@ -6,53 +6,60 @@
   
 // this sample show how to use insrcdata with a classification hierarchy
 
-// order of row in the tables is important :
-// we relly on a depth first table order for the `Chapter` table
+// the `closure` option of the `parent` join generates a depth first iterator on subchapters
 
  
 // print the fields contained by a hierarchy node and all it's children
 void print_chapter_content(const char* code)
 {
-      // for large tables, this algorith should perform better
-      // than the implementation used by Rust sample
-      
       // initialize the start with searched code
       chapter_iter_t  iter = chapter_code_range(code, code);
-      const  chapter_t* start = chapter_next(&iter);
-      if( start==NULL ){
+      const  chapter_t* chapter = chapter_next(&iter);
+      if( chapter==NULL ){
             return;
       }
       
-      // detect end of subtree
-      const  chapter_t* end = start+1;
-      for( ; end<CHAPTER_TABLE+CHAPTER_TABLE_COUNT; end++ ){
-            if( chapter_parent(end)<start ){
-                  break;
+      // the chapter and all its subchapters in depth first order
+      chapter_descendants_t descendants = chapter_parent_descendants(chapter);
+      unsigned depth;
+      for( ; chapter!=NULL; chapter = chapter_descendants_next(&descendants, &depth) ){
+            leave_iter_t leaves = chapter_leaves(chapter);
+            const  leave_t* leave;
+            while( (leave = leave_next(&leaves)) ){
+                  printf("%s\n", leave_title(leave));
             }
       }
+}
+
+void test(void)
+{
+      chapter_iter_t  iter = chapter_code_range("0", "0");
+      const  chapter_t* root = chapter_next(&iter);
+      iter = chapter_code_range("A1", "A1");
+      const  chapter_t* dogs = chapter_next(&iter);
+      iter = chapter_code_range("B", "B");
+      const  chapter_t* plants = chapter_next(&iter);
       
-      // first chapter with content
-      const  leave_t* leave = NULL;
-      for( ;(leave==NULL) && (start<end); start++){
-            leave_iter_t leaves = chapter_leaves(start);
-            leave = leave_next(&leaves);
-      }
-      if( leave==NULL ){
-            return;
-      }
+      assert( chapter_parent_is_ancestor_of(root, dogs) );
+      assert( !chapter_parent_is_ancestor_of(plants, dogs) );
+      assert( !chapter_parent_is_ancestor_of(dogs, dogs) );
+      assert( !chapter_parent_is_ancestor_of(dogs, root) ); // root is an ancestor of dogs, not a descendant
       
-      // all content of hirarchy
-      for( ; leave<LEAVE_TABLE+LEAVE_TABLE_COUNT; leave++ ){
-            if( leave_chapter(leave)>=end ){
-                  break;
-            }
-            printf("%s\n", leave_title(leave));
-      }
+      chapter_descendants_t descendants = chapter_parent_descendants(root);
+      unsigned depth;
+      const  chapter_t* chapter = chapter_descendants_next(&descendants, &depth);
+      assert( strcmp(chapter_code(chapter), "A")==0 && depth==1 );
+      chapter = chapter_descendants_next(&descendants, &depth);
+      assert( chapter==dogs && depth==2 );
+      
+      descendants = chapter_parent_descendants(dogs);
+      assert( chapter_descendants_next(&descendants, &depth)==NULL );
 }
  
- 
 int main()
 {
+      test();
+      
       // print all animals
       print_chapter_content("A");
       
@@ -1,5 +1,22 @@
 // generated by insrcdata version 0.3.0
 
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
 pub struct Leave {
     title_ : &'static str,
     chapter_code_ : &'static str,
@@ -7,33 +24,55 @@ pub struct Leave {
 }
 impl PartialEq<Self> for Leave {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Leave {}
-impl std::hash::Hash for Leave {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Leave {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         leave::index_of(self).hash(state);
     }
 }
 
 impl Leave {
-    pub fn title(&self) -> &'static str { self.title_ }
-    pub fn chapter_code(&self) -> &'static str { self.chapter_code_ }
+    pub const fn title(&self) -> &'static str { self.title_ }
+    pub const fn chapter_code(&self) -> &'static str { self.chapter_code_ }
     pub fn chapter(&self) -> &'static Chapter { &chapter::TABLE[self.chapter_ as usize]}
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 3] = ["title", "chapter_code", "chapter"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 3] {
+        [
+            ("title", FieldValue::Str(self.title())),
+            ("chapter_code", FieldValue::Str(self.chapter_code())),
+            ("chapter", FieldValue::Join("Chapter", chapter::index_of(self.chapter()))),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 9 }
     /// Reference to the table containing all the values
     pub fn array() -> &'static [Leave; 9] { &leave::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { leave::index_of(self) }
 }
 
+impl core::fmt::Debug for Leave {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Leave")
+            .field("title", &self.title())
+            .field("chapter_code", &self.chapter_code())
+            .field("chapter", &chapter::DebugRef(self.chapter()))
+            .finish()
+    }
+}
+
 mod leave {use super::*;
 
 pub fn index_of(fic:&Leave) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Leave>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Leave>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -46,8 +85,14 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
 
 const fn r(title:&'static str, chapter_code:&'static str, chapter:u8, ) -> Leave {
     Leave{title_:title, chapter_code_:chapter_code, chapter_:chapter, }
@@ -78,19 +123,19 @@ pub struct Chapter {
 }
 impl PartialEq<Self> for Chapter {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Chapter {}
-impl std::hash::Hash for Chapter {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Chapter {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         chapter::index_of(self).hash(state);
     }
 }
 
 impl Chapter {
-    pub fn title(&self) -> &'static str { self.title_ }
-    pub fn code(&self) -> &'static str { self.code_ }
+    pub const fn title(&self) -> &'static str { self.title_ }
+    pub const fn code(&self) -> &'static str { self.code_ }
     pub fn code_range(start:& str, stop:& str) -> chapter::IndexIter {
         let mut lo = 0;
         let mut hi = chapter::CODE_INDEX.len();
@@ -114,10 +159,48 @@ impl Chapter {
             }
         }
         chapter::IndexIter {
-            indexes: Box::new(chapter::CODE_INDEX[begin..lo].iter()),
+            indexes: chapter::CODE_INDEX[begin..lo].iter(),
         }
     }
+    /// Number of rows returned by code_range
+    pub fn code_count(start:& str, stop:& str) -> usize {
+        let mut lo = 0;
+        let mut hi = chapter::CODE_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > chapter::TABLE[chapter::CODE_INDEX[mid] as usize].code_ {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = chapter::CODE_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < chapter::TABLE[chapter::CODE_INDEX[mid] as usize].code_ {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo - begin
+    }
     pub fn parent(&self) -> &'static Chapter { &chapter::TABLE[self.parent_ as usize]}
+    pub fn parent_descendants(&self) -> chapter::DescendantIter {
+        let row = chapter::index_of(self);
+        chapter::DescendantIter {
+            rows: chapter::PARENT_PREORDER[chapter::PARENT_ENTER[row] as usize + 1..chapter::PARENT_EXIT[row] as usize].iter(),
+            depths: &chapter::PARENT_DEPTH,
+            base: chapter::PARENT_DEPTH[row] as usize,
+        }
+    }
+    /// Check if this row is reached by following parent links from other row
+    pub fn parent_is_ancestor_of(&self, other: &Self) -> bool {
+        let (row, other) = (chapter::index_of(self), chapter::index_of(other));
+        chapter::PARENT_ENTER[row] < chapter::PARENT_ENTER[other] && chapter::PARENT_ENTER[other] < chapter::PARENT_EXIT[row]
+    }
     pub fn leaves(&self) -> LeaveIter {
         let cons = chapter::index_of(self) as u8;
 
@@ -146,22 +229,44 @@ impl Chapter {
         }
 
         leave::IndexIter {
-            indexes: Box::new(leave::CHAPTER_INDEX[start..lo].iter()),
+            indexes: leave::CHAPTER_INDEX[start..lo].iter(),
         }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 3] = ["title", "code", "parent"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 3] {
+        [
+            ("title", FieldValue::Str(self.title())),
+            ("code", FieldValue::Str(self.code())),
+            ("parent", FieldValue::Join("Chapter", chapter::index_of(self.parent()))),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 7 }
     /// Reference to the table containing all the values
     pub fn array() -> &'static [Chapter; 7] { &chapter::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { chapter::index_of(self) }
 }
 
+impl core::fmt::Debug for Chapter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Chapter")
+            .field("title", &self.title())
+            .field("code", &self.code())
+            .field("parent", &chapter::DebugRef(self.parent()))
+            .finish()
+    }
+}
+
 mod chapter {use super::*;
 
 pub fn index_of(fic:&Chapter) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Chapter>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Chapter>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -174,8 +279,35 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
+
+pub struct DebugRef(pub &'static Chapter);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Chapter[{}]", index_of(self.0))
+    }
+}
+pub struct DescendantIter {
+    pub rows : core::slice::Iter<'static, u8>,
+    pub depths : &'static [u8],
+    pub base : usize,
+}
+
+impl Iterator for DescendantIter {
+    type Item = (&'static Chapter, usize);
+
+    fn next(&mut self) -> Option<(&'static Chapter, usize)> {
+        let row = *self.rows.next()? as usize;
+        Some((&TABLE[row], self.depths[row] as usize - self.base))
+    }
+}
 
 const fn r(title:&'static str, code:&'static str, parent:u8, ) -> Chapter {
     Chapter{title_:title, code_:code, parent_:parent, }
@@ -196,7 +328,20 @@ pub static CODE_INDEX : [ u8 ; 7 ] = [
 pub static PARENT_INDEX : [ u8 ; 7 ] = [
     0, 1, 4, 2, 3, 5, 6, 
 ];
+pub static PARENT_ENTER : [ u8 ; 7 ] = [
+    0, 1, 2, 3, 4, 5, 6, 
+];
+pub static PARENT_EXIT : [ u8 ; 7 ] = [
+    7, 4, 3, 4, 7, 6, 7, 
+];
+pub static PARENT_PREORDER : [ u8 ; 7 ] = [
+    0, 1, 2, 3, 4, 5, 6, 
+];
+pub static PARENT_DEPTH : [ u8 ; 7 ] = [
+    0, 1, 2, 2, 1, 2, 2, 
+];
 
 } // mod chapter
 
 pub use chapter::IndexIter as ChapterIter;
+pub use chapter::DescendantIter as ChapterDescendants;
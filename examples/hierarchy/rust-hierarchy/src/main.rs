@@ -1,9 +1,6 @@
-use std::collections::HashSet;
-
 // this sample show how to use insrcdata with a classification hierarchy
 
-// order of row in the tables is important :
-// we relly on a depth first table order for the `Chapter` table
+// the `closure` option of the `parent` join generates a depth first iterator on subchapters
 
 #[allow(dead_code)]
 #[allow(unused_variables)]
@@ -11,30 +8,47 @@ mod insrcdata;
 
 // print the fields contained by a hierarchy node and all it's children
 fn print_chapter_content(code: &str) {
-    // this algorithm could be optimized with public access to the `parent` and `chapter` fields
-    // but is already quite fast for tables of average size
-    // see C language sample for an alternative usable in larger tables
-
-    // initialize the roots with searched code
-    let mut parents: HashSet<&str> = HashSet::new();
-    parents.insert(code);
-
-    // collect all subchapters of roots
-    for f in insrcdata::Chapter::array() {
-        if parents.contains(&f.parent().code()) {
-            parents.insert(f.code());
-        }
-    }
+    // search the chapter with code
+    let Some(start) = insrcdata::Chapter::code_range(code, code).next() else {
+        return;
+    };
+
+    // the chapter and all its subchapters in depth first order
+    let chapters = std::iter::once(start).chain(start.parent_descendants().map(|(c, _depth)| c));
 
     // print all the leaves corresponding to collected chapters
-    for f in insrcdata::Leave::array() {
-        if parents.contains(&f.chapter().code()) {
-            println!("{}", f.title());
+    for chapter in chapters {
+        for leave in chapter.leaves() {
+            println!("{}", leave.title());
         }
     }
 }
 
+fn test() {
+    let root = insrcdata::Chapter::code_range("0", "0").next().unwrap();
+    let animals = insrcdata::Chapter::code_range("A", "A").next().unwrap();
+    let dogs = insrcdata::Chapter::code_range("A1", "A1").next().unwrap();
+    let plants = insrcdata::Chapter::code_range("B", "B").next().unwrap();
+
+    assert!(root.parent_is_ancestor_of(dogs));
+    assert!(animals.parent_is_ancestor_of(dogs));
+    assert!(!plants.parent_is_ancestor_of(dogs));
+    assert!(!dogs.parent_is_ancestor_of(dogs));
+    // dogs is a descendant of animals, not an ancestor
+    assert!(!dogs.parent_is_ancestor_of(animals));
+
+    let depths: Vec<(&str, usize)> = root
+        .parent_descendants()
+        .map(|(c, depth)| (c.code(), depth))
+        .collect();
+    assert!(depths.len() == 6);
+    assert!(depths[0] == ("A", 1) && depths[1] == ("A1", 2));
+    assert!(dogs.parent_descendants().count() == 0);
+}
+
 fn main() {
+    test();
+
     // print all animals
     print_chapter_content("A");
 }
@@ -25,20 +25,31 @@ static unsigned const CHAPTER_TABLE_COUNT = 7;
 extern const chapter_t CHAPTER_TABLE[CHAPTER_TABLE_COUNT];
 typedef struct { uint8_t* ptr; uint8_t* end; } chapter_iter_t;
 extern const chapter_t* chapter_next(chapter_iter_t* idx);
+typedef struct { const uint8_t* ptr; const uint8_t* end; const uint8_t* depths; unsigned base; } chapter_descendants_t;
+extern const chapter_t* chapter_descendants_next(chapter_descendants_t* iter, unsigned* depth);
 
 
 
 // ------    
+// Number of rows in the table
+static inline size_t leave_len(void) { return 9; }
 static inline const char* leave_title(const leave_t* s) { return s->title_; }
 static inline const char* leave_chapter_code(const leave_t* s) { return s->chapter_code_; }
 extern const chapter_t* leave_chapter(const leave_t* s);
 
 
 // ------    
+// Number of rows in the table
+static inline size_t chapter_len(void) { return 7; }
 static inline const char* chapter_title(const chapter_t* s) { return s->title_; }
 static inline const char* chapter_code(const chapter_t* s) { return s->code_; }
 extern chapter_iter_t  chapter_code_range( const char* start, const char* stop);
+// Number of rows returned by chapter_code_range
+extern size_t  chapter_code_count( const char* start, const char* stop);
 extern const chapter_t* chapter_parent(const chapter_t* s);
+extern chapter_descendants_t chapter_parent_descendants(const chapter_t* s);
+// Check if s is reached by following parent links from other row
+extern bool chapter_parent_is_ancestor_of(const chapter_t* s, const chapter_t* other);
 extern leave_iter_t chapter_leaves(const chapter_t* s);
 
 // swift bindings
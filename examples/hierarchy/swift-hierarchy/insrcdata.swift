@@ -10,6 +10,7 @@ public struct Leave : Hashable {
     init(index:Int){
         cstruct = LEAVE_TABLE_PTR()+index
     }
+    static var len : Int { Int(leave_len()) }
     var title: String { String(cString:  leave_title(cstruct)) }
     var chapter_code: String { String(cString:  leave_chapter_code(cstruct)) }
     var chapter : Chapter { Chapter(cstruct:leave_chapter(cstruct)) }
@@ -36,6 +37,7 @@ public struct Chapter : Hashable {
     init(index:Int){
         cstruct = CHAPTER_TABLE_PTR()+index
     }
+    static var len : Int { Int(chapter_len()) }
     var title: String { String(cString:  chapter_title(cstruct)) }
     var code: String { String(cString:  chapter_code(cstruct)) }
     static func codeRange(start:String, stop:String) -> chapter_iter_t {
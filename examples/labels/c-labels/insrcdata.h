@@ -30,6 +30,8 @@ typedef enum {
 const label_t* label_from_labels(labels_t label);
 labels_t label_labels(const label_t *s);
             
+// Number of rows in the table
+static inline size_t label_len(void) { return 10; }
 static inline const char* label_title(const label_t* s) { return s->title_; }
 
 #endif //  INSRCDATA_H 
@@ -1,5 +1,22 @@
 // generated by insrcdata version 0.3.0
 
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Labels {
@@ -26,7 +43,7 @@ impl From<&Labels> for  &'static Label{
 }
 impl PartialEq<Labels> for &Label {
     fn eq(&self, other: &Labels) -> bool {
-        std::ptr::eq(<&Label>::from(other), *self)
+        core::ptr::eq(<&Label>::from(other), *self)
     }
 }
 
@@ -36,29 +53,49 @@ pub struct Label {
 }
 impl PartialEq<Self> for Label {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Label {}
-impl std::hash::Hash for Label {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Label {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         label::index_of(self).hash(state);
     }
 }
 
 impl Label {
-    pub fn title(&self) -> &'static str { self.title_ }
-    pub fn as_label(&self) -> &Labels { &self.as_label_}
+    pub const fn title(&self) -> &'static str { self.title_ }
+    pub const fn as_label(&self) -> &Labels { &self.as_label_}
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["title", "as_label"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("title", FieldValue::Str(self.title())),
+            ("as_label", FieldValue::Label(self.as_label())),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 10 }
     /// Reference to the table containing all the values
     pub fn array() -> &'static [Label; 10] { &label::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { label::index_of(self) }
 }
 
+impl core::fmt::Debug for Label {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Label")
+            .field("title", &self.title())
+            .field("as_label", &self.as_label())
+            .finish()
+    }
+}
+
 mod label {use super::*;
 
 pub fn index_of(fic:&Label) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Label>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Label>()
 }
 const fn r(title:&'static str, as_label:Labels, ) -> Label {
     Label{title_:title, as_label_:as_label, }
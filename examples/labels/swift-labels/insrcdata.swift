@@ -14,6 +14,7 @@ public struct Label : Hashable {
     init(index:Int){
         cstruct = LABEL_TABLE_PTR()+index
     }
+    static var len : Int { Int(label_len()) }
     var title: String { String(cString:  label_title(cstruct)) }
 
 } // struct Label
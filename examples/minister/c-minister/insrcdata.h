@@ -32,9 +32,13 @@ typedef enum {
 const minister_t* minister_from_ministers(ministers_t label);
 ministers_t minister_ministers(const minister_t *s);
             
+// Number of rows in the table
+static inline size_t minister_len(void) { return 3; }
 static inline const char* minister_name(const minister_t* s) { return s->name_; }
 static inline uint16_t minister_birth(const minister_t* s) { return s->birth_; }
 extern minister_iter_t  minister_birth_range( uint16_t start, uint16_t stop);
+// Number of rows returned by minister_birth_range
+extern size_t  minister_birth_count( uint16_t start, uint16_t stop);
 extern const country_t* minister_country(const minister_t* s);
 
 
@@ -46,6 +50,8 @@ typedef enum {
 const country_t* country_from_countries(countries_t label);
 countries_t country_countries(const country_t *s);
             
+// Number of rows in the table
+static inline size_t country_len(void) { return 2; }
 static inline const char* country_code(const country_t* s) { return s->code_; }
 static inline const char* country_name(const country_t* s) { return s->name_; }
 extern minister_iter_t country_ministers(const country_t* s);
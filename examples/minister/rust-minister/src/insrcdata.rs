@@ -1,7 +1,24 @@
 // generated by insrcdata version 0.3.0
 
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Ministers {
     DavidCameron = 0,
     GordonBrown = 1,
@@ -19,7 +36,7 @@ impl From<&Ministers> for  &'static Minister{
 }
 impl PartialEq<Ministers> for &Minister {
     fn eq(&self, other: &Ministers) -> bool {
-        std::ptr::eq(<&Minister>::from(other), *self)
+        core::ptr::eq(<&Minister>::from(other), *self)
     }
 }
 
@@ -30,19 +47,19 @@ pub struct Minister {
 }
 impl PartialEq<Self> for Minister {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Minister {}
-impl std::hash::Hash for Minister {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Minister {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         minister::index_of(self).hash(state);
     }
 }
 
 impl Minister {
-    pub fn name(&self) -> &'static str { self.name_ }
-    pub fn birth(&self) -> u16 { self.birth_ }
+    pub const fn name(&self) -> &'static str { self.name_ }
+    pub const fn birth(&self) -> u16 { self.birth_ }
     pub fn birth_range(start:u16, stop:u16) -> minister::IndexIter {
         let mut lo = 0;
         let mut hi = minister::BIRTH_INDEX.len();
@@ -66,19 +83,77 @@ impl Minister {
             }
         }
         minister::IndexIter {
-            indexes: Box::new(minister::BIRTH_INDEX[begin..lo].iter()),
+            indexes: minister::BIRTH_INDEX[begin..lo].iter(),
+        }
+    }
+    /// Number of rows returned by birth_range
+    pub fn birth_count(start:u16, stop:u16) -> usize {
+        let mut lo = 0;
+        let mut hi = minister::BIRTH_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > minister::TABLE[minister::BIRTH_INDEX[mid] as usize].birth_ {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = minister::BIRTH_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < minister::TABLE[minister::BIRTH_INDEX[mid] as usize].birth_ {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
         }
+        lo - begin
     }
     pub fn country(&self) -> &'static Country { &country::TABLE[self.country_ as usize]}
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 3] = ["name", "birth", "country"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 3] {
+        [
+            ("name", FieldValue::Str(self.name())),
+            ("birth", FieldValue::Uint(self.birth() as u64)),
+            ("country", FieldValue::Join("Country", country::index_of(self.country()))),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 3 }
+}
+
+impl core::fmt::Debug for Minister {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Minister")
+            .field("name", &self.name())
+            .field("birth", &self.birth())
+            .field("country", &country::DebugRef(self.country()))
+            .finish()
+    }
+}
+
+impl serde::Serialize for Minister {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Minister", 3)?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("birth", &self.birth())?;
+        state.serialize_field("country", &self.country())?;
+        state.end()
+    }
 }
 
 mod minister {use super::*;
 
 pub fn index_of(fic:&Minister) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Minister>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Minister>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -91,8 +166,14 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
 
 const fn r(name:&'static str, birth:u16, country:u8, ) -> Minister {
     Minister{name_:name, birth_:birth, country_:country, }
@@ -114,7 +195,7 @@ pub static COUNTRY_INDEX : [ u8 ; 3 ] = [
 
 pub use minister::IndexIter as MinisterIter;
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Countries {
     Uk = 0,
     It = 1,
@@ -131,7 +212,7 @@ impl From<&Countries> for  &'static Country{
 }
 impl PartialEq<Countries> for &Country {
     fn eq(&self, other: &Countries) -> bool {
-        std::ptr::eq(<&Country>::from(other), *self)
+        core::ptr::eq(<&Country>::from(other), *self)
     }
 }
 
@@ -141,19 +222,19 @@ pub struct Country {
 }
 impl PartialEq<Self> for Country {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Country {}
-impl std::hash::Hash for Country {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Country {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         country::index_of(self).hash(state);
     }
 }
 
 impl Country {
-    pub fn code(&self) -> &'static str { self.code_ }
-    pub fn name(&self) -> &'static str { self.name_ }
+    pub const fn code(&self) -> &'static str { self.code_ }
+    pub const fn name(&self) -> &'static str { self.name_ }
     pub fn ministers(&self) -> MinisterIter {
         let cons = country::index_of(self) as u8;
 
@@ -182,18 +263,48 @@ impl Country {
         }
 
         minister::IndexIter {
-            indexes: Box::new(minister::COUNTRY_INDEX[start..lo].iter()),
+            indexes: minister::COUNTRY_INDEX[start..lo].iter(),
         }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["code", "name"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("code", FieldValue::Str(self.code())),
+            ("name", FieldValue::Str(self.name())),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 2 }
+}
+
+impl core::fmt::Debug for Country {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Country")
+            .field("code", &self.code())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+impl serde::Serialize for Country {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Country", 2)?;
+        state.serialize_field("code", &self.code())?;
+        state.serialize_field("name", &self.name())?;
+        state.end()
+    }
 }
 
 mod country {use super::*;
 
 pub fn index_of(fic:&Country) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Country>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Country>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -206,9 +317,25 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
 
+pub struct DebugRef(pub &'static Country);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match index_of(self.0) {
+            0 => f.write_str("Countries::Uk"),
+            1 => f.write_str("Countries::It"),
+            i => write!(f, "Country[{i}]"),
+        }
+    }
+}
 const fn r(code:&'static str, name:&'static str, ) -> Country {
     Country{code_:code, name_:name, }
 }
@@ -4,6 +4,8 @@ use crate::insrcdata::{Countries, Country, Minister, Ministers};
 #[allow(unused_variables)]
 mod insrcdata;
 mod opendataset;
+#[allow(dead_code)]
+mod serdekey;
 
 // overview of insrcdata
 
@@ -26,6 +28,16 @@ fn main() {
         println!("{}", minister.name());
     }
 
+    // serialize rows with serde
+    println!("{}", serde_json::to_string(g_brown).unwrap());
+    let label: Ministers = serde_json::from_str("\"GordonBrown\"").unwrap();
+    assert!(<&Minister>::from(label) == g_brown);
+
+    // in key mode, a join without key column is serialized by the label of the joined row
+    let romano: &serdekey::Minister = serdekey::Ministers::RomanoProdi.into();
+    let json = serde_json::to_string(romano).unwrap();
+    assert!(json == r#"{"name":"Romano Prodi","country":"It"}"#);
+
     // sample of implementation for open dataset model
     opendataset::inapp::sample();
     opendataset::inlib::sample();
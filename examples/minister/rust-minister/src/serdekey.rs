@@ -0,0 +1,214 @@
+// generated by insrcdata version 0.3.0
+
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Ministers {
+    DavidCameron = 0,
+    GordonBrown = 1,
+    RomanoProdi = 2,
+}
+impl From<Ministers> for  &'static Minister{
+    fn from(value:Ministers) -> Self {
+        &minister::TABLE[value as usize]
+    }
+}
+impl From<&Ministers> for  &'static Minister{
+    fn from(value: &Ministers) -> Self {
+        &minister::TABLE[*value as usize]
+    }
+}
+impl PartialEq<Ministers> for &Minister {
+    fn eq(&self, other: &Ministers) -> bool {
+        core::ptr::eq(<&Minister>::from(other), *self)
+    }
+}
+
+pub struct Minister {
+    name_ : &'static str,
+    country_ : u8,
+}
+impl PartialEq<Self> for Minister {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+impl Eq for Minister {}
+impl core::hash::Hash for Minister {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        minister::index_of(self).hash(state);
+    }
+}
+
+impl Minister {
+    pub const fn name(&self) -> &'static str { self.name_ }
+    pub fn country(&self) -> &'static Country { &country::TABLE[self.country_ as usize]}
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["name", "country"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("name", FieldValue::Str(self.name())),
+            ("country", FieldValue::Join("Country", country::index_of(self.country()))),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 3 }
+}
+
+impl core::fmt::Debug for Minister {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Minister")
+            .field("name", &self.name())
+            .field("country", &country::DebugRef(self.country()))
+            .finish()
+    }
+}
+
+impl serde::Serialize for Minister {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Minister", 2)?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("country", &country::SerdeRef(self.country()))?;
+        state.end()
+    }
+}
+
+mod minister {use super::*;
+
+pub fn index_of(fic:&Minister) -> usize {
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Minister>()
+}
+const fn r(name:&'static str, country:u8, ) -> Minister {
+    Minister{name_:name, country_:country, }
+}
+
+pub static TABLE : [ Minister ; 3 ] = [
+   {r("David Cameron", 0, )},
+   {r("Gordon Brown", 0, )},
+   {r("Romano Prodi", 1, )},
+];
+
+} // mod minister
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Countries {
+    Uk = 0,
+    It = 1,
+}
+impl From<Countries> for  &'static Country{
+    fn from(value:Countries) -> Self {
+        &country::TABLE[value as usize]
+    }
+}
+impl From<&Countries> for  &'static Country{
+    fn from(value: &Countries) -> Self {
+        &country::TABLE[*value as usize]
+    }
+}
+impl PartialEq<Countries> for &Country {
+    fn eq(&self, other: &Countries) -> bool {
+        core::ptr::eq(<&Country>::from(other), *self)
+    }
+}
+
+pub struct Country {
+    name_ : &'static str,
+}
+impl PartialEq<Self> for Country {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+impl Eq for Country {}
+impl core::hash::Hash for Country {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        country::index_of(self).hash(state);
+    }
+}
+
+impl Country {
+    pub const fn name(&self) -> &'static str { self.name_ }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 1] = ["name"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 1] {
+        [
+            ("name", FieldValue::Str(self.name())),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 2 }
+}
+
+impl core::fmt::Debug for Country {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Country")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+impl serde::Serialize for Country {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Country", 1)?;
+        state.serialize_field("name", &self.name())?;
+        state.end()
+    }
+}
+
+mod country {use super::*;
+
+pub fn index_of(fic:&Country) -> usize {
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Country>()
+}
+pub struct DebugRef(pub &'static Country);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match index_of(self.0) {
+            0 => f.write_str("Countries::Uk"),
+            1 => f.write_str("Countries::It"),
+            i => write!(f, "Country[{i}]"),
+        }
+    }
+}
+pub struct SerdeRef(pub &'static Country);
+impl serde::Serialize for SerdeRef {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match index_of(self.0) {
+            0 => serde::Serialize::serialize(&Countries::Uk, serializer),
+            1 => serde::Serialize::serialize(&Countries::It, serializer),
+            i => serializer.serialize_u64(i as u64),
+        }
+    }
+}
+const fn r(name:&'static str, ) -> Country {
+    Country{name_:name, }
+}
+
+pub static TABLE : [ Country ; 2 ] = [
+   {r("United Kingdom", )},
+   {r("Italy", )},
+];
+
+} // mod country
+
@@ -72,6 +72,11 @@ minister_iter_t  minister_birth_range( uint16_t start, uint16_t stop) {
     minister_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  minister_birth_count( uint16_t start, uint16_t stop) {
+    minister_iter_t iter = minister_birth_range(start, stop);
+    return iter.end - iter.ptr;
+}
 const country_t* minister_country(const minister_t* s) { return &COUNTRY_TABLE[s->country_];}
 minister_iter_t country_ministers(const country_t* s) {
     long cons = s - COUNTRY_TABLE;
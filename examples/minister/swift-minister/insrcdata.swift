@@ -11,6 +11,7 @@ public struct Minister : Hashable {
         cstruct = minister_from_ministers(ref)
     }
     var ministers: ministers_t { minister_ministers(cstruct) }
+    static var len : Int { Int(minister_len()) }
     var name: String { String(cString:  minister_name(cstruct)) }
     var birth: UInt16 {  minister_birth(cstruct) }
     static func birthRange(start:UInt16, stop:UInt16) -> minister_iter_t {
@@ -41,6 +42,7 @@ public struct Country : Hashable {
         cstruct = country_from_countries(ref)
     }
     var countries: countries_t { country_countries(cstruct) }
+    static var len : Int { Int(country_len()) }
     var code: String { String(cString:  country_code(cstruct)) }
     var name: String { String(cString:  country_name(cstruct)) }
     var ministers:minister_iter_t { country_ministers(cstruct) }
@@ -0,0 +1,148 @@
+// generated by insrcdata version 0.3.0
+
+#[allow(unused_imports)]
+use super::*;
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Clients {
+    John = 0,
+    Alix = 1,
+    David = 2,
+}
+impl From<Clients> for  &'static Client{
+    fn from(value:Clients) -> Self {
+        &client::TABLE[value as usize]
+    }
+}
+impl From<&Clients> for  &'static Client{
+    fn from(value: &Clients) -> Self {
+        &client::TABLE[*value as usize]
+    }
+}
+impl PartialEq<Clients> for &Client {
+    fn eq(&self, other: &Clients) -> bool {
+        core::ptr::eq(<&Client>::from(other), *self)
+    }
+}
+
+pub struct Client {
+    pub(super) name_ : &'static str,
+}
+impl PartialEq<Self> for Client {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+impl Eq for Client {}
+impl core::hash::Hash for Client {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        client::index_of(self).hash(state);
+    }
+}
+
+impl Client {
+    pub const fn name(&self) -> &'static str { self.name_ }
+    pub fn transactions(&self) -> TransactionIter {
+        let cons = client::index_of(self) as u8;
+
+        // bissect left
+        let mut lo = 0;
+        let mut hi = transaction::CLIENT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if cons > transaction::TABLE[transaction::CLIENT_INDEX[mid] as usize].client_ {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        let start = lo;
+
+        // bissect-right
+        hi = transaction::CLIENT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if cons < transaction::TABLE[transaction::CLIENT_INDEX[mid] as usize].client_  {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+
+        transaction::IndexIter {
+            indexes: transaction::CLIENT_INDEX[start..lo].iter(),
+        }
+    }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 1] = ["name"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 1] {
+        [
+            ("name", FieldValue::Str(self.name())),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 3 }
+}
+
+impl core::fmt::Debug for Client {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Client")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+pub(super) mod client {use super::*;
+
+pub fn index_of(fic:&Client) -> usize {
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Client>()
+}
+pub struct IndexIter {
+    pub indexes : core::slice::Iter<'static, u8>,
+}
+
+impl Iterator for IndexIter {
+    type Item = & 'static Client;
+
+    fn next(&mut self) -> Option<&'static Client> {
+        let idx = self.indexes.next();
+        match idx {
+            Some(v) => Some(&TABLE[*v as usize]),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IndexIter {}
+
+
+pub struct DebugRef(pub &'static Client);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match index_of(self.0) {
+            0 => f.write_str("Clients::John"),
+            1 => f.write_str("Clients::Alix"),
+            2 => f.write_str("Clients::David"),
+            i => write!(f, "Client[{i}]"),
+        }
+    }
+}
+const fn r(name:&'static str, ) -> Client {
+    Client{name_:name, }
+}
+
+pub static TABLE : [ Client ; 3 ] = [
+   {r("John", )},
+   {r("Alix", )},
+   {r("David", )},
+];
+
+} // mod client
+
+pub use client::IndexIter as ClientIter;
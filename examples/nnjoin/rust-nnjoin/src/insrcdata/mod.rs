@@ -0,0 +1,25 @@
+// generated by insrcdata version 0.3.0
+
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
+mod client_table;
+pub use client_table::*;
+mod product_table;
+pub use product_table::*;
+mod transaction_table;
+pub use transaction_table::*;
@@ -0,0 +1,151 @@
+// generated by insrcdata version 0.3.0
+
+#[allow(unused_imports)]
+use super::*;
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Products {
+    Apple = 0,
+    Banana = 1,
+    Peach = 2,
+    Cherry = 3,
+}
+impl From<Products> for  &'static Product{
+    fn from(value:Products) -> Self {
+        &product::TABLE[value as usize]
+    }
+}
+impl From<&Products> for  &'static Product{
+    fn from(value: &Products) -> Self {
+        &product::TABLE[*value as usize]
+    }
+}
+impl PartialEq<Products> for &Product {
+    fn eq(&self, other: &Products) -> bool {
+        core::ptr::eq(<&Product>::from(other), *self)
+    }
+}
+
+pub struct Product {
+    pub(super) name_ : &'static str,
+}
+impl PartialEq<Self> for Product {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+impl Eq for Product {}
+impl core::hash::Hash for Product {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        product::index_of(self).hash(state);
+    }
+}
+
+impl Product {
+    pub const fn name(&self) -> &'static str { self.name_ }
+    pub fn transactions(&self) -> TransactionIter {
+        let cons = product::index_of(self) as u8;
+
+        // bissect left
+        let mut lo = 0;
+        let mut hi = transaction::PRODUCT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if cons > transaction::TABLE[transaction::PRODUCT_INDEX[mid] as usize].product_ {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        let start = lo;
+
+        // bissect-right
+        hi = transaction::PRODUCT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if cons < transaction::TABLE[transaction::PRODUCT_INDEX[mid] as usize].product_  {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+
+        transaction::IndexIter {
+            indexes: transaction::PRODUCT_INDEX[start..lo].iter(),
+        }
+    }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 1] = ["name"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 1] {
+        [
+            ("name", FieldValue::Str(self.name())),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 4 }
+}
+
+impl core::fmt::Debug for Product {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Product")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+pub(super) mod product {use super::*;
+
+pub fn index_of(fic:&Product) -> usize {
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Product>()
+}
+pub struct IndexIter {
+    pub indexes : core::slice::Iter<'static, u8>,
+}
+
+impl Iterator for IndexIter {
+    type Item = & 'static Product;
+
+    fn next(&mut self) -> Option<&'static Product> {
+        let idx = self.indexes.next();
+        match idx {
+            Some(v) => Some(&TABLE[*v as usize]),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IndexIter {}
+
+
+pub struct DebugRef(pub &'static Product);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match index_of(self.0) {
+            0 => f.write_str("Products::Apple"),
+            1 => f.write_str("Products::Banana"),
+            2 => f.write_str("Products::Peach"),
+            3 => f.write_str("Products::Cherry"),
+            i => write!(f, "Product[{i}]"),
+        }
+    }
+}
+const fn r(name:&'static str, ) -> Product {
+    Product{name_:name, }
+}
+
+pub static TABLE : [ Product ; 4 ] = [
+   {r("Apple", )},
+   {r("Banana", )},
+   {r("Peach", )},
+   {r("Cherry", )},
+];
+
+} // mod product
+
+pub use product::IndexIter as ProductIter;
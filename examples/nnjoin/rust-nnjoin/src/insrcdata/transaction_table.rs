@@ -0,0 +1,97 @@
+// generated by insrcdata version 0.3.0
+
+#[allow(unused_imports)]
+use super::*;
+
+pub struct Transaction {
+    pub(super) client_ : u8,
+    pub(super) product_ : u8,
+}
+impl PartialEq<Self> for Transaction {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+impl Eq for Transaction {}
+impl core::hash::Hash for Transaction {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        transaction::index_of(self).hash(state);
+    }
+}
+
+impl Transaction {
+    pub fn client(&self) -> &'static Client { &client::TABLE[self.client_ as usize]}
+    pub fn product(&self) -> &'static Product { &product::TABLE[self.product_ as usize]}
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["client", "product"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("client", FieldValue::Join("Client", client::index_of(self.client()))),
+            ("product", FieldValue::Join("Product", product::index_of(self.product()))),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 7 }
+}
+
+impl core::fmt::Debug for Transaction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("client", &client::DebugRef(self.client()))
+            .field("product", &product::DebugRef(self.product()))
+            .finish()
+    }
+}
+
+pub(super) mod transaction {use super::*;
+
+pub fn index_of(fic:&Transaction) -> usize {
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Transaction>()
+}
+pub struct IndexIter {
+    pub indexes : core::slice::Iter<'static, u8>,
+}
+
+impl Iterator for IndexIter {
+    type Item = & 'static Transaction;
+
+    fn next(&mut self) -> Option<&'static Transaction> {
+        let idx = self.indexes.next();
+        match idx {
+            Some(v) => Some(&TABLE[*v as usize]),
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IndexIter {}
+
+
+const fn r(client:u8, product:u8, ) -> Transaction {
+    Transaction{client_:client, product_:product, }
+}
+
+pub static TABLE : [ Transaction ; 7 ] = [
+   {r(0, 0, )},
+   {r(0, 1, )},
+   {r(1, 0, )},
+   {r(1, 2, )},
+   {r(2, 0, )},
+   {r(2, 1, )},
+   {r(2, 2, )},
+];
+pub static CLIENT_INDEX : [ u8 ; 7 ] = [
+    0, 1, 2, 3, 4, 5, 6, 
+];
+pub static PRODUCT_INDEX : [ u8 ; 7 ] = [
+    0, 2, 4, 1, 5, 3, 6, 
+];
+
+} // mod transaction
+
+pub use transaction::IndexIter as TransactionIter;
@@ -36,6 +36,8 @@ typedef enum {
 const client_t* client_from_clients(clients_t label);
 clients_t client_clients(const client_t *s);
             
+// Number of rows in the table
+static inline size_t client_len(void) { return 3; }
 static inline const char* client_name(const client_t* s) { return s->name_; }
 extern transaction_iter_t client_transactions(const client_t* s);
 
@@ -50,11 +52,15 @@ typedef enum {
 const product_t* product_from_products(products_t label);
 products_t product_products(const product_t *s);
             
+// Number of rows in the table
+static inline size_t product_len(void) { return 4; }
 static inline const char* product_name(const product_t* s) { return s->name_; }
 extern transaction_iter_t product_transactions(const product_t* s);
 
 
 // ------    
+// Number of rows in the table
+static inline size_t transaction_len(void) { return 7; }
 extern const client_t* transaction_client(const transaction_t* s);
 extern const product_t* transaction_product(const transaction_t* s);
 
@@ -11,6 +11,7 @@ public struct Client : Hashable {
         cstruct = client_from_clients(ref)
     }
     var clients: clients_t { client_clients(cstruct) }
+    static var len : Int { Int(client_len()) }
     var name: String { String(cString:  client_name(cstruct)) }
     var transactions:transaction_iter_t { client_transactions(cstruct) }
 
@@ -37,6 +38,7 @@ public struct Product : Hashable {
         cstruct = product_from_products(ref)
     }
     var products: products_t { product_products(cstruct) }
+    static var len : Int { Int(product_len()) }
     var name: String { String(cString:  product_name(cstruct)) }
     var transactions:transaction_iter_t { product_transactions(cstruct) }
 
@@ -56,6 +58,7 @@ extension product_iter_t : Sequence, IteratorProtocol {
 public struct Transaction : Hashable {
         var cstruct : UnsafePointer<transaction_t>
     
+    static var len : Int { Int(transaction_len()) }
     var client : Client { Client(cstruct:transaction_client(cstruct)) }
     var product : Product { Product(cstruct:transaction_product(cstruct)) }
 
@@ -1,5 +1,22 @@
 // generated by insrcdata version 0.3.0
 
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Copy, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Adhocs {
@@ -18,7 +35,7 @@ impl From<&Adhocs> for  &'static Adhoc{
 }
 impl PartialEq<Adhocs> for &Adhoc {
     fn eq(&self, other: &Adhocs) -> bool {
-        std::ptr::eq(<&Adhoc>::from(other), *self)
+        core::ptr::eq(<&Adhoc>::from(other), *self)
     }
 }
 
@@ -28,25 +45,45 @@ pub struct Adhoc {
 }
 impl PartialEq<Self> for Adhoc {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Adhoc {}
-impl std::hash::Hash for Adhoc {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Adhoc {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         adhoc::index_of(self).hash(state);
     }
 }
 
 impl Adhoc {
-    pub fn score_data(&self) -> f32 { self.score_data_ }
-    pub fn count_data(&self) -> u16 { self.count_data_ as u16 }
+    pub const fn score_data(&self) -> f32 { self.score_data_ }
+    pub const fn count_data(&self) -> u16 { self.count_data_ as u16 }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["score_data", "count_data"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("score_data", FieldValue::Float(self.score_data() as f64)),
+            ("count_data", FieldValue::Uint(self.count_data() as u64)),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 2 }
+}
+
+impl core::fmt::Debug for Adhoc {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Adhoc")
+            .field("score_data", &self.score_data())
+            .field("count_data", &self.count_data())
+            .finish()
+    }
 }
 
 mod adhoc {use super::*;
 
 pub fn index_of(fic:&Adhoc) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Adhoc>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Adhoc>()
 }
 const fn r(score_data:f32, count_data:u8, ) -> Adhoc {
     Adhoc{score_data_:score_data, count_data_:count_data, }
@@ -64,24 +101,48 @@ pub struct Score {
 }
 impl PartialEq<Self> for Score {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Score {}
-impl std::hash::Hash for Score {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Score {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         score::index_of(self).hash(state);
     }
 }
 
 impl Score {
-    pub fn value(&self) -> f32 { self.value_ }
+    pub const fn value(&self) -> f32 { self.value_ }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 1] = ["value"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 1] {
+        [
+            ("value", FieldValue::Float(self.value() as f64)),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 1 }
+}
+
+impl core::fmt::Debug for Score {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Score")
+            .field("value", &self.value())
+            .finish()
+    }
 }
 
 mod score {use super::*;
 
 pub fn index_of(fic:&Score) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Score>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Score>()
+}
+pub struct DebugRef(pub &'static Score);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Score[{}]", index_of(self.0))
+    }
 }
 const fn r(value:f32, ) -> Score {
     Score{value_:value, }
@@ -98,24 +159,48 @@ pub struct Count {
 }
 impl PartialEq<Self> for Count {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Count {}
-impl std::hash::Hash for Count {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Count {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         count::index_of(self).hash(state);
     }
 }
 
 impl Count {
-    pub fn value(&self) -> u16 { self.value_ as u16 }
+    pub const fn value(&self) -> u16 { self.value_ as u16 }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 1] = ["value"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 1] {
+        [
+            ("value", FieldValue::Uint(self.value() as u64)),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 1 }
+}
+
+impl core::fmt::Debug for Count {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Count")
+            .field("value", &self.value())
+            .finish()
+    }
 }
 
 mod count {use super::*;
 
 pub fn index_of(fic:&Count) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Count>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Count>()
+}
+pub struct DebugRef(pub &'static Count);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Count[{}]", index_of(self.0))
+    }
 }
 const fn r(value:u8, ) -> Count {
     Count{value_:value, }
@@ -145,7 +230,7 @@ impl From<&Optjoins> for  &'static Optjoin{
 }
 impl PartialEq<Optjoins> for &Optjoin {
     fn eq(&self, other: &Optjoins) -> bool {
-        std::ptr::eq(<&Optjoin>::from(other), *self)
+        core::ptr::eq(<&Optjoin>::from(other), *self)
     }
 }
 
@@ -155,12 +240,12 @@ pub struct Optjoin {
 }
 impl PartialEq<Self> for Optjoin {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Optjoin {}
-impl std::hash::Hash for Optjoin {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Optjoin {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         optjoin::index_of(self).hash(state);
     }
 }
@@ -174,12 +259,32 @@ impl Optjoin {
         let index = self.count_join_;
         if index==0 { None } else { Some(&count::TABLE[index as usize -1]) }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["score_join", "count_join"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("score_join", match self.score_join() { Some(v) => FieldValue::Join("Score", score::index_of(v)), None => FieldValue::Null }),
+            ("count_join", match self.count_join() { Some(v) => FieldValue::Join("Count", count::index_of(v)), None => FieldValue::Null }),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 2 }
+}
+
+impl core::fmt::Debug for Optjoin {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Optjoin")
+            .field("score_join", &self.score_join().map(score::DebugRef))
+            .field("count_join", &self.count_join().map(count::DebugRef))
+            .finish()
+    }
 }
 
 mod optjoin {use super::*;
 
 pub fn index_of(fic:&Optjoin) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Optjoin>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Optjoin>()
 }
 const fn r(score_join:u8, count_join:u8, ) -> Optjoin {
     Optjoin{score_join_:score_join, count_join_:count_join, }
@@ -34,15 +34,21 @@ typedef enum {
 const adhoc_t* adhoc_from_adhocs(adhocs_t label);
 adhocs_t adhoc_adhocs(const adhoc_t *s);
             
+// Number of rows in the table
+static inline size_t adhoc_len(void) { return 2; }
 static inline float adhoc_score_data(const adhoc_t* s) { return s->score_data_; }
 static inline uint16_t adhoc_count_data(const adhoc_t* s) { return s->count_data_; }
 
 
 // ------    
+// Number of rows in the table
+static inline size_t score_len(void) { return 1; }
 static inline float score_value(const score_t* s) { return s->value_; }
 
 
 // ------    
+// Number of rows in the table
+static inline size_t count_len(void) { return 1; }
 static inline uint16_t count_value(const count_t* s) { return s->value_; }
 
 
@@ -54,6 +60,8 @@ typedef enum {
 const optjoin_t* optjoin_from_optjoins(optjoins_t label);
 optjoins_t optjoin_optjoins(const optjoin_t *s);
             
+// Number of rows in the table
+static inline size_t optjoin_len(void) { return 2; }
 extern bool optjoin_score_join(const optjoin_t* s, const score_t** ptr);
 extern bool optjoin_count_join(const optjoin_t* s, const count_t** ptr);
 
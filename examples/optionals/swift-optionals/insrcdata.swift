@@ -11,6 +11,7 @@ public struct Adhoc : Hashable {
         cstruct = adhoc_from_adhocs(ref)
     }
     var adhocs: adhocs_t { adhoc_adhocs(cstruct) }
+    static var len : Int { Int(adhoc_len()) }
     var score_data: Float32 {  adhoc_score_data(cstruct) }
     var count_data: UInt16 {  adhoc_count_data(cstruct) }
 
@@ -21,6 +22,7 @@ public struct Adhoc : Hashable {
 public struct Score : Hashable {
         var cstruct : UnsafePointer<score_t>
     
+    static var len : Int { Int(score_len()) }
     var value: Float32 {  score_value(cstruct) }
 
 } // struct Score
@@ -30,6 +32,7 @@ public struct Score : Hashable {
 public struct Count : Hashable {
         var cstruct : UnsafePointer<count_t>
     
+    static var len : Int { Int(count_len()) }
     var value: UInt16 {  count_value(cstruct) }
 
 } // struct Count
@@ -46,6 +49,7 @@ public struct Optjoin : Hashable {
         cstruct = optjoin_from_optjoins(ref)
     }
     var optjoins: optjoins_t { optjoin_optjoins(cstruct) }
+    static var len : Int { Int(optjoin_len()) }
     var score_join : Score? {
         var p = UnsafePointer<score_t>?(nil)
         if optjoin_score_join(cstruct, &p) { return Score(cstruct:p!) } else { return nil }
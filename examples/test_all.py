@@ -188,6 +188,210 @@ class sample_c(sample):
             assert r==0, f"failed cc build : {self.name}"
         
 
+class sample_cpp(sample):
+      LANG = "cpp"
+      
+      def dest(self):
+            return f"../cpp-{self.name}/insrcdata.hpp"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/target/{self.name}"
+
+      def build(self):
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+           
+            # build
+            r = os.system(f"cd {self.lang_path()}; c++ -std=c++17 main.cpp -o ./target/{self.name} ")
+            assert r==0, f"failed c++ build : {self.name}"
+        
+
+class sample_csharp(sample):
+      LANG = "csharp"
+      
+      def dest(self):
+            return f"../csharp-{self.name}/Insrcdata.cs"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/target/{self.name}.sh"
+
+      def build(self):
+            # build
+            r = os.system(f"cd {self.lang_path()}; dotnet build --nologo -v quiet -o target/bin")
+            assert r==0, f"failed dotnet build : {self.name}"
+            open(self.product_path(), "w").write(f"#!/bin/sh\nexec dotnet {os.path.abspath(self.lang_path())}/target/bin/{self.name}.dll\n")
+            os.chmod(self.product_path(), 0o755)
+        
+
+class sample_go(sample):
+      LANG = "go"
+      
+      def dest(self):
+            return f"../go-{self.name}/insrcdata/insrcdata.go"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/target/{self.name}"
+
+      def build(self):
+            # build
+            r = os.system(f"cd {self.lang_path()}; go vet ./... && go build -o ./target/{self.name} .")
+            assert r==0, f"failed go build : {self.name}"
+        
+
+class sample_java(sample):
+      LANG = "java"
+      
+      def dest(self):
+            return f"../java-{self.name}/src/main/java/{self.name}/Insrcdata.java"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/target/{self.name}.sh"
+
+      def build(self):
+            # build
+            r = os.system(f"cd {self.lang_path()}; javac -encoding UTF-8 -d target/classes $(find src -name '*.java')")
+            assert r==0, f"failed javac build : {self.name}"
+            open(self.product_path(), "w").write(f"#!/bin/sh\nexec java -cp {os.path.abspath(self.lang_path())}/target/classes {self.name}.Main\n")
+            os.chmod(self.product_path(), 0o755)
+        
+
+class sample_python(sample):
+      LANG = "python"
+      
+      def dest(self):
+            return f"../python-{self.name}/insrcdata.py"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/main.py"
+
+      def build(self):
+            # nothing to compile : main.py is run by the python interpreter
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+        
+
+class sample_json(sample):
+      LANG = "json"
+      
+      def dest(self):
+            return f"../json-{self.name}/insrcdata.json"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/main.py"
+
+      def build(self):
+            # nothing to compile : main.py read the json document
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+        
+
+class sample_sql(sample):
+      LANG = "sql"
+      
+      def dest(self):
+            return f"../sql-{self.name}/insrcdata.sql"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/main.py"
+
+      def build(self):
+            # nothing to compile : main.py load the script in an in-memory sqlite database
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+        
+
+class sample_js(sample):
+      LANG = "js"
+      
+      def dest(self):
+            return f"../js-{self.name}/insrcdata.js"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/target/{self.name}.sh"
+
+      def build(self):
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+            open(self.product_path(), "w").write(f"#!/bin/sh\nexec node {os.path.abspath(self.lang_path())}/main.js\n")
+            os.chmod(self.product_path(), 0o755)
+        
+
+class sample_ts(sample_js):
+      LANG = "ts"
+      
+      def dest(self):
+            return f"../ts-{self.name}/insrcdata.ts"
+
+      def build(self):
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+           
+            # build
+            r = os.system(f"cd {self.lang_path()}; npm install --silent && npx tsc")
+            assert r==0, f"failed tsc build : {self.name}"
+            open(self.product_path(), "w").write(f"#!/bin/sh\nexec node {os.path.abspath(self.lang_path())}/target/dist/main.js\n")
+            os.chmod(self.product_path(), 0o755)
+        
+
+class sample_zig(sample):
+      LANG = "zig"
+      
+      def dest(self):
+            return f"../zig-{self.name}/insrcdata.zig"
+    
+      def regress_path(self):
+            return f"{self.lang_path()}/target/regression"
+
+      def product_path(self):
+            return f"{self.lang_path()}/target/{self.name}"
+
+      def build(self):
+            try:
+                  os.mkdir(f"{self.lang_path()}/target")
+            except FileExistsError:
+                  pass
+           
+            # build
+            r = os.system(f"cd {self.lang_path()}; zig build-exe main.zig -femit-bin=target/{self.name}")
+            assert r==0, f"failed zig build : {self.name}"
+        
+
 def test_sample(name):
       r = sample_rust(name)
       r.test()
@@ -195,6 +399,36 @@ def test_sample(name):
       w.test()
       c = sample_c(name)
       c.test()
+      if os.path.exists(sample_cpp(name).lang_path()):
+            p = sample_cpp(name)
+            p.test()
+      if os.path.exists(sample_csharp(name).lang_path()):
+            s = sample_csharp(name)
+            s.test()
+      if os.path.exists(sample_go(name).lang_path()):
+            g = sample_go(name)
+            g.test()
+      if os.path.exists(sample_java(name).lang_path()):
+            v = sample_java(name)
+            v.test()
+      if os.path.exists(sample_python(name).lang_path()):
+            y = sample_python(name)
+            y.test()
+      if os.path.exists(sample_json(name).lang_path()):
+            n = sample_json(name)
+            n.test()
+      if os.path.exists(sample_sql(name).lang_path()):
+            q = sample_sql(name)
+            q.test()
+      if os.path.exists(sample_js(name).lang_path()):
+            j = sample_js(name)
+            j.test()
+      if os.path.exists(sample_ts(name).lang_path()):
+            t = sample_ts(name)
+            t.test()
+      if os.path.exists(sample_zig(name).lang_path()):
+            z = sample_zig(name)
+            z.test()
 
 def test_examples():
       sample_path = f"{PATH}/examples"
@@ -9,17 +9,23 @@
 
 typedef struct  {
     const char* name_;
-    bool woman_;
     double score_;
-    uint8_t spouse_;
-    uint8_t father_;
-    uint8_t mother_;
+    uint8_t packed_[1];
 } person_t;
 typedef struct { uint8_t* ptr; uint8_t* end; } person_iter_t;
 extern const person_t* person_next(person_iter_t* idx);
+typedef struct { const uint8_t* bound; const uint8_t* end; uint8_t* index; } person_groups_t;
+typedef struct { const person_t* row; unsigned depth; } person_ancestors_t;
+typedef struct { uint64_t words[1]; } person_bitmap_t;
+typedef struct { person_bitmap_t bitmap; unsigned word; } person_bitmap_iter_t;
+extern person_bitmap_t person_bitmap_and(person_bitmap_t a, person_bitmap_t b);
+extern person_bitmap_t person_bitmap_or(person_bitmap_t a, person_bitmap_t b);
+extern size_t person_bitmap_count(person_bitmap_t bitmap);
+extern person_bitmap_iter_t person_bitmap_iter(person_bitmap_t bitmap);
+extern const person_t* person_bitmap_next(person_bitmap_iter_t* iter);
 
 typedef struct  {
-    const char* text_;
+    uint8_t text_;
 } strencoding_t;
 static unsigned const STRENCODING_TABLE_COUNT = 6;
 extern const strencoding_t STRENCODING_TABLE[STRENCODING_TABLE_COUNT];
@@ -27,7 +33,6 @@ typedef struct { uint8_t* ptr; uint8_t* end; } strencoding_iter_t;
 extern const strencoding_t* strencoding_next(strencoding_iter_t* idx);
 
 typedef struct  {
-    const char* name_;
     transformer_t* transformer_;
     const point_t* point_;
 } lettercase_t;
@@ -35,8 +40,7 @@ typedef struct { uint8_t* ptr; uint8_t* end; } lettercase_iter_t;
 extern const lettercase_t* lettercase_next(lettercase_iter_t* idx);
 
 typedef struct  {
-    uint32_t qid_;
-    uint8_t object_;
+    uint32_t packed_[1];
 } wikidata_t;
 static unsigned const WIKIDATA_TABLE_COUNT = 3;
 extern const wikidata_t WIKIDATA_TABLE[WIKIDATA_TABLE_COUNT];
@@ -57,8 +61,7 @@ typedef struct {
 } wikidata_object_t;
 
 typedef struct  {
-    const char* lccn_;
-    uint8_t object_;
+    uint8_t unused_;
 } congress_t;
 static unsigned const CONGRESS_TABLE_COUNT = 4;
 extern const congress_t CONGRESS_TABLE[CONGRESS_TABLE_COUNT];
@@ -91,20 +94,48 @@ typedef enum {
 const person_t* person_from_persons(persons_t label);
 persons_t person_persons(const person_t *s);
             
+// Number of rows in the table
+static inline size_t person_len(void) { return 4; }
 static inline const char* person_name(const person_t* s) { return s->name_; }
-static inline bool person_woman(const person_t* s) { return s->woman_; }
+static inline bool person_woman(const person_t* s) { return ((s->packed_[0] & 0x1ULL) != 0); }
+extern person_bitmap_t person_where_woman(bool value);
 static inline double person_score(const person_t* s) { return s->score_; }
 extern person_iter_t  person_score_range( double start, double stop);
+// Number of rows returned by person_score_range
+extern size_t  person_score_count( double start, double stop);
+extern person_groups_t  person_score_groups(void);
+// Next distinct value of score with its rows, return false after last group
+extern bool  person_score_groups_next(person_groups_t* groups, double* value, person_iter_t* rows);
+// min of score column values
+static double const PERSON_SCORE_MIN = 1.0;
+// max of score column values
+static double const PERSON_SCORE_MAX = 3.2;
+// sum of score column values
+static double const PERSON_SCORE_SUM = 8.4;
+// mean of score column values
+static double const PERSON_SCORE_MEAN = 2.1;
 extern const person_t* person_spouse(const person_t* s);
 extern bool person_father(const person_t* s, const person_t** ptr);
+extern person_ancestors_t person_father_ancestors(const person_t* s);
+// Next ancestor with its distance, return NULL after the root
+extern const person_t* person_father_ancestors_next(person_ancestors_t* iter, unsigned* depth);
+// Check if s is reached by following father links from other row
+extern bool person_father_is_ancestor_of(const person_t* s, const person_t* other);
 extern bool person_mother(const person_t* s, const person_t** ptr);
 extern wikidata_iter_t person_wdata(const person_t* s);
 extern congress_iter_t person_congress(const person_t* s);
 
 
 // ------    
-static inline const char* strencoding_text(const strencoding_t* s) { return s->text_; }
+// Number of rows in the table
+static inline size_t strencoding_len(void) { return 6; }
+// Length of the longest text value, buffers of STRENCODING_TEXT_MAX_LEN+1 bytes hold any value
+static unsigned const STRENCODING_TEXT_MAX_LEN = 72;
+// Decode text in buf of size bytes, truncated if buf is too small
+extern const char* strencoding_text_decode(const strencoding_t* s, char* buf, size_t size);
 extern strencoding_iter_t  strencoding_text_range( const char* start, const char* stop);
+// Number of rows returned by strencoding_text_range
+extern size_t  strencoding_text_count( const char* start, const char* stop);
 
 
 // ------    
@@ -116,7 +147,9 @@ typedef enum {
 const lettercase_t* lettercase_from_lettercases(lettercases_t label);
 lettercases_t lettercase_lettercases(const lettercase_t *s);
             
-static inline const char* lettercase_name(const lettercase_t* s) { return s->name_; }
+// Number of rows in the table
+static inline size_t lettercase_len(void) { return 3; }
+extern const char* lettercase_name(const lettercase_t* s);
 static inline transformer_t* lettercase_transformer(const lettercase_t* s) { return s->transformer_; }
 static inline const point_t* lettercase_point(const lettercase_t* s) { return s->point_; }
 extern wikidata_iter_t lettercase_wdata2(const lettercase_t* s);
@@ -124,12 +157,19 @@ extern congress_iter_t lettercase_congress(const lettercase_t* s);
 
 
 // ------    
-static inline uint32_t wikidata_qid(const wikidata_t* s) { return s->qid_; }
+// Number of rows in the table
+static inline size_t wikidata_len(void) { return 3; }
+static inline uint32_t wikidata_qid(const wikidata_t* s) { return ((uint32_t)((s->packed_[0] & 0x7fffffULL) + 7186ULL)); }
 extern wikidata_object_t wikidata_object(const wikidata_t* s);
 
 
 // ------    
-static inline const char* congress_lccn(const congress_t* s) { return s->lccn_; }
+// Number of rows in the table
+static inline size_t congress_len(void) { return 4; }
+// Length of the longest lccn value, buffers of CONGRESS_LCCN_MAX_LEN+1 bytes hold any value
+static unsigned const CONGRESS_LCCN_MAX_LEN = 11;
+// Decode lccn in buf of size bytes, truncated if buf is too small
+extern const char* congress_lccn_decode(const congress_t* s, char* buf, size_t size);
 extern congress_object_t congress_object(const congress_t* s);
 
 #endif //  INSRCDATA_H 
@@ -34,6 +34,16 @@ void  test_innerjoin(void) {
       
       assert(person_mother(irene, &parent) && parent==marie);
       assert(person_father(irene, &parent) && parent==pierre);
+      
+      // closure of self join
+      unsigned depth;
+      person_ancestors_t ancestors = person_father_ancestors(irene);
+      assert(person_father_ancestors_next(&ancestors, &depth)==pierre && depth==1);
+      assert(person_father_ancestors_next(&ancestors, &depth)==NULL);
+      assert(person_father_ancestors_next(&ancestors, &depth)==NULL);
+      assert(person_father_is_ancestor_of(pierre, irene));
+      assert(!person_father_is_ancestor_of(irene, pierre));
+      assert(!person_father_is_ancestor_of(marie, irene));
 }
 
 void test_bool(void) {
@@ -52,6 +62,19 @@ void test_bool(void) {
       assert( person_next(&iter)==person_from_persons(PERSONS_IRENE) );
       assert( person_next(&iter)==NULL );
        */
+
+      // test bitmap index
+      person_bitmap_iter_t biter = person_bitmap_iter(person_where_woman(true));
+      assert( person_bitmap_next(&biter)==person_from_persons(PERSONS_MARIE) );
+      assert( person_bitmap_next(&biter)==person_from_persons(PERSONS_IRENE) );
+      assert( person_bitmap_next(&biter)==NULL );
+      assert( person_bitmap_count(person_where_woman(false))==2 );
+
+      // combine bitmaps
+      person_bitmap_t womens = person_where_woman(true);
+      person_bitmap_t mens = person_where_woman(false);
+      assert( person_bitmap_count(person_bitmap_and(womens, mens))==0 );
+      assert( person_bitmap_count(person_bitmap_or(womens, mens))==4 );
 }
 
 void test_float(void) {
@@ -84,6 +107,30 @@ void test_float(void) {
       iter = person_score_range(0.0, 1.0);
       assert( person_next(&iter)==person_from_persons(PERSONS_MARIE)  );
       assert( person_next(&iter)==NULL );
+
+      // count without iteration
+      assert( person_score_count(2.1, 3.2)==3 );
+      assert( person_score_count(2.0, 1.9)==0 );
+      assert( person_len()==4 );
+
+      // aggregates
+      assert( PERSON_SCORE_MIN==1.0 );
+      assert( PERSON_SCORE_MAX==3.2 );
+      assert( PERSON_SCORE_MEAN==PERSON_SCORE_SUM/4 );
+
+      // groups
+      person_groups_t groups = person_score_groups();
+      double score;
+      assert( person_score_groups_next(&groups, &score, &iter) && score==1.0 );
+      assert( person_next(&iter)==person_from_persons(PERSONS_MARIE) );
+      assert( person_next(&iter)==NULL );
+      assert( person_score_groups_next(&groups, &score, &iter) && score==2.1 );
+      assert( person_score_count(2.1, 2.1)==2 );
+      assert( person_score(person_next(&iter))==2.1 );
+      assert( person_score(person_next(&iter))==2.1 );
+      assert( person_next(&iter)==NULL );
+      assert( person_score_groups_next(&groups, &score, &iter) && score==3.2 );
+      assert( !person_score_groups_next(&groups, &score, &iter) );
       
       // over
       iter = person_score_range(10.0, 9000.0);
@@ -128,7 +175,8 @@ void  test_strencoding(void) {
             strencoding_iter_t iter = strencoding_text_range(*refstr, *refstr);
             const strencoding_t* row = strencoding_next(&iter);
             assert(row!=NULL);
-            const char* text = strencoding_text(row);
+            char buf[STRENCODING_TEXT_MAX_LEN+1];
+            const char* text = strencoding_text_decode(row, buf, sizeof(buf));
             assert( strcmp(text, *refstr)==0 );
             assert(strencoding_next(&iter)==NULL);
     }
@@ -217,6 +265,17 @@ void test_variant_optional(void) {
       assert(obj_france.type==CONGRESS_NONE);
 }
 
+void test_compression(void) {
+      // compressed values are decoded in a caller supplied buffer
+      const congress_t * q_marie = &CONGRESS_TABLE[0];
+      char buf[CONGRESS_LCCN_MAX_LEN+1];
+      assert(strcmp(congress_lccn_decode(q_marie, buf, sizeof(buf)), "n2009011553")==0);
+
+      // too small buffer truncate the value
+      char small[5];
+      assert(strcmp(congress_lccn_decode(q_marie, small, sizeof(small)), "n200")==0);
+}
+
 int main(void) {
       // the join column reference a record in the same table
       test_innerjoin();
@@ -242,6 +301,9 @@ int main(void) {
       // variant with unmatched rows
       test_variant_optional();
       
+      // compressed str columns
+      test_compression();
+      
       return 0;
 }
 
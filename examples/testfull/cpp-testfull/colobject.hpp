@@ -0,0 +1,45 @@
+//
+//  colobject.hpp
+//  testfull
+//
+
+#ifndef colobject_hpp
+#define colobject_hpp
+
+#include <cctype>
+#include <string>
+
+namespace colobject {
+
+using Transformer = void (*)(std::string&);
+
+inline void make_capitalize(std::string& s) {
+    bool start = true;
+    for (char& c : s) {
+        c = start ? std::toupper(c) : std::tolower(c);
+        start = std::isspace(c);
+    }
+}
+
+inline void make_upper(std::string& s) {
+    for (char& c : s) {
+        c = std::toupper(c);
+    }
+}
+
+inline void make_lower(std::string& s) {
+    for (char& c : s) {
+        c = std::tolower(c);
+    }
+}
+
+struct Point {
+    float x;
+    float y;
+};
+inline constexpr Point POINT_ZERO{0.0, 0.0};
+inline constexpr Point POINT_ONE{1.0, 1.0};
+
+} // namespace colobject
+
+#endif /* colobject_hpp */
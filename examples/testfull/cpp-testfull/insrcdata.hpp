@@ -0,0 +1,347 @@
+// generated by insrcdata version 0.3.0
+
+#ifndef INSRCDATA_INSRCDATA_HPP
+#define INSRCDATA_INSRCDATA_HPP
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <functional>
+#include <iterator>
+#include <optional>
+#include <string_view>
+#include <variant>
+#include "colobject.hpp"
+
+namespace insrcdata {
+
+namespace detail {
+
+// rows of a table selected by a slice of index
+template <typename Row, typename Index>
+class RowRange {
+public:
+    class iterator {
+    public:
+        using iterator_category = std::forward_iterator_tag;
+        using value_type = Row;
+        using difference_type = std::ptrdiff_t;
+        using pointer = const Row*;
+        using reference = const Row&;
+
+        constexpr iterator(const Row* table, const Index* ptr) : table_(table), ptr_(ptr) {}
+        constexpr const Row& operator*() const { return table_[*ptr_]; }
+        constexpr const Row* operator->() const { return &table_[*ptr_]; }
+        constexpr iterator& operator++() { ++ptr_; return *this; }
+        constexpr iterator operator++(int) { iterator it = *this; ++ptr_; return it; }
+        constexpr bool operator==(const iterator& other) const { return ptr_ == other.ptr_; }
+        constexpr bool operator!=(const iterator& other) const { return ptr_ != other.ptr_; }
+
+    private:
+        const Row* table_;
+        const Index* ptr_;
+    };
+
+    constexpr RowRange(const Row* table, const Index* first, const Index* last)
+        : table_(table), first_(first), last_(last) {}
+    constexpr iterator begin() const { return iterator(table_, first_); }
+    constexpr iterator end() const { return iterator(table_, last_); }
+    constexpr std::size_t size() const { return static_cast<std::size_t>(last_ - first_); }
+    constexpr bool empty() const { return first_ == last_; }
+
+private:
+    const Row* table_;
+    const Index* first_;
+    const Index* last_;
+};
+
+// rows with proj(row) in [start..=stop], index must be sorted by proj(row)
+template <typename Row, std::size_t M, typename Index, std::size_t N, typename Key, typename Proj>
+constexpr RowRange<Row, Index> bisect(const std::array<Row, M>& table, const std::array<Index, N>& index,
+                                      Key start, Key stop, Proj proj) {
+    const Index* lo = index.data();
+    const Index* hi = index.data() + N;
+    while (lo < hi) {
+        const Index* mid = lo + (hi - lo) / 2;
+        if (proj(table[*mid]) < start) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    const Index* begin = lo;
+    hi = index.data() + N;
+    while (lo < hi) {
+        const Index* mid = lo + (hi - lo) / 2;
+        if (stop < proj(table[*mid])) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    return RowRange<Row, Index>(table.data(), begin, lo);
+}
+
+} // namespace detail
+
+enum class Persons {
+    Marie = 0,
+    Pierre = 1,
+    Irene = 2,
+    Frederic = 3,
+};
+
+enum class Lettercases {
+    Capital = 0,
+    Upper = 1,
+    Lower = 2,
+};
+
+struct Person;
+struct Strencoding;
+struct Lettercase;
+struct Wikidata;
+struct Congress;
+using PersonRange = detail::RowRange<Person, uint8_t>;
+using StrencodingRange = detail::RowRange<Strencoding, uint8_t>;
+using LettercaseRange = detail::RowRange<Lettercase, uint8_t>;
+using WikidataRange = detail::RowRange<Wikidata, uint8_t>;
+using WikidataObject = std::variant<const Person*, const Lettercase*>;
+using CongressRange = detail::RowRange<Congress, uint8_t>;
+using CongressObject = std::variant<std::monostate, const Person*, const Lettercase*>;
+
+struct Person {
+    std::string_view name_;
+    bool woman_;
+    double score_;
+    uint8_t spouse_;
+    uint8_t father_;
+    uint8_t mother_;
+
+    constexpr std::string_view name() const { return name_; }
+    constexpr bool woman() const { return woman_; }
+    constexpr double score() const { return score_; }
+    static constexpr PersonRange score_range(double start, double stop);
+    /// Number of rows returned by score_range
+    static constexpr std::size_t score_count(double start, double stop) { return score_range(start, stop).size(); }
+    /// min of score column values
+    static constexpr double SCORE_MIN = 1.0;
+    /// max of score column values
+    static constexpr double SCORE_MAX = 3.2;
+    /// sum of score column values
+    static constexpr double SCORE_SUM = 8.4;
+    /// mean of score column values
+    static constexpr double SCORE_MEAN = 2.1;
+    constexpr const Person& spouse() const;
+    std::optional<std::reference_wrapper<const Person>> father() const;
+    std::optional<std::reference_wrapper<const Person>> mother() const;
+    constexpr WikidataRange wdata() const;
+    constexpr CongressRange congress() const;
+    /// Row corresponding to label
+    static constexpr const Person& from(Persons label);
+    /// Label of the current row
+    constexpr Persons persons() const;
+    /// Number of rows in the table
+    static constexpr std::size_t len() { return 4; }
+    /// Index of the current record in the table
+    constexpr std::size_t as_index() const;
+
+    constexpr bool operator==(const Person& other) const { return this == &other; }
+    constexpr bool operator!=(const Person& other) const { return this != &other; }
+};
+
+struct Strencoding {
+    std::string_view text_;
+
+    constexpr std::string_view text() const { return text_; }
+    static constexpr StrencodingRange text_range(std::string_view start, std::string_view stop);
+    /// Number of rows returned by text_range
+    static constexpr std::size_t text_count(std::string_view start, std::string_view stop) { return text_range(start, stop).size(); }
+    /// Number of rows in the table
+    static constexpr std::size_t len() { return 6; }
+    /// Index of the current record in the table
+    constexpr std::size_t as_index() const;
+    /// Reference to the table containing all the values
+    static constexpr const std::array<Strencoding, 6>& array();
+
+    constexpr bool operator==(const Strencoding& other) const { return this == &other; }
+    constexpr bool operator!=(const Strencoding& other) const { return this != &other; }
+};
+
+struct Lettercase {
+    std::string_view name_;
+    colobject::Transformer transformer_;
+    const colobject::Point* point_;
+
+    constexpr std::string_view name() const { return name_; }
+    constexpr colobject::Transformer transformer() const { return transformer_; }
+    constexpr const colobject::Point* point() const { return point_; }
+    constexpr WikidataRange wdata2() const;
+    constexpr CongressRange congress() const;
+    /// Row corresponding to label
+    static constexpr const Lettercase& from(Lettercases label);
+    /// Label of the current row
+    constexpr Lettercases lettercases() const;
+    /// Number of rows in the table
+    static constexpr std::size_t len() { return 3; }
+    /// Index of the current record in the table
+    constexpr std::size_t as_index() const;
+
+    constexpr bool operator==(const Lettercase& other) const { return this == &other; }
+    constexpr bool operator!=(const Lettercase& other) const { return this != &other; }
+};
+
+struct Wikidata {
+    uint32_t qid_;
+    uint8_t object_;
+
+    constexpr uint32_t qid() const { return qid_; }
+    constexpr WikidataObject object() const;
+    /// Number of rows in the table
+    static constexpr std::size_t len() { return 3; }
+    /// Index of the current record in the table
+    constexpr std::size_t as_index() const;
+    /// Reference to the table containing all the values
+    static constexpr const std::array<Wikidata, 3>& array();
+
+    constexpr bool operator==(const Wikidata& other) const { return this == &other; }
+    constexpr bool operator!=(const Wikidata& other) const { return this != &other; }
+};
+
+struct Congress {
+    std::string_view lccn_;
+    uint8_t object_;
+
+    constexpr std::string_view lccn() const { return lccn_; }
+    constexpr CongressObject object() const;
+    /// Number of rows in the table
+    static constexpr std::size_t len() { return 4; }
+    /// Index of the current record in the table
+    constexpr std::size_t as_index() const;
+    /// Reference to the table containing all the values
+    static constexpr const std::array<Congress, 4>& array();
+
+    constexpr bool operator==(const Congress& other) const { return this == &other; }
+    constexpr bool operator!=(const Congress& other) const { return this != &other; }
+};
+
+namespace detail {
+
+inline constexpr std::array<Person, 4> PERSON_TABLE = {{
+    {"Marie Curie", true, 1.0, 1, 0, 0, },
+    {"Pierre Curie", false, 2.1, 0, 0, 0, },
+    {"Irène Joliot-Curie", true, 3.2, 3, 2, 1, },
+    {"Frédéric Joliot-Curie", false, 2.1, 2, 0, 0, },
+}};
+inline constexpr std::array<uint8_t, 4> PERSON_SCORE_INDEX = {{
+    0, 1, 3, 2, 
+}};
+
+inline constexpr std::array<Strencoding, 6> STRENCODING_TABLE = {{
+    {"𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ", },
+    {"hello", },
+    {"κόσμε", },
+    {"いろはにほへとちりぬるを", },
+    {"éventuellement validé", },
+    {"Да, но фальшивый экземпляр", },
+}};
+inline constexpr std::array<uint8_t, 6> STRENCODING_TEXT_INDEX = {{
+    1, 4, 2, 5, 3, 0, 
+}};
+
+inline constexpr std::array<Lettercase, 3> LETTERCASE_TABLE = {{
+    {"Capitalised case", colobject::make_capitalize, &colobject::POINT_ZERO, },
+    {"Upper case", colobject::make_upper, &colobject::POINT_ONE, },
+    {"Lower case", colobject::make_lower, &colobject::POINT_ONE, },
+}};
+
+inline constexpr std::array<Wikidata, 3> WIKIDATA_TABLE = {{
+    {7186, 0, },
+    {8185162, 6, },
+    {150989, 3, },
+}};
+inline constexpr std::array<uint8_t, 3> WIKIDATA_OBJECT_INDEX = {{
+    0, 2, 1, 
+}};
+
+inline constexpr std::array<Congress, 4> CONGRESS_TABLE = {{
+    {"n2009011553", 1, },
+    {"sh85148650", 7, },
+    {"n80159913", 4, },
+    {"n79006404", 0, },
+}};
+inline constexpr std::array<uint8_t, 3> CONGRESS_OBJECT_INDEX = {{
+    0, 2, 1, 
+}};
+
+} // namespace detail
+
+constexpr PersonRange Person::score_range(double start, double stop) {
+    return detail::bisect(detail::PERSON_TABLE, detail::PERSON_SCORE_INDEX, start, stop, [](const Person& r) { return r.score(); });
+}
+constexpr const Person& Person::spouse() const { return detail::PERSON_TABLE[spouse_]; }
+inline std::optional<std::reference_wrapper<const Person>> Person::father() const {
+    if (father_ == 0) {
+        return std::nullopt;
+    }
+    return std::cref(detail::PERSON_TABLE[father_ - 1]);
+}
+inline std::optional<std::reference_wrapper<const Person>> Person::mother() const {
+    if (mother_ == 0) {
+        return std::nullopt;
+    }
+    return std::cref(detail::PERSON_TABLE[mother_ - 1]);
+}
+constexpr WikidataRange Person::wdata() const {
+    const auto cons = static_cast<uint8_t>(as_index());
+    return detail::bisect(detail::WIKIDATA_TABLE, detail::WIKIDATA_OBJECT_INDEX, cons, cons, [](const Wikidata& r) { return r.object_; });
+}
+constexpr CongressRange Person::congress() const {
+    const auto cons = static_cast<uint8_t>(as_index() + 1);
+    return detail::bisect(detail::CONGRESS_TABLE, detail::CONGRESS_OBJECT_INDEX, cons, cons, [](const Congress& r) { return r.object_; });
+}
+constexpr const Person& Person::from(Persons label) { return detail::PERSON_TABLE[static_cast<std::size_t>(label)]; }
+constexpr Persons Person::persons() const { return static_cast<Persons>(as_index()); }
+constexpr std::size_t Person::as_index() const { return static_cast<std::size_t>(this - detail::PERSON_TABLE.data()); }
+
+constexpr StrencodingRange Strencoding::text_range(std::string_view start, std::string_view stop) {
+    return detail::bisect(detail::STRENCODING_TABLE, detail::STRENCODING_TEXT_INDEX, start, stop, [](const Strencoding& r) { return r.text(); });
+}
+constexpr std::size_t Strencoding::as_index() const { return static_cast<std::size_t>(this - detail::STRENCODING_TABLE.data()); }
+constexpr const std::array<Strencoding, 6>& Strencoding::array() { return detail::STRENCODING_TABLE; }
+
+constexpr WikidataRange Lettercase::wdata2() const {
+    const auto cons = static_cast<uint8_t>(as_index() + 4);
+    return detail::bisect(detail::WIKIDATA_TABLE, detail::WIKIDATA_OBJECT_INDEX, cons, cons, [](const Wikidata& r) { return r.object_; });
+}
+constexpr CongressRange Lettercase::congress() const {
+    const auto cons = static_cast<uint8_t>(as_index() + 5);
+    return detail::bisect(detail::CONGRESS_TABLE, detail::CONGRESS_OBJECT_INDEX, cons, cons, [](const Congress& r) { return r.object_; });
+}
+constexpr const Lettercase& Lettercase::from(Lettercases label) { return detail::LETTERCASE_TABLE[static_cast<std::size_t>(label)]; }
+constexpr Lettercases Lettercase::lettercases() const { return static_cast<Lettercases>(as_index()); }
+constexpr std::size_t Lettercase::as_index() const { return static_cast<std::size_t>(this - detail::LETTERCASE_TABLE.data()); }
+
+constexpr WikidataObject Wikidata::object() const {
+    const std::size_t v = object_;
+    if (v <= 3) { return WikidataObject(&detail::PERSON_TABLE[v]); }
+    if (v <= 6) { return WikidataObject(&detail::LETTERCASE_TABLE[v - 4]); }
+    std::abort(); // insrcdata variant index overflow
+}
+constexpr std::size_t Wikidata::as_index() const { return static_cast<std::size_t>(this - detail::WIKIDATA_TABLE.data()); }
+constexpr const std::array<Wikidata, 3>& Wikidata::array() { return detail::WIKIDATA_TABLE; }
+
+constexpr CongressObject Congress::object() const {
+    const std::size_t v = object_;
+    if (v <= 0) { return CongressObject(std::monostate{}); }
+    if (v <= 4) { return CongressObject(&detail::PERSON_TABLE[v - 1]); }
+    if (v <= 7) { return CongressObject(&detail::LETTERCASE_TABLE[v - 5]); }
+    std::abort(); // insrcdata variant index overflow
+}
+constexpr std::size_t Congress::as_index() const { return static_cast<std::size_t>(this - detail::CONGRESS_TABLE.data()); }
+constexpr const std::array<Congress, 4>& Congress::array() { return detail::CONGRESS_TABLE; }
+
+} // namespace insrcdata
+
+#endif // INSRCDATA_INSRCDATA_HPP
@@ -0,0 +1,153 @@
+#include <cassert>
+#include <string>
+#include <vector>
+#include "insrcdata.hpp"
+
+namespace db = insrcdata;
+
+// tables are constexpr : they can be queried at compile time
+static_assert(db::Person::len() == 4);
+static_assert(db::Person::from(db::Persons::Marie).spouse() == db::Person::from(db::Persons::Pierre));
+static_assert(db::Person::score_count(2.1, 3.2) == 3);
+static_assert(db::Person::SCORE_MAX == 3.2);
+
+// the join column reference a record in the same table
+void test_innerjoin() {
+    const db::Person& marie = db::Person::from(db::Persons::Marie);
+    const db::Person& pierre = db::Person::from(db::Persons::Pierre);
+    const db::Person& irene = db::Person::from(db::Persons::Irene);
+    const db::Person& frederic = db::Person::from(db::Persons::Frederic);
+
+    assert(marie.name() == "Marie Curie");
+    assert(pierre.name() == "Pierre Curie");
+    assert(irene.name() == "Irène Joliot-Curie");
+    assert(frederic.name() == "Frédéric Joliot-Curie");
+
+    // inner join with 1-1 cardinality
+    assert(pierre == marie.spouse());
+    assert(marie == pierre.spouse());
+    assert(frederic == irene.spouse());
+    assert(irene == frederic.spouse());
+
+    // inner join with 0-1 cardinality
+    assert(!marie.mother().has_value());
+    assert(!marie.father().has_value());
+    assert(!pierre.mother().has_value());
+    assert(!frederic.father().has_value());
+    assert(marie == irene.mother()->get());
+    assert(pierre == irene.father()->get());
+}
+
+// bool data type
+void test_bool() {
+    assert(db::Person::from(db::Persons::Marie).woman());
+    assert(!db::Person::from(db::Persons::Pierre).woman());
+}
+
+// float data type
+void test_float() {
+    assert(db::Person::from(db::Persons::Marie).score() == 1.0);
+    assert(db::Person::from(db::Persons::Pierre).score() == 2.1);
+
+    // the range is stable : table order is preserved for equal values
+    std::vector<std::string_view> middle;
+    for (const db::Person& p : db::Person::score_range(2.1, 3.2)) {
+        middle.push_back(p.name());
+    }
+    assert((middle == std::vector<std::string_view>{"Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie"}));
+
+    // reversed, under and over
+    assert(db::Person::score_range(2.0, 1.9).empty());
+    assert(db::Person::score_count(0.0, 0.9) == 0);
+    assert(db::Person::score_count(0.0, 1.0) == 1);
+    assert(db::Person::score_count(10.0, 9000.0) == 0);
+    assert(db::Person::score_count(3.2, 9000.0) == 1);
+
+    // aggregates
+    assert(db::Person::SCORE_MIN == 1.0);
+    assert(db::Person::SCORE_MEAN == db::Person::SCORE_SUM / 4.0);
+}
+
+// retrieve label from record reference
+void test_fictolabel() {
+    assert(db::Person::from(db::Persons::Irene).persons() == db::Persons::Irene);
+    assert(db::Lettercase::from(db::Lettercases::Lower).lettercases() == db::Lettercases::Lower);
+}
+
+// check string comparison for various encoded unicode strings
+void test_strencoding() {
+    std::vector<std::string_view> texts;
+    for (const db::Strencoding& s : db::Strencoding::text_range("", "\xff")) {
+        texts.push_back(s.text());
+    }
+    assert(texts.size() == db::Strencoding::len());
+    assert(texts[0] == "hello");
+    for (std::size_t i = 1; i < texts.size(); i++) {
+        assert(texts[i - 1] <= texts[i]);
+    }
+    assert(db::Strencoding::text_count("hello", "hello") == 1);
+    assert(db::Strencoding::array()[1].text() == "hello");
+}
+
+// object type column : reference to native objects
+void test_colobject() {
+    std::string hello1 = "hello";
+    const db::Lettercase& upper = db::Lettercase::from(db::Lettercases::Upper);
+    upper.transformer()(hello1);
+    assert(hello1 == "HELLO");
+    assert(upper.point()->x == 1.0f);
+
+    std::string hello2 = "hello";
+    const db::Lettercase& capital = db::Lettercase::from(db::Lettercases::Capital);
+    capital.transformer()(hello2);
+    assert(hello2 == "Hello");
+    assert(capital.point()->x == 0.0f);
+}
+
+// variant
+void test_variant_non_optional() {
+    const db::Wikidata& q_marie = db::Wikidata::array()[0];
+    assert(std::get<const db::Person*>(q_marie.object()) == &db::Person::from(db::Persons::Marie));
+
+    const db::Wikidata& q_lower = db::Wikidata::array()[1];
+    assert(std::get<const db::Lettercase*>(q_lower.object()) == &db::Lettercase::from(db::Lettercases::Lower));
+
+    db::WikidataRange lower = db::Lettercase::from(db::Lettercases::Lower).wdata2();
+    assert(lower.size() == 1);
+    assert(*lower.begin() == q_lower);
+
+    assert(db::Person::from(db::Persons::Pierre).wdata().empty());
+}
+
+// variant with unmatched rows
+void test_variant_optional() {
+    const db::Congress& q_marie = db::Congress::array()[0];
+    assert(std::get<const db::Person*>(q_marie.object()) == &db::Person::from(db::Persons::Marie));
+
+    const db::Congress& q_lower = db::Congress::array()[1];
+    const db::CongressObject lower_variant = q_lower.object();
+    const db::Lettercase* const* lower_object = std::get_if<const db::Lettercase*>(&lower_variant);
+    assert(lower_object != nullptr && *lower_object == &db::Lettercase::from(db::Lettercases::Lower));
+
+    db::CongressRange lower = db::Lettercase::from(db::Lettercases::Lower).congress();
+    assert(lower.size() == 1);
+    assert(*lower.begin() == q_lower);
+
+    assert(db::Person::from(db::Persons::Pierre).congress().empty());
+
+    // not matched
+    const db::Congress& q_france = db::Congress::array()[3];
+    assert(std::holds_alternative<std::monostate>(q_france.object()));
+}
+
+int main() {
+    test_innerjoin();
+    test_bool();
+    test_float();
+    test_fictolabel();
+    test_strencoding();
+    test_colobject();
+    test_variant_non_optional();
+    test_variant_optional();
+    return 0;
+}
@@ -0,0 +1,20 @@
+//
+//  Colobject.cs
+//  testfull
+//
+
+public static class Colobject
+{
+    public static string make_capitalize(string s) =>
+        s.Length == 0 ? s : s.Substring(0, 1).ToUpperInvariant() + s.Substring(1).ToLowerInvariant();
+
+    public static string make_upper(string s) => s.ToUpperInvariant();
+
+    public static string make_lower(string s) => s.ToLowerInvariant();
+
+    // native object referenced from the lettercase table
+    public sealed record Point(float X, float Y);
+
+    public static readonly Point POINT_ZERO = new Point(0.0f, 0.0f);
+    public static readonly Point POINT_ONE = new Point(1.0f, 1.0f);
+}
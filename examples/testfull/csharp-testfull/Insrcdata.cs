@@ -0,0 +1,480 @@
+// generated by insrcdata version 0.3.0
+
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text;
+
+public static partial class Insrcdata
+{
+    // compare strings by unicode code points, string.CompareOrdinal use UTF-16 code units
+    private static int CompareCodePoints(string a, string b)
+    {
+        // surrogates encode code points above the basic plane
+        static int Fix(int c) => c >= 0xe000 ? c - 0x800 : c >= 0xd800 ? c + 0x2000 : c;
+        int n = Math.Min(a.Length, b.Length);
+        for (int i = 0; i < n; i++)
+        {
+            if (a[i] != b[i])
+            {
+                return Fix(a[i]).CompareTo(Fix(b[i]));
+            }
+        }
+        return a.Length.CompareTo(b.Length);
+    }
+
+    public enum Persons : byte
+    {
+        Marie = 0,
+        Pierre = 1,
+        Irene = 2,
+        Frederic = 3,
+    }
+
+    public enum Lettercases : byte
+    {
+        Capital = 0,
+        Upper = 1,
+        Lower = 2,
+    }
+
+    /// <summary>One of the rows that can be referenced by Wikidata.Object</summary>
+    public interface IWikidataObject { }
+
+    /// <summary>One of the rows that can be referenced by Congress.Object, null for rows without match</summary>
+    public interface ICongressObject { }
+
+    public readonly record struct Person : IWikidataObject, ICongressObject
+    {
+        private readonly int index;
+
+        internal Person(int index)
+        {
+            this.index = index;
+        }
+
+        // getters are not printed, joins may be cyclic
+        private bool PrintMembers(StringBuilder builder)
+        {
+            builder.Append("Index = ").Append(index);
+            return true;
+        }
+
+        /// <summary>The number of rows in the table</summary>
+        public const int Len = 4;
+
+        private static readonly string[] NameColumn = new string[]
+        {
+            "Marie Curie",
+            "Pierre Curie",
+            "Irène Joliot-Curie",
+            "Frédéric Joliot-Curie",
+        };
+        private static ReadOnlySpan<bool> WomanColumn => new bool[] { true, false, true, false };
+        private static ReadOnlySpan<double> ScoreColumn => new double[] { 1.0, 2.1, 3.2, 2.1 };
+        private static ReadOnlySpan<byte> SpouseColumn => new byte[] { 1, 0, 3, 2 };
+        private static ReadOnlySpan<byte> FatherColumn => new byte[] { 0, 0, 2, 0 };
+        private static ReadOnlySpan<byte> MotherColumn => new byte[] { 0, 0, 1, 0 };
+        internal static ReadOnlySpan<byte> ScoreIndex => new byte[] { 0, 1, 3, 2 };
+
+        public string Name => NameColumn[index];
+
+        public bool Woman => WomanColumn[index];
+
+        /// <summary>The min of score column values</summary>
+        public const double ScoreMin = 1.0;
+
+        /// <summary>The max of score column values</summary>
+        public const double ScoreMax = 3.2;
+
+        /// <summary>The sum of score column values</summary>
+        public const double ScoreSum = 8.4;
+
+        /// <summary>The mean of score column values</summary>
+        public const double ScoreMean = 2.1;
+
+        public double Score => ScoreColumn[index];
+
+        // binary search of the first position in ScoreIndex with a value greater (or equal) than value
+        internal static int ScoreBound(double value, bool upper)
+        {
+            int lo = 0;
+            int hi = ScoreIndex.Length;
+            while (lo < hi)
+            {
+                int mid = (lo + hi) >> 1;
+                int c = ScoreColumn[ScoreIndex[mid]].CompareTo(value);
+                if (c < 0 || (upper && c == 0))
+                {
+                    lo = mid + 1;
+                }
+                else
+                {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /// <summary>Returns the rows with Score in [start..=stop]</summary>
+        public static IEnumerable<Person> ScoreRange(double start, double stop)
+        {
+            int lo = ScoreBound(start, false);
+            int hi = Math.Max(lo, ScoreBound(stop, true));
+            return Enumerable.Range(lo, hi - lo).Select(i => new Person(ScoreIndex[i]));
+        }
+
+        /// <summary>Returns the number of rows returned by ScoreRange</summary>
+        public static int ScoreCount(double start, double stop)
+        {
+            int lo = ScoreBound(start, false);
+            return Math.Max(lo, ScoreBound(stop, true)) - lo;
+        }
+
+        public Person Spouse => new Person(SpouseColumn[index]);
+
+        public Person? Father
+        {
+            get
+            {
+                int v = FatherColumn[index];
+                return v == 0 ? null : new Person(v - 1);
+            }
+        }
+
+        public Person? Mother
+        {
+            get
+            {
+                int v = MotherColumn[index];
+                return v == 0 ? null : new Person(v - 1);
+            }
+        }
+
+        /// <summary>Returns the Wikidata rows that reference this row</summary>
+        public IEnumerable<Wikidata> Wdata()
+        {
+            var cons = (byte)index;
+            int lo = Insrcdata.Wikidata.ObjectBound(cons, false);
+            int hi = Insrcdata.Wikidata.ObjectBound(cons, true);
+            return Enumerable.Range(lo, hi - lo).Select(i => new Wikidata(Insrcdata.Wikidata.ObjectIndex[i]));
+        }
+
+        /// <summary>Returns the Congress rows that reference this row</summary>
+        public IEnumerable<Congress> Congress()
+        {
+            var cons = (byte)(index + 1);
+            int lo = Insrcdata.Congress.ObjectBound(cons, false);
+            int hi = Insrcdata.Congress.ObjectBound(cons, true);
+            return Enumerable.Range(lo, hi - lo).Select(i => new Congress(Insrcdata.Congress.ObjectIndex[i]));
+        }
+
+        /// <summary>Returns the row corresponding to label</summary>
+        public static Person FromPersons(Persons label) => new Person((int)label);
+
+        /// <summary>Returns the label of the row</summary>
+        public Persons Persons => (Persons)index;
+
+        /// <summary>Returns the position of the row in the table</summary>
+        public int AsIndex => index;
+    }
+
+    public readonly record struct Strencoding
+    {
+        private readonly int index;
+
+        internal Strencoding(int index)
+        {
+            this.index = index;
+        }
+
+        // getters are not printed, joins may be cyclic
+        private bool PrintMembers(StringBuilder builder)
+        {
+            builder.Append("Index = ").Append(index);
+            return true;
+        }
+
+        /// <summary>The number of rows in the table</summary>
+        public const int Len = 6;
+
+        private static readonly string[] TextColumn = new string[]
+        {
+            "𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ",
+            "hello",
+            "κόσμε",
+            "いろはにほへとちりぬるを",
+            "éventuellement validé",
+            "Да, но фальшивый экземпляр",
+        };
+        internal static ReadOnlySpan<byte> TextIndex => new byte[] { 1, 4, 2, 5, 3, 0 };
+
+        public string Text => TextColumn[index];
+
+        // binary search of the first position in TextIndex with a value greater (or equal) than value
+        internal static int TextBound(string value, bool upper)
+        {
+            int lo = 0;
+            int hi = TextIndex.Length;
+            while (lo < hi)
+            {
+                int mid = (lo + hi) >> 1;
+                int c = CompareCodePoints(TextColumn[TextIndex[mid]], value);
+                if (c < 0 || (upper && c == 0))
+                {
+                    lo = mid + 1;
+                }
+                else
+                {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /// <summary>Returns the rows with Text in [start..=stop]</summary>
+        public static IEnumerable<Strencoding> TextRange(string start, string stop)
+        {
+            int lo = TextBound(start, false);
+            int hi = Math.Max(lo, TextBound(stop, true));
+            return Enumerable.Range(lo, hi - lo).Select(i => new Strencoding(TextIndex[i]));
+        }
+
+        /// <summary>Returns the number of rows returned by TextRange</summary>
+        public static int TextCount(string start, string stop)
+        {
+            int lo = TextBound(start, false);
+            return Math.Max(lo, TextBound(stop, true)) - lo;
+        }
+
+        /// <summary>Returns the position of the row in the table</summary>
+        public int AsIndex => index;
+
+        /// <summary>Returns all the rows of the table</summary>
+        public static IReadOnlyList<Strencoding> Array { get; } = Enumerable.Range(0, Len).Select(i => new Strencoding(i)).ToList().AsReadOnly();
+    }
+
+    public readonly record struct Lettercase : IWikidataObject, ICongressObject
+    {
+        private readonly int index;
+
+        internal Lettercase(int index)
+        {
+            this.index = index;
+        }
+
+        // getters are not printed, joins may be cyclic
+        private bool PrintMembers(StringBuilder builder)
+        {
+            builder.Append("Index = ").Append(index);
+            return true;
+        }
+
+        /// <summary>The number of rows in the table</summary>
+        public const int Len = 3;
+
+        private static readonly string[] NameColumn = new string[]
+        {
+            "Capitalised case",
+            "Upper case",
+            "Lower case",
+        };
+        private static readonly Func<string, string>[] TransformerColumn = new Func<string, string>[]
+        {
+            Colobject.make_capitalize,
+            Colobject.make_upper,
+            Colobject.make_lower,
+        };
+        private static readonly Colobject.Point[] PointColumn = new Colobject.Point[]
+        {
+            Colobject.POINT_ZERO,
+            Colobject.POINT_ONE,
+            Colobject.POINT_ONE,
+        };
+
+        public string Name => NameColumn[index];
+
+        public Func<string, string> Transformer => TransformerColumn[index];
+
+        public Colobject.Point Point => PointColumn[index];
+
+        /// <summary>Returns the Wikidata rows that reference this row</summary>
+        public IEnumerable<Wikidata> Wdata2()
+        {
+            var cons = (byte)(index + 4);
+            int lo = Insrcdata.Wikidata.ObjectBound(cons, false);
+            int hi = Insrcdata.Wikidata.ObjectBound(cons, true);
+            return Enumerable.Range(lo, hi - lo).Select(i => new Wikidata(Insrcdata.Wikidata.ObjectIndex[i]));
+        }
+
+        /// <summary>Returns the Congress rows that reference this row</summary>
+        public IEnumerable<Congress> Congress()
+        {
+            var cons = (byte)(index + 5);
+            int lo = Insrcdata.Congress.ObjectBound(cons, false);
+            int hi = Insrcdata.Congress.ObjectBound(cons, true);
+            return Enumerable.Range(lo, hi - lo).Select(i => new Congress(Insrcdata.Congress.ObjectIndex[i]));
+        }
+
+        /// <summary>Returns the row corresponding to label</summary>
+        public static Lettercase FromLettercases(Lettercases label) => new Lettercase((int)label);
+
+        /// <summary>Returns the label of the row</summary>
+        public Lettercases Lettercases => (Lettercases)index;
+
+        /// <summary>Returns the position of the row in the table</summary>
+        public int AsIndex => index;
+    }
+
+    public readonly record struct Wikidata
+    {
+        private readonly int index;
+
+        internal Wikidata(int index)
+        {
+            this.index = index;
+        }
+
+        // getters are not printed, joins may be cyclic
+        private bool PrintMembers(StringBuilder builder)
+        {
+            builder.Append("Index = ").Append(index);
+            return true;
+        }
+
+        /// <summary>The number of rows in the table</summary>
+        public const int Len = 3;
+
+        private static ReadOnlySpan<uint> QidColumn => new uint[] { 7186, 8185162, 150989 };
+        private static ReadOnlySpan<byte> ObjectColumn => new byte[] { 0, 6, 3 };
+        internal static ReadOnlySpan<byte> ObjectIndex => new byte[] { 0, 2, 1 };
+
+        public uint Qid => QidColumn[index];
+
+        public IWikidataObject Object
+        {
+            get
+            {
+                int v = ObjectColumn[index];
+                if (v <= 3)
+                {
+                    return new Person(v);
+                }
+                if (v <= 6)
+                {
+                    return new Lettercase(v - 4);
+                }
+                throw new InvalidOperationException("insrcdata variant index overflow in Wikidata");
+            }
+        }
+
+        // binary search of the first position in ObjectIndex with a value greater (or equal) than value
+        internal static int ObjectBound(byte value, bool upper)
+        {
+            int lo = 0;
+            int hi = ObjectIndex.Length;
+            while (lo < hi)
+            {
+                int mid = (lo + hi) >> 1;
+                int c = ObjectColumn[ObjectIndex[mid]].CompareTo(value);
+                if (c < 0 || (upper && c == 0))
+                {
+                    lo = mid + 1;
+                }
+                else
+                {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /// <summary>Returns the position of the row in the table</summary>
+        public int AsIndex => index;
+
+        /// <summary>Returns all the rows of the table</summary>
+        public static IReadOnlyList<Wikidata> Array { get; } = Enumerable.Range(0, Len).Select(i => new Wikidata(i)).ToList().AsReadOnly();
+    }
+
+    public readonly record struct Congress
+    {
+        private readonly int index;
+
+        internal Congress(int index)
+        {
+            this.index = index;
+        }
+
+        // getters are not printed, joins may be cyclic
+        private bool PrintMembers(StringBuilder builder)
+        {
+            builder.Append("Index = ").Append(index);
+            return true;
+        }
+
+        /// <summary>The number of rows in the table</summary>
+        public const int Len = 4;
+
+        private static readonly string[] LccnColumn = new string[]
+        {
+            "n2009011553",
+            "sh85148650",
+            "n80159913",
+            "n79006404",
+        };
+        private static ReadOnlySpan<byte> ObjectColumn => new byte[] { 1, 7, 4, 0 };
+        internal static ReadOnlySpan<byte> ObjectIndex => new byte[] { 0, 2, 1 };
+
+        public string Lccn => LccnColumn[index];
+
+        public ICongressObject? Object
+        {
+            get
+            {
+                int v = ObjectColumn[index];
+                if (v <= 0)
+                {
+                    return null;
+                }
+                if (v <= 4)
+                {
+                    return new Person(v - 1);
+                }
+                if (v <= 7)
+                {
+                    return new Lettercase(v - 5);
+                }
+                throw new InvalidOperationException("insrcdata variant index overflow in Congress");
+            }
+        }
+
+        // binary search of the first position in ObjectIndex with a value greater (or equal) than value
+        internal static int ObjectBound(byte value, bool upper)
+        {
+            int lo = 0;
+            int hi = ObjectIndex.Length;
+            while (lo < hi)
+            {
+                int mid = (lo + hi) >> 1;
+                int c = ObjectColumn[ObjectIndex[mid]].CompareTo(value);
+                if (c < 0 || (upper && c == 0))
+                {
+                    lo = mid + 1;
+                }
+                else
+                {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /// <summary>Returns the position of the row in the table</summary>
+        public int AsIndex => index;
+
+        /// <summary>Returns all the rows of the table</summary>
+        public static IReadOnlyList<Congress> Array { get; } = Enumerable.Range(0, Len).Select(i => new Congress(i)).ToList().AsReadOnly();
+    }
+}
@@ -0,0 +1,152 @@
+//
+//  Program.cs
+//  testfull
+//
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using static Insrcdata;
+
+static class Program
+{
+    static void Check(bool ok)
+    {
+        if (!ok)
+        {
+            throw new Exception("assertion failed");
+        }
+    }
+
+    static List<string> Names(IEnumerable<Person> persons) => persons.Select(p => p.Name).ToList();
+
+    // the join column reference a record in the same table
+    static void TestInnerjoin()
+    {
+        var marie = Person.FromPersons(Persons.Marie);
+        var pierre = Person.FromPersons(Persons.Pierre);
+        var irene = Person.FromPersons(Persons.Irene);
+        var frederic = Person.FromPersons(Persons.Frederic);
+
+        Check(marie.Name == "Marie Curie");
+        Check(pierre.Name == "Pierre Curie");
+        Check(irene.Name == "Irène Joliot-Curie");
+        Check(frederic.Name == "Frédéric Joliot-Curie");
+
+        // inner join with 1-1 cardinality
+        Check(marie.Spouse == pierre);
+        Check(pierre.Spouse == marie);
+        Check(irene.Spouse == frederic);
+        Check(frederic.Spouse == irene);
+
+        // inner join with 0-1 cardinality
+        Check(marie.Mother == null);
+        Check(marie.Father == null);
+        Check(frederic.Father == null);
+        Check(irene.Mother == marie);
+        Check(irene.Father == pierre);
+    }
+
+    // bool data type
+    static void TestBool()
+    {
+        Check(Person.FromPersons(Persons.Marie).Woman);
+        Check(!Person.FromPersons(Persons.Pierre).Woman);
+    }
+
+    // float data type
+    static void TestFloat()
+    {
+        // the range is stable : table order is preserved for equal values
+        var middle = Names(Person.ScoreRange(2.1, 3.2));
+        Check(middle.SequenceEqual(new[] { "Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie" }));
+
+        // reversed, under and over
+        Check(!Person.ScoreRange(2.0, 1.9).Any());
+        Check(Person.ScoreCount(0.0, 0.9) == 0);
+        Check(Person.ScoreCount(0.0, 1.0) == 1);
+        Check(Person.ScoreCount(10.0, 9000.0) == 0);
+        Check(Person.ScoreCount(3.2, 9000.0) == 1);
+        Check(Person.Len == 4);
+
+        // aggregates
+        Check(Person.ScoreMin == 1.0);
+        Check(Person.ScoreMax == 3.2);
+    }
+
+    // retrieve label from record reference
+    static void TestFictolabel()
+    {
+        Check(Person.FromPersons(Persons.Irene).Persons == Persons.Irene);
+        Check(Lettercase.FromLettercases(Lettercases.Lower).Lettercases == Lettercases.Lower);
+        Check(Enum.GetValues<Persons>().Length == 4);
+    }
+
+    // check string comparison for various encoded unicode strings
+    static void TestStrencoding()
+    {
+        var texts = Strencoding.TextRange("", "\U0010ffff").ToList();
+        Check(texts.Count == Strencoding.Len);
+        Check(texts[0].Text == "hello");
+        // the surrogate pair string is the last one in code point order
+        Check(texts[texts.Count - 1] == Strencoding.Array[0]);
+        Check(Strencoding.TextCount("hello", "hello") == 1);
+        Check(Strencoding.Array[1] == texts[0]);
+    }
+
+    // object type column : reference to native objects
+    static void TestColobject()
+    {
+        var upper = Lettercase.FromLettercases(Lettercases.Upper);
+        Check(upper.Transformer("hello") == "HELLO");
+        Check(upper.Point.X == 1.0f);
+
+        var capital = Lettercase.FromLettercases(Lettercases.Capital);
+        Check(capital.Transformer("hello") == "Hello");
+        Check(capital.Point.X == 0.0f);
+    }
+
+    // variant
+    static void TestVariantNonOptional()
+    {
+        var qMarie = Wikidata.Array[0];
+        Check(qMarie.Object is Person p && p == Person.FromPersons(Persons.Marie));
+
+        var qLower = Wikidata.Array[1];
+        var lower = Lettercase.FromLettercases(Lettercases.Lower);
+        Check(qLower.Object is Lettercase l && l == lower);
+        Check(lower.Wdata2().SequenceEqual(new[] { qLower }));
+
+        Check(!Person.FromPersons(Persons.Pierre).Wdata().Any());
+    }
+
+    // variant with unmatched rows
+    static void TestVariantOptional()
+    {
+        var qMarie = Congress.Array[0];
+        Check(qMarie.Object is Person p && p == Person.FromPersons(Persons.Marie));
+
+        var qLower = Congress.Array[1];
+        var lower = Lettercase.FromLettercases(Lettercases.Lower);
+        Check(qLower.Object is Lettercase l && l == lower);
+        Check(lower.Congress().SequenceEqual(new[] { qLower }));
+
+        Check(!Person.FromPersons(Persons.Pierre).Congress().Any());
+
+        // not matched
+        var qFrance = Congress.Array[3];
+        Check(qFrance.Object == null);
+    }
+
+    static void Main()
+    {
+        TestInnerjoin();
+        TestBool();
+        TestFloat();
+        TestFictolabel();
+        TestStrencoding();
+        TestColobject();
+        TestVariantNonOptional();
+        TestVariantOptional();
+    }
+}
@@ -0,0 +1,32 @@
+//
+//  colobject.go
+//  testfull
+//
+
+package colobject
+
+import "strings"
+
+// Transformer change the case of a string
+type Transformer func(string) string
+
+// Transformers by name, referenced from the lettercase table
+var Transformers = map[string]Transformer{
+	"make_capitalize": func(s string) string {
+		if s == "" {
+			return s
+		}
+		return strings.ToUpper(s[:1]) + strings.ToLower(s[1:])
+	},
+	"make_upper": strings.ToUpper,
+	"make_lower": strings.ToLower,
+}
+
+// Point is a native object referenced from the lettercase table
+type Point struct {
+	X float32
+	Y float32
+}
+
+var POINT_ZERO = Point{0.0, 0.0}
+var POINT_ONE = Point{1.0, 1.0}
@@ -0,0 +1,3 @@
+module testfull
+
+go 1.18
@@ -0,0 +1,409 @@
+// Code generated by insrcdata version 0.3.0. DO NOT EDIT.
+
+package insrcdata
+
+import (
+	"sort"
+	"testfull/colobject"
+)
+
+type Persons uint8
+
+const (
+	PersonsMarie    Persons = 0
+	PersonsPierre   Persons = 1
+	PersonsIrene    Persons = 2
+	PersonsFrederic Persons = 3
+)
+
+// PersonsCount is the number of Persons labels
+const PersonsCount = 4
+
+type Lettercases uint8
+
+const (
+	LettercasesCapital Lettercases = 0
+	LettercasesUpper   Lettercases = 1
+	LettercasesLower   Lettercases = 2
+)
+
+type Person struct {
+	index_ uint8
+	name   string
+	woman  bool
+	score  float64
+	spouse uint8
+	father uint8
+	mother uint8
+}
+
+type Strencoding struct {
+	index_ uint8
+	text   string
+}
+
+type Lettercase struct {
+	index_      uint8
+	name        string
+	transformer colobject.Transformer
+	point       *colobject.Point
+}
+
+type Wikidata struct {
+	index_ uint8
+	qid    uint32
+	object uint8
+}
+
+// WikidataObject is one of the rows that can be referenced by Wikidata.Object()
+type WikidataObject interface {
+	isWikidataObject()
+}
+
+func (*Person) isWikidataObject() {}
+func (*Lettercase) isWikidataObject() {}
+
+type Congress struct {
+	index_ uint8
+	lccn   string
+	object uint8
+}
+
+// CongressObject is one of the rows that can be referenced by Congress.Object(), nil for rows without match
+type CongressObject interface {
+	isCongressObject()
+}
+
+func (*Person) isCongressObject() {}
+func (*Lettercase) isCongressObject() {}
+
+var personTable = []Person{
+	{0, "Marie Curie", true, 1.0, 1, 0, 0},
+	{1, "Pierre Curie", false, 2.1, 0, 0, 0},
+	{2, "Irène Joliot-Curie", true, 3.2, 3, 2, 1},
+	{3, "Frédéric Joliot-Curie", false, 2.1, 2, 0, 0},
+}
+
+var personScoreIndex = []uint8{0, 1, 3, 2}
+
+var strencodingTable = []Strencoding{
+	{0, "𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ"},
+	{1, "hello"},
+	{2, "κόσμε"},
+	{3, "いろはにほへとちりぬるを"},
+	{4, "éventuellement validé"},
+	{5, "Да, но фальшивый экземпляр"},
+}
+
+var strencodingTextIndex = []uint8{1, 4, 2, 5, 3, 0}
+
+var lettercaseTable = []Lettercase{
+	{0, "Capitalised case", colobject.Transformers["make_capitalize"], &colobject.POINT_ZERO},
+	{1, "Upper case", colobject.Transformers["make_upper"], &colobject.POINT_ONE},
+	{2, "Lower case", colobject.Transformers["make_lower"], &colobject.POINT_ONE},
+}
+
+var wikidataTable = []Wikidata{
+	{0, 7186, 0},
+	{1, 8185162, 6},
+	{2, 150989, 3},
+}
+
+var wikidataObjectIndex = []uint8{0, 2, 1}
+
+var congressTable = []Congress{
+	{0, "n2009011553", 1},
+	{1, "sh85148650", 7},
+	{2, "n80159913", 4},
+	{3, "n79006404", 0},
+}
+
+var congressObjectIndex = []uint8{0, 2, 1}
+
+// PersonLen is the number of rows in the table
+const PersonLen = 4
+
+// personRows returns the rows selected by a slice of index
+func personRows(index []uint8) []*Person {
+	rows := make([]*Person, len(index))
+	for i, v := range index {
+		rows[i] = &personTable[v]
+	}
+	return rows
+}
+
+func (r *Person) Name() string {
+	return r.name
+}
+
+func (r *Person) Woman() bool {
+	return r.woman
+}
+
+// PersonScoreMin is the min of score column values
+const PersonScoreMin float64 = 1.0
+
+// PersonScoreMax is the max of score column values
+const PersonScoreMax float64 = 3.2
+
+// PersonScoreSum is the sum of score column values
+const PersonScoreSum float64 = 8.4
+
+// PersonScoreMean is the mean of score column values
+const PersonScoreMean float64 = 2.1
+
+func (r *Person) Score() float64 {
+	return r.score
+}
+
+// personScoreBounds returns the positions in personScoreIndex of rows with score in [start..=stop]
+func personScoreBounds(start, stop float64) (int, int) {
+	lo := sort.Search(len(personScoreIndex), func(i int) bool { return personTable[personScoreIndex[i]].score >= start })
+	hi := sort.Search(len(personScoreIndex), func(i int) bool { return personTable[personScoreIndex[i]].score > stop })
+	if hi < lo {
+		hi = lo
+	}
+	return lo, hi
+}
+
+// PersonScoreRange returns the rows with score in [start..=stop]
+func PersonScoreRange(start, stop float64) []*Person {
+	lo, hi := personScoreBounds(start, stop)
+	return personRows(personScoreIndex[lo:hi])
+}
+
+// PersonScoreCount returns the number of rows returned by PersonScoreRange
+func PersonScoreCount(start, stop float64) int {
+	lo, hi := personScoreBounds(start, stop)
+	return hi - lo
+}
+
+func (r *Person) Spouse() *Person {
+	return &personTable[r.spouse]
+}
+
+func (r *Person) Father() *Person {
+	if r.father == 0 {
+		return nil
+	}
+	return &personTable[r.father-1]
+}
+
+func (r *Person) Mother() *Person {
+	if r.mother == 0 {
+		return nil
+	}
+	return &personTable[r.mother-1]
+}
+
+// Wdata returns the Wikidata rows that reference this row
+func (r *Person) Wdata() []*Wikidata {
+	cons := uint8(int(r.index_))
+	lo := sort.Search(len(wikidataObjectIndex), func(i int) bool { return wikidataTable[wikidataObjectIndex[i]].object >= cons })
+	hi := sort.Search(len(wikidataObjectIndex), func(i int) bool { return wikidataTable[wikidataObjectIndex[i]].object > cons })
+	return wikidataRows(wikidataObjectIndex[lo:hi])
+}
+
+// Congress returns the Congress rows that reference this row
+func (r *Person) Congress() []*Congress {
+	cons := uint8(int(r.index_) + 1)
+	lo := sort.Search(len(congressObjectIndex), func(i int) bool { return congressTable[congressObjectIndex[i]].object >= cons })
+	hi := sort.Search(len(congressObjectIndex), func(i int) bool { return congressTable[congressObjectIndex[i]].object > cons })
+	return congressRows(congressObjectIndex[lo:hi])
+}
+
+// PersonFromPersons returns the row corresponding to label
+func PersonFromPersons(label Persons) *Person {
+	return &personTable[label]
+}
+
+// Persons returns the label of the row
+func (r *Person) Persons() Persons {
+	return Persons(r.index_)
+}
+
+// Index returns the position of the row in the table
+func (r *Person) Index() int {
+	return int(r.index_)
+}
+
+// StrencodingLen is the number of rows in the table
+const StrencodingLen = 6
+
+// strencodingRows returns the rows selected by a slice of index
+func strencodingRows(index []uint8) []*Strencoding {
+	rows := make([]*Strencoding, len(index))
+	for i, v := range index {
+		rows[i] = &strencodingTable[v]
+	}
+	return rows
+}
+
+func (r *Strencoding) Text() string {
+	return r.text
+}
+
+// strencodingTextBounds returns the positions in strencodingTextIndex of rows with text in [start..=stop]
+func strencodingTextBounds(start, stop string) (int, int) {
+	lo := sort.Search(len(strencodingTextIndex), func(i int) bool { return strencodingTable[strencodingTextIndex[i]].text >= start })
+	hi := sort.Search(len(strencodingTextIndex), func(i int) bool { return strencodingTable[strencodingTextIndex[i]].text > stop })
+	if hi < lo {
+		hi = lo
+	}
+	return lo, hi
+}
+
+// StrencodingTextRange returns the rows with text in [start..=stop]
+func StrencodingTextRange(start, stop string) []*Strencoding {
+	lo, hi := strencodingTextBounds(start, stop)
+	return strencodingRows(strencodingTextIndex[lo:hi])
+}
+
+// StrencodingTextCount returns the number of rows returned by StrencodingTextRange
+func StrencodingTextCount(start, stop string) int {
+	lo, hi := strencodingTextBounds(start, stop)
+	return hi - lo
+}
+
+// Index returns the position of the row in the table
+func (r *Strencoding) Index() int {
+	return int(r.index_)
+}
+
+// StrencodingArray returns the slice containing all the rows, it must not be modified
+func StrencodingArray() []Strencoding {
+	return strencodingTable
+}
+
+// LettercaseLen is the number of rows in the table
+const LettercaseLen = 3
+
+// lettercaseRows returns the rows selected by a slice of index
+func lettercaseRows(index []uint8) []*Lettercase {
+	rows := make([]*Lettercase, len(index))
+	for i, v := range index {
+		rows[i] = &lettercaseTable[v]
+	}
+	return rows
+}
+
+func (r *Lettercase) Name() string {
+	return r.name
+}
+
+func (r *Lettercase) Transformer() colobject.Transformer {
+	return r.transformer
+}
+
+func (r *Lettercase) Point() *colobject.Point {
+	return r.point
+}
+
+// Wdata2 returns the Wikidata rows that reference this row
+func (r *Lettercase) Wdata2() []*Wikidata {
+	cons := uint8(int(r.index_) + 4)
+	lo := sort.Search(len(wikidataObjectIndex), func(i int) bool { return wikidataTable[wikidataObjectIndex[i]].object >= cons })
+	hi := sort.Search(len(wikidataObjectIndex), func(i int) bool { return wikidataTable[wikidataObjectIndex[i]].object > cons })
+	return wikidataRows(wikidataObjectIndex[lo:hi])
+}
+
+// Congress returns the Congress rows that reference this row
+func (r *Lettercase) Congress() []*Congress {
+	cons := uint8(int(r.index_) + 5)
+	lo := sort.Search(len(congressObjectIndex), func(i int) bool { return congressTable[congressObjectIndex[i]].object >= cons })
+	hi := sort.Search(len(congressObjectIndex), func(i int) bool { return congressTable[congressObjectIndex[i]].object > cons })
+	return congressRows(congressObjectIndex[lo:hi])
+}
+
+// LettercaseFromLettercases returns the row corresponding to label
+func LettercaseFromLettercases(label Lettercases) *Lettercase {
+	return &lettercaseTable[label]
+}
+
+// Lettercases returns the label of the row
+func (r *Lettercase) Lettercases() Lettercases {
+	return Lettercases(r.index_)
+}
+
+// Index returns the position of the row in the table
+func (r *Lettercase) Index() int {
+	return int(r.index_)
+}
+
+// WikidataLen is the number of rows in the table
+const WikidataLen = 3
+
+// wikidataRows returns the rows selected by a slice of index
+func wikidataRows(index []uint8) []*Wikidata {
+	rows := make([]*Wikidata, len(index))
+	for i, v := range index {
+		rows[i] = &wikidataTable[v]
+	}
+	return rows
+}
+
+func (r *Wikidata) Qid() uint32 {
+	return r.qid
+}
+
+func (r *Wikidata) Object() WikidataObject {
+	v := int(r.object)
+	if v <= 3 {
+		return &personTable[v]
+	}
+	if v <= 6 {
+		return &lettercaseTable[v-4]
+	}
+	panic("insrcdata variant index overflow in Wikidata")
+}
+
+// Index returns the position of the row in the table
+func (r *Wikidata) Index() int {
+	return int(r.index_)
+}
+
+// WikidataArray returns the slice containing all the rows, it must not be modified
+func WikidataArray() []Wikidata {
+	return wikidataTable
+}
+
+// CongressLen is the number of rows in the table
+const CongressLen = 4
+
+// congressRows returns the rows selected by a slice of index
+func congressRows(index []uint8) []*Congress {
+	rows := make([]*Congress, len(index))
+	for i, v := range index {
+		rows[i] = &congressTable[v]
+	}
+	return rows
+}
+
+func (r *Congress) Lccn() string {
+	return r.lccn
+}
+
+func (r *Congress) Object() CongressObject {
+	v := int(r.object)
+	if v <= 0 {
+		return nil
+	}
+	if v <= 4 {
+		return &personTable[v-1]
+	}
+	if v <= 7 {
+		return &lettercaseTable[v-5]
+	}
+	panic("insrcdata variant index overflow in Congress")
+}
+
+// Index returns the position of the row in the table
+func (r *Congress) Index() int {
+	return int(r.index_)
+}
+
+// CongressArray returns the slice containing all the rows, it must not be modified
+func CongressArray() []Congress {
+	return congressTable
+}
+
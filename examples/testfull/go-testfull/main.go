@@ -0,0 +1,148 @@
+package main
+
+import (
+	"reflect"
+
+	db "testfull/insrcdata"
+)
+
+func assert(ok bool) {
+	if !ok {
+		panic("assertion failed")
+	}
+}
+
+func names(persons []*db.Person) []string {
+	var names []string
+	for _, p := range persons {
+		names = append(names, p.Name())
+	}
+	return names
+}
+
+// the join column reference a record in the same table
+func testInnerjoin() {
+	marie := db.PersonFromPersons(db.PersonsMarie)
+	pierre := db.PersonFromPersons(db.PersonsPierre)
+	irene := db.PersonFromPersons(db.PersonsIrene)
+	frederic := db.PersonFromPersons(db.PersonsFrederic)
+
+	assert(marie.Name() == "Marie Curie")
+	assert(pierre.Name() == "Pierre Curie")
+	assert(irene.Name() == "Irène Joliot-Curie")
+	assert(frederic.Name() == "Frédéric Joliot-Curie")
+
+	// inner join with 1-1 cardinality
+	assert(marie.Spouse() == pierre)
+	assert(pierre.Spouse() == marie)
+	assert(irene.Spouse() == frederic)
+	assert(frederic.Spouse() == irene)
+
+	// inner join with 0-1 cardinality
+	assert(marie.Mother() == nil)
+	assert(marie.Father() == nil)
+	assert(frederic.Father() == nil)
+	assert(irene.Mother() == marie)
+	assert(irene.Father() == pierre)
+}
+
+// bool data type
+func testBool() {
+	assert(db.PersonFromPersons(db.PersonsMarie).Woman())
+	assert(!db.PersonFromPersons(db.PersonsPierre).Woman())
+}
+
+// float data type
+func testFloat() {
+	// the range is stable : table order is preserved for equal values
+	middle := names(db.PersonScoreRange(2.1, 3.2))
+	assert(reflect.DeepEqual(middle, []string{"Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie"}))
+
+	// reversed, under and over
+	assert(len(db.PersonScoreRange(2.0, 1.9)) == 0)
+	assert(db.PersonScoreCount(0.0, 0.9) == 0)
+	assert(db.PersonScoreCount(0.0, 1.0) == 1)
+	assert(db.PersonScoreCount(10.0, 9000.0) == 0)
+	assert(db.PersonScoreCount(3.2, 9000.0) == 1)
+	assert(db.PersonLen == 4)
+
+	// aggregates
+	assert(db.PersonScoreMin == 1.0)
+	assert(db.PersonScoreMax == 3.2)
+}
+
+// retrieve label from record reference
+func testFictolabel() {
+	assert(db.PersonFromPersons(db.PersonsIrene).Persons() == db.PersonsIrene)
+	assert(db.LettercaseFromLettercases(db.LettercasesLower).Lettercases() == db.LettercasesLower)
+	assert(db.PersonsCount == 4)
+}
+
+// check string comparison for various encoded unicode strings
+func testStrencoding() {
+	texts := db.StrencodingTextRange("", "\U0010ffff")
+	assert(len(texts) == db.StrencodingLen)
+	assert(texts[0].Text() == "hello")
+	for i := 1; i < len(texts); i++ {
+		assert(texts[i-1].Text() <= texts[i].Text())
+	}
+	assert(db.StrencodingTextCount("hello", "hello") == 1)
+	assert(&db.StrencodingArray()[1] == texts[0])
+}
+
+// object type column : reference to native objects
+func testColobject() {
+	upper := db.LettercaseFromLettercases(db.LettercasesUpper)
+	assert(upper.Transformer()("hello") == "HELLO")
+	assert(upper.Point().X == 1.0)
+
+	capital := db.LettercaseFromLettercases(db.LettercasesCapital)
+	assert(capital.Transformer()("hello") == "Hello")
+	assert(capital.Point().X == 0.0)
+}
+
+// variant
+func testVariantNonOptional() {
+	qMarie := &db.WikidataArray()[0]
+	assert(qMarie.Object() == db.PersonFromPersons(db.PersonsMarie))
+
+	qLower := &db.WikidataArray()[1]
+	lower := db.LettercaseFromLettercases(db.LettercasesLower)
+	switch object := qLower.Object().(type) {
+	case *db.Lettercase:
+		assert(object == lower)
+	default:
+		panic("lettercase expected")
+	}
+	assert(reflect.DeepEqual(lower.Wdata2(), []*db.Wikidata{qLower}))
+
+	assert(len(db.PersonFromPersons(db.PersonsPierre).Wdata()) == 0)
+}
+
+// variant with unmatched rows
+func testVariantOptional() {
+	qMarie := &db.CongressArray()[0]
+	assert(qMarie.Object() == db.PersonFromPersons(db.PersonsMarie))
+
+	qLower := &db.CongressArray()[1]
+	lower := db.LettercaseFromLettercases(db.LettercasesLower)
+	assert(qLower.Object() == lower)
+	assert(reflect.DeepEqual(lower.Congress(), []*db.Congress{qLower}))
+
+	assert(len(db.PersonFromPersons(db.PersonsPierre).Congress()) == 0)
+
+	// not matched
+	qFrance := &db.CongressArray()[3]
+	assert(qFrance.Object() == nil)
+}
+
+func main() {
+	testInnerjoin()
+	testBool()
+	testFloat()
+	testFictolabel()
+	testStrencoding()
+	testColobject()
+	testVariantNonOptional()
+	testVariantOptional()
+}
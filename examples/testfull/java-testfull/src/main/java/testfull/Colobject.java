@@ -0,0 +1,39 @@
+//
+//  Colobject.java
+//  testfull
+//
+
+package testfull;
+
+public final class Colobject {
+    private Colobject() {}
+
+    public static String make_capitalize(String s) {
+        if (s.isEmpty()) {
+            return s;
+        }
+        return s.substring(0, 1).toUpperCase() + s.substring(1).toLowerCase();
+    }
+
+    public static String make_upper(String s) {
+        return s.toUpperCase();
+    }
+
+    public static String make_lower(String s) {
+        return s.toLowerCase();
+    }
+
+    /** native object referenced from the lettercase table */
+    public static final class Point {
+        public final float x;
+        public final float y;
+
+        Point(float x, float y) {
+            this.x = x;
+            this.y = y;
+        }
+    }
+
+    public static final Point POINT_ZERO = new Point(0.0f, 0.0f);
+    public static final Point POINT_ONE = new Point(1.0f, 1.0f);
+}
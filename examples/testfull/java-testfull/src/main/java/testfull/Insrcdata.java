@@ -0,0 +1,558 @@
+// generated by insrcdata version 0.3.0
+
+package testfull;
+
+import java.util.AbstractList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.function.UnaryOperator;
+
+public final class Insrcdata {
+    private Insrcdata() {}
+
+    // read only view on the rows selected by a slice of an index
+    private static final class RowSlice<T> extends AbstractList<T> {
+        private final T[] table;
+        private final int[] index;
+        private final int start;
+        private final int stop;
+
+        RowSlice(T[] table, int[] index, int start, int stop) {
+            this.table = table;
+            this.index = index;
+            this.start = start;
+            this.stop = stop;
+        }
+
+        @Override
+        public T get(int i) {
+            if (i < 0 || i >= stop - start) {
+                throw new IndexOutOfBoundsException("Index: " + i + ", Size: " + size());
+            }
+            return table[index[start + i]];
+        }
+
+        @Override
+        public int size() {
+            return stop - start;
+        }
+    }
+
+    // decode an index from chunks of comma separated values
+    private static int[] decodeIndex(int len, String... chunks) {
+        int[] index = new int[len];
+        int i = 0;
+        for (String chunk : chunks) {
+            for (String v : chunk.split(",")) {
+                index[i++] = Integer.parseInt(v);
+            }
+        }
+        return index;
+    }
+
+    // compare strings by unicode code points, String.compareTo use UTF-16 code units
+    private static int compareCodePoints(String a, String b) {
+        int i = 0;
+        int j = 0;
+        while (i < a.length() && j < b.length()) {
+            int ca = a.codePointAt(i);
+            int cb = b.codePointAt(j);
+            if (ca != cb) {
+                return Integer.compare(ca, cb);
+            }
+            i += Character.charCount(ca);
+            j += Character.charCount(cb);
+        }
+        return Integer.compare(a.length() - i, b.length() - j);
+    }
+
+    public enum Persons {
+        MARIE(0),
+        PIERRE(1),
+        IRENE(2),
+        FREDERIC(3);
+
+        private final int index;
+
+        Persons(int index) {
+            this.index = index;
+        }
+
+        private static final Persons[] BY_INDEX = new Persons[4];
+
+        static {
+            for (Persons label : values()) {
+                BY_INDEX[label.index] = label;
+            }
+        }
+
+        /** Returns the row corresponding to the label */
+        public Person row() {
+            return Person.TABLE[index];
+        }
+    }
+
+    public enum Lettercases {
+        CAPITAL(0),
+        UPPER(1),
+        LOWER(2);
+
+        private final int index;
+
+        Lettercases(int index) {
+            this.index = index;
+        }
+
+        private static final Lettercases[] BY_INDEX = new Lettercases[3];
+
+        static {
+            for (Lettercases label : values()) {
+                BY_INDEX[label.index] = label;
+            }
+        }
+
+        /** Returns the row corresponding to the label */
+        public Lettercase row() {
+            return Lettercase.TABLE[index];
+        }
+    }
+
+    /** One of the rows that can be referenced by Wikidata.object() */
+    public interface WikidataObject {}
+
+    /** One of the rows that can be referenced by Congress.object(), null for rows without match */
+    public interface CongressObject {}
+
+    public static final class Person implements WikidataObject, CongressObject {
+        private static final Person[] TABLE = new Person[4];
+
+        static {
+            PersonRows0.fill(TABLE);
+        }
+
+        private static final int[] SCORE_INDEX = decodeIndex(4, "0,1,3,2");
+
+        private final int index_;
+        private final String name;
+        private final boolean woman;
+        private final double score;
+        private final int spouse;
+        private final int father;
+        private final int mother;
+
+        private Person(int index_, String name, boolean woman, double score, int spouse, int father, int mother) {
+            this.index_ = index_;
+            this.name = name;
+            this.woman = woman;
+            this.score = score;
+            this.spouse = spouse;
+            this.father = father;
+            this.mother = mother;
+        }
+
+        /** The number of rows in the table */
+        public static final int LEN = 4;
+
+        public String name() {
+            return name;
+        }
+
+        public boolean woman() {
+            return woman;
+        }
+
+        /** The min of score column values */
+        public static final double SCORE_MIN = 1.0;
+
+        /** The max of score column values */
+        public static final double SCORE_MAX = 3.2;
+
+        /** The sum of score column values */
+        public static final double SCORE_SUM = 8.4;
+
+        /** The mean of score column values */
+        public static final double SCORE_MEAN = 2.1;
+
+        public double score() {
+            return score;
+        }
+
+        // binary search of the first position in SCORE_INDEX with a value greater (or equal) than value
+        private static int scoreBound(double value, boolean upper) {
+            int lo = 0;
+            int hi = SCORE_INDEX.length;
+            while (lo < hi) {
+                int mid = (lo + hi) >>> 1;
+                int c = Double.compare(TABLE[SCORE_INDEX[mid]].score, value);
+                if (c < 0 || (upper && c == 0)) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /** Returns the rows with score in [start..=stop] */
+        public static List<Person> scoreRange(double start, double stop) {
+            int lo = scoreBound(start, false);
+            int hi = Math.max(lo, scoreBound(stop, true));
+            return new RowSlice<>(TABLE, SCORE_INDEX, lo, hi);
+        }
+
+        /** Returns the number of rows returned by scoreRange */
+        public static int scoreCount(double start, double stop) {
+            int lo = scoreBound(start, false);
+            return Math.max(lo, scoreBound(stop, true)) - lo;
+        }
+
+        public Person spouse() {
+            return Person.TABLE[spouse];
+        }
+
+        public Person father() {
+            if (father == 0) {
+                return null;
+            }
+            return Person.TABLE[father - 1];
+        }
+
+        public Person mother() {
+            if (mother == 0) {
+                return null;
+            }
+            return Person.TABLE[mother - 1];
+        }
+
+        /** Returns the Wikidata rows that reference this row */
+        public List<Wikidata> wdata() {
+            int cons = index_;
+            int lo = Wikidata.objectBound(cons, false);
+            int hi = Wikidata.objectBound(cons, true);
+            return new RowSlice<>(Wikidata.TABLE, Wikidata.OBJECT_INDEX, lo, hi);
+        }
+
+        /** Returns the Congress rows that reference this row */
+        public List<Congress> congress() {
+            int cons = index_ + 1;
+            int lo = Congress.objectBound(cons, false);
+            int hi = Congress.objectBound(cons, true);
+            return new RowSlice<>(Congress.TABLE, Congress.OBJECT_INDEX, lo, hi);
+        }
+
+        /** Returns the label of the row, null if the row has no label */
+        public Persons persons() {
+            return Persons.BY_INDEX[index_];
+        }
+
+        /** Returns the position of the row in the table */
+        public int asIndex() {
+            return index_;
+        }
+    }
+
+    public static final class Strencoding {
+        private static final Strencoding[] TABLE = new Strencoding[6];
+
+        static {
+            StrencodingRows0.fill(TABLE);
+        }
+
+        private static final int[] TEXT_INDEX = decodeIndex(6, "1,4,2,5,3,0");
+
+        private final int index_;
+        private final String text;
+
+        private Strencoding(int index_, String text) {
+            this.index_ = index_;
+            this.text = text;
+        }
+
+        /** The number of rows in the table */
+        public static final int LEN = 6;
+
+        public String text() {
+            return text;
+        }
+
+        // binary search of the first position in TEXT_INDEX with a value greater (or equal) than value
+        private static int textBound(String value, boolean upper) {
+            int lo = 0;
+            int hi = TEXT_INDEX.length;
+            while (lo < hi) {
+                int mid = (lo + hi) >>> 1;
+                int c = compareCodePoints(TABLE[TEXT_INDEX[mid]].text, value);
+                if (c < 0 || (upper && c == 0)) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /** Returns the rows with text in [start..=stop] */
+        public static List<Strencoding> textRange(String start, String stop) {
+            int lo = textBound(start, false);
+            int hi = Math.max(lo, textBound(stop, true));
+            return new RowSlice<>(TABLE, TEXT_INDEX, lo, hi);
+        }
+
+        /** Returns the number of rows returned by textRange */
+        public static int textCount(String start, String stop) {
+            int lo = textBound(start, false);
+            return Math.max(lo, textBound(stop, true)) - lo;
+        }
+
+        /** Returns the position of the row in the table */
+        public int asIndex() {
+            return index_;
+        }
+
+        /** Returns a read only list of all the rows of the table */
+        public static List<Strencoding> array() {
+            return Collections.unmodifiableList(Arrays.asList(TABLE));
+        }
+    }
+
+    public static final class Lettercase implements WikidataObject, CongressObject {
+        private static final Lettercase[] TABLE = new Lettercase[3];
+
+        static {
+            LettercaseRows0.fill(TABLE);
+        }
+
+        private final int index_;
+        private final String name;
+        private final UnaryOperator<String> transformer;
+        private final Colobject.Point point;
+
+        private Lettercase(int index_, String name, UnaryOperator<String> transformer, Colobject.Point point) {
+            this.index_ = index_;
+            this.name = name;
+            this.transformer = transformer;
+            this.point = point;
+        }
+
+        /** The number of rows in the table */
+        public static final int LEN = 3;
+
+        public String name() {
+            return name;
+        }
+
+        public UnaryOperator<String> transformer() {
+            return transformer;
+        }
+
+        public Colobject.Point point() {
+            return point;
+        }
+
+        /** Returns the Wikidata rows that reference this row */
+        public List<Wikidata> wdata2() {
+            int cons = index_ + 4;
+            int lo = Wikidata.objectBound(cons, false);
+            int hi = Wikidata.objectBound(cons, true);
+            return new RowSlice<>(Wikidata.TABLE, Wikidata.OBJECT_INDEX, lo, hi);
+        }
+
+        /** Returns the Congress rows that reference this row */
+        public List<Congress> congress() {
+            int cons = index_ + 5;
+            int lo = Congress.objectBound(cons, false);
+            int hi = Congress.objectBound(cons, true);
+            return new RowSlice<>(Congress.TABLE, Congress.OBJECT_INDEX, lo, hi);
+        }
+
+        /** Returns the label of the row, null if the row has no label */
+        public Lettercases lettercases() {
+            return Lettercases.BY_INDEX[index_];
+        }
+
+        /** Returns the position of the row in the table */
+        public int asIndex() {
+            return index_;
+        }
+    }
+
+    public static final class Wikidata {
+        private static final Wikidata[] TABLE = new Wikidata[3];
+
+        static {
+            WikidataRows0.fill(TABLE);
+        }
+
+        private static final int[] OBJECT_INDEX = decodeIndex(3, "0,2,1");
+
+        private final int index_;
+        private final long qid;
+        private final int object;
+
+        private Wikidata(int index_, long qid, int object) {
+            this.index_ = index_;
+            this.qid = qid;
+            this.object = object;
+        }
+
+        /** The number of rows in the table */
+        public static final int LEN = 3;
+
+        public long qid() {
+            return qid;
+        }
+
+        public WikidataObject object() {
+            if (object <= 3) {
+                return Person.TABLE[object];
+            }
+            if (object <= 6) {
+                return Lettercase.TABLE[object - 4];
+            }
+            throw new IllegalStateException("insrcdata variant index overflow in Wikidata");
+        }
+
+        // binary search of the first position in OBJECT_INDEX with a value greater (or equal) than value
+        private static int objectBound(int value, boolean upper) {
+            int lo = 0;
+            int hi = OBJECT_INDEX.length;
+            while (lo < hi) {
+                int mid = (lo + hi) >>> 1;
+                int c = Integer.compare(TABLE[OBJECT_INDEX[mid]].object, value);
+                if (c < 0 || (upper && c == 0)) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /** Returns the position of the row in the table */
+        public int asIndex() {
+            return index_;
+        }
+
+        /** Returns a read only list of all the rows of the table */
+        public static List<Wikidata> array() {
+            return Collections.unmodifiableList(Arrays.asList(TABLE));
+        }
+    }
+
+    public static final class Congress {
+        private static final Congress[] TABLE = new Congress[4];
+
+        static {
+            CongressRows0.fill(TABLE);
+        }
+
+        private static final int[] OBJECT_INDEX = decodeIndex(3, "0,2,1");
+
+        private final int index_;
+        private final String lccn;
+        private final int object;
+
+        private Congress(int index_, String lccn, int object) {
+            this.index_ = index_;
+            this.lccn = lccn;
+            this.object = object;
+        }
+
+        /** The number of rows in the table */
+        public static final int LEN = 4;
+
+        public String lccn() {
+            return lccn;
+        }
+
+        public CongressObject object() {
+            if (object <= 0) {
+                return null;
+            }
+            if (object <= 4) {
+                return Person.TABLE[object - 1];
+            }
+            if (object <= 7) {
+                return Lettercase.TABLE[object - 5];
+            }
+            throw new IllegalStateException("insrcdata variant index overflow in Congress");
+        }
+
+        // binary search of the first position in OBJECT_INDEX with a value greater (or equal) than value
+        private static int objectBound(int value, boolean upper) {
+            int lo = 0;
+            int hi = OBJECT_INDEX.length;
+            while (lo < hi) {
+                int mid = (lo + hi) >>> 1;
+                int c = Integer.compare(TABLE[OBJECT_INDEX[mid]].object, value);
+                if (c < 0 || (upper && c == 0)) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            return lo;
+        }
+
+        /** Returns the position of the row in the table */
+        public int asIndex() {
+            return index_;
+        }
+
+        /** Returns a read only list of all the rows of the table */
+        public static List<Congress> array() {
+            return Collections.unmodifiableList(Arrays.asList(TABLE));
+        }
+    }
+
+    // rows 0 to 3 of Person
+    private static final class PersonRows0 {
+        static void fill(Person[] t) {
+            t[0] = new Person(0, "Marie Curie", true, 1.0, 1, 0, 0);
+            t[1] = new Person(1, "Pierre Curie", false, 2.1, 0, 0, 0);
+            t[2] = new Person(2, "Ir\u00e8ne Joliot-Curie", true, 3.2, 3, 2, 1);
+            t[3] = new Person(3, "Fr\u00e9d\u00e9ric Joliot-Curie", false, 2.1, 2, 0, 0);
+        }
+    }
+
+    // rows 0 to 5 of Strencoding
+    private static final class StrencodingRows0 {
+        static void fill(Strencoding[] t) {
+            t[0] = new Strencoding(0, "\ud835\udcbe\u0148\ud835\uddcc\u044f\u010d\u1e13\u1e9a\u1d75\u1d8f : \ud835\udd22\u1d86\u1e03\u212e\ud835\ude8d \u1d8c\ud835\udd94\u1eeb\u1d73 \u2146\u1eb1\u01ab\u2c65");
+            t[1] = new Strencoding(1, "hello");
+            t[2] = new Strencoding(2, "\u03ba\u03cc\u03c3\u03bc\u03b5");
+            t[3] = new Strencoding(3, "\u3044\u308d\u306f\u306b\u307b\u3078\u3068\u3061\u308a\u306c\u308b\u3092");
+            t[4] = new Strencoding(4, "\u00e9ventuellement valid\u00e9");
+            t[5] = new Strencoding(5, "\u0414\u0430, \u043d\u043e \u0444\u0430\u043b\u044c\u0448\u0438\u0432\u044b\u0439 \u044d\u043a\u0437\u0435\u043c\u043f\u043b\u044f\u0440");
+        }
+    }
+
+    // rows 0 to 2 of Lettercase
+    private static final class LettercaseRows0 {
+        static void fill(Lettercase[] t) {
+            t[0] = new Lettercase(0, "Capitalised case", Colobject::make_capitalize, Colobject.POINT_ZERO);
+            t[1] = new Lettercase(1, "Upper case", Colobject::make_upper, Colobject.POINT_ONE);
+            t[2] = new Lettercase(2, "Lower case", Colobject::make_lower, Colobject.POINT_ONE);
+        }
+    }
+
+    // rows 0 to 2 of Wikidata
+    private static final class WikidataRows0 {
+        static void fill(Wikidata[] t) {
+            t[0] = new Wikidata(0, 7186L, 0);
+            t[1] = new Wikidata(1, 8185162L, 6);
+            t[2] = new Wikidata(2, 150989L, 3);
+        }
+    }
+
+    // rows 0 to 3 of Congress
+    private static final class CongressRows0 {
+        static void fill(Congress[] t) {
+            t[0] = new Congress(0, "n2009011553", 1);
+            t[1] = new Congress(1, "sh85148650", 7);
+            t[2] = new Congress(2, "n80159913", 4);
+            t[3] = new Congress(3, "n79006404", 0);
+        }
+    }
+}
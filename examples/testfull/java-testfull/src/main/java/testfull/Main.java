@@ -0,0 +1,156 @@
+//
+//  Main.java
+//  testfull
+//
+
+package testfull;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+import testfull.Insrcdata.Congress;
+import testfull.Insrcdata.Lettercase;
+import testfull.Insrcdata.Lettercases;
+import testfull.Insrcdata.Person;
+import testfull.Insrcdata.Persons;
+import testfull.Insrcdata.Strencoding;
+import testfull.Insrcdata.Wikidata;
+
+public final class Main {
+    private Main() {}
+
+    static void check(boolean ok) {
+        if (!ok) {
+            throw new AssertionError("assertion failed");
+        }
+    }
+
+    static List<String> names(List<Person> persons) {
+        List<String> names = new ArrayList<>();
+        for (Person p : persons) {
+            names.add(p.name());
+        }
+        return names;
+    }
+
+    // the join column reference a record in the same table
+    static void testInnerjoin() {
+        Person marie = Persons.MARIE.row();
+        Person pierre = Persons.PIERRE.row();
+        Person irene = Persons.IRENE.row();
+        Person frederic = Persons.FREDERIC.row();
+
+        check(marie.name().equals("Marie Curie"));
+        check(pierre.name().equals("Pierre Curie"));
+        check(irene.name().equals("Irène Joliot-Curie"));
+        check(frederic.name().equals("Frédéric Joliot-Curie"));
+
+        // inner join with 1-1 cardinality
+        check(marie.spouse() == pierre);
+        check(pierre.spouse() == marie);
+        check(irene.spouse() == frederic);
+        check(frederic.spouse() == irene);
+
+        // inner join with 0-1 cardinality
+        check(marie.mother() == null);
+        check(marie.father() == null);
+        check(frederic.father() == null);
+        check(irene.mother() == marie);
+        check(irene.father() == pierre);
+    }
+
+    // bool data type
+    static void testBool() {
+        check(Persons.MARIE.row().woman());
+        check(!Persons.PIERRE.row().woman());
+    }
+
+    // float data type
+    static void testFloat() {
+        // the range is stable : table order is preserved for equal values
+        List<String> middle = names(Person.scoreRange(2.1, 3.2));
+        check(middle.equals(Arrays.asList("Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie")));
+
+        // reversed, under and over
+        check(Person.scoreRange(2.0, 1.9).isEmpty());
+        check(Person.scoreCount(0.0, 0.9) == 0);
+        check(Person.scoreCount(0.0, 1.0) == 1);
+        check(Person.scoreCount(10.0, 9000.0) == 0);
+        check(Person.scoreCount(3.2, 9000.0) == 1);
+        check(Person.LEN == 4);
+
+        // aggregates
+        check(Person.SCORE_MIN == 1.0);
+        check(Person.SCORE_MAX == 3.2);
+    }
+
+    // retrieve label from record reference
+    static void testFictolabel() {
+        check(Persons.IRENE.row().persons() == Persons.IRENE);
+        check(Lettercases.LOWER.row().lettercases() == Lettercases.LOWER);
+        check(Persons.values().length == 4);
+    }
+
+    // check string comparison for various encoded unicode strings
+    static void testStrencoding() {
+        List<Strencoding> texts = Strencoding.textRange("", "􏿿");
+        check(texts.size() == Strencoding.LEN);
+        check(texts.get(0).text().equals("hello"));
+        // the surrogate pair string is the last one in code point order
+        check(texts.get(texts.size() - 1) == Strencoding.array().get(0));
+        check(Strencoding.textCount("hello", "hello") == 1);
+        check(Strencoding.array().get(1) == texts.get(0));
+    }
+
+    // object type column : reference to native objects
+    static void testColobject() {
+        Lettercase upper = Lettercases.UPPER.row();
+        check(upper.transformer().apply("hello").equals("HELLO"));
+        check(upper.point().x == 1.0f);
+
+        Lettercase capital = Lettercases.CAPITAL.row();
+        check(capital.transformer().apply("hello").equals("Hello"));
+        check(capital.point().x == 0.0f);
+    }
+
+    // variant
+    static void testVariantNonOptional() {
+        Wikidata qMarie = Wikidata.array().get(0);
+        check(qMarie.object() == Persons.MARIE.row());
+
+        Wikidata qLower = Wikidata.array().get(1);
+        Lettercase lower = Lettercases.LOWER.row();
+        check(qLower.object() instanceof Lettercase && qLower.object() == lower);
+        check(lower.wdata2().equals(Arrays.asList(qLower)));
+
+        check(Persons.PIERRE.row().wdata().isEmpty());
+    }
+
+    // variant with unmatched rows
+    static void testVariantOptional() {
+        Congress qMarie = Congress.array().get(0);
+        check(qMarie.object() == Persons.MARIE.row());
+
+        Congress qLower = Congress.array().get(1);
+        Lettercase lower = Lettercases.LOWER.row();
+        check(qLower.object() == lower);
+        check(lower.congress().equals(Arrays.asList(qLower)));
+
+        check(Persons.PIERRE.row().congress().isEmpty());
+
+        // not matched
+        Congress qFrance = Congress.array().get(3);
+        check(qFrance.object() == null);
+    }
+
+    public static void main(String[] args) {
+        testInnerjoin();
+        testBool();
+        testFloat();
+        testFictolabel();
+        testStrencoding();
+        testColobject();
+        testVariantNonOptional();
+        testVariantOptional();
+    }
+}
@@ -0,0 +1,17 @@
+//
+//  colobject.d.ts
+//  testfull
+//
+
+export type Transformer = (s: string) => string;
+
+export declare function make_capitalize(s: string): string;
+export declare function make_upper(s: string): string;
+export declare function make_lower(s: string): string;
+
+export interface Point {
+    readonly x: number;
+    readonly y: number;
+}
+export declare const POINT_ZERO: Point;
+export declare const POINT_ONE: Point;
@@ -0,0 +1,19 @@
+//
+//  colobject.js
+//  testfull
+//
+
+export function make_capitalize(s) {
+    return s.charAt(0).toUpperCase() + s.slice(1).toLowerCase();
+}
+
+export function make_upper(s) {
+    return s.toUpperCase();
+}
+
+export function make_lower(s) {
+    return s.toLowerCase();
+}
+
+export const POINT_ZERO = { x: 0.0, y: 0.0 };
+export const POINT_ONE = { x: 1.0, y: 1.0 };
@@ -0,0 +1,133 @@
+// generated by insrcdata version 0.3.0
+
+import * as colobject from "./colobject.js";
+
+export declare const Persons: {
+    readonly Marie: 0;
+    readonly Pierre: 1;
+    readonly Irene: 2;
+    readonly Frederic: 3;
+};
+export type Persons = (typeof Persons)[keyof typeof Persons];
+
+export declare const Lettercases: {
+    readonly Capital: 0;
+    readonly Upper: 1;
+    readonly Lower: 2;
+};
+export type Lettercases = (typeof Lettercases)[keyof typeof Lettercases];
+
+export interface Person {
+    /** @internal */
+    readonly _index: number;
+    readonly name: string;
+    readonly woman: boolean;
+    readonly score: number;
+    /** @internal */
+    readonly _spouse: number;
+    /** @internal */
+    readonly _father: number;
+    /** @internal */
+    readonly _mother: number;
+}
+
+export interface Strencoding {
+    /** @internal */
+    readonly _index: number;
+    readonly text: string;
+}
+
+export interface Lettercase {
+    /** @internal */
+    readonly _index: number;
+    readonly name: string;
+    readonly transformer: colobject.Transformer;
+    readonly point: colobject.Point;
+}
+
+export interface Wikidata {
+    /** @internal */
+    readonly _index: number;
+    readonly qid: number;
+    /** @internal */
+    readonly _object: number;
+}
+
+export type WikidataObject =
+    | { readonly kind: "Person"; readonly row: Person }
+    | { readonly kind: "Lettercase"; readonly row: Lettercase };
+
+export interface Congress {
+    /** @internal */
+    readonly _index: number;
+    readonly lccn: string;
+    /** @internal */
+    readonly _object: number;
+}
+
+export type CongressObject =
+    | { readonly kind: "Person"; readonly row: Person }
+    | { readonly kind: "Lettercase"; readonly row: Lettercase };
+
+/** All the rows of the table */
+export declare const STRENCODING_TABLE: readonly Strencoding[];
+/** All the rows of the table */
+export declare const WIKIDATA_TABLE: readonly Wikidata[];
+/** All the rows of the table */
+export declare const CONGRESS_TABLE: readonly Congress[];
+/** Number of rows in the table */
+export declare const PERSON_LEN: number;
+/** min of score column values */
+export declare const PERSON_SCORE_MIN: number;
+/** max of score column values */
+export declare const PERSON_SCORE_MAX: number;
+/** sum of score column values */
+export declare const PERSON_SCORE_SUM: number;
+/** mean of score column values */
+export declare const PERSON_SCORE_MEAN: number;
+
+export declare function personScoreRange(start: number, stop: number): Person[];
+/** Number of rows returned by personScoreRange */
+export declare function personScoreCount(start: number, stop: number): number;
+export declare function personSpouse(row: Person): Person;
+export declare function personFather(row: Person): Person | undefined;
+export declare function personMother(row: Person): Person | undefined;
+export declare function personWdata(row: Person): Wikidata[];
+export declare function personCongress(row: Person): Congress[];
+/** Row corresponding to label */
+export declare function personFromPersons(label: Persons): Person;
+/** Label of the row */
+export declare function personPersons(row: Person): Persons;
+/** Index of the row in the table */
+export declare function personAsIndex(row: Person): number;
+/** Number of rows in the table */
+export declare const STRENCODING_LEN: number;
+
+export declare function strencodingTextRange(start: string, stop: string): Strencoding[];
+/** Number of rows returned by strencodingTextRange */
+export declare function strencodingTextCount(start: string, stop: string): number;
+/** Index of the row in the table */
+export declare function strencodingAsIndex(row: Strencoding): number;
+/** Number of rows in the table */
+export declare const LETTERCASE_LEN: number;
+
+export declare function lettercaseWdata2(row: Lettercase): Wikidata[];
+export declare function lettercaseCongress(row: Lettercase): Congress[];
+/** Row corresponding to label */
+export declare function lettercaseFromLettercases(label: Lettercases): Lettercase;
+/** Label of the row */
+export declare function lettercaseLettercases(row: Lettercase): Lettercases;
+/** Index of the row in the table */
+export declare function lettercaseAsIndex(row: Lettercase): number;
+/** Number of rows in the table */
+export declare const WIKIDATA_LEN: number;
+
+export declare function wikidataObject(row: Wikidata): WikidataObject;
+/** Index of the row in the table */
+export declare function wikidataAsIndex(row: Wikidata): number;
+/** Number of rows in the table */
+export declare const CONGRESS_LEN: number;
+
+export declare function congressObject(row: Congress): CongressObject | undefined;
+/** Index of the row in the table */
+export declare function congressAsIndex(row: Congress): number;
@@ -0,0 +1,121 @@
+import assert from "node:assert/strict";
+import * as db from "./insrcdata.js";
+
+// the join column reference a record in the same table
+function testInnerjoin() {
+    const marie = db.personFromPersons(db.Persons.Marie);
+    const pierre = db.personFromPersons(db.Persons.Pierre);
+    const irene = db.personFromPersons(db.Persons.Irene);
+    const frederic = db.personFromPersons(db.Persons.Frederic);
+
+    assert.equal(marie.name, "Marie Curie");
+    assert.equal(pierre.name, "Pierre Curie");
+    assert.equal(irene.name, "Irène Joliot-Curie");
+    assert.equal(frederic.name, "Frédéric Joliot-Curie");
+
+    // inner join with 1-1 cardinality
+    assert.equal(db.personSpouse(marie), pierre);
+    assert.equal(db.personSpouse(pierre), marie);
+    assert.equal(db.personSpouse(irene), frederic);
+    assert.equal(db.personSpouse(frederic), irene);
+
+    // inner join with 0-1 cardinality
+    assert.equal(db.personMother(marie), undefined);
+    assert.equal(db.personFather(marie), undefined);
+    assert.equal(db.personFather(frederic), undefined);
+    assert.equal(db.personMother(irene), marie);
+    assert.equal(db.personFather(irene), pierre);
+}
+
+// bool data type
+function testBool() {
+    assert.ok(db.personFromPersons(db.Persons.Marie).woman);
+    assert.ok(!db.personFromPersons(db.Persons.Pierre).woman);
+}
+
+// float data type
+function testFloat() {
+    // the range is stable : table order is preserved for equal values
+    const middle = db.personScoreRange(2.1, 3.2).map((p) => p.name);
+    assert.deepEqual(middle, ["Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie"]);
+
+    // reversed, under and over
+    assert.deepEqual(db.personScoreRange(2.0, 1.9), []);
+    assert.equal(db.personScoreCount(0.0, 0.9), 0);
+    assert.equal(db.personScoreCount(0.0, 1.0), 1);
+    assert.equal(db.personScoreCount(10.0, 9000.0), 0);
+    assert.equal(db.personScoreCount(3.2, 9000.0), 1);
+    assert.equal(db.PERSON_LEN, 4);
+
+    // aggregates
+    assert.equal(db.PERSON_SCORE_MIN, 1.0);
+    assert.equal(db.PERSON_SCORE_MAX, 3.2);
+}
+
+// retrieve label from record reference
+function testFictolabel() {
+    assert.equal(db.personPersons(db.personFromPersons(db.Persons.Irene)), db.Persons.Irene);
+    assert.equal(db.lettercaseLettercases(db.lettercaseFromLettercases(db.Lettercases.Lower)), db.Lettercases.Lower);
+}
+
+// check string comparison for various encoded unicode strings
+function testStrencoding() {
+    const texts = db.strencodingTextRange("", "\u{10ffff}").map((s) => s.text);
+    assert.equal(texts.length, db.STRENCODING_LEN);
+    assert.equal(texts[0], "hello");
+    // the mathematical script letters are encoded with surrogates but sort last
+    assert.equal(texts[texts.length - 1], db.STRENCODING_TABLE[0].text);
+    assert.equal(db.strencodingTextCount("hello", "hello"), 1);
+}
+
+// object type column : reference to native objects
+function testColobject() {
+    const upper = db.lettercaseFromLettercases(db.Lettercases.Upper);
+    assert.equal(upper.transformer("hello"), "HELLO");
+    assert.equal(upper.point.x, 1.0);
+
+    const capital = db.lettercaseFromLettercases(db.Lettercases.Capital);
+    assert.equal(capital.transformer("hello"), "Hello");
+    assert.equal(capital.point.x, 0.0);
+}
+
+// variant
+function testVariantNonOptional() {
+    const qMarie = db.WIKIDATA_TABLE[0];
+    assert.deepEqual(db.wikidataObject(qMarie), { kind: "Person", row: db.personFromPersons(db.Persons.Marie) });
+
+    const qLower = db.WIKIDATA_TABLE[1];
+    const lower = db.lettercaseFromLettercases(db.Lettercases.Lower);
+    const object = db.wikidataObject(qLower);
+    assert.equal(object.kind, "Lettercase");
+    assert.equal(object.row, lower);
+    assert.deepEqual(db.lettercaseWdata2(lower), [qLower]);
+
+    assert.deepEqual(db.personWdata(db.personFromPersons(db.Persons.Pierre)), []);
+}
+
+// variant with unmatched rows
+function testVariantOptional() {
+    const qMarie = db.CONGRESS_TABLE[0];
+    assert.equal(db.congressObject(qMarie).row, db.personFromPersons(db.Persons.Marie));
+
+    const qLower = db.CONGRESS_TABLE[1];
+    const lower = db.lettercaseFromLettercases(db.Lettercases.Lower);
+    assert.equal(db.congressObject(qLower).row, lower);
+    assert.deepEqual(db.lettercaseCongress(lower), [qLower]);
+
+    assert.deepEqual(db.personCongress(db.personFromPersons(db.Persons.Pierre)), []);
+
+    // not matched
+    const qFrance = db.CONGRESS_TABLE[3];
+    assert.equal(db.congressObject(qFrance), undefined);
+}
+
+testInnerjoin();
+testBool();
+testFloat();
+testFictolabel();
+testStrencoding();
+testColobject();
+testVariantNonOptional();
+testVariantOptional();
@@ -0,0 +1,122 @@
+#!/usr/bin/env python3
+import json
+import os
+
+PATH = os.path.dirname(os.path.abspath(__file__))
+
+db = json.load(open(f"{PATH}/insrcdata.json", encoding="utf-8"))
+tables = {t["name"]: t for t in db["tables"]}
+
+
+def column(table, name):
+    names = [c["name"] for c in tables[table]["columns"]]
+    return names.index(name)
+
+
+def row(table, label):
+    t = tables[table]
+    for rows in t["labels"].values():
+        if label in rows:
+            return t["rows"][rows[label]]
+    raise KeyError(label)
+
+
+def value(table, r, name):
+    return r[column(table, name)]
+
+
+# the join column reference a record in the same table
+def test_innerjoin():
+    persons = tables["Person"]["rows"]
+    marie = row("Person", "marie")
+    pierre = row("Person", "pierre")
+    irene = row("Person", "irene")
+    frederic = row("Person", "frederic")
+
+    assert value("Person", marie, "name") == "Marie Curie"
+    assert value("Person", pierre, "name") == "Pierre Curie"
+    assert value("Person", irene, "name") == "Irène Joliot-Curie"
+    assert value("Person", frederic, "name") == "Frédéric Joliot-Curie"
+
+    # inner join with 1-1 cardinality
+    assert persons[value("Person", marie, "spouse")] is pierre
+    assert persons[value("Person", irene, "spouse")] is frederic
+
+    # inner join with 0-1 cardinality
+    assert value("Person", marie, "mother") is None
+    assert value("Person", marie, "father") is None
+    assert persons[value("Person", irene, "mother")] is marie
+    assert persons[value("Person", irene, "father")] is pierre
+
+
+# bool data type
+def test_bool():
+    assert value("Person", row("Person", "marie"), "woman") is True
+    assert value("Person", row("Person", "pierre"), "woman") is False
+
+
+# float data type
+def test_float():
+    persons = tables["Person"]["rows"]
+    # the index is stable : table order is preserved for equal values
+    index = tables["Person"]["indexes"]["score"]
+    assert [value("Person", persons[i], "name") for i in index[1:]] == [
+        "Pierre Curie",
+        "Frédéric Joliot-Curie",
+        "Irène Joliot-Curie",
+    ]
+    assert tables["Person"]["len"] == 4
+
+    # aggregates
+    score = tables["Person"]["columns"][column("Person", "score")]
+    assert score["type"] == "f64"
+    assert score["aggregates"]["min"] == 1.0
+    assert score["aggregates"]["max"] == 3.2
+
+
+# schema of join and labels
+def test_schema():
+    father = tables["Person"]["columns"][column("Person", "father")]
+    assert father == {"name": "father", "type": "join", "table": "Person", "optional": True}
+    assert tables["lettercase"]["labels"]["lettercases"] == {"capital": 0, "upper": 1, "lower": 2}
+    assert tables["lettercase"]["exhaustive"]
+
+
+# check string order for various encoded unicode strings
+def test_strencoding():
+    t = tables["strencoding"]
+    texts = [t["rows"][i][0] for i in t["indexes"]["text"]]
+    assert texts == sorted(r[0] for r in t["rows"])
+    assert texts[0] == "hello"
+
+
+# object type column : exported with the json target template
+def test_colobject():
+    upper = row("lettercase", "upper")
+    assert value("lettercase", upper, "transformer") == "make_upper"
+    assert value("lettercase", upper, "point") == "ONE"
+
+
+# variant
+def test_variant_non_optional():
+    objects = [value("wikidata", r, "object") for r in tables["wikidata"]["rows"]]
+    assert objects[0] == {"table": "Person", "row": 0}
+    assert objects[1] == {"table": "lettercase", "row": 2}
+
+
+# variant with unmatched rows
+def test_variant_optional():
+    objects = [value("congress", r, "object") for r in tables["congress"]["rows"]]
+    assert objects[1] == {"table": "lettercase", "row": 2}
+    assert objects[3] is None
+
+
+if __name__ == "__main__":
+    test_innerjoin()
+    test_bool()
+    test_float()
+    test_schema()
+    test_strencoding()
+    test_colobject()
+    test_variant_non_optional()
+    test_variant_optional()
@@ -0,0 +1,28 @@
+#
+#  colobject.py
+#  testfull
+#
+
+from dataclasses import dataclass
+
+
+def make_capitalize(s: str) -> str:
+    return s.capitalize()
+
+
+def make_upper(s: str) -> str:
+    return s.upper()
+
+
+def make_lower(s: str) -> str:
+    return s.lower()
+
+
+@dataclass(frozen=True)
+class Point:
+    x: float
+    y: float
+
+
+POINT_ZERO = Point(0.0, 0.0)
+POINT_ONE = Point(1.0, 1.0)
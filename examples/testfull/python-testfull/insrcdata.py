@@ -0,0 +1,282 @@
+# generated by insrcdata version 0.3.0
+
+from __future__ import annotations
+
+import bisect
+import enum
+from dataclasses import dataclass, field
+from typing import Iterator, Optional, Tuple, Union
+import colobject
+
+
+def _bounds(table, index, start, stop, key):
+    # positions in index of rows with key(row) in [start..=stop], index must be sorted by key(row)
+    lo = bisect.bisect_left(index, start, key=lambda i: key(table[i]))
+    hi = bisect.bisect_right(index, stop, lo, key=lambda i: key(table[i]))
+    return lo, hi
+
+
+def _rows(table, index, start, stop, key):
+    # rows of table with key(row) in [start..=stop]
+    lo, hi = _bounds(table, index, start, stop, key)
+    return (table[i] for i in index[lo:hi])
+
+
+class Persons(enum.IntEnum):
+    MARIE = 0
+    PIERRE = 1
+    IRENE = 2
+    FREDERIC = 3
+
+
+class Lettercases(enum.IntEnum):
+    CAPITAL = 0
+    UPPER = 1
+    LOWER = 2
+
+
+@dataclass(frozen=True, eq=False, slots=True)
+class Person:
+    _index: int = field(repr=False)
+    name: str
+    woman: bool
+    score: float
+    _spouse: int
+    _father: int
+    _mother: int
+    SCORE_MIN = 1.0
+    """min of score column values"""
+    SCORE_MAX = 3.2
+    """max of score column values"""
+    SCORE_SUM = 8.4
+    """sum of score column values"""
+    SCORE_MEAN = 2.1
+    """mean of score column values"""
+
+    @staticmethod
+    def score_range(start: float, stop: float) -> Iterator[Person]:
+        return _rows(PERSON_TABLE, _PERSON_SCORE_INDEX, start, stop, lambda r: r.score)
+
+    @staticmethod
+    def score_count(start: float, stop: float) -> int:
+        """Number of rows returned by score_range"""
+        lo, hi = _bounds(PERSON_TABLE, _PERSON_SCORE_INDEX, start, stop, lambda r: r.score)
+        return hi - lo
+
+    @property
+    def spouse(self) -> Person:
+        return PERSON_TABLE[self._spouse]
+
+    @property
+    def father(self) -> Optional[Person]:
+        return PERSON_TABLE[self._father - 1] if self._father else None
+
+    @property
+    def mother(self) -> Optional[Person]:
+        return PERSON_TABLE[self._mother - 1] if self._mother else None
+
+    @property
+    def wdata(self) -> Iterator[Wikidata]:
+        cons = self._index
+        return _rows(WIKIDATA_TABLE, _WIKIDATA_OBJECT_INDEX, cons, cons, lambda r: r._object)
+
+    @property
+    def congress(self) -> Iterator[Congress]:
+        cons = self._index + 1
+        return _rows(CONGRESS_TABLE, _CONGRESS_OBJECT_INDEX, cons, cons, lambda r: r._object)
+
+    @staticmethod
+    def from_persons(label: Persons) -> Person:
+        """Row corresponding to label"""
+        return PERSON_TABLE[label]
+
+    @property
+    def persons(self) -> Persons:
+        """Label of the current row"""
+        return Persons(self._index)
+
+    @staticmethod
+    def len() -> int:
+        """Number of rows in the table"""
+        return len(PERSON_TABLE)
+
+    def as_index(self) -> int:
+        """Index of the current record in the table"""
+        return self._index
+
+
+@dataclass(frozen=True, eq=False, slots=True)
+class Strencoding:
+    _index: int = field(repr=False)
+    text: str
+
+    @staticmethod
+    def text_range(start: str, stop: str) -> Iterator[Strencoding]:
+        return _rows(STRENCODING_TABLE, _STRENCODING_TEXT_INDEX, start, stop, lambda r: r.text)
+
+    @staticmethod
+    def text_count(start: str, stop: str) -> int:
+        """Number of rows returned by text_range"""
+        lo, hi = _bounds(STRENCODING_TABLE, _STRENCODING_TEXT_INDEX, start, stop, lambda r: r.text)
+        return hi - lo
+
+    @staticmethod
+    def len() -> int:
+        """Number of rows in the table"""
+        return len(STRENCODING_TABLE)
+
+    def as_index(self) -> int:
+        """Index of the current record in the table"""
+        return self._index
+
+    @staticmethod
+    def array() -> Tuple[Strencoding, ...]:
+        """Tuple containing all the values"""
+        return STRENCODING_TABLE
+
+
+@dataclass(frozen=True, eq=False, slots=True)
+class Lettercase:
+    _index: int = field(repr=False)
+    name: str
+    transformer: Callable[[str], str]
+    point: colobject.Point
+
+    @property
+    def wdata2(self) -> Iterator[Wikidata]:
+        cons = self._index + 4
+        return _rows(WIKIDATA_TABLE, _WIKIDATA_OBJECT_INDEX, cons, cons, lambda r: r._object)
+
+    @property
+    def congress(self) -> Iterator[Congress]:
+        cons = self._index + 5
+        return _rows(CONGRESS_TABLE, _CONGRESS_OBJECT_INDEX, cons, cons, lambda r: r._object)
+
+    @staticmethod
+    def from_lettercases(label: Lettercases) -> Lettercase:
+        """Row corresponding to label"""
+        return LETTERCASE_TABLE[label]
+
+    @property
+    def lettercases(self) -> Lettercases:
+        """Label of the current row"""
+        return Lettercases(self._index)
+
+    @staticmethod
+    def len() -> int:
+        """Number of rows in the table"""
+        return len(LETTERCASE_TABLE)
+
+    def as_index(self) -> int:
+        """Index of the current record in the table"""
+        return self._index
+
+
+@dataclass(frozen=True, eq=False, slots=True)
+class Wikidata:
+    _index: int = field(repr=False)
+    qid: int
+    _object: int
+
+    @property
+    def object(self) -> Union[Person, Lettercase]:
+        v = self._object
+        if v <= 3:
+            return PERSON_TABLE[v]
+        if v <= 6:
+            return LETTERCASE_TABLE[v - 4]
+        raise IndexError("insrcdata variant index overflow in Wikidata")
+
+    @staticmethod
+    def len() -> int:
+        """Number of rows in the table"""
+        return len(WIKIDATA_TABLE)
+
+    def as_index(self) -> int:
+        """Index of the current record in the table"""
+        return self._index
+
+    @staticmethod
+    def array() -> Tuple[Wikidata, ...]:
+        """Tuple containing all the values"""
+        return WIKIDATA_TABLE
+
+
+@dataclass(frozen=True, eq=False, slots=True)
+class Congress:
+    _index: int = field(repr=False)
+    lccn: str
+    _object: int
+
+    @property
+    def object(self) -> Union[None, Person, Lettercase]:
+        v = self._object
+        if v <= 0:
+            return None
+        if v <= 4:
+            return PERSON_TABLE[v - 1]
+        if v <= 7:
+            return LETTERCASE_TABLE[v - 5]
+        raise IndexError("insrcdata variant index overflow in Congress")
+
+    @staticmethod
+    def len() -> int:
+        """Number of rows in the table"""
+        return len(CONGRESS_TABLE)
+
+    def as_index(self) -> int:
+        """Index of the current record in the table"""
+        return self._index
+
+    @staticmethod
+    def array() -> Tuple[Congress, ...]:
+        """Tuple containing all the values"""
+        return CONGRESS_TABLE
+
+
+PERSON_TABLE: Tuple[Person, ...] = (
+    Person(0, "Marie Curie", True, 1.0, 1, 0, 0),
+    Person(1, "Pierre Curie", False, 2.1, 0, 0, 0),
+    Person(2, "Irène Joliot-Curie", True, 3.2, 3, 2, 1),
+    Person(3, "Frédéric Joliot-Curie", False, 2.1, 2, 0, 0),
+)
+_PERSON_SCORE_INDEX: Tuple[int, ...] = (
+    0, 1, 3, 2, 
+)
+
+STRENCODING_TABLE: Tuple[Strencoding, ...] = (
+    Strencoding(0, "𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ"),
+    Strencoding(1, "hello"),
+    Strencoding(2, "κόσμε"),
+    Strencoding(3, "いろはにほへとちりぬるを"),
+    Strencoding(4, "éventuellement validé"),
+    Strencoding(5, "Да, но фальшивый экземпляр"),
+)
+_STRENCODING_TEXT_INDEX: Tuple[int, ...] = (
+    1, 4, 2, 5, 3, 0, 
+)
+
+LETTERCASE_TABLE: Tuple[Lettercase, ...] = (
+    Lettercase(0, "Capitalised case", colobject.make_capitalize, colobject.POINT_ZERO),
+    Lettercase(1, "Upper case", colobject.make_upper, colobject.POINT_ONE),
+    Lettercase(2, "Lower case", colobject.make_lower, colobject.POINT_ONE),
+)
+
+WIKIDATA_TABLE: Tuple[Wikidata, ...] = (
+    Wikidata(0, 7186, 0),
+    Wikidata(1, 8185162, 6),
+    Wikidata(2, 150989, 3),
+)
+_WIKIDATA_OBJECT_INDEX: Tuple[int, ...] = (
+    0, 2, 1, 
+)
+
+CONGRESS_TABLE: Tuple[Congress, ...] = (
+    Congress(0, "n2009011553", 1),
+    Congress(1, "sh85148650", 7),
+    Congress(2, "n80159913", 4),
+    Congress(3, "n79006404", 0),
+)
+_CONGRESS_OBJECT_INDEX: Tuple[int, ...] = (
+    0, 2, 1, 
+)
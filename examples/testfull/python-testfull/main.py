@@ -0,0 +1,124 @@
+#!/usr/bin/env python3
+import insrcdata as db
+
+
+# the join column reference a record in the same table
+def test_innerjoin():
+    marie = db.Person.from_persons(db.Persons.MARIE)
+    pierre = db.Person.from_persons(db.Persons.PIERRE)
+    irene = db.Person.from_persons(db.Persons.IRENE)
+    frederic = db.Person.from_persons(db.Persons.FREDERIC)
+
+    assert marie.name == "Marie Curie"
+    assert pierre.name == "Pierre Curie"
+    assert irene.name == "Irène Joliot-Curie"
+    assert frederic.name == "Frédéric Joliot-Curie"
+
+    # inner join with 1-1 cardinality
+    assert marie.spouse is pierre
+    assert pierre.spouse is marie
+    assert irene.spouse is frederic
+    assert frederic.spouse is irene
+
+    # inner join with 0-1 cardinality
+    assert marie.mother is None
+    assert marie.father is None
+    assert pierre.mother is None
+    assert frederic.father is None
+    assert irene.mother is marie
+    assert irene.father is pierre
+
+
+# bool data type
+def test_bool():
+    assert db.Person.from_persons(db.Persons.MARIE).woman
+    assert not db.Person.from_persons(db.Persons.PIERRE).woman
+
+
+# float data type
+def test_float():
+    assert db.Person.from_persons(db.Persons.MARIE).score == 1.0
+    assert db.Person.from_persons(db.Persons.PIERRE).score == 2.1
+
+    # the range is stable : table order is preserved for equal values
+    middle = [p.name for p in db.Person.score_range(2.1, 3.2)]
+    assert middle == ["Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie"]
+
+    # reversed, under and over
+    assert list(db.Person.score_range(2.0, 1.9)) == []
+    assert db.Person.score_count(0.0, 0.9) == 0
+    assert db.Person.score_count(0.0, 1.0) == 1
+    assert db.Person.score_count(10.0, 9000.0) == 0
+    assert db.Person.score_count(3.2, 9000.0) == 1
+    assert db.Person.len() == 4
+
+    # aggregates
+    assert db.Person.SCORE_MIN == 1.0
+    assert db.Person.SCORE_MAX == 3.2
+
+
+# retrieve label from record reference
+def test_fictolabel():
+    assert db.Person.from_persons(db.Persons.IRENE).persons == db.Persons.IRENE
+    assert db.Lettercase.from_lettercases(db.Lettercases.LOWER).lettercases == db.Lettercases.LOWER
+
+
+# check string comparison for various encoded unicode strings
+def test_strencoding():
+    texts = [s.text for s in db.Strencoding.text_range("", "\U0010ffff")]
+    assert texts == sorted(s.text for s in db.Strencoding.array())
+    assert texts[0] == "hello"
+    assert db.Strencoding.text_count("hello", "hello") == 1
+
+
+# object type column : reference to native objects
+def test_colobject():
+    upper = db.Lettercase.from_lettercases(db.Lettercases.UPPER)
+    assert upper.transformer("hello") == "HELLO"
+    assert upper.point.x == 1.0
+
+    capital = db.Lettercase.from_lettercases(db.Lettercases.CAPITAL)
+    assert capital.transformer("hello") == "Hello"
+    assert capital.point.x == 0.0
+
+
+# variant
+def test_variant_non_optional():
+    q_marie = db.Wikidata.array()[0]
+    assert q_marie.object is db.Person.from_persons(db.Persons.MARIE)
+
+    q_lower = db.Wikidata.array()[1]
+    lower = db.Lettercase.from_lettercases(db.Lettercases.LOWER)
+    assert q_lower.object is lower
+    assert list(lower.wdata2) == [q_lower]
+
+    assert list(db.Person.from_persons(db.Persons.PIERRE).wdata) == []
+
+
+# variant with unmatched rows
+def test_variant_optional():
+    q_marie = db.Congress.array()[0]
+    assert q_marie.object is db.Person.from_persons(db.Persons.MARIE)
+
+    q_lower = db.Congress.array()[1]
+    lower = db.Lettercase.from_lettercases(db.Lettercases.LOWER)
+    assert isinstance(q_lower.object, db.Lettercase)
+    assert q_lower.object is lower
+    assert list(lower.congress) == [q_lower]
+
+    assert list(db.Person.from_persons(db.Persons.PIERRE).congress) == []
+
+    # not matched
+    q_france = db.Congress.array()[3]
+    assert q_france.object is None
+
+
+if __name__ == "__main__":
+    test_innerjoin()
+    test_bool()
+    test_float()
+    test_fictolabel()
+    test_strencoding()
+    test_colobject()
+    test_variant_non_optional()
+    test_variant_optional()
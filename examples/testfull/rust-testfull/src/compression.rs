@@ -0,0 +1,25 @@
+use crate::insrcdata as db;
+
+// compressed str columns : values are decoded on demand
+pub fn test_compression() {
+    let q_marie = &db::Congress::array()[0];
+
+    // decode in a caller supplied buffer without allocation
+    let mut buf = [0u8; db::Congress::LCCN_MAX_LEN];
+    assert!(q_marie.lccn_decode(&mut buf) == Some("n2009011553"));
+    assert!(q_marie.lccn_decode(&mut [0u8; 4]).is_none());
+
+    // allocated copy
+    assert!(q_marie.lccn() == "n2009011553");
+
+    // compressed values are shown by Debug
+    assert!(format!("{q_marie:?}").contains("lccn: \"n2009011553\""));
+
+    // and by fields() as a decoded string
+    let lccn = q_marie.fields().into_iter().find(|(name, _)| *name == "lccn");
+    assert!(matches!(lccn, Some((_, db::FieldValue::String(v))) if v == "n2009011553"));
+
+    // range search on huffman compressed column
+    let hello: Vec<String> = db::Strencoding::text_range("h", "i").map(|r| r.text()).collect();
+    assert!(hello == ["hello"]);
+}
@@ -19,7 +19,15 @@ fn nobel_year(x: &db::Person) -> u16 {
     //  to embed enum reference in table see labels sample for usage informations
 }
 
+// const fn accessors : table values are available at compile time
+const PIERRE: &db::Person = db::Person::from_persons(db::Persons::Pierre).spouse().spouse();
+const MIDDLE_SCORES: usize = db::Person::score_count(2.1, 3.2);
+static SCORE_BUCKETS: [u8; MIDDLE_SCORES] = [0; MIDDLE_SCORES];
+
 pub fn test_fictolabel() {
+    assert!(PIERRE.name() == "Pierre Curie");
+    assert!(SCORE_BUCKETS.len() == 3);
+
     let marie: &db::Person = db::Persons::Marie.into();
     let irene: &db::Person = db::Persons::Irene.into();
 
@@ -27,6 +27,14 @@ pub fn test_innerjoin() {
     assert!(frederic.father().is_none());
     assert!(marie == irene.mother().expect("Irene has mother"));
     assert!(pierre == irene.father().expect("Irene has father"));
+
+    // closure of self join
+    let ancestors: Vec<(&db::Person, usize)> = irene.father_ancestors().collect();
+    assert!(ancestors == vec![(pierre, 1)]);
+    assert!(pierre.father_ancestors().next().is_none());
+    assert!(pierre.father_is_ancestor_of(irene));
+    assert!(!irene.father_is_ancestor_of(pierre));
+    assert!(!marie.father_is_ancestor_of(irene));
 }
 
 pub fn test_bool() {
@@ -45,6 +53,21 @@ pub fn test_bool() {
         .collect();
     assert!(womens == vec!["Marie Curie", "Irène Joliot-Curie"]);
     */
+
+    // test bitmap index
+    let womens: Vec<String> = db::Person::where_woman(true)
+        .iter()
+        .map(|n| n.name().to_string())
+        .collect();
+    assert!(womens == vec!["Marie Curie", "Irène Joliot-Curie"]);
+    assert!(db::Person::where_woman(false).count() == 2);
+
+    // combine bitmaps
+    let womens = db::Person::where_woman(true);
+    let mens = db::Person::where_woman(false);
+    assert!(womens.and(&mens).is_empty());
+    assert!(womens.or(&mens).count() == 4);
+    assert!(womens.and(&womens) == womens);
 }
 
 pub fn test_float() {
@@ -85,6 +108,28 @@ pub fn test_float() {
     // over
     assert!(db::Person::score_range(10.0, 9000.0).count() == 0);
     assert!(db::Person::score_range(3.2, 9000.0).count() == 1);
+
+    // count without iteration
+    assert!(db::Person::score_count(2.1, 3.2) == 3);
+    assert!(db::Person::score_count(2.0, 1.9) == 0);
+    assert!(db::Person::len() == 4);
+
+    // aggregates
+    assert_eq!(db::Person::SCORE_MIN, 1.0);
+    assert_eq!(db::Person::SCORE_MAX, 3.2);
+    assert_eq!(db::Person::SCORE_SUM, 1.0 + 2.1 + 3.2 + 2.1);
+    assert_eq!(db::Person::SCORE_MEAN, db::Person::SCORE_SUM / 4.0);
+
+    // groups
+    let groups: Vec<(f64, usize)> = db::Person::score_groups()
+        .map(|(score, rows)| (score, rows.count()))
+        .collect();
+    assert!(groups == vec![(1.0, 1), (2.1, 2), (3.2, 1)]);
+    for (score, rows) in db::Person::score_groups() {
+        for p in rows {
+            assert!(p.score() == score);
+        }
+    }
 }
 
 // variant column
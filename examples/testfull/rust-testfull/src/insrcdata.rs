@@ -1,5 +1,72 @@
 // generated by insrcdata version 0.3.0
 
+/// Dynamic value of a column returned by fields()
+#[derive(Clone, Debug)]
+pub enum FieldValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+    /// compressed string column, decoded
+    String(String),
+    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}
+
+fn huffman_decode<'a>(blob: &[u8], counts: &[u16], symbols: &[u16], offset: usize, buf: &'a mut [u8]) -> Option<&'a str> {
+    let mut bit = offset * 8;
+    let mut len = 0;
+    loop {
+        // canonical code : codes of each length follow the codes of shorter length
+        let (mut code, mut first, mut index) = (0, 0, 0);
+        let mut symbol = 256;
+        for count in &counts[1..] {
+            code |= ((blob[bit / 8] >> (7 - bit % 8)) & 1) as usize;
+            bit += 1;
+            let count = *count as usize;
+            if code < first + count {
+                symbol = symbols[index + code - first] as usize;
+                break;
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        if symbol == 256 {
+            break;
+        }
+        *buf.get_mut(len)? = symbol as u8;
+        len += 1;
+    }
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
+fn fsst_decode<'a>(blob: &[u8], symbols: &[u8], starts: &[u16], offset: usize, buf: &'a mut [u8]) -> Option<&'a str> {
+    let mut pos = offset;
+    let mut len = 0;
+    loop {
+        let code = blob[pos] as usize;
+        pos += 1;
+        let bytes = match code {
+            254 => break,
+            255 => {
+                pos += 1;
+                &blob[pos - 1..pos]
+            }
+            _ => &symbols[starts[code] as usize..starts[code + 1] as usize],
+        };
+        buf.get_mut(len..len + bytes.len())?.copy_from_slice(bytes);
+        len += bytes.len();
+    }
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Persons {
@@ -20,40 +87,48 @@ impl From<&Persons> for  &'static Person{
 }
 impl PartialEq<Persons> for &Person {
     fn eq(&self, other: &Persons) -> bool {
-        std::ptr::eq(<&Person>::from(other), *self)
+        core::ptr::eq(<&Person>::from(other), *self)
+    }
+}
+
+impl Person {
+    /// Row corresponding to label, usable in const context
+    pub const fn from_persons(value: Persons) -> &'static Person {
+        &person::TABLE[value as usize]
     }
 }
 
 pub struct Person {
-    name_ : &'static str,
-    woman_ : bool,
-    score_ : f64,
-    spouse_ : u8,
-    father_ : u8,
-    mother_ : u8,
+    index_ : u8,
 }
 impl PartialEq<Self> for Person {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Person {}
-impl std::hash::Hash for Person {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Person {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         person::index_of(self).hash(state);
     }
 }
 
 impl Person {
-    pub fn name(&self) -> &'static str { self.name_ }
-    pub fn woman(&self) -> bool { self.woman_ }
-    pub fn score(&self) -> f64 { self.score_ }
+    pub const fn name(&self) -> &'static str { person::NAME_VALUES[self.index_ as usize] }
+    pub const fn woman(&self) -> bool { (person::PACKED_VALUES[self.index_ as usize][0] & 0x1) != 0 }
+    pub fn where_woman(value:bool) -> PersonBitmap {
+        match person::WOMAN_BITMAP_VALUES.binary_search_by(|v| v.cmp(&value)) {
+            Ok(i) => person::WOMAN_BITMAPS[i],
+            Err(_) => PersonBitmap::EMPTY,
+        }
+    }
+    pub const fn score(&self) -> f64 { person::SCORE_VALUES[self.index_ as usize] }
     pub fn score_range(start:f64, stop:f64) -> person::IndexIter {
         let mut lo = 0;
         let mut hi = person::SCORE_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if start > person::TABLE[person::SCORE_INDEX[mid] as usize].score_ {
+            if start > person::SCORE_VALUES[person::SCORE_INDEX[mid] as usize] {
                  lo = mid + 1;
             } else {
                  hi = mid;
@@ -64,23 +139,75 @@ impl Person {
         hi = person::SCORE_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if stop < person::TABLE[person::SCORE_INDEX[mid] as usize].score_ {
+            if stop < person::SCORE_VALUES[person::SCORE_INDEX[mid] as usize] {
                 hi = mid;
             } else {
                 lo = mid + 1;
             }
         }
         person::IndexIter {
-            indexes: Box::new(person::SCORE_INDEX[begin..lo].iter()),
+            indexes: person::SCORE_INDEX[begin..lo].iter(),
         }
     }
-    pub fn spouse(&self) -> &'static Person { &person::TABLE[self.spouse_ as usize]}
-    pub fn father(&self) -> Option<&'static Person> {
-        let index = self.father_;
+    /// Number of rows returned by score_range
+    pub const fn score_count(start:f64, stop:f64) -> usize {
+        let mut lo = 0;
+        let mut hi = person::SCORE_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > person::SCORE_VALUES[person::SCORE_INDEX[mid] as usize] {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = person::SCORE_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < person::SCORE_VALUES[person::SCORE_INDEX[mid] as usize] {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo - begin
+    }
+    pub fn score_groups() -> person::GroupIter<f64> {
+        person::GroupIter {
+            index: &person::SCORE_INDEX,
+            bounds: person::SCORE_GROUPS.windows(2),
+            value: Person::score,
+        }
+    }
+    /// min of score column values
+    pub const SCORE_MIN: f64 = 1.0;
+    /// max of score column values
+    pub const SCORE_MAX: f64 = 3.2;
+    /// sum of score column values
+    pub const SCORE_SUM: f64 = 8.4;
+    /// mean of score column values
+    pub const SCORE_MEAN: f64 = 2.1;
+    pub const fn spouse(&self) -> &'static Person { &person::TABLE[((person::PACKED_VALUES[self.index_ as usize][0] >> 1) & 0x3) as usize]}
+    pub const fn father(&self) -> Option<&'static Person> {
+        let index = (person::PACKED_VALUES[self.index_ as usize][0] >> 3) & 0x3;
         if index==0 { None } else { Some(&person::TABLE[index as usize -1]) }
     }
-    pub fn mother(&self) -> Option<&'static Person> {
-        let index = self.mother_;
+    pub fn father_ancestors(&self) -> person::AncestorIter {
+        person::AncestorIter {
+            row: Some(&person::TABLE[person::index_of(self)]),
+            depth: 0,
+            parent: |s| s.father().filter(|p| !core::ptr::eq(*p, s)),
+        }
+    }
+    /// Check if this row is reached by following father links from other row
+    pub fn father_is_ancestor_of(&self, other: &Self) -> bool {
+        let (row, other) = (person::index_of(self), person::index_of(other));
+        person::FATHER_ENTER[row] < person::FATHER_ENTER[other] && person::FATHER_ENTER[other] < person::FATHER_EXIT[row]
+    }
+    pub const fn mother(&self) -> Option<&'static Person> {
+        let index = (person::PACKED_VALUES[self.index_ as usize][0] >> 5) & 0x1;
         if index==0 { None } else { Some(&person::TABLE[index as usize -1]) }
     }
     pub fn wdata(&self) -> WikidataIter {
@@ -91,7 +218,7 @@ impl Person {
         let mut hi = wikidata::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons > wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].object_ {
+            if cons > ((wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].packed_[0] >> 23) & 0x7) as u8 {
                 lo = mid + 1;
             } else {
                 hi = mid;
@@ -103,7 +230,7 @@ impl Person {
         hi = wikidata::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons < wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].object_  {
+            if cons < ((wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].packed_[0] >> 23) & 0x7) as u8  {
                 hi = mid;
             } else {
                 lo = mid + 1;
@@ -111,7 +238,7 @@ impl Person {
         }
 
         wikidata::IndexIter {
-            indexes: Box::new(wikidata::OBJECT_INDEX[start..lo].iter()),
+            indexes: wikidata::OBJECT_INDEX[start..lo].iter(),
         }
     }
     pub fn congress(&self) -> CongressIter {
@@ -122,7 +249,7 @@ impl Person {
         let mut hi = congress::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons > congress::TABLE[congress::OBJECT_INDEX[mid] as usize].object_ {
+            if cons > congress::BLOB[congress::OBJECT_INDEX[mid] as usize * congress::ROW_SIZE + 1] {
                 lo = mid + 1;
             } else {
                 hi = mid;
@@ -134,7 +261,7 @@ impl Person {
         hi = congress::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons < congress::TABLE[congress::OBJECT_INDEX[mid] as usize].object_  {
+            if cons < congress::BLOB[congress::OBJECT_INDEX[mid] as usize * congress::ROW_SIZE + 1]  {
                 hi = mid;
             } else {
                 lo = mid + 1;
@@ -142,18 +269,51 @@ impl Person {
         }
 
         congress::IndexIter {
-            indexes: Box::new(congress::OBJECT_INDEX[start..lo].iter()),
+            indexes: congress::OBJECT_INDEX[start..lo].iter(),
         }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 6] = ["name", "woman", "score", "spouse", "father", "mother"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 6] {
+        [
+            ("name", FieldValue::Str(self.name())),
+            ("woman", FieldValue::Bool(self.woman())),
+            ("score", FieldValue::Float(self.score())),
+            ("spouse", FieldValue::Join("Person", person::index_of(self.spouse()))),
+            ("father", match self.father() { Some(v) => FieldValue::Join("Person", person::index_of(v)), None => FieldValue::Null }),
+            ("mother", match self.mother() { Some(v) => FieldValue::Join("Person", person::index_of(v)), None => FieldValue::Null }),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 4 }
+}
+
+impl core::fmt::Debug for Person {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Person")
+            .field("name", &self.name())
+            .field("woman", &self.woman())
+            .field("score", &self.score())
+            .field("spouse", &person::DebugRef(self.spouse()))
+            .field("father", &self.father().map(person::DebugRef))
+            .field("mother", &self.mother().map(person::DebugRef))
+            .finish()
+    }
+}
+impl core::fmt::Display for Person {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.name(), f)
+    }
 }
 
 mod person {use super::*;
 
 pub fn index_of(fic:&Person) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Person>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Person>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -166,49 +326,211 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IndexIter {}
+
+
+pub struct DebugRef(pub &'static Person);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match index_of(self.0) {
+            0 => f.write_str("Persons::Marie"),
+            1 => f.write_str("Persons::Pierre"),
+            2 => f.write_str("Persons::Irene"),
+            3 => f.write_str("Persons::Frederic"),
+            i => write!(f, "Person[{i}]"),
+        }
+    }
+}
+pub struct GroupIter<T> {
+    pub index : &'static [u8],
+    pub bounds : core::slice::Windows<'static, u8>,
+    pub value : fn(&'static Person) -> T,
+}
+
+impl<T> Iterator for GroupIter<T> {
+    type Item = (T, IndexIter);
+
+    fn next(&mut self) -> Option<(T, IndexIter)> {
+        let bounds = self.bounds.next()?;
+        let rows = &self.index[bounds[0] as usize..bounds[1] as usize];
+        let value = (self.value)(&TABLE[rows[0] as usize]);
+        Some((value, IndexIter { indexes: rows.iter() }))
+    }
+}
+
+pub struct AncestorIter {
+    pub row : Option<&'static Person>,
+    pub depth : usize,
+    pub parent : fn(&'static Person) -> Option<&'static Person>,
 }
 
+impl Iterator for AncestorIter {
+    type Item = (&'static Person, usize);
 
-const fn r(name:&'static str, woman:bool, score:f64, spouse:u8, father:u8, mother:u8, ) -> Person {
-    Person{name_:name, woman_:woman, score_:score, spouse_:spouse, father_:father, mother_:mother, }
+    fn next(&mut self) -> Option<(&'static Person, usize)> {
+        self.row = (self.parent)(self.row?);
+        self.depth += 1;
+        self.row.map(|row| (row, self.depth))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    words : [u64; 1],
+}
+
+impl Bitmap {
+    /// Bitmap without any row
+    pub const EMPTY: Bitmap = Bitmap { words: [0; 1] };
+
+    /// Rows present in both bitmaps
+    pub fn and(&self, other: &Bitmap) -> Bitmap {
+        let mut words = self.words;
+        for (word, o) in words.iter_mut().zip(other.words.iter()) {
+            *word &= o;
+        }
+        Bitmap { words }
+    }
+
+    /// Rows present in at least one of the bitmaps
+    pub fn or(&self, other: &Bitmap) -> Bitmap {
+        let mut words = self.words;
+        for (word, o) in words.iter_mut().zip(other.words.iter()) {
+            *word |= o;
+        }
+        Bitmap { words }
+    }
+
+    /// Number of rows in bitmap
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Check if bitmap has no row
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Iterate the rows in table order
+    pub fn iter(&self) -> BitmapIter {
+        BitmapIter { words: self.words, word: 0 }
+    }
+}
+
+impl IntoIterator for Bitmap {
+    type Item = &'static Person;
+    type IntoIter = BitmapIter;
+
+    fn into_iter(self) -> BitmapIter {
+        self.iter()
+    }
+}
+
+pub struct BitmapIter {
+    words : [u64; 1],
+    word : usize,
+}
+
+impl Iterator for BitmapIter {
+    type Item = &'static Person;
+
+    fn next(&mut self) -> Option<&'static Person> {
+        while self.word < 1 {
+            let w = self.words[self.word];
+            if w != 0 {
+                self.words[self.word] = w & (w - 1);
+                return Some(&TABLE[self.word * 64 + w.trailing_zeros() as usize]);
+            }
+            self.word += 1;
+        }
+        None
+    }
 }
 
 pub static TABLE : [ Person ; 4 ] = [
-   {r("Marie Curie", true, 1.0, 1, 0, 0, )},
-   {r("Pierre Curie", false, 2.1, 0, 0, 0, )},
-   {r("Irène Joliot-Curie", true, 3.2, 3, 2, 1, )},
-   {r("Frédéric Joliot-Curie", false, 2.1, 2, 0, 0, )},
+    Person{index_:0}, Person{index_:1}, Person{index_:2}, Person{index_:3}, 
+];
+pub static NAME_VALUES : [ &str ; 4 ] = [
+    "Marie Curie",
+    "Pierre Curie",
+    "Irène Joliot-Curie",
+    "Frédéric Joliot-Curie",
+];
+pub static SCORE_VALUES : [ f64 ; 4 ] = [
+    1.0,
+    2.1,
+    3.2,
+    2.1,
+];
+pub static PACKED_VALUES : [ [u8; 1] ; 4 ] = [
+    [0x3],
+    [0x0],
+    [0x37],
+    [0x4],
+];
+pub static WOMAN_BITMAP_VALUES : [ bool ; 2 ] = [
+    false, true, 
+];
+pub static WOMAN_BITMAPS : [ Bitmap ; 2 ] = [
+    Bitmap { words: [0x000000000000000a, ] },
+    Bitmap { words: [0x0000000000000005, ] },
 ];
 pub static SCORE_INDEX : [ u8 ; 4 ] = [
     0, 1, 3, 2, 
 ];
+pub static SCORE_GROUPS : [ u8 ; 4 ] = [
+    0, 1, 3, 4, 
+];
+pub static FATHER_ENTER : [ u8 ; 4 ] = [
+    0, 1, 2, 3, 
+];
+pub static FATHER_EXIT : [ u8 ; 4 ] = [
+    1, 3, 3, 4, 
+];
 
 } // mod person
 
 pub use person::IndexIter as PersonIter;
+pub use person::GroupIter as PersonGroups;
+pub use person::AncestorIter as PersonAncestors;
+pub use person::Bitmap as PersonBitmap;
+pub use person::BitmapIter as PersonBitmapIter;
 pub struct Strencoding {
-    text_ : &'static str,
+    text_ : u8,
 }
 impl PartialEq<Self> for Strencoding {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Strencoding {}
-impl std::hash::Hash for Strencoding {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Strencoding {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         strencoding::index_of(self).hash(state);
     }
 }
 
 impl Strencoding {
-    pub fn text(&self) -> &'static str { self.text_ }
+    /// Size of a buffer large enough to decode any text value
+    pub const TEXT_MAX_LEN: usize = 72;
+    /// Decode text in buf, None if buf is smaller than the value
+    pub fn text_decode<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> { huffman_decode(&strencoding::TEXT_BLOB, &strencoding::TEXT_COUNTS, &strencoding::TEXT_SYMBOLS, self.text_ as usize, buf) }
+    pub fn text(&self) -> String {
+        let mut buf = [0u8; Self::TEXT_MAX_LEN];
+        String::from(self.text_decode(&mut buf).unwrap_or_default())
+    }
     pub fn text_range(start:& str, stop:& str) -> strencoding::IndexIter {
         let mut lo = 0;
         let mut hi = strencoding::TEXT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if start > strencoding::TABLE[strencoding::TEXT_INDEX[mid] as usize].text_ {
+            if start > huffman_decode(&strencoding::TEXT_BLOB, &strencoding::TEXT_COUNTS, &strencoding::TEXT_SYMBOLS, strencoding::TABLE[strencoding::TEXT_INDEX[mid] as usize].text_ as usize, &mut [0u8; 72]).unwrap_or_default() {
                  lo = mid + 1;
             } else {
                  hi = mid;
@@ -219,29 +541,72 @@ impl Strencoding {
         hi = strencoding::TEXT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if stop < strencoding::TABLE[strencoding::TEXT_INDEX[mid] as usize].text_ {
+            if stop < huffman_decode(&strencoding::TEXT_BLOB, &strencoding::TEXT_COUNTS, &strencoding::TEXT_SYMBOLS, strencoding::TABLE[strencoding::TEXT_INDEX[mid] as usize].text_ as usize, &mut [0u8; 72]).unwrap_or_default() {
                 hi = mid;
             } else {
                 lo = mid + 1;
             }
         }
         strencoding::IndexIter {
-            indexes: Box::new(strencoding::TEXT_INDEX[begin..lo].iter()),
+            indexes: strencoding::TEXT_INDEX[begin..lo].iter(),
         }
     }
+    /// Number of rows returned by text_range
+    pub fn text_count(start:& str, stop:& str) -> usize {
+        let mut lo = 0;
+        let mut hi = strencoding::TEXT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if start > huffman_decode(&strencoding::TEXT_BLOB, &strencoding::TEXT_COUNTS, &strencoding::TEXT_SYMBOLS, strencoding::TABLE[strencoding::TEXT_INDEX[mid] as usize].text_ as usize, &mut [0u8; 72]).unwrap_or_default() {
+                 lo = mid + 1;
+            } else {
+                 hi = mid;
+            }
+        }
+
+        let begin = lo;
+        hi = strencoding::TEXT_INDEX.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if stop < huffman_decode(&strencoding::TEXT_BLOB, &strencoding::TEXT_COUNTS, &strencoding::TEXT_SYMBOLS, strencoding::TABLE[strencoding::TEXT_INDEX[mid] as usize].text_ as usize, &mut [0u8; 72]).unwrap_or_default() {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo - begin
+    }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 1] = ["text"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 1] {
+        [
+            ("text", FieldValue::String(self.text())),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 6 }
     /// Reference to the table containing all the values
-    pub fn array() -> &'static [Strencoding; 6] { &strencoding::TABLE }
+    pub const fn array() -> &'static [Strencoding; 6] { &strencoding::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { strencoding::index_of(self) }
 }
 
+impl core::fmt::Debug for Strencoding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Strencoding")
+            .field("text", &self.text_decode(&mut [0u8; Self::TEXT_MAX_LEN]).unwrap_or_default())
+            .finish()
+    }
+}
+
 mod strencoding {use super::*;
 
 pub fn index_of(fic:&Strencoding) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Strencoding>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Strencoding>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -254,24 +619,49 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
 
-const fn r(text:&'static str, ) -> Strencoding {
+const fn r(text:u8, ) -> Strencoding {
     Strencoding{text_:text, }
 }
 
 pub static TABLE : [ Strencoding ; 6 ] = [
-   {r("𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ", )},
-   {r("hello", )},
-   {r("κόσμε", )},
-   {r("いろはにほへとちりぬるを", )},
-   {r("éventuellement validé", )},
-   {r("Да, но фальшивый экземпляр", )},
+   {r(0, )},
+   {r(53, )},
+   {r(58, )},
+   {r(67, )},
+   {r(92, )},
+   {r(112, )},
 ];
 pub static TEXT_INDEX : [ u8 ; 6 ] = [
     1, 4, 2, 5, 3, 0, 
 ];
+pub static TEXT_BLOB : [ u8 ; 146 ] = [
+    106, 175, 244, 241, 113, 170, 197,   3,  34, 239,   8, 205, 128, 103, 198,  43,  88, 203,  17, 189, 
+    204, 213,  71, 148, 101, 182,  51,  89, 169, 253,  77,  86,  56,  76,  50, 192, 213,  97, 140, 104, 
+    144,  87, 188, 213,  90, 182,  51, 237, 243,  37,  91, 115,  56, 250,  58,  17, 250, 224, 166, 127, 
+     80, 122, 179,  79,  42,  86, 224,  36, 190,  79,  66,  73, 214,  73, 144, 147,  65,  38,  98,  78, 
+    114,  78,  66, 122, 233,  39,  73,  61, 120, 158, 190, 224, 237, 162, 193, 235,  87, 254,  58,  16, 
+    127, 195, 214, 174, 107,  31,   8, 251, 249, 237, 161, 192,   8, 195, 103, 177, 131, 152, 116,  51, 
+     31,  13, 129,  67,  32, 101, 192, 152,  55,  50, 240, 113,  51,  33,   9, 195, 128,  44,  57,   7, 
+     80, 161, 145,  51, 253, 192, 
+];
+pub static TEXT_COUNTS : [ u16 ; 9 ] = [
+    0, 0, 0, 0, 3, 9, 13, 37, 10, 
+];
+pub static TEXT_SYMBOLS : [ u16 ; 72 ] = [
+    208, 225, 227, 32, 101, 108, 129, 157, 181, 209, 240, 256, 130, 132, 140, 141, 143, 148, 171, 182, 
+    184, 186, 187, 206, 226, 110, 116, 118, 131, 133, 134, 136, 138, 139, 146, 147, 150, 151, 154, 161, 
+    162, 165, 168, 169, 172, 174, 175, 176, 177, 178, 179, 183, 185, 188, 189, 190, 191, 195, 196, 197, 
+    198, 207, 44, 58, 97, 100, 104, 105, 109, 111, 117, 128, 
+];
 
 } // mod strencoding
 
@@ -294,31 +684,36 @@ impl From<&Lettercases> for  &'static Lettercase{
 }
 impl PartialEq<Lettercases> for &Lettercase {
     fn eq(&self, other: &Lettercases) -> bool {
-        std::ptr::eq(<&Lettercase>::from(other), *self)
+        core::ptr::eq(<&Lettercase>::from(other), *self)
+    }
+}
+
+impl Lettercase {
+    /// Row corresponding to label, usable in const context
+    pub const fn from_lettercases(value: Lettercases) -> &'static Lettercase {
+        &lettercase::TABLE[value as usize]
     }
 }
 
 pub struct Lettercase {
-    name_ : &'static str,
-    transformer_ : fn(&str)->String,
-    point_ : &'static crate::colobject::Point,
+    index_ : u8,
 }
 impl PartialEq<Self> for Lettercase {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Lettercase {}
-impl std::hash::Hash for Lettercase {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Lettercase {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         lettercase::index_of(self).hash(state);
     }
 }
 
 impl Lettercase {
-    pub fn name(&self) -> &'static str { self.name_ }
-    pub fn transformer(&self) -> fn(&str)->String { self.transformer_ }
-    pub fn point(&self) -> &'static crate::colobject::Point { self.point_ }
+    pub const fn name(&self) -> &'static str { lettercase::str_at(self.index_ as usize * lettercase::ROW_SIZE) }
+    pub const fn transformer(&self) -> fn(&str)->String { lettercase::TRANSFORMER_VALUES[self.index_ as usize] }
+    pub const fn point(&self) -> &'static crate::colobject::Point { lettercase::POINT_VALUES[self.index_ as usize] }
     pub fn wdata2(&self) -> WikidataIter {
         let cons = lettercase::index_of(self) as u8 + 4;
 
@@ -327,7 +722,7 @@ impl Lettercase {
         let mut hi = wikidata::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons > wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].object_ {
+            if cons > ((wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].packed_[0] >> 23) & 0x7) as u8 {
                 lo = mid + 1;
             } else {
                 hi = mid;
@@ -339,7 +734,7 @@ impl Lettercase {
         hi = wikidata::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons < wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].object_  {
+            if cons < ((wikidata::TABLE[wikidata::OBJECT_INDEX[mid] as usize].packed_[0] >> 23) & 0x7) as u8  {
                 hi = mid;
             } else {
                 lo = mid + 1;
@@ -347,7 +742,7 @@ impl Lettercase {
         }
 
         wikidata::IndexIter {
-            indexes: Box::new(wikidata::OBJECT_INDEX[start..lo].iter()),
+            indexes: wikidata::OBJECT_INDEX[start..lo].iter(),
         }
     }
     pub fn congress(&self) -> CongressIter {
@@ -358,7 +753,7 @@ impl Lettercase {
         let mut hi = congress::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons > congress::TABLE[congress::OBJECT_INDEX[mid] as usize].object_ {
+            if cons > congress::BLOB[congress::OBJECT_INDEX[mid] as usize * congress::ROW_SIZE + 1] {
                 lo = mid + 1;
             } else {
                 hi = mid;
@@ -370,7 +765,7 @@ impl Lettercase {
         hi = congress::OBJECT_INDEX.len();
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if cons < congress::TABLE[congress::OBJECT_INDEX[mid] as usize].object_  {
+            if cons < congress::BLOB[congress::OBJECT_INDEX[mid] as usize * congress::ROW_SIZE + 1]  {
                 hi = mid;
             } else {
                 lo = mid + 1;
@@ -378,19 +773,39 @@ impl Lettercase {
         }
 
         congress::IndexIter {
-            indexes: Box::new(congress::OBJECT_INDEX[start..lo].iter()),
+            indexes: congress::OBJECT_INDEX[start..lo].iter(),
         }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 3] = ["name", "transformer", "point"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 3] {
+        [
+            ("name", FieldValue::Str(self.name())),
+            ("transformer", FieldValue::Object),
+            ("point", FieldValue::Object),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 3 }
+}
+
+impl core::fmt::Debug for Lettercase {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Lettercase")
+            .field("name", &self.name())
+            .finish_non_exhaustive()
+    }
 }
 
 mod lettercase {use super::*;
 use crate::colobject as co;
 
 pub fn index_of(fic:&Lettercase) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Lettercase>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Lettercase>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -403,61 +818,131 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
 
-const fn r(name:&'static str, transformer:fn(&str)->String, point:&'static crate::colobject::Point, ) -> Lettercase {
-    Lettercase{name_:name, transformer_:transformer, point_:point, }
+pub struct DebugRef(pub &'static Lettercase);
+impl core::fmt::Debug for DebugRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match index_of(self.0) {
+            0 => f.write_str("Lettercases::Capital"),
+            1 => f.write_str("Lettercases::Upper"),
+            2 => f.write_str("Lettercases::Lower"),
+            i => write!(f, "Lettercase[{i}]"),
+        }
+    }
+}
+pub static BLOB : &[u8; 63] = include_bytes!("insrcdata_lettercase.bin");
+pub const ROW_SIZE : usize = 8;
+
+pub const fn bytes<const N: usize>(pos: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        bytes[i] = BLOB[pos + i];
+        i += 1;
+    }
+    bytes
 }
 
-pub static TABLE : [ Lettercase ; 3 ] = [
-   {r("Capitalised case", co::make_capitalize, &co::ZERO, )},
-   {r("Upper case", co::make_upper, &co::ONE, )},
-   {r("Lower case", co::make_lower, &co::ONE, )},
+// str are stored after the rows, located by u32 offset and length
+pub const fn str_at(pos: usize) -> &'static str {
+    let offset = u32::from_le_bytes(bytes::<4>(pos)) as usize;
+    let len = u32::from_le_bytes(bytes::<4>(pos + 4)) as usize;
+    match core::str::from_utf8(BLOB.split_at(offset).1.split_at(len).0) {
+        Ok(s) => s,
+        Err(_) => "",
+    }
+}
+
+pub static TABLE : [ Lettercase ; 3 ] = {
+    const ROW : Lettercase = Lettercase{index_:0};
+    let mut table = [ROW; 3];
+    let mut i = 0;
+    while i < 3 {
+        table[i].index_ = i as u8;
+        i += 1;
+    }
+    table
+};
+pub static TRANSFORMER_VALUES : [ fn(&str)->String ; 3 ] = [
+    co::make_capitalize,
+    co::make_upper,
+    co::make_lower,
+];
+pub static POINT_VALUES : [ &crate::colobject::Point ; 3 ] = [
+    &co::ZERO,
+    &co::ONE,
+    &co::ONE,
 ];
 
 } // mod lettercase
 
 pub use lettercase::IndexIter as LettercaseIter;
 pub struct Wikidata {
-    qid_ : u32,
-    object_ : u8,
+    packed_ : [u32; 1],
 }
 impl PartialEq<Self> for Wikidata {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Wikidata {}
-impl std::hash::Hash for Wikidata {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Wikidata {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         wikidata::index_of(self).hash(state);
     }
 }
 
 impl Wikidata {
-    pub fn qid(&self) -> u32 { self.qid_ }
-    pub fn object(&self) -> WikidataObject { 
-        let v = self.object_ ;
+    pub const fn qid(&self) -> u32 { ((self.packed_[0] & 0x7fffff) as u64 + 7186) as u32 }
+    pub const fn object(&self) -> WikidataObject { 
+        let v = ((self.packed_[0] >> 23) & 0x7) as u8 ;
         match v {
              0..=3 => WikidataObject::Person(&person::TABLE[v as usize ]),
              4..=6 => WikidataObject::Lettercase(&lettercase::TABLE[v as usize  - 4]),
              _ => panic!("insrcdata variant index overflow"),
         }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["qid", "object"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("qid", FieldValue::Uint(self.qid() as u64)),
+            ("object", match self.object() { WikidataObject::Person(v) => FieldValue::Join("Person", person::index_of(v)), WikidataObject::Lettercase(v) => FieldValue::Join("Lettercase", lettercase::index_of(v)) }),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 3 }
     /// Reference to the table containing all the values
-    pub fn array() -> &'static [Wikidata; 3] { &wikidata::TABLE }
+    pub const fn array() -> &'static [Wikidata; 3] { &wikidata::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { wikidata::index_of(self) }
 }
 
+impl core::fmt::Debug for Wikidata {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Wikidata")
+            .field("qid", &self.qid())
+            .field("object", &self.object())
+            .finish()
+    }
+}
+
 mod wikidata {use super::*;
 
 pub fn index_of(fic:&Wikidata) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Wikidata>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Wikidata>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -470,17 +955,23 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
 
-const fn r(qid:u32, object:u8, ) -> Wikidata {
-    Wikidata{qid_:qid, object_:object, }
+
+const fn r(packed:[u32; 1], ) -> Wikidata {
+    Wikidata{packed_:packed, }
 }
 
 pub static TABLE : [ Wikidata ; 3 ] = [
-   {r(7186, 0, )},
-   {r(8185162, 6, )},
-   {r(150989, 3, )},
+   {r([0x0], )},
+   {r([0x37cc938], )},
+   {r([0x18231bb], )},
 ];
 pub static OBJECT_INDEX : [ u8 ; 3 ] = [
     0, 2, 1, 
@@ -495,26 +986,41 @@ pub enum  WikidataObject {
      Lettercase(&'static Lettercase),
 }
 
+impl core::fmt::Debug for WikidataObject {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WikidataObject::Person(v) => f.debug_tuple("Person").field(&person::DebugRef(v)).finish(),
+            WikidataObject::Lettercase(v) => f.debug_tuple("Lettercase").field(&lettercase::DebugRef(v)).finish(),
+        }
+    }
+}
+
 pub struct Congress {
-    lccn_ : &'static str,
-    object_ : u8,
+    index_ : u8,
 }
 impl PartialEq<Self> for Congress {
     fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }
 }
 impl Eq for Congress {}
-impl std::hash::Hash for Congress {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for Congress {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         congress::index_of(self).hash(state);
     }
 }
 
 impl Congress {
-    pub fn lccn(&self) -> &'static str { self.lccn_ }
-    pub fn object(&self) -> CongressObject { 
-        let v = self.object_ ;
+    /// Size of a buffer large enough to decode any lccn value
+    pub const LCCN_MAX_LEN: usize = 11;
+    /// Decode lccn in buf, None if buf is smaller than the value
+    pub fn lccn_decode<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> { fsst_decode(&congress::LCCN_BLOB, &congress::LCCN_SYMBOLS, &congress::LCCN_SYMBOL_STARTS, congress::BLOB[self.index_ as usize * congress::ROW_SIZE] as usize, buf) }
+    pub fn lccn(&self) -> String {
+        let mut buf = [0u8; Self::LCCN_MAX_LEN];
+        String::from(self.lccn_decode(&mut buf).unwrap_or_default())
+    }
+    pub const fn object(&self) -> CongressObject { 
+        let v = congress::BLOB[self.index_ as usize * congress::ROW_SIZE + 1] ;
         match v {
              0..=0 => CongressObject::None,
              1..=4 => CongressObject::Person(&person::TABLE[v as usize  - 1]),
@@ -522,19 +1028,39 @@ impl Congress {
              _ => panic!("insrcdata variant index overflow"),
         }
     }
+    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; 2] = ["lccn", "object"];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); 2] {
+        [
+            ("lccn", FieldValue::String(self.lccn())),
+            ("object", match self.object() { CongressObject::None => FieldValue::Null, CongressObject::Person(v) => FieldValue::Join("Person", person::index_of(v)), CongressObject::Lettercase(v) => FieldValue::Join("Lettercase", lettercase::index_of(v)) }),
+        ]
+    }
+    /// Number of rows in the table
+    pub const fn len() -> usize { 4 }
     /// Reference to the table containing all the values
-    pub fn array() -> &'static [Congress; 4] { &congress::TABLE }
+    pub const fn array() -> &'static [Congress; 4] { &congress::TABLE }
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize { congress::index_of(self) }
 }
 
+impl core::fmt::Debug for Congress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Congress")
+            .field("lccn", &self.lccn_decode(&mut [0u8; Self::LCCN_MAX_LEN]).unwrap_or_default())
+            .field("object", &self.object())
+            .finish()
+    }
+}
+
 mod congress {use super::*;
 
 pub fn index_of(fic:&Congress) -> usize {
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<Congress>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<Congress>()
 }
 pub struct IndexIter {
-    pub indexes : Box<dyn Iterator<Item=&'static u8>>,
+    pub indexes : core::slice::Iter<'static, u8>,
 }
 
 impl Iterator for IndexIter {
@@ -547,18 +1073,57 @@ impl Iterator for IndexIter {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
 }
 
+impl ExactSizeIterator for IndexIter {}
+
+
+pub static BLOB : &[u8; 8] = include_bytes!("insrcdata_congress.bin");
+pub const ROW_SIZE : usize = 2;
 
-const fn r(lccn:&'static str, object:u8, ) -> Congress {
-    Congress{lccn_:lccn, object_:object, }
+pub const fn bytes<const N: usize>(pos: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        bytes[i] = BLOB[pos + i];
+        i += 1;
+    }
+    bytes
+}
+
+// str are stored after the rows, located by u32 offset and length
+pub const fn str_at(pos: usize) -> &'static str {
+    let offset = u32::from_le_bytes(bytes::<4>(pos)) as usize;
+    let len = u32::from_le_bytes(bytes::<4>(pos + 4)) as usize;
+    match core::str::from_utf8(BLOB.split_at(offset).1.split_at(len).0) {
+        Ok(s) => s,
+        Err(_) => "",
+    }
 }
 
-pub static TABLE : [ Congress ; 4 ] = [
-   {r("n2009011553", 1, )},
-   {r("sh85148650", 7, )},
-   {r("n80159913", 4, )},
-   {r("n79006404", 0, )},
+pub static TABLE : [ Congress ; 4 ] = {
+    const ROW : Congress = Congress{index_:0};
+    let mut table = [ROW; 4];
+    let mut i = 0;
+    while i < 4 {
+        table[i].index_ = i as u8;
+        i += 1;
+    }
+    table
+};
+pub static LCCN_BLOB : [ u8 ; 12 ] = [
+     0,  4, 254,  3,  5, 254,  2,  6, 254,  1,  7, 254, 
+];
+pub static LCCN_SYMBOLS : [ u8 ; 39 ] = [
+    110, 50, 48, 48, 57, 48, 49, 49, 110, 55, 57, 48, 48, 54, 52, 48, 110, 56, 48, 49, 
+    53, 57, 57, 49, 115, 104, 56, 53, 49, 52, 56, 54, 53, 53, 51, 53, 48, 51, 52, 
+];
+pub static LCCN_SYMBOL_STARTS : [ u16 ; 9 ] = [
+    0, 8, 16, 24, 32, 35, 37, 38, 39, 
 ];
 pub static OBJECT_INDEX : [ u8 ; 3 ] = [
     0, 2, 1, 
@@ -574,3 +1139,13 @@ pub enum  CongressObject {
      Lettercase(&'static Lettercase),
 }
 
+impl core::fmt::Debug for CongressObject {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CongressObject::None => f.write_str("None"),
+            CongressObject::Person(v) => f.debug_tuple("Person").field(&person::DebugRef(v)).finish(),
+            CongressObject::Lettercase(v) => f.debug_tuple("Lettercase").field(&lettercase::DebugRef(v)).finish(),
+        }
+    }
+}
+
@@ -5,8 +5,10 @@ extern crate core;
 mod insrcdata;
 
 mod colobject;
+mod compression;
 mod fictolabel;
 mod innerjoin;
+mod reflection;
 
 mod strencoding;
 
@@ -35,7 +37,13 @@ fn main() {
     innerjoin::test_variant_non_optional();
 
     // variant with unmatched rows
-    innerjoin::test_variant_optional()
+    innerjoin::test_variant_optional();
+
+    // Debug, Display and column reflection
+    reflection::test_reflection();
+
+    // compressed str columns
+    compression::test_compression()
 }
 
 // TODO : show how to use a second insrcdata database in the same project
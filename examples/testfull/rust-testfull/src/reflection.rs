@@ -0,0 +1,48 @@
+use crate::insrcdata as db;
+
+// Debug, Display and fields() : inspect rows without writing formatting code
+pub fn test_reflection() {
+    let irene: &db::Person = db::Persons::Irene.into();
+
+    // Display use the column set by the display table option
+    assert!(format!("{irene}") == "Irène Joliot-Curie");
+
+    // Debug render joined rows by label instead of recursing
+    assert!(
+        format!("{irene:?}")
+            == "Person { name: \"Irène Joliot-Curie\", woman: true, score: 3.2, \
+                spouse: Persons::Frederic, father: Some(Persons::Pierre), mother: Some(Persons::Marie) }"
+    );
+
+    // object columns are not displayed
+    let lower: &db::Lettercase = db::Lettercases::Lower.into();
+    assert!(format!("{lower:?}") == "Lettercase { name: \"Lower case\", .. }");
+
+    // variant columns
+    let wikidata = &db::Wikidata::array()[1];
+    assert!(format!("{:?}", wikidata.object()) == "Lettercase(Lettercases::Lower)");
+
+    // column names and values
+    assert!(db::Person::COLUMNS[0] == "name");
+    let fields = irene.fields();
+    assert!(fields.len() == db::Person::COLUMNS.len());
+    assert!(matches!(
+        fields[0],
+        ("name", db::FieldValue::Str("Irène Joliot-Curie"))
+    ));
+    assert!(matches!(fields[1], ("woman", db::FieldValue::Bool(true))));
+    assert!(matches!(
+        fields[3],
+        ("spouse", db::FieldValue::Join("Person", 3))
+    ));
+    assert!(matches!(
+        fields[4],
+        ("father", db::FieldValue::Join("Person", 1))
+    ));
+
+    let marie: &db::Person = db::Persons::Marie.into();
+    assert!(matches!(
+        marie.fields()[5],
+        ("mother", db::FieldValue::Null)
+    ));
+}
@@ -0,0 +1,110 @@
+-- generated by insrcdata version 0.3.0
+
+BEGIN TRANSACTION;
+
+CREATE TABLE "person" (
+    "_id" INTEGER PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "woman" BOOLEAN NOT NULL,
+    "score" REAL NOT NULL,
+    "spouse" INTEGER NOT NULL,
+    "father" INTEGER,
+    "mother" INTEGER,
+    FOREIGN KEY ("spouse") REFERENCES "person" ("_id") DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY ("father") REFERENCES "person" ("_id") DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY ("mother") REFERENCES "person" ("_id") DEFERRABLE INITIALLY DEFERRED
+);
+
+CREATE TABLE "persons" (
+    "_id" INTEGER PRIMARY KEY,
+    "label" TEXT NOT NULL UNIQUE,
+    FOREIGN KEY ("_id") REFERENCES "person" ("_id") DEFERRABLE INITIALLY DEFERRED
+);
+
+CREATE TABLE "strencoding" (
+    "_id" INTEGER PRIMARY KEY,
+    "text" TEXT NOT NULL
+);
+
+CREATE TABLE "lettercase" (
+    "_id" INTEGER PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "transformer" TEXT NOT NULL,
+    "point" TEXT NOT NULL
+);
+
+CREATE TABLE "lettercases" (
+    "_id" INTEGER PRIMARY KEY,
+    "label" TEXT NOT NULL UNIQUE,
+    FOREIGN KEY ("_id") REFERENCES "lettercase" ("_id") DEFERRABLE INITIALLY DEFERRED
+);
+
+CREATE TABLE "wikidata" (
+    "_id" INTEGER PRIMARY KEY,
+    "qid" INTEGER NOT NULL,
+    "object_person" INTEGER,
+    "object_lettercase" INTEGER,
+    FOREIGN KEY ("object_person") REFERENCES "person" ("_id") DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY ("object_lettercase") REFERENCES "lettercase" ("_id") DEFERRABLE INITIALLY DEFERRED,
+    CHECK (("object_person" IS NOT NULL) + ("object_lettercase" IS NOT NULL) = 1)
+);
+
+CREATE TABLE "congress" (
+    "_id" INTEGER PRIMARY KEY,
+    "lccn" TEXT NOT NULL,
+    "object_person" INTEGER,
+    "object_lettercase" INTEGER,
+    FOREIGN KEY ("object_person") REFERENCES "person" ("_id") DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY ("object_lettercase") REFERENCES "lettercase" ("_id") DEFERRABLE INITIALLY DEFERRED,
+    CHECK (("object_person" IS NOT NULL) + ("object_lettercase" IS NOT NULL) <= 1)
+);
+
+INSERT INTO "person" ("_id", "name", "woman", "score", "spouse", "father", "mother") VALUES
+    (0, 'Marie Curie', TRUE, 1.0, 1, NULL, NULL),
+    (1, 'Pierre Curie', FALSE, 2.1, 0, NULL, NULL),
+    (2, 'Irène Joliot-Curie', TRUE, 3.2, 3, 1, 0),
+    (3, 'Frédéric Joliot-Curie', FALSE, 2.1, 2, NULL, NULL);
+
+INSERT INTO "persons" ("_id", "label") VALUES
+    (0, 'marie'),
+    (1, 'pierre'),
+    (2, 'irene'),
+    (3, 'frederic');
+
+INSERT INTO "strencoding" ("_id", "text") VALUES
+    (0, '𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ'),
+    (1, 'hello'),
+    (2, 'κόσμε'),
+    (3, 'いろはにほへとちりぬるを'),
+    (4, 'éventuellement validé'),
+    (5, 'Да, но фальшивый экземпляр');
+
+INSERT INTO "lettercase" ("_id", "name", "transformer", "point") VALUES
+    (0, 'Capitalised case', 'make_capitalize', 'ZERO'),
+    (1, 'Upper case', 'make_upper', 'ONE'),
+    (2, 'Lower case', 'make_lower', 'ONE');
+
+INSERT INTO "lettercases" ("_id", "label") VALUES
+    (0, 'capital'),
+    (1, 'upper'),
+    (2, 'lower');
+
+INSERT INTO "wikidata" ("_id", "qid", "object_person", "object_lettercase") VALUES
+    (0, 7186, 0, NULL),
+    (1, 8185162, NULL, 2),
+    (2, 150989, 3, NULL);
+
+INSERT INTO "congress" ("_id", "lccn", "object_person", "object_lettercase") VALUES
+    (0, 'n2009011553', 0, NULL),
+    (1, 'sh85148650', NULL, 2),
+    (2, 'n80159913', 3, NULL),
+    (3, 'n79006404', NULL, NULL);
+
+CREATE INDEX "person_score" ON "person" ("score");
+CREATE INDEX "strencoding_text" ON "strencoding" ("text");
+CREATE INDEX "wikidata_object_person" ON "wikidata" ("object_person");
+CREATE INDEX "wikidata_object_lettercase" ON "wikidata" ("object_lettercase");
+CREATE INDEX "congress_object_person" ON "congress" ("object_person");
+CREATE INDEX "congress_object_lettercase" ON "congress" ("object_lettercase");
+
+COMMIT;
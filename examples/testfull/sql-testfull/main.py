@@ -0,0 +1,138 @@
+#!/usr/bin/env python3
+import os
+import sqlite3
+
+PATH = os.path.dirname(os.path.abspath(__file__))
+
+db = sqlite3.connect(":memory:")
+db.execute("PRAGMA foreign_keys = ON")
+db.executescript(open(f"{PATH}/insrcdata.sql", encoding="utf-8").read())
+
+
+def query(sql, *args):
+    return db.execute(sql, args).fetchall()
+
+
+def person(label):
+    return query("SELECT person.* FROM person JOIN persons USING (_id) WHERE persons.label = ?", label)[0]
+
+
+# every foreign key reference an existing row
+def test_foreign_keys():
+    assert query("PRAGMA foreign_key_check") == []
+
+
+# the join column reference a record in the same table
+def test_innerjoin():
+    marie = person("marie")
+    pierre = person("pierre")
+    irene = person("irene")
+    frederic = person("frederic")
+
+    assert marie[1] == "Marie Curie"
+    assert pierre[1] == "Pierre Curie"
+    assert irene[1] == "Irène Joliot-Curie"
+    assert frederic[1] == "Frédéric Joliot-Curie"
+
+    # inner join with 1-1 cardinality
+    spouses = query("SELECT a.name, b.name FROM person a JOIN person b ON a.spouse = b._id ORDER BY a._id")
+    assert spouses == [
+        ("Marie Curie", "Pierre Curie"),
+        ("Pierre Curie", "Marie Curie"),
+        ("Irène Joliot-Curie", "Frédéric Joliot-Curie"),
+        ("Frédéric Joliot-Curie", "Irène Joliot-Curie"),
+    ]
+
+    # inner join with 0-1 cardinality
+    parents = query(
+        "SELECT c.name, f.name, m.name FROM person c"
+        " LEFT JOIN person f ON c.father = f._id LEFT JOIN person m ON c.mother = m._id ORDER BY c._id"
+    )
+    assert parents[0] == ("Marie Curie", None, None)
+    assert parents[2] == ("Irène Joliot-Curie", "Pierre Curie", "Marie Curie")
+
+
+# bool data type
+def test_bool():
+    assert person("marie")[2] == 1
+    assert person("pierre")[2] == 0
+    assert query("SELECT count(*) FROM person WHERE woman") == [(2,)]
+
+
+# float data type
+def test_float():
+    # same order as the range iterator of the code targets
+    middle = query("SELECT name FROM person WHERE score BETWEEN 2.1 AND 3.2 ORDER BY score, _id")
+    assert middle == [("Pierre Curie",), ("Frédéric Joliot-Curie",), ("Irène Joliot-Curie",)]
+    assert query("SELECT count(*) FROM person WHERE score BETWEEN 0.0 AND 0.9") == [(0,)]
+    assert query("SELECT count(*) FROM person WHERE score BETWEEN 3.2 AND 9000.0") == [(1,)]
+    assert query("SELECT min(score), max(score), count(*) FROM person") == [(1.0, 3.2, 4)]
+
+
+# labels are exported as lookup tables
+def test_labels():
+    assert query("SELECT label FROM lettercases ORDER BY _id") == [("capital",), ("upper",), ("lower",)]
+    assert query("SELECT name FROM lettercase JOIN lettercases USING (_id) WHERE label = 'lower'") == [
+        ("Lower case",)
+    ]
+
+
+# check string comparison for various encoded unicode strings
+def test_strencoding():
+    texts = [t for (t,) in query("SELECT text FROM strencoding ORDER BY text")]
+    assert texts[0] == "hello"
+    assert len(texts) == 6
+    assert query("SELECT _id FROM strencoding WHERE text = 'éventuellement validé'") == [(4,)]
+
+
+# object type column : exported with the sql target template
+def test_colobject():
+    assert query("SELECT transformer, point FROM lettercase WHERE _id = 1") == [("make_upper", "ONE")]
+
+
+# variant : one nullable column for each alternative
+def test_variant_non_optional():
+    assert query("SELECT object_person, object_lettercase FROM wikidata ORDER BY _id") == [
+        (0, None),
+        (None, 2),
+        (3, None),
+    ]
+    try:
+        db.execute("INSERT INTO wikidata (_id, qid) VALUES (99, 0)")
+    except sqlite3.IntegrityError:
+        pass
+    else:
+        assert False, "variant check constraint"
+
+
+# variant with unmatched rows
+def test_variant_optional():
+    rows = query(
+        "SELECT lccn, person.name, lettercase.name FROM congress"
+        " LEFT JOIN person ON object_person = person._id"
+        " LEFT JOIN lettercase ON object_lettercase = lettercase._id ORDER BY congress._id"
+    )
+    assert rows[0] == ("n2009011553", "Marie Curie", None)
+    assert rows[1] == ("sh85148650", None, "Lower case")
+    assert rows[3] == ("n79006404", None, None)
+
+
+# range columns are indexed
+def test_indexes():
+    names = [n for (n,) in query("SELECT name FROM sqlite_master WHERE type = 'index' ORDER BY name")]
+    assert "person_score" in names
+    assert "strencoding_text" in names
+
+
+
+if __name__ == "__main__":
+    test_foreign_keys()
+    test_innerjoin()
+    test_bool()
+    test_float()
+    test_labels()
+    test_strencoding()
+    test_colobject()
+    test_variant_non_optional()
+    test_variant_optional()
+    test_indexes()
@@ -5,21 +5,115 @@
 #include <stdio.h>
 #include <stdlib.h>
 
+// little endian value of size bytes
+static inline uint64_t insrcdata_read(const uint8_t* p, unsigned size) {
+    uint64_t v = 0;
+    while( size ) {
+        v = ( v << 8 ) | p[--size];
+    }
+    return v;
+}
+
+static inline float insrcdata_f32(uint32_t bits) {
+    float f;
+    memcpy(&f, &bits, sizeof f);
+    return f;
+}
+
+static inline double insrcdata_f64(uint64_t bits) {
+    double f;
+    memcpy(&f, &bits, sizeof f);
+    return f;
+}
+
+static const char* insrcdata_huffman_decode(const uint8_t* blob, const uint16_t* counts, unsigned maxbits, const uint16_t* symbols, size_t offset, char* buf, size_t size) {
+    size_t bit = offset * 8;
+    size_t len = 0;
+    for(;;) {
+        // canonical code : codes of each length follow the codes of shorter length
+        unsigned code = 0, first = 0, index = 0;
+        unsigned symbol = 256;
+        for( unsigned bits = 1; bits <= maxbits; bits++ ) {
+            code |= ( blob[bit / 8] >> ( 7 - bit % 8 ) ) & 1;
+            bit++;
+            if( code < first + counts[bits] ) {
+                symbol = symbols[index + code - first];
+                break;
+            }
+            index += counts[bits];
+            first = ( first + counts[bits] ) << 1;
+            code <<= 1;
+        }
+        if( symbol == 256 ) {
+            break;
+        }
+        if( len + 1 < size ) {
+            buf[len++] = (char)symbol;
+        }
+    }
+    if( size ) {
+        buf[len] = 0;
+    }
+    return buf;
+}
+
+static const char* insrcdata_fsst_decode(const uint8_t* blob, const uint8_t* symbols, const uint16_t* starts, size_t offset, char* buf, size_t size) {
+    size_t pos = offset;
+    size_t len = 0;
+    for(;;) {
+        unsigned code = blob[pos++];
+        if( code == 254 ) {
+            break;
+        }
+        const uint8_t* bytes = code == 255 ? blob + pos++ : symbols + starts[code];
+        const uint8_t* end = code == 255 ? bytes + 1 : symbols + starts[code + 1];
+        while( bytes < end && len + 1 < size ) {
+            buf[len++] = (char)*bytes++;
+        }
+    }
+    if( size ) {
+        buf[len] = 0;
+    }
+    return buf;
+}
+
 static unsigned const PERSON_TABLE_COUNT = 4;
 static const person_t PERSON_TABLE[PERSON_TABLE_COUNT] = {
-   {"Marie Curie", true, 1.0, 1, 0, 0, },
-   {"Pierre Curie", false, 2.1, 0, 0, 0, },
-   {"Irène Joliot-Curie", true, 3.2, 3, 2, 1, },
-   {"Frédéric Joliot-Curie", false, 2.1, 2, 0, 0, },
+   {"Marie Curie", 1.0, {0x3u, }, },
+   {"Pierre Curie", 2.1, {0x0u, }, },
+   {"Irène Joliot-Curie", 3.2, {0x37u, }, },
+   {"Frédéric Joliot-Curie", 2.1, {0x4u, }, },
 };
 
 const person_t* person_next(person_iter_t* idx) { return idx->ptr<idx->end ? &PERSON_TABLE[*idx->ptr++] : NULL; }
 
+static unsigned const PERSON_WOMAN_BITMAP_COUNT  =  2;
+static bool const PERSON_WOMAN_BITMAP_VALUES[2] = {
+    false, true, 
+};
+static const person_bitmap_t PERSON_WOMAN_BITMAPS[2] = {
+    { { 0x000000000000000aULL, } },
+    { { 0x0000000000000005ULL, } },
+};
+
 static unsigned const PERSON_SCORE_INDEX_COUNT  =  4;
 static uint8_t PERSON_SCORE_INDEX   [PERSON_SCORE_INDEX_COUNT] = {
     0, 1, 3, 2, 
 };
 
+static unsigned const PERSON_SCORE_GROUPS_COUNT = 4;
+static const uint8_t PERSON_SCORE_GROUPS[4] = {
+    0, 1, 3, 4, 
+};
+
+static const uint8_t PERSON_FATHER_ENTER[4] = {
+    0, 1, 2, 3, 
+};
+
+static const uint8_t PERSON_FATHER_EXIT[4] = {
+    1, 3, 3, 4, 
+};
+
 const person_t* person_from_persons(persons_t label) {
     return &PERSON_TABLE[label];
 }
@@ -28,12 +122,12 @@ persons_t person_persons(const person_t *s) {
 }
 
 const strencoding_t STRENCODING_TABLE[STRENCODING_TABLE_COUNT] = {
-   {"𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ", },
-   {"hello", },
-   {"κόσμε", },
-   {"いろはにほへとちりぬるを", },
-   {"éventuellement validé", },
-   {"Да, но фальшивый экземпляр", },
+   {0, },
+   {53, },
+   {58, },
+   {67, },
+   {92, },
+   {112, },
 };
 
 const strencoding_t* strencoding_next(strencoding_iter_t* idx) { return idx->ptr<idx->end ? &STRENCODING_TABLE[*idx->ptr++] : NULL; }
@@ -43,11 +137,41 @@ static uint8_t STRENCODING_TEXT_INDEX   [STRENCODING_TEXT_INDEX_COUNT] = {
     1, 4, 2, 5, 3, 0, 
 };
 
+static const uint8_t STRENCODING_TEXT_BLOB[146] = {
+    106, 175, 244, 241, 113, 170, 197,   3,  34, 239,   8, 205, 128, 103, 198,  43,  88, 203,  17, 189, 
+    204, 213,  71, 148, 101, 182,  51,  89, 169, 253,  77,  86,  56,  76,  50, 192, 213,  97, 140, 104, 
+    144,  87, 188, 213,  90, 182,  51, 237, 243,  37,  91, 115,  56, 250,  58,  17, 250, 224, 166, 127, 
+     80, 122, 179,  79,  42,  86, 224,  36, 190,  79,  66,  73, 214,  73, 144, 147,  65,  38,  98,  78, 
+    114,  78,  66, 122, 233,  39,  73,  61, 120, 158, 190, 224, 237, 162, 193, 235,  87, 254,  58,  16, 
+    127, 195, 214, 174, 107,  31,   8, 251, 249, 237, 161, 192,   8, 195, 103, 177, 131, 152, 116,  51, 
+     31,  13, 129,  67,  32, 101, 192, 152,  55,  50, 240, 113,  51,  33,   9, 195, 128,  44,  57,   7, 
+     80, 161, 145,  51, 253, 192, 
+};
+
+static const uint16_t STRENCODING_TEXT_COUNTS[9] = {
+    0, 0, 0, 0, 3, 9, 13, 37, 10, 
+};
+
+static const uint16_t STRENCODING_TEXT_SYMBOLS[72] = {
+    208, 225, 227, 32, 101, 108, 129, 157, 181, 209, 240, 256, 130, 132, 140, 141, 143, 148, 171, 182, 
+    184, 186, 187, 206, 226, 110, 116, 118, 131, 133, 134, 136, 138, 139, 146, 147, 150, 151, 154, 161, 
+    162, 165, 168, 169, 172, 174, 175, 176, 177, 178, 179, 183, 185, 188, 189, 190, 191, 195, 196, 197, 
+    198, 207, 44, 58, 97, 100, 104, 105, 109, 111, 117, 128, 
+};
+
+static unsigned const LETTERCASE_ROW_SIZE = 8;
+static const uint8_t LETTERCASE_BLOB[63] = {
+    0x18, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 
+    0x34, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x43, 0x61, 0x70, 0x69, 0x74, 0x61, 0x6c, 0x69, 
+    0x73, 0x65, 0x64, 0x20, 0x63, 0x61, 0x73, 0x65, 0x00, 0x55, 0x70, 0x70, 0x65, 0x72, 0x20, 0x63, 
+    0x61, 0x73, 0x65, 0x00, 0x4c, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x63, 0x61, 0x73, 0x65, 0x00, 
+};
+
 static unsigned const LETTERCASE_TABLE_COUNT = 3;
 static const lettercase_t LETTERCASE_TABLE[LETTERCASE_TABLE_COUNT] = {
-   {"Capitalised case", make_capitalize, ZERO, },
-   {"Upper case", make_upper, ONE, },
-   {"Lower case", make_lower, ONE, },
+   {make_capitalize, ZERO, },
+   {make_upper, ONE, },
+   {make_lower, ONE, },
 };
 
 const lettercase_t* lettercase_next(lettercase_iter_t* idx) { return idx->ptr<idx->end ? &LETTERCASE_TABLE[*idx->ptr++] : NULL; }
@@ -60,9 +184,9 @@ lettercases_t lettercase_lettercases(const lettercase_t *s) {
 }
 
 const wikidata_t WIKIDATA_TABLE[WIKIDATA_TABLE_COUNT] = {
-   {7186, 0, },
-   {8185162, 6, },
-   {150989, 3, },
+   {{0x0u, }, },
+   {{0x37cc938u, }, },
+   {{0x18231bbu, }, },
 };
 
 const wikidata_t* wikidata_next(wikidata_iter_t* idx) { return idx->ptr<idx->end ? &WIKIDATA_TABLE[*idx->ptr++] : NULL; }
@@ -72,20 +196,51 @@ static uint8_t WIKIDATA_OBJECT_INDEX   [WIKIDATA_OBJECT_INDEX_COUNT] = {
     0, 2, 1, 
 };
 
-const congress_t CONGRESS_TABLE[CONGRESS_TABLE_COUNT] = {
-   {"n2009011553", 1, },
-   {"sh85148650", 7, },
-   {"n80159913", 4, },
-   {"n79006404", 0, },
+static unsigned const CONGRESS_ROW_SIZE = 2;
+static const uint8_t CONGRESS_BLOB[8] = {
+    0x00, 0x01, 0x03, 0x07, 0x06, 0x04, 0x09, 0x00, 
 };
 
+const congress_t CONGRESS_TABLE[CONGRESS_TABLE_COUNT] = {{0}};
+
 const congress_t* congress_next(congress_iter_t* idx) { return idx->ptr<idx->end ? &CONGRESS_TABLE[*idx->ptr++] : NULL; }
 
+static const uint8_t CONGRESS_LCCN_BLOB[12] = {
+     0,  4, 254,  3,  5, 254,  2,  6, 254,  1,  7, 254, 
+};
+
+static const uint8_t CONGRESS_LCCN_SYMBOLS[39] = {
+    110, 50, 48, 48, 57, 48, 49, 49, 110, 55, 57, 48, 48, 54, 52, 48, 110, 56, 48, 49, 
+    53, 57, 57, 49, 115, 104, 56, 53, 49, 52, 56, 54, 53, 53, 51, 53, 48, 51, 52, 
+};
+
+static const uint16_t CONGRESS_LCCN_SYMBOL_STARTS[9] = {
+    0, 8, 16, 24, 32, 35, 37, 38, 39, 
+};
+
 static unsigned const CONGRESS_OBJECT_INDEX_COUNT  =  3;
 static uint8_t CONGRESS_OBJECT_INDEX   [CONGRESS_OBJECT_INDEX_COUNT] = {
     0, 2, 1, 
 };
 
+person_bitmap_t person_where_woman(bool value) {
+    unsigned lo = 0;
+    unsigned hi = PERSON_WOMAN_BITMAP_COUNT;
+    while( lo < hi ) {
+        unsigned mid = lo + ( hi-lo)/2;
+        if( value>PERSON_WOMAN_BITMAP_VALUES[mid] ) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    if( lo < PERSON_WOMAN_BITMAP_COUNT && !( value<PERSON_WOMAN_BITMAP_VALUES[lo] ) ) {
+        return PERSON_WOMAN_BITMAPS[lo];
+    }
+    person_bitmap_t empty = { { 0 } };
+    return empty;
+}
+
 person_iter_t  person_score_range( double start, double stop) {
     uint8_t* lo = PERSON_SCORE_INDEX;
     uint8_t*  hi = PERSON_SCORE_INDEX + PERSON_SCORE_INDEX_COUNT;
@@ -112,17 +267,65 @@ person_iter_t  person_score_range( double start, double stop) {
     person_iter_t res = {  begin,  lo };
     return res;
 }
-const person_t* person_spouse(const person_t* s) { return &PERSON_TABLE[s->spouse_];}
+
+size_t  person_score_count( double start, double stop) {
+    person_iter_t iter = person_score_range(start, stop);
+    return iter.end - iter.ptr;
+}
+person_groups_t  person_score_groups(void) {
+    person_groups_t res = { PERSON_SCORE_GROUPS, PERSON_SCORE_GROUPS + PERSON_SCORE_GROUPS_COUNT - 1, PERSON_SCORE_INDEX };
+    return res;
+}
+
+bool  person_score_groups_next(person_groups_t* groups, double* value, person_iter_t* rows) {
+    if( groups->bound >= groups->end ) {
+        return false;
+    }
+    rows->ptr = groups->index + groups->bound[0];
+    rows->end = groups->index + groups->bound[1];
+    groups->bound++;
+    *value = person_score(&PERSON_TABLE[*rows->ptr]);
+    return true;
+}
+
+const person_t* person_spouse(const person_t* s) { return &PERSON_TABLE[((uint8_t)((s->packed_[0] >> 1) & 0x3ULL))];}
 bool person_father(const person_t* s, const person_t** ptr) {
-    if( s->father_) {
-        *ptr = &PERSON_TABLE[s->father_-1];
+    if( ((uint8_t)((s->packed_[0] >> 3) & 0x3ULL))) {
+        *ptr = &PERSON_TABLE[((uint8_t)((s->packed_[0] >> 3) & 0x3ULL))-1];
         return true;
     }
     return false;
 }
+person_ancestors_t person_father_ancestors(const person_t* s) {
+    person_ancestors_t res = { s, 0 };
+    return res;
+}
+
+const person_t* person_father_ancestors_next(person_ancestors_t* iter, unsigned* depth) {
+    if( iter->row == NULL ) {
+        return NULL;
+    }
+    const person_t* parent = NULL;
+    if( !person_father(iter->row, &parent) ) {
+        parent = NULL;
+    }
+    if( parent == iter->row ) {
+        parent = NULL;
+    }
+    iter->row = parent;
+    *depth = ++iter->depth;
+    return parent;
+}
+
+bool person_father_is_ancestor_of(const person_t* s, const person_t* other) {
+    size_t row = s - PERSON_TABLE;
+    size_t orow = other - PERSON_TABLE;
+    return PERSON_FATHER_ENTER[row] < PERSON_FATHER_ENTER[orow] && PERSON_FATHER_ENTER[orow] < PERSON_FATHER_EXIT[row];
+}
+
 bool person_mother(const person_t* s, const person_t** ptr) {
-    if( s->mother_) {
-        *ptr = &PERSON_TABLE[s->mother_-1];
+    if( ((uint8_t)((s->packed_[0] >> 5) & 0x1ULL))) {
+        *ptr = &PERSON_TABLE[((uint8_t)((s->packed_[0] >> 5) & 0x1ULL))-1];
         return true;
     }
     return false;
@@ -136,7 +339,7 @@ wikidata_iter_t person_wdata(const person_t* s) {
    
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if ( cons > WIKIDATA_TABLE[*mid].object_ ) {
+        if ( cons > ((uint8_t)((WIKIDATA_TABLE[*mid].packed_[0] >> 23) & 0x7ULL)) ) {
              lo = mid + 1;
         } else {
              hi = mid;
@@ -148,7 +351,7 @@ wikidata_iter_t person_wdata(const person_t* s) {
     hi = WIKIDATA_OBJECT_INDEX +  WIKIDATA_OBJECT_INDEX_COUNT;
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if( cons < WIKIDATA_TABLE[*mid].object_ )  {
+        if( cons < ((uint8_t)((WIKIDATA_TABLE[*mid].packed_[0] >> 23) & 0x7ULL)) )  {
             hi = mid;
         } else {
             lo = mid + 1;
@@ -168,7 +371,7 @@ congress_iter_t person_congress(const person_t* s) {
    
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if ( cons > CONGRESS_TABLE[*mid].object_ ) {
+        if ( cons > ((uint8_t)insrcdata_read((CONGRESS_BLOB + (size_t)*mid * CONGRESS_ROW_SIZE + 1), 1)) ) {
              lo = mid + 1;
         } else {
              hi = mid;
@@ -180,7 +383,7 @@ congress_iter_t person_congress(const person_t* s) {
     hi = CONGRESS_OBJECT_INDEX +  CONGRESS_OBJECT_INDEX_COUNT;
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if( cons < CONGRESS_TABLE[*mid].object_ )  {
+        if( cons < ((uint8_t)insrcdata_read((CONGRESS_BLOB + (size_t)*mid * CONGRESS_ROW_SIZE + 1), 1)) )  {
             hi = mid;
         } else {
             lo = mid + 1;
@@ -191,12 +394,58 @@ congress_iter_t person_congress(const person_t* s) {
     return res;
 }
 
+person_bitmap_t person_bitmap_and(person_bitmap_t a, person_bitmap_t b) {
+    for( unsigned i = 0; i < 1; i++ ) {
+        a.words[i] &= b.words[i];
+    }
+    return a;
+}
+
+person_bitmap_t person_bitmap_or(person_bitmap_t a, person_bitmap_t b) {
+    for( unsigned i = 0; i < 1; i++ ) {
+        a.words[i] |= b.words[i];
+    }
+    return a;
+}
+
+size_t person_bitmap_count(person_bitmap_t bitmap) {
+    size_t count = 0;
+    for( unsigned i = 0; i < 1; i++ ) {
+        for( uint64_t w = bitmap.words[i]; w; w &= w - 1 ) {
+            count++;
+        }
+    }
+    return count;
+}
+
+person_bitmap_iter_t person_bitmap_iter(person_bitmap_t bitmap) {
+    person_bitmap_iter_t iter = { bitmap, 0 };
+    return iter;
+}
+
+const person_t* person_bitmap_next(person_bitmap_iter_t* iter) {
+    while( iter->word < 1 ) {
+        uint64_t w = iter->bitmap.words[iter->word];
+        if( w ) {
+            unsigned bit = 0;
+            while( !( ( w >> bit ) & 1 ) ) {
+                bit++;
+            }
+            iter->bitmap.words[iter->word] = w & ( w - 1 );
+            return &PERSON_TABLE[iter->word * 64 + bit];
+        }
+        iter->word++;
+    }
+    return NULL;
+}
+
+const char* strencoding_text_decode(const strencoding_t* s, char* buf, size_t size) { return insrcdata_huffman_decode(STRENCODING_TEXT_BLOB, STRENCODING_TEXT_COUNTS, 8, STRENCODING_TEXT_SYMBOLS, s->text_, buf, size); }
 strencoding_iter_t  strencoding_text_range( const char* start, const char* stop) {
     uint8_t* lo = STRENCODING_TEXT_INDEX;
     uint8_t*  hi = STRENCODING_TEXT_INDEX + STRENCODING_TEXT_INDEX_COUNT;
     while( lo < hi ){
         uint8_t*  mid = lo + ( hi-lo)/2;
-        if( strcmp(start,STRENCODING_TABLE[*mid].text_ )>0 ){
+        if( strcmp(start,insrcdata_huffman_decode(STRENCODING_TEXT_BLOB, STRENCODING_TEXT_COUNTS, 8, STRENCODING_TEXT_SYMBOLS, STRENCODING_TABLE[*mid].text_ , (char[73]){0}, 73))>0 ){
              lo = mid + 1;
         } else {
              hi = mid;
@@ -207,7 +456,7 @@ strencoding_iter_t  strencoding_text_range( const char* start, const char* stop)
     hi = STRENCODING_TEXT_INDEX + STRENCODING_TEXT_INDEX_COUNT;
     while( lo < hi ){
          uint8_t* mid = lo + ( hi-lo)/2;
-        if( strcmp(stop,STRENCODING_TABLE[*mid].text_ )<0 ){
+        if( strcmp(stop,insrcdata_huffman_decode(STRENCODING_TEXT_BLOB, STRENCODING_TEXT_COUNTS, 8, STRENCODING_TEXT_SYMBOLS, STRENCODING_TABLE[*mid].text_ , (char[73]){0}, 73))<0 ){
             hi = mid;
         } else {
             lo = mid + 1;
@@ -217,6 +466,12 @@ strencoding_iter_t  strencoding_text_range( const char* start, const char* stop)
     strencoding_iter_t res = {  begin,  lo };
     return res;
 }
+
+size_t  strencoding_text_count( const char* start, const char* stop) {
+    strencoding_iter_t iter = strencoding_text_range(start, stop);
+    return iter.end - iter.ptr;
+}
+const char* lettercase_name(const lettercase_t* s) { return ((const char*)LETTERCASE_BLOB + insrcdata_read((LETTERCASE_BLOB + (size_t)(s - LETTERCASE_TABLE) * LETTERCASE_ROW_SIZE), 4)); }
 wikidata_iter_t lettercase_wdata2(const lettercase_t* s) {
     long cons = s - LETTERCASE_TABLE + 4;
 
@@ -226,7 +481,7 @@ wikidata_iter_t lettercase_wdata2(const lettercase_t* s) {
    
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if ( cons > WIKIDATA_TABLE[*mid].object_ ) {
+        if ( cons > ((uint8_t)((WIKIDATA_TABLE[*mid].packed_[0] >> 23) & 0x7ULL)) ) {
              lo = mid + 1;
         } else {
              hi = mid;
@@ -238,7 +493,7 @@ wikidata_iter_t lettercase_wdata2(const lettercase_t* s) {
     hi = WIKIDATA_OBJECT_INDEX +  WIKIDATA_OBJECT_INDEX_COUNT;
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if( cons < WIKIDATA_TABLE[*mid].object_ )  {
+        if( cons < ((uint8_t)((WIKIDATA_TABLE[*mid].packed_[0] >> 23) & 0x7ULL)) )  {
             hi = mid;
         } else {
             lo = mid + 1;
@@ -258,7 +513,7 @@ congress_iter_t lettercase_congress(const lettercase_t* s) {
    
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if ( cons > CONGRESS_TABLE[*mid].object_ ) {
+        if ( cons > ((uint8_t)insrcdata_read((CONGRESS_BLOB + (size_t)*mid * CONGRESS_ROW_SIZE + 1), 1)) ) {
              lo = mid + 1;
         } else {
              hi = mid;
@@ -270,7 +525,7 @@ congress_iter_t lettercase_congress(const lettercase_t* s) {
     hi = CONGRESS_OBJECT_INDEX +  CONGRESS_OBJECT_INDEX_COUNT;
     while( lo < hi ){
         uint8_t*  mid =  lo + ( hi-lo)/2;
-        if( cons < CONGRESS_TABLE[*mid].object_ )  {
+        if( cons < ((uint8_t)insrcdata_read((CONGRESS_BLOB + (size_t)*mid * CONGRESS_ROW_SIZE + 1), 1)) )  {
             hi = mid;
         } else {
             lo = mid + 1;
@@ -282,14 +537,15 @@ congress_iter_t lettercase_congress(const lettercase_t* s) {
 }
 
 wikidata_object_t wikidata_object(const wikidata_t* s){
-    int v = s->object_ ;
+    int v = ((uint8_t)((s->packed_[0] >> 23) & 0x7ULL)) ;
     if(v<=3) {return (wikidata_object_t){.type=WIKIDATA_PERSON, .person=PERSON_TABLE+v-0}; }
     if(v<=6) {return (wikidata_object_t){.type=WIKIDATA_LETTERCASE, .lettercase=LETTERCASE_TABLE+v-4}; }
     perror("insrcdata variant index overflow");
     abort();
 }
+const char* congress_lccn_decode(const congress_t* s, char* buf, size_t size) { return insrcdata_fsst_decode(CONGRESS_LCCN_BLOB, CONGRESS_LCCN_SYMBOLS, CONGRESS_LCCN_SYMBOL_STARTS, ((uint8_t)insrcdata_read((CONGRESS_BLOB + (size_t)(s - CONGRESS_TABLE) * CONGRESS_ROW_SIZE), 1)), buf, size); }
 congress_object_t congress_object(const congress_t* s){
-    int v = s->object_ ;
+    int v = ((uint8_t)insrcdata_read((CONGRESS_BLOB + (size_t)(s - CONGRESS_TABLE) * CONGRESS_ROW_SIZE + 1), 1)) ;
     if(v<=0) {return (congress_object_t){.type=CONGRESS_NONE}; }
     if(v<=4) {return (congress_object_t){.type=CONGRESS_PERSON, .person=PERSON_TABLE+v-1}; }
     if(v<=7) {return (congress_object_t){.type=CONGRESS_LETTERCASE, .lettercase=LETTERCASE_TABLE+v-5}; }
@@ -11,12 +11,22 @@ public struct Person : Hashable {
         cstruct = person_from_persons(ref)
     }
     var persons: persons_t { person_persons(cstruct) }
+    static var len : Int { Int(person_len()) }
     var name: String { String(cString:  person_name(cstruct)) }
     var woman: Bool {  person_woman(cstruct) }
     var score: Float64 {  person_score(cstruct) }
     static func scoreRange(start:Float64, stop:Float64) -> person_iter_t {
         person_score_range(start, stop) 
     }
+    static func scoreGroups() -> AnySequence<(Float64, person_iter_t)> {
+        var groups = person_score_groups()
+        return AnySequence(AnyIterator {
+            var value = Float64()
+            var rows = person_iter_t()
+            if person_score_groups_next(&groups, &value, &rows) { return (value, rows) }
+            return nil
+        })
+    }
     var spouse : Person { Person(cstruct:person_spouse(cstruct)) }
     var father : Person? {
         var p = UnsafePointer<person_t>?(nil)
@@ -51,7 +61,11 @@ public struct Strencoding : Hashable {
     init(index:Int){
         cstruct = STRENCODING_TABLE_PTR()+index
     }
-    var text: String { String(cString:  strencoding_text(cstruct)) }
+    static var len : Int { Int(strencoding_len()) }
+    var text: String {
+        var buf = [CChar](repeating: 0, count: Int(STRENCODING_TEXT_MAX_LEN) + 1)
+        return String(cString: strencoding_text_decode(cstruct, &buf, buf.count))
+    }
     static func textRange(start:String, stop:String) -> strencoding_iter_t {
         strencoding_text_range(start.cString(using:.utf8), stop.cString(using:.utf8)) 
     }
@@ -79,6 +93,7 @@ public struct Lettercase : Hashable {
         cstruct = lettercase_from_lettercases(ref)
     }
     var lettercases: lettercases_t { lettercase_lettercases(cstruct) }
+    static var len : Int { Int(lettercase_len()) }
     var name: String { String(cString:  lettercase_name(cstruct)) }
     var transformer: Transformer_t {  lettercase_transformer(cstruct) }
     var point: Point_t {  lettercase_point(cstruct) }
@@ -107,6 +122,7 @@ public struct Wikidata : Hashable {
     init(index:Int){
         cstruct = WIKIDATA_TABLE_PTR()+index
     }
+    static var len : Int { Int(wikidata_len()) }
     var qid: UInt32 {  wikidata_qid(cstruct) }
     var object : WikidataObject { 
        let w = wikidata_object(cstruct)
@@ -144,7 +160,11 @@ public struct Congress : Hashable {
     init(index:Int){
         cstruct = CONGRESS_TABLE_PTR()+index
     }
-    var lccn: String { String(cString:  congress_lccn(cstruct)) }
+    static var len : Int { Int(congress_len()) }
+    var lccn: String {
+        var buf = [CChar](repeating: 0, count: Int(CONGRESS_LCCN_MAX_LEN) + 1)
+        return String(cString: congress_lccn_decode(cstruct, &buf, buf.count))
+    }
     var object : CongressObject { 
        let w = congress_object(cstruct)
        switch w.type {
@@ -0,0 +1,25 @@
+//
+//  colobject.ts
+//  testfull
+//
+
+export type Transformer = (s: string) => string;
+
+export function make_capitalize(s: string): string {
+    return s.charAt(0).toUpperCase() + s.slice(1).toLowerCase();
+}
+
+export function make_upper(s: string): string {
+    return s.toUpperCase();
+}
+
+export function make_lower(s: string): string {
+    return s.toLowerCase();
+}
+
+export interface Point {
+    readonly x: number;
+    readonly y: number;
+}
+export const POINT_ZERO: Point = { x: 0.0, y: 0.0 };
+export const POINT_ONE: Point = { x: 1.0, y: 1.0 };
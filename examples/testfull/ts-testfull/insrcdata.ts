@@ -0,0 +1,321 @@
+// generated by insrcdata version 0.3.0
+
+import * as colobject from "./colobject.js";
+
+type Key = number | string | boolean;
+
+// compare strings in code point order like the generator sort, not in utf-16 code unit order
+function less(a: Key, b: Key): boolean {
+    if (typeof a === "string" && typeof b === "string") {
+        const len = Math.min(a.length, b.length);
+        for (let i = 0; i < len; i++) {
+            const x = a.charCodeAt(i);
+            const y = b.charCodeAt(i);
+            if (x !== y) {
+                // surrogates encode code points above the basic plane
+                const fix = (c: number) => (c >= 0xe000 ? c - 0x800 : c >= 0xd800 ? c + 0x2000 : c);
+                return fix(x) < fix(y);
+            }
+        }
+        return a.length < b.length;
+    }
+    return Number(a) < Number(b);
+}
+
+// positions in index of rows with key(row) in [start..=stop], index must be sorted by key(row)
+function bounds<R, K extends Key>(
+    table: readonly R[],
+    index: readonly number[],
+    start: K,
+    stop: K,
+    key: (row: R) => K,
+): [number, number] {
+    let lo = 0;
+    let hi = index.length;
+    while (lo < hi) {
+        const mid = (lo + hi) >>> 1;
+        if (less(key(table[index[mid]]), start)) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    const begin = lo;
+    hi = index.length;
+    while (lo < hi) {
+        const mid = (lo + hi) >>> 1;
+        if (less(stop, key(table[index[mid]]))) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    return [begin, lo];
+}
+
+// rows of table with key(row) in [start..=stop]
+function rows<R, K extends Key>(
+    table: readonly R[],
+    index: readonly number[],
+    start: K,
+    stop: K,
+    key: (row: R) => K,
+): R[] {
+    const [lo, hi] = bounds(table, index, start, stop, key);
+    return index.slice(lo, hi).map((i) => table[i]);
+}
+
+export const Persons = {
+    Marie: 0,
+    Pierre: 1,
+    Irene: 2,
+    Frederic: 3,
+} as const;
+export type Persons = (typeof Persons)[keyof typeof Persons];
+
+export const Lettercases = {
+    Capital: 0,
+    Upper: 1,
+    Lower: 2,
+} as const;
+export type Lettercases = (typeof Lettercases)[keyof typeof Lettercases];
+
+export interface Person {
+    /** @internal */
+    readonly _index: number;
+    readonly name: string;
+    readonly woman: boolean;
+    readonly score: number;
+    /** @internal */
+    readonly _spouse: number;
+    /** @internal */
+    readonly _father: number;
+    /** @internal */
+    readonly _mother: number;
+}
+
+export interface Strencoding {
+    /** @internal */
+    readonly _index: number;
+    readonly text: string;
+}
+
+export interface Lettercase {
+    /** @internal */
+    readonly _index: number;
+    readonly name: string;
+    readonly transformer: colobject.Transformer;
+    readonly point: colobject.Point;
+}
+
+export interface Wikidata {
+    /** @internal */
+    readonly _index: number;
+    readonly qid: number;
+    /** @internal */
+    readonly _object: number;
+}
+
+export type WikidataObject =
+    | { readonly kind: "Person"; readonly row: Person }
+    | { readonly kind: "Lettercase"; readonly row: Lettercase };
+
+export interface Congress {
+    /** @internal */
+    readonly _index: number;
+    readonly lccn: string;
+    /** @internal */
+    readonly _object: number;
+}
+
+export type CongressObject =
+    | { readonly kind: "Person"; readonly row: Person }
+    | { readonly kind: "Lettercase"; readonly row: Lettercase };
+
+const PERSON_TABLE: readonly Person[] = [
+    { _index: 0, name: "Marie Curie", woman: true, score: 1.0, _spouse: 1, _father: 0, _mother: 0 },
+    { _index: 1, name: "Pierre Curie", woman: false, score: 2.1, _spouse: 0, _father: 0, _mother: 0 },
+    { _index: 2, name: "Irène Joliot-Curie", woman: true, score: 3.2, _spouse: 3, _father: 2, _mother: 1 },
+    { _index: 3, name: "Frédéric Joliot-Curie", woman: false, score: 2.1, _spouse: 2, _father: 0, _mother: 0 },
+];
+const PERSON_SCORE_INDEX = [0, 1, 3, 2] as const;
+
+/** All the rows of the table */
+export const STRENCODING_TABLE: readonly Strencoding[] = [
+    { _index: 0, text: "𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ" },
+    { _index: 1, text: "hello" },
+    { _index: 2, text: "κόσμε" },
+    { _index: 3, text: "いろはにほへとちりぬるを" },
+    { _index: 4, text: "éventuellement validé" },
+    { _index: 5, text: "Да, но фальшивый экземпляр" },
+];
+const STRENCODING_TEXT_INDEX = [1, 4, 2, 5, 3, 0] as const;
+
+const LETTERCASE_TABLE: readonly Lettercase[] = [
+    { _index: 0, name: "Capitalised case", transformer: colobject.make_capitalize, point: colobject.POINT_ZERO },
+    { _index: 1, name: "Upper case", transformer: colobject.make_upper, point: colobject.POINT_ONE },
+    { _index: 2, name: "Lower case", transformer: colobject.make_lower, point: colobject.POINT_ONE },
+];
+
+/** All the rows of the table */
+export const WIKIDATA_TABLE: readonly Wikidata[] = [
+    { _index: 0, qid: 7186, _object: 0 },
+    { _index: 1, qid: 8185162, _object: 6 },
+    { _index: 2, qid: 150989, _object: 3 },
+];
+const WIKIDATA_OBJECT_INDEX = [0, 2, 1] as const;
+
+/** All the rows of the table */
+export const CONGRESS_TABLE: readonly Congress[] = [
+    { _index: 0, lccn: "n2009011553", _object: 1 },
+    { _index: 1, lccn: "sh85148650", _object: 7 },
+    { _index: 2, lccn: "n80159913", _object: 4 },
+    { _index: 3, lccn: "n79006404", _object: 0 },
+];
+const CONGRESS_OBJECT_INDEX = [0, 2, 1] as const;
+
+/** Number of rows in the table */
+export const PERSON_LEN: number = 4;
+/** min of score column values */
+export const PERSON_SCORE_MIN: number = 1.0;
+/** max of score column values */
+export const PERSON_SCORE_MAX: number = 3.2;
+/** sum of score column values */
+export const PERSON_SCORE_SUM: number = 8.4;
+/** mean of score column values */
+export const PERSON_SCORE_MEAN: number = 2.1;
+
+export function personScoreRange(start: number, stop: number): Person[] {
+    return rows(PERSON_TABLE, PERSON_SCORE_INDEX, start, stop, (r: Person) => r.score);
+}
+
+/** Number of rows returned by personScoreRange */
+export function personScoreCount(start: number, stop: number): number {
+    const [lo, hi] = bounds(PERSON_TABLE, PERSON_SCORE_INDEX, start, stop, (r: Person) => r.score);
+    return hi - lo;
+}
+
+export function personSpouse(row: Person): Person {
+    return PERSON_TABLE[row._spouse];
+}
+
+export function personFather(row: Person): Person | undefined {
+    return row._father === 0 ? undefined : PERSON_TABLE[row._father - 1];
+}
+
+export function personMother(row: Person): Person | undefined {
+    return row._mother === 0 ? undefined : PERSON_TABLE[row._mother - 1];
+}
+
+export function personWdata(row: Person): Wikidata[] {
+    const cons = row._index;
+    return rows(WIKIDATA_TABLE, WIKIDATA_OBJECT_INDEX, cons, cons, (r: Wikidata) => r._object);
+}
+
+export function personCongress(row: Person): Congress[] {
+    const cons = row._index + 1;
+    return rows(CONGRESS_TABLE, CONGRESS_OBJECT_INDEX, cons, cons, (r: Congress) => r._object);
+}
+
+/** Row corresponding to label */
+export function personFromPersons(label: Persons): Person {
+    return PERSON_TABLE[label];
+}
+
+/** Label of the row */
+export function personPersons(row: Person): Persons {
+    return row._index as Persons;
+}
+
+/** Index of the row in the table */
+export function personAsIndex(row: Person): number {
+    return row._index;
+}
+
+/** Number of rows in the table */
+export const STRENCODING_LEN: number = 6;
+
+export function strencodingTextRange(start: string, stop: string): Strencoding[] {
+    return rows(STRENCODING_TABLE, STRENCODING_TEXT_INDEX, start, stop, (r: Strencoding) => r.text);
+}
+
+/** Number of rows returned by strencodingTextRange */
+export function strencodingTextCount(start: string, stop: string): number {
+    const [lo, hi] = bounds(STRENCODING_TABLE, STRENCODING_TEXT_INDEX, start, stop, (r: Strencoding) => r.text);
+    return hi - lo;
+}
+
+/** Index of the row in the table */
+export function strencodingAsIndex(row: Strencoding): number {
+    return row._index;
+}
+
+/** Number of rows in the table */
+export const LETTERCASE_LEN: number = 3;
+
+export function lettercaseWdata2(row: Lettercase): Wikidata[] {
+    const cons = row._index + 4;
+    return rows(WIKIDATA_TABLE, WIKIDATA_OBJECT_INDEX, cons, cons, (r: Wikidata) => r._object);
+}
+
+export function lettercaseCongress(row: Lettercase): Congress[] {
+    const cons = row._index + 5;
+    return rows(CONGRESS_TABLE, CONGRESS_OBJECT_INDEX, cons, cons, (r: Congress) => r._object);
+}
+
+/** Row corresponding to label */
+export function lettercaseFromLettercases(label: Lettercases): Lettercase {
+    return LETTERCASE_TABLE[label];
+}
+
+/** Label of the row */
+export function lettercaseLettercases(row: Lettercase): Lettercases {
+    return row._index as Lettercases;
+}
+
+/** Index of the row in the table */
+export function lettercaseAsIndex(row: Lettercase): number {
+    return row._index;
+}
+
+/** Number of rows in the table */
+export const WIKIDATA_LEN: number = 3;
+
+export function wikidataObject(row: Wikidata): WikidataObject {
+    const v = row._object;
+    if (v <= 3) {
+        return { kind: "Person", row: PERSON_TABLE[v] };
+    }
+    if (v <= 6) {
+        return { kind: "Lettercase", row: LETTERCASE_TABLE[v - 4] };
+    }
+    throw new RangeError("insrcdata variant index overflow in Wikidata");
+}
+
+/** Index of the row in the table */
+export function wikidataAsIndex(row: Wikidata): number {
+    return row._index;
+}
+
+/** Number of rows in the table */
+export const CONGRESS_LEN: number = 4;
+
+export function congressObject(row: Congress): CongressObject | undefined {
+    const v = row._object;
+    if (v <= 0) {
+        return undefined;
+    }
+    if (v <= 4) {
+        return { kind: "Person", row: PERSON_TABLE[v - 1] };
+    }
+    if (v <= 7) {
+        return { kind: "Lettercase", row: LETTERCASE_TABLE[v - 5] };
+    }
+    throw new RangeError("insrcdata variant index overflow in Congress");
+}
+
+/** Index of the row in the table */
+export function congressAsIndex(row: Congress): number {
+    return row._index;
+}
+
@@ -0,0 +1,33 @@
+//
+//  colobject.zig
+//  testfull
+//
+
+const std = @import("std");
+
+/// change the case of s into out, returns the used part of out
+pub const Transformer = *const fn (out: []u8, s: []const u8) []u8;
+
+pub fn make_capitalize(out: []u8, s: []const u8) []u8 {
+    for (s, 0..) |c, i| {
+        out[i] = if (i == 0) std.ascii.toUpper(c) else std.ascii.toLower(c);
+    }
+    return out[0..s.len];
+}
+
+pub fn make_upper(out: []u8, s: []const u8) []u8 {
+    return std.ascii.upperString(out, s);
+}
+
+pub fn make_lower(out: []u8, s: []const u8) []u8 {
+    return std.ascii.lowerString(out, s);
+}
+
+/// native object referenced from the lettercase table
+pub const Point = struct {
+    x: f32,
+    y: f32,
+};
+
+pub const POINT_ZERO = Point{ .x = 0.0, .y = 0.0 };
+pub const POINT_ONE = Point{ .x = 1.0, .y = 1.0 };
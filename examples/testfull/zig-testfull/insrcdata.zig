@@ -0,0 +1,321 @@
+// generated by insrcdata version 0.3.0
+
+const colobject = @import("colobject.zig");
+const std = @import("std");
+
+/// Iterator on the rows selected by a slice of an index
+pub fn Rows(comptime T: type, comptime I: type) type {
+    return struct {
+        table: []const T,
+        index: []const I,
+
+        /// Returns the next row, null at the end of the iteration
+        pub fn next(self: *@This()) ?*const T {
+            if (self.index.len == 0) return null;
+            const row = &self.table[self.index[0]];
+            self.index = self.index[1..];
+            return row;
+        }
+
+        /// Returns the number of remaining rows
+        pub fn len(self: @This()) usize {
+            return self.index.len;
+        }
+    };
+}
+
+// ordering used by insrcdata to sort indexes, utf-8 byte order is unicode code point order
+fn order(a: anytype, b: @TypeOf(a)) std.math.Order {
+    return switch (@TypeOf(a)) {
+        []const u8 => std.mem.order(u8, a, b),
+        bool => std.math.order(@intFromBool(a), @intFromBool(b)),
+        else => std.math.order(a, b),
+    };
+}
+
+// binary search of the first position in index with a field value greater (or equal) than value
+fn bound(comptime T: type, comptime field: []const u8, table: []const T, index: anytype, value: anytype, upper: bool) usize {
+    var lo: usize = 0;
+    var hi: usize = index.len;
+    while (lo < hi) {
+        const mid = lo + (hi - lo) / 2;
+        const o = order(@field(table[index[mid]], field), value);
+        if (o == .lt or (upper and o == .eq)) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    return lo;
+}
+
+pub const Persons = enum(u8) {
+    marie = 0,
+    pierre = 1,
+    irene = 2,
+    frederic = 3,
+    _,
+
+    /// Returns the row corresponding to label
+    pub fn row(self: Persons) *const Person {
+        return &person_table[@intFromEnum(self)];
+    }
+};
+
+pub const Lettercases = enum(u8) {
+    capital = 0,
+    upper = 1,
+    lower = 2,
+
+    /// Returns the row corresponding to label
+    pub fn row(self: Lettercases) *const Lettercase {
+        return &lettercase_table[@intFromEnum(self)];
+    }
+};
+
+pub const Person = struct {
+    index_: u8,
+    name: []const u8,
+    woman: bool,
+    score: f64,
+    spouse_index: u8,
+    father_index: u8,
+    mother_index: u8,
+
+    /// The number of rows in the table
+    pub const len = 4;
+
+    /// The min of score column values
+    pub const score_min: f64 = 1.0;
+
+    /// The max of score column values
+    pub const score_max: f64 = 3.2;
+
+    /// The sum of score column values
+    pub const score_sum: f64 = 8.4;
+
+    /// The mean of score column values
+    pub const score_mean: f64 = 2.1;
+
+    /// Returns the rows with score in [start..=stop]
+    pub fn scoreRange(start: f64, stop: f64) Rows(Person, u8) {
+        const lo = bound(Person, "score", &person_table, &person_score_index, start, false);
+        const hi = @max(lo, bound(Person, "score", &person_table, &person_score_index, stop, true));
+        return .{ .table = &person_table, .index = person_score_index[lo..hi] };
+    }
+
+    /// Returns the number of rows returned by scoreRange
+    pub fn scoreCount(start: f64, stop: f64) usize {
+        const lo = bound(Person, "score", &person_table, &person_score_index, start, false);
+        return @max(lo, bound(Person, "score", &person_table, &person_score_index, stop, true)) - lo;
+    }
+
+    pub fn spouse(self: *const Person) *const Person {
+        return &person_table[self.spouse_index];
+    }
+
+    pub fn father(self: *const Person) ?*const Person {
+        if (self.father_index == 0) return null;
+        return &person_table[self.father_index - 1];
+    }
+
+    pub fn mother(self: *const Person) ?*const Person {
+        if (self.mother_index == 0) return null;
+        return &person_table[self.mother_index - 1];
+    }
+
+    /// Returns the Wikidata rows that reference this row
+    pub fn wdata(self: *const Person) Rows(Wikidata, u8) {
+        const cons: u8 = @intCast(self.index_);
+        const lo = bound(Wikidata, "object_index", &wikidata_table, &wikidata_object_index, cons, false);
+        const hi = bound(Wikidata, "object_index", &wikidata_table, &wikidata_object_index, cons, true);
+        return .{ .table = &wikidata_table, .index = wikidata_object_index[lo..hi] };
+    }
+
+    /// Returns the Congress rows that reference this row
+    pub fn congress(self: *const Person) Rows(Congress, u8) {
+        const cons: u8 = @intCast(@as(usize, self.index_) + 1);
+        const lo = bound(Congress, "object_index", &congress_table, &congress_object_index, cons, false);
+        const hi = bound(Congress, "object_index", &congress_table, &congress_object_index, cons, true);
+        return .{ .table = &congress_table, .index = congress_object_index[lo..hi] };
+    }
+
+    /// Returns the label of the row
+    pub fn persons(self: *const Person) Persons {
+        return @enumFromInt(self.index_);
+    }
+
+    /// Returns the position of the row in the table
+    pub fn asIndex(self: *const Person) usize {
+        return self.index_;
+    }
+};
+
+pub const Strencoding = struct {
+    index_: u8,
+    text: []const u8,
+
+    /// The number of rows in the table
+    pub const len = 6;
+
+    /// All the rows of the table
+    pub const array: []const Strencoding = &strencoding_table;
+
+    /// Returns the rows with text in [start..=stop]
+    pub fn textRange(start: []const u8, stop: []const u8) Rows(Strencoding, u8) {
+        const lo = bound(Strencoding, "text", &strencoding_table, &strencoding_text_index, start, false);
+        const hi = @max(lo, bound(Strencoding, "text", &strencoding_table, &strencoding_text_index, stop, true));
+        return .{ .table = &strencoding_table, .index = strencoding_text_index[lo..hi] };
+    }
+
+    /// Returns the number of rows returned by textRange
+    pub fn textCount(start: []const u8, stop: []const u8) usize {
+        const lo = bound(Strencoding, "text", &strencoding_table, &strencoding_text_index, start, false);
+        return @max(lo, bound(Strencoding, "text", &strencoding_table, &strencoding_text_index, stop, true)) - lo;
+    }
+
+    /// Returns the position of the row in the table
+    pub fn asIndex(self: *const Strencoding) usize {
+        return self.index_;
+    }
+};
+
+pub const Lettercase = struct {
+    index_: u8,
+    name: []const u8,
+    transformer: colobject.Transformer,
+    point: *const colobject.Point,
+
+    /// The number of rows in the table
+    pub const len = 3;
+
+    /// Returns the Wikidata rows that reference this row
+    pub fn wdata2(self: *const Lettercase) Rows(Wikidata, u8) {
+        const cons: u8 = @intCast(@as(usize, self.index_) + 4);
+        const lo = bound(Wikidata, "object_index", &wikidata_table, &wikidata_object_index, cons, false);
+        const hi = bound(Wikidata, "object_index", &wikidata_table, &wikidata_object_index, cons, true);
+        return .{ .table = &wikidata_table, .index = wikidata_object_index[lo..hi] };
+    }
+
+    /// Returns the Congress rows that reference this row
+    pub fn congress(self: *const Lettercase) Rows(Congress, u8) {
+        const cons: u8 = @intCast(@as(usize, self.index_) + 5);
+        const lo = bound(Congress, "object_index", &congress_table, &congress_object_index, cons, false);
+        const hi = bound(Congress, "object_index", &congress_table, &congress_object_index, cons, true);
+        return .{ .table = &congress_table, .index = congress_object_index[lo..hi] };
+    }
+
+    /// Returns the label of the row
+    pub fn lettercases(self: *const Lettercase) Lettercases {
+        return @enumFromInt(self.index_);
+    }
+
+    /// Returns the position of the row in the table
+    pub fn asIndex(self: *const Lettercase) usize {
+        return self.index_;
+    }
+};
+
+/// One of the rows that can be referenced by Wikidata.object()
+pub const WikidataObject = union(enum) {
+    person: *const Person,
+    lettercase: *const Lettercase,
+};
+
+pub const Wikidata = struct {
+    index_: u8,
+    qid: u32,
+    object_index: u8,
+
+    /// The number of rows in the table
+    pub const len = 3;
+
+    /// All the rows of the table
+    pub const array: []const Wikidata = &wikidata_table;
+
+    pub fn object(self: *const Wikidata) WikidataObject {
+        const v = self.object_index;
+        if (v <= 3) return .{ .person = &person_table[v] };
+        if (v <= 6) return .{ .lettercase = &lettercase_table[v - 4] };
+        unreachable;
+    }
+
+    /// Returns the position of the row in the table
+    pub fn asIndex(self: *const Wikidata) usize {
+        return self.index_;
+    }
+};
+
+/// One of the rows that can be referenced by Congress.object(), null for rows without match
+pub const CongressObject = union(enum) {
+    person: *const Person,
+    lettercase: *const Lettercase,
+};
+
+pub const Congress = struct {
+    index_: u8,
+    lccn: []const u8,
+    object_index: u8,
+
+    /// The number of rows in the table
+    pub const len = 4;
+
+    /// All the rows of the table
+    pub const array: []const Congress = &congress_table;
+
+    pub fn object(self: *const Congress) ?CongressObject {
+        const v = self.object_index;
+        if (v <= 0) return null;
+        if (v <= 4) return .{ .person = &person_table[v - 1] };
+        if (v <= 7) return .{ .lettercase = &lettercase_table[v - 5] };
+        unreachable;
+    }
+
+    /// Returns the position of the row in the table
+    pub fn asIndex(self: *const Congress) usize {
+        return self.index_;
+    }
+};
+
+pub const person_table = [_]Person{
+    .{ .index_ = 0, .name = "Marie Curie", .woman = true, .score = 1.0, .spouse_index = 1, .father_index = 0, .mother_index = 0 },
+    .{ .index_ = 1, .name = "Pierre Curie", .woman = false, .score = 2.1, .spouse_index = 0, .father_index = 0, .mother_index = 0 },
+    .{ .index_ = 2, .name = "Irène Joliot-Curie", .woman = true, .score = 3.2, .spouse_index = 3, .father_index = 2, .mother_index = 1 },
+    .{ .index_ = 3, .name = "Frédéric Joliot-Curie", .woman = false, .score = 2.1, .spouse_index = 2, .father_index = 0, .mother_index = 0 },
+};
+
+const person_score_index = [_]u8{ 0, 1, 3, 2 };
+
+pub const strencoding_table = [_]Strencoding{
+    .{ .index_ = 0, .text = "𝒾ň𝗌яčḓẚᵵᶏ : 𝔢ᶆḃ℮𝚍 ᶌ𝖔ừᵳ ⅆằƫⱥ" },
+    .{ .index_ = 1, .text = "hello" },
+    .{ .index_ = 2, .text = "κόσμε" },
+    .{ .index_ = 3, .text = "いろはにほへとちりぬるを" },
+    .{ .index_ = 4, .text = "éventuellement validé" },
+    .{ .index_ = 5, .text = "Да, но фальшивый экземпляр" },
+};
+
+const strencoding_text_index = [_]u8{ 1, 4, 2, 5, 3, 0 };
+
+pub const lettercase_table = [_]Lettercase{
+    .{ .index_ = 0, .name = "Capitalised case", .transformer = &colobject.make_capitalize, .point = &colobject.POINT_ZERO },
+    .{ .index_ = 1, .name = "Upper case", .transformer = &colobject.make_upper, .point = &colobject.POINT_ONE },
+    .{ .index_ = 2, .name = "Lower case", .transformer = &colobject.make_lower, .point = &colobject.POINT_ONE },
+};
+
+pub const wikidata_table = [_]Wikidata{
+    .{ .index_ = 0, .qid = 7186, .object_index = 0 },
+    .{ .index_ = 1, .qid = 8185162, .object_index = 6 },
+    .{ .index_ = 2, .qid = 150989, .object_index = 3 },
+};
+
+const wikidata_object_index = [_]u8{ 0, 2, 1 };
+
+pub const congress_table = [_]Congress{
+    .{ .index_ = 0, .lccn = "n2009011553", .object_index = 1 },
+    .{ .index_ = 1, .lccn = "sh85148650", .object_index = 7 },
+    .{ .index_ = 2, .lccn = "n80159913", .object_index = 4 },
+    .{ .index_ = 3, .lccn = "n79006404", .object_index = 0 },
+};
+
+const congress_object_index = [_]u8{ 0, 2, 1 };
@@ -0,0 +1,151 @@
+//
+//  main.zig
+//  testfull
+//
+
+const std = @import("std");
+const db = @import("insrcdata.zig");
+
+fn check(ok: bool) void {
+    if (!ok) @panic("assertion failed");
+}
+
+// compare the names of the rows returned by an iterator
+fn checkNames(rows: anytype, expected: []const []const u8) void {
+    var it = rows;
+    check(it.len() == expected.len);
+    for (expected) |name| {
+        check(std.mem.eql(u8, it.next().?.name, name));
+    }
+    check(it.next() == null);
+}
+
+// the join column reference a record in the same table
+fn testInnerjoin() void {
+    const marie = db.Persons.marie.row();
+    const pierre = db.Persons.pierre.row();
+    const irene = db.Persons.irene.row();
+    const frederic = db.Persons.frederic.row();
+
+    check(std.mem.eql(u8, marie.name, "Marie Curie"));
+    check(std.mem.eql(u8, pierre.name, "Pierre Curie"));
+    check(std.mem.eql(u8, irene.name, "Irène Joliot-Curie"));
+    check(std.mem.eql(u8, frederic.name, "Frédéric Joliot-Curie"));
+
+    // inner join with 1-1 cardinality
+    check(marie.spouse() == pierre);
+    check(pierre.spouse() == marie);
+    check(irene.spouse() == frederic);
+    check(frederic.spouse() == irene);
+
+    // inner join with 0-1 cardinality
+    check(marie.mother() == null);
+    check(marie.father() == null);
+    check(frederic.father() == null);
+    check(irene.mother().? == marie);
+    check(irene.father().? == pierre);
+}
+
+// bool data type
+fn testBool() void {
+    check(db.Persons.marie.row().woman);
+    check(!db.Persons.pierre.row().woman);
+}
+
+// float data type
+fn testFloat() void {
+    // the range is stable : table order is preserved for equal values
+    checkNames(db.Person.scoreRange(2.1, 3.2), &.{ "Pierre Curie", "Frédéric Joliot-Curie", "Irène Joliot-Curie" });
+
+    // reversed, under and over
+    check(db.Person.scoreRange(2.0, 1.9).len() == 0);
+    check(db.Person.scoreCount(0.0, 0.9) == 0);
+    check(db.Person.scoreCount(0.0, 1.0) == 1);
+    check(db.Person.scoreCount(10.0, 9000.0) == 0);
+    check(db.Person.scoreCount(3.2, 9000.0) == 1);
+    check(db.Person.len == 4);
+
+    // aggregates
+    check(db.Person.score_min == 1.0);
+    check(db.Person.score_max == 3.2);
+}
+
+// retrieve label from record reference
+fn testFictolabel() void {
+    check(db.Persons.irene.row().persons() == .irene);
+    check(db.Lettercases.lower.row().lettercases() == .lower);
+}
+
+// check string comparison for various encoded unicode strings
+fn testStrencoding() void {
+    var texts = db.Strencoding.textRange("", "\u{10ffff}");
+    check(texts.len() == db.Strencoding.len);
+    var previous = texts.next().?;
+    check(std.mem.eql(u8, previous.text, "hello"));
+    check(previous == &db.Strencoding.array[1]);
+    while (texts.next()) |text| {
+        check(std.mem.order(u8, previous.text, text.text) == .lt);
+        previous = text;
+    }
+    check(db.Strencoding.textCount("hello", "hello") == 1);
+}
+
+// object type column : reference to native objects
+fn testColobject() void {
+    var buffer: [16]u8 = undefined;
+    const upper = db.Lettercases.upper.row();
+    check(std.mem.eql(u8, upper.transformer(&buffer, "hello"), "HELLO"));
+    check(upper.point.x == 1.0);
+
+    const capital = db.Lettercases.capital.row();
+    check(std.mem.eql(u8, capital.transformer(&buffer, "hello"), "Hello"));
+    check(capital.point.x == 0.0);
+}
+
+// variant
+fn testVariantNonOptional() void {
+    const q_marie = &db.Wikidata.array[0];
+    check(q_marie.object().person == db.Persons.marie.row());
+
+    const q_lower = &db.Wikidata.array[1];
+    const lower = db.Lettercases.lower.row();
+    switch (q_lower.object()) {
+        .lettercase => |object| check(object == lower),
+        else => @panic("lettercase expected"),
+    }
+    var wdata2 = lower.wdata2();
+    check(wdata2.next().? == q_lower);
+    check(wdata2.next() == null);
+
+    check(db.Persons.pierre.row().wdata().len() == 0);
+}
+
+// variant with unmatched rows
+fn testVariantOptional() void {
+    const q_marie = &db.Congress.array[0];
+    check(q_marie.object().?.person == db.Persons.marie.row());
+
+    const q_lower = &db.Congress.array[1];
+    const lower = db.Lettercases.lower.row();
+    check(q_lower.object().?.lettercase == lower);
+    var congress = lower.congress();
+    check(congress.next().? == q_lower);
+    check(congress.next() == null);
+
+    check(db.Persons.pierre.row().congress().len() == 0);
+
+    // not matched
+    const q_france = &db.Congress.array[3];
+    check(q_france.object() == null);
+}
+
+pub fn main() void {
+    testInnerjoin();
+    testBool();
+    testFloat();
+    testFictolabel();
+    testStrencoding();
+    testColobject();
+    testVariantNonOptional();
+    testVariantOptional();
+}
@@ -8,7 +8,7 @@
 use std::ops::RangeInclusive;
 use std::{cmp, fmt};
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum BaseType {
     #[allow(dead_code)]
     Label {
@@ -101,6 +101,21 @@ impl BaseType {
             | BaseType::Variant => 0,
         }
     }
+
+    // size of a value in bytes, None for types without fixed size representation
+    pub fn byte_size(&self) -> Option<usize> {
+        match self {
+            BaseType::Bool | BaseType::I8 | BaseType::U8 => Some(1),
+            BaseType::I16 | BaseType::U16 => Some(2),
+            BaseType::I32 | BaseType::U32 | BaseType::F32 => Some(4),
+            BaseType::I64 | BaseType::U64 | BaseType::F64 => Some(8),
+            BaseType::Str
+            | BaseType::Label { .. }
+            | BaseType::Join { .. }
+            | BaseType::Object { .. }
+            | BaseType::Variant => None,
+        }
+    }
 }
 
 impl fmt::Display for BaseType {
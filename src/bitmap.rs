@@ -0,0 +1,66 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Bitmap index generator
+//
+
+use crate::language::Language;
+use crate::table;
+
+// rows matching a column value, packed in 64 bits words
+pub struct Bitmap {
+    pub value: String, // value formatted for target language
+    pub words: Vec<u64>,
+}
+
+// number of words needed for a table
+pub fn word_count(len: usize) -> usize {
+    std::cmp::max(1, len.div_ceil(64))
+}
+
+// one bitmap for each distinct value of column, sorted by value
+pub fn bitmaps(col: &dyn table::Column, lang: &dyn Language) -> Vec<Bitmap> {
+    let len = col.info().len;
+    let mut bitmaps: Vec<Bitmap> = vec![];
+    for row in col.indexes() {
+        let value = col.emit_table_cell(row, lang);
+        let is_new = match bitmaps.last() {
+            Some(last) => last.value != value,
+            None => true,
+        };
+        if is_new {
+            bitmaps.push(Bitmap {
+                value,
+                words: vec![0; word_count(len)],
+            });
+        }
+        let bitmap = bitmaps.last_mut().expect("bitmap must exist");
+        bitmap.words[row / 64] |= 1 << (row % 64);
+    }
+    bitmaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colbool::ColBool;
+    use crate::langrust;
+    use crate::table::ColumnConfig;
+
+    #[test]
+    fn bool_bitmaps() {
+        let mut strvals = vec!["false".to_string(); 70];
+        strvals[1] = "true".to_string();
+        strvals[65] = "true".to_string();
+        let c = ColBool::parse(ColumnConfig::default(), &strvals).expect("");
+
+        let bitmaps = bitmaps(c.as_ref(), langrust::RUST);
+        assert!(bitmaps.len() == 2);
+        assert!(bitmaps[0].value == "false");
+        assert!(bitmaps[1].value == "true");
+        assert!(bitmaps[1].words == vec![0b10, 0b10]);
+        assert!(bitmaps[0].words[0] == !0b10);
+        assert!(bitmaps[0].words[1] == 0b11_1101);
+    }
+}
@@ -0,0 +1,169 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// BK-tree generator for approximate string lookup
+//
+
+use std::cmp::max;
+
+// flattened tree : nodes are stored in breadth first order
+pub struct BkTree {
+    pub nodes: Vec<usize>,     // table row of each node
+    pub distances: Vec<usize>, // distance from each node to its parent
+    pub children: Vec<usize>,  // children of node i are nodes children[i]..children[i+1]
+    pub stack_size: usize,     // upper bound of the depth first search stack
+    pub max_len: usize,        // number of chars of the longest value
+}
+
+struct Node {
+    row: usize,
+    children: Vec<(usize, usize)>, // (distance, node)
+}
+
+// edit distance between two strings, counted in unicode chars
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diag
+            } else {
+                1 + diag.min(above).min(row[j])
+            };
+            diag = above;
+        }
+    }
+    row[b.len()]
+}
+
+impl BkTree {
+    pub fn new(values: &[String]) -> BkTree {
+        // build linked tree
+        let mut tree: Vec<Node> = vec![];
+        for (row, value) in values.iter().enumerate() {
+            if !tree.is_empty() {
+                let mut current = 0;
+                loop {
+                    let distance = levenshtein(value, &values[tree[current].row]);
+                    match tree[current].children.iter().find(|c| c.0 == distance) {
+                        Some(&(_, child)) => current = child,
+                        None => {
+                            let node = tree.len();
+                            tree[current].children.push((distance, node));
+                            break;
+                        }
+                    }
+                }
+            }
+            tree.push(Node {
+                row,
+                children: vec![],
+            });
+        }
+        for node in &mut tree {
+            node.children.sort();
+        }
+
+        // flatten in breadth first order
+        let mut bktree = BkTree {
+            nodes: vec![],
+            distances: vec![],
+            children: vec![],
+            stack_size: 1,
+            max_len: values.iter().map(|v| v.chars().count()).max().unwrap_or(0),
+        };
+        let mut queue: Vec<(usize, usize)> = vec![];
+        if !tree.is_empty() {
+            queue.push((0, 0));
+        }
+        let mut next = 1;
+        for i in 0.. {
+            let Some(&(distance, node)) = queue.get(i) else {
+                break;
+            };
+            bktree.nodes.push(tree[node].row);
+            bktree.distances.push(distance);
+            bktree.children.push(next);
+            next += tree[node].children.len();
+            queue.extend(tree[node].children.iter());
+        }
+        bktree.children.push(next);
+
+        if !tree.is_empty() {
+            bktree.stack_size = max(1, stack_size(&tree, 0));
+        }
+        bktree
+    }
+}
+
+// a stack entry is added for each child along the search path
+fn stack_size(tree: &[Node], node: usize) -> usize {
+    let children = &tree[node].children;
+    let deepest = children
+        .iter()
+        .map(|&(_, child)| stack_size(tree, child))
+        .max()
+        .unwrap_or(0);
+    children.len() + deepest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // same algorithm as generated code
+    fn search(tree: &BkTree, values: &[String], query: &str, max_distance: usize) -> Vec<usize> {
+        let mut found = vec![];
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            assert!(stack.len() < tree.stack_size);
+            let row = tree.nodes[node];
+            let distance = levenshtein(query, &values[row]);
+            if distance <= max_distance {
+                found.push(row);
+            }
+            for child in tree.children[node]..tree.children[node + 1] {
+                let edge = tree.distances[child];
+                if edge + max_distance >= distance && edge <= distance + max_distance {
+                    stack.push(child);
+                }
+            }
+        }
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn distance() {
+        assert!(levenshtein("kitten", "sitting") == 3);
+        assert!(levenshtein("", "abc") == 3);
+        assert!(levenshtein("éte", "ete") == 1);
+    }
+
+    #[test]
+    fn search_match_brute_force() {
+        let values: Vec<String> = [
+            "France", "Frances", "Germany", "Greece", "Grenada", "France",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let tree = BkTree::new(&values);
+        assert!(tree.nodes.len() == values.len());
+        assert!(tree.max_len == 7);
+
+        for query in ["france", "Grece", "Spain"] {
+            for max_distance in 0..4 {
+                let expected: Vec<usize> = (0..values.len())
+                    .filter(|&row| levenshtein(query, &values[row]) <= max_distance)
+                    .collect();
+                assert!(search(&tree, &values, query, max_distance) == expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Binary blob holding table rows as fixed size little endian records
+//
+
+use crate::basetype::BaseType;
+use crate::packing::Packing;
+use crate::table;
+
+// size of a str field : u32 offset and u32 length of utf-8 bytes stored after the rows
+// with a nul terminator
+pub const STR_SIZE: usize = 8;
+
+// location of a column value in a row record
+pub struct Field {
+    pub name: String, // column name
+    pub offset: usize,
+    pub typ: BaseType, // type of the stored value
+}
+
+pub struct Blob {
+    pub row_size: usize,
+    pub fields: Vec<Field>,
+    pub words: Vec<usize>, // offset of packed words
+    pub bytes: Vec<u8>,    // rows followed by str values
+}
+
+impl Blob {
+    pub fn new(datacols: &[&dyn table::Column], packing: Option<&Packing>, len: usize) -> Blob {
+        let mut row_size = 0;
+        let mut fields = vec![];
+        for col in datacols {
+            if packing.and_then(|p| p.field(*col)).is_some() {
+                continue;
+            }
+            let typ = match col.compressed() {
+                Some(compressed) => compressed.offset_type(),
+                None => col.info().table_type.clone(),
+            };
+            // label and object values stay in source code
+            let size = match typ {
+                BaseType::Str => STR_SIZE,
+                _ => match typ.byte_size() {
+                    Some(size) => size,
+                    None => continue,
+                },
+            };
+            fields.push(Field {
+                name: col.name().to_string(),
+                offset: row_size,
+                typ,
+            });
+            row_size += size;
+        }
+        let mut words = vec![];
+        let word_size = packing.map_or(0, |p| p.word_bits as usize / 8);
+        for _ in 0..packing.map_or(0, |p| p.words) {
+            words.push(row_size);
+            row_size += word_size;
+        }
+
+        let mut bytes = Vec::with_capacity(row_size * len);
+        let mut strs: Vec<u8> = vec![];
+        for row in 0..len {
+            for field in &fields {
+                let col = datacols
+                    .iter()
+                    .find(|col| col.name() == &field.name)
+                    .expect("field column");
+                if let Some(compressed) = col.compressed() {
+                    let size = field.typ.byte_size().expect("offset size");
+                    bytes.extend(&compressed.offsets[row].to_le_bytes()[..size]);
+                    continue;
+                }
+                let value = col.blob_value(row).expect("column value in blob");
+                if field.typ == BaseType::Str {
+                    let offset = row_size * len + strs.len();
+                    bytes.extend((offset as u32).to_le_bytes());
+                    bytes.extend((value.len() as u32).to_le_bytes());
+                    strs.extend(value);
+                    strs.push(0);
+                } else {
+                    bytes.extend(value);
+                }
+            }
+            if let Some(packing) = packing {
+                for word in packing.row(datacols, row) {
+                    bytes.extend(&word.to_le_bytes()[..word_size]);
+                }
+            }
+        }
+        bytes.extend(strs);
+
+        Blob {
+            row_size,
+            fields,
+            words,
+            bytes,
+        }
+    }
+
+    pub fn field(&self, col: &dyn table::Column) -> Option<&Field> {
+        self.fields.iter().find(|f| &f.name == col.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colfloat::ColF32;
+    use crate::colint::ColInt;
+    use crate::colstr::ColStr;
+    use crate::table::ColumnConfig;
+
+    fn config(name: &str) -> ColumnConfig {
+        ColumnConfig {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn blob_rows() {
+        let strvals = |v: &[&str]| -> Vec<String> { v.iter().map(|s| s.to_string()).collect() };
+        let a = ColInt::parse(config("a"), &strvals(&["-2", "300"]), BaseType::I16).unwrap();
+        let b = ColStr::parse(config("b"), &strvals(&["xy", "z"])).unwrap();
+        let c = ColF32::parse(config("c"), &strvals(&["1.5", "0"])).unwrap();
+        let datacols = vec![a.as_ref(), b.as_ref(), c.as_ref()];
+
+        let blob = Blob::new(&datacols, None, 2);
+        assert!(blob.row_size == 2 + STR_SIZE + 4);
+        assert!(blob.field(datacols[1]).unwrap().offset == 2);
+        assert!(blob.words.is_empty());
+        assert!(blob.bytes.len() == 2 * blob.row_size + 5);
+
+        // first row
+        assert!(blob.bytes[0..2] == (-2i16).to_le_bytes());
+        assert!(blob.bytes[2..6] == 28u32.to_le_bytes());
+        assert!(blob.bytes[6..10] == 2u32.to_le_bytes());
+        assert!(blob.bytes[10..14] == 1.5f32.to_le_bytes());
+        assert!(&blob.bytes[28..] == b"xy\0z\0");
+    }
+}
@@ -0,0 +1,121 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Transitive closure of self join
+//
+
+// depth first numbering of the forest defined by parent links
+// descendants of row r are preorder[enter[r]+1..exit[r]]
+pub struct Closure {
+    pub preorder: Vec<usize>, // rows in depth first order
+    pub enter: Vec<usize>,    // position of each row in preorder
+    pub exit: Vec<usize>,     // position following the last descendant of each row
+    pub depth: Vec<usize>,    // distance of each row from its root
+}
+
+// parent of a row, roots are rows without parent or linked to themselves
+fn parent(parents: &[Option<usize>], row: usize) -> Option<usize> {
+    parents[row].filter(|p| *p != row && *p < parents.len())
+}
+
+impl Closure {
+    pub fn new(parents: &[Option<usize>]) -> Closure {
+        let len = parents.len();
+        let mut children: Vec<Vec<usize>> = vec![vec![]; len];
+        for row in 0..len {
+            if let Some(p) = parent(parents, row) {
+                children[p].push(row);
+            }
+        }
+
+        // rows in cycles are not reachable from a root and keep an empty range
+        let mut closure = Closure {
+            preorder: vec![],
+            enter: vec![len; len],
+            exit: vec![len; len],
+            depth: vec![0; len],
+        };
+        for root in (0..len).filter(|r| parent(parents, *r).is_none()) {
+            // (row, next child to visit)
+            let mut stack = vec![(root, 0)];
+            closure.enter[root] = closure.preorder.len();
+            closure.preorder.push(root);
+            while let Some((row, child)) = stack.pop() {
+                match children[row].get(child) {
+                    Some(&next) => {
+                        stack.push((row, child + 1));
+                        closure.enter[next] = closure.preorder.len();
+                        closure.depth[next] = stack.len();
+                        closure.preorder.push(next);
+                        stack.push((next, 0));
+                    }
+                    None => closure.exit[row] = closure.preorder.len(),
+                }
+            }
+        }
+        closure
+    }
+}
+
+// rows that are their own ancestor
+pub fn cycles(parents: &[Option<usize>]) -> Vec<usize> {
+    const UNVISITED: u8 = 0;
+    const VISITING: u8 = 1;
+    const DONE: u8 = 2;
+
+    let mut state = vec![UNVISITED; parents.len()];
+    let mut cycles = vec![];
+    for start in 0..parents.len() {
+        let mut path = vec![];
+        let mut current = Some(start);
+        while let Some(row) = current {
+            match state[row] {
+                UNVISITED => {
+                    state[row] = VISITING;
+                    path.push(row);
+                    current = parent(parents, row);
+                }
+                VISITING => {
+                    let begin = path.iter().position(|r| *r == row).unwrap_or(0);
+                    cycles.extend(&path[begin..]);
+                    break;
+                }
+                _ => break,
+            }
+        }
+        for row in path {
+            state[row] = DONE;
+        }
+    }
+    cycles.sort();
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forest_numbering() {
+        // 0 and 4 are roots :  0 -> (1 -> 3, 2),  4 -> 5
+        let parents = [None, Some(0), Some(0), Some(1), Some(4), Some(4)];
+        let closure = Closure::new(&parents);
+        assert!(closure.preorder == vec![0, 1, 3, 2, 4, 5]);
+        assert!(closure.enter == vec![0, 1, 3, 2, 4, 5]);
+        assert!(closure.exit == vec![4, 3, 4, 3, 6, 6]);
+        assert!(closure.depth == vec![0, 1, 1, 2, 0, 1]);
+        assert!(cycles(&parents).is_empty());
+    }
+
+    #[test]
+    fn detect_cycles() {
+        // 1 -> 2 -> 3 -> 1 is a cycle, 4 is attached to it
+        let parents = [None, Some(2), Some(3), Some(1), Some(1)];
+        assert!(cycles(&parents) == vec![1, 2, 3]);
+
+        let closure = Closure::new(&parents);
+        assert!(closure.preorder == vec![0]);
+        assert!(closure.enter[4] == closure.exit[4]);
+    }
+}
@@ -20,12 +20,12 @@ impl table::Column for ColBool {
         &self.info
     }
 
-    fn emit_table_cell(&self, row: usize, _lang: &dyn Language) -> String {
-        if self.values[row] {
-            "true".to_string()
-        } else {
-            "false".to_string()
-        }
+    fn emit_table_cell(&self, row: usize, lang: &dyn Language) -> String {
+        lang.emit_bool(self.values[row])
+    }
+
+    fn packed_value(&self, row: usize) -> Option<i64> {
+        Some(self.values[row] as i64)
     }
 
     fn indexes(&self) -> Vec<usize> {
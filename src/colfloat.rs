@@ -9,6 +9,46 @@ use crate::language::Language;
 use crate::table::ColumnConfig;
 use crate::{aperror, table};
 use crate::{basetype, lint};
+use std::fmt::Debug;
+
+// statistics computed from values, sum and mean use double precision
+fn aggregates<T: Copy + Debug + Default + Into<f64>>(
+    info: &table::ColumnInfo,
+    values: &[T],
+) -> Vec<table::Aggregate> {
+    let cmp = |a: &&T, b: &&T| (**a).into().total_cmp(&(**b).into());
+    let sum: f64 = values.iter().map(|v| (*v).into()).sum();
+
+    let mut aggregates = vec![];
+    for name in &info.config.aggregates {
+        let aggregate = match name.as_str() {
+            "min" => table::Aggregate {
+                name: name.to_string(),
+                typ: info.interface_type.clone(),
+                value: format!(
+                    "{:?}",
+                    values.iter().min_by(cmp).copied().unwrap_or_default()
+                ),
+            },
+            "max" => table::Aggregate {
+                name: name.to_string(),
+                typ: info.interface_type.clone(),
+                value: format!(
+                    "{:?}",
+                    values.iter().max_by(cmp).copied().unwrap_or_default()
+                ),
+            },
+            "sum" => table::Aggregate::float(name, basetype::BaseType::F64, sum),
+            "mean" => {
+                let mean = sum / values.len() as f64;
+                table::Aggregate::float(name, basetype::BaseType::F64, mean)
+            }
+            _ => continue,
+        };
+        aggregates.push(aggregate);
+    }
+    aggregates
+}
 
 // ================================================================================================
 // F64
@@ -23,6 +63,10 @@ impl table::Column for ColF64 {
         &self.info
     }
 
+    fn blob_value(&self, row: usize) -> Option<Vec<u8>> {
+        Some(self.values[row].to_le_bytes().to_vec())
+    }
+
     fn emit_table_cell(&self, row: usize, _lang: &dyn Language) -> String {
         let v = &self.values[row];
         format!("{:?}", v)
@@ -36,6 +80,10 @@ impl table::Column for ColF64 {
     }
 
     fn lint(&self, _linter: &lint::Linter) {}
+
+    fn aggregates(&self) -> Vec<table::Aggregate> {
+        aggregates(&self.info, &self.values)
+    }
 }
 
 impl ColF64 {
@@ -69,6 +117,10 @@ impl table::Column for ColF32 {
         &self.info
     }
 
+    fn blob_value(&self, row: usize) -> Option<Vec<u8>> {
+        Some(self.values[row].to_le_bytes().to_vec())
+    }
+
     fn emit_table_cell(&self, row: usize, _lang: &dyn Language) -> String {
         let v = &self.values[row];
         format!("{:?}", v)
@@ -82,6 +134,10 @@ impl table::Column for ColF32 {
     }
 
     fn lint(&self, _linter: &lint::Linter) {}
+
+    fn aggregates(&self) -> Vec<table::Aggregate> {
+        aggregates(&self.info, &self.values)
+    }
 }
 
 impl ColF32 {
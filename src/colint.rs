@@ -27,6 +27,10 @@ impl table::Column for ColInt {
         v.to_string()
     }
 
+    fn packed_value(&self, row: usize) -> Option<i64> {
+        Some(self.values[row])
+    }
+
     fn indexes(&self) -> Vec<usize> {
         let values = &self.values;
         let mut indexes = Vec::from_iter(0..values.len());
@@ -50,10 +54,52 @@ impl table::Column for ColInt {
                 self.min, self.info.interface_type
             ),
         );
+        if self.info.config.aggregates.iter().any(|a| a == "sum") {
+            let sum = self.sum();
+            linter.err(
+                sum >= i64::MIN as i128 && sum <= self.sum_type().max() as i128,
+                &format!("sum overflow {} for type {}", sum, self.sum_type()),
+            );
+        }
+    }
+
+    fn aggregates(&self) -> Vec<table::Aggregate> {
+        let mut aggregates = vec![];
+        for name in &self.info.config.aggregates {
+            let integer = |typ: basetype::BaseType, value: String| table::Aggregate {
+                name: name.to_string(),
+                typ,
+                value,
+            };
+            let aggregate = match name.as_str() {
+                "min" => integer(self.info.interface_type.clone(), self.min.to_string()),
+                "max" => integer(self.info.interface_type.clone(), self.max.to_string()),
+                "sum" => integer(self.sum_type(), self.sum().to_string()),
+                "mean" => {
+                    let mean = self.sum() as f64 / self.values.len() as f64;
+                    table::Aggregate::float(name, basetype::BaseType::F64, mean)
+                }
+                _ => continue,
+            };
+            aggregates.push(aggregate);
+        }
+        aggregates
     }
 }
 
 impl ColInt {
+    fn sum(&self) -> i128 {
+        self.values.iter().map(|v| *v as i128).sum()
+    }
+
+    fn sum_type(&self) -> basetype::BaseType {
+        if self.info.interface_type.min() < 0 {
+            basetype::BaseType::I64
+        } else {
+            basetype::BaseType::U64
+        }
+    }
+
     pub fn parse(
         config: ColumnConfig,
         strvals: &[String],
@@ -86,7 +132,7 @@ mod tests {
     fn u8_no_oveflow() {
         let c = ColInt::parse(
             ColumnConfig::default(),
-            &vec!["123".to_string()],
+            &["123".to_string()],
             basetype::BaseType::U8,
         )
         .expect("");
@@ -99,7 +145,7 @@ mod tests {
     fn u8_oveflow() {
         let c = ColInt::parse(
             ColumnConfig::default(),
-            &vec!["300".to_string()],
+            &["300".to_string()],
             basetype::BaseType::U8,
         )
         .expect("");
@@ -112,7 +158,7 @@ mod tests {
     fn u8_underflow() {
         let c = ColInt::parse(
             ColumnConfig::default(),
-            &vec!["-1".to_string()],
+            &["-1".to_string()],
             basetype::BaseType::U8,
         )
         .expect("");
@@ -120,4 +166,19 @@ mod tests {
         c.lint(&linter);
         assert!(linter.errors() == 1);
     }
+
+    #[test]
+    fn i8_aggregates() {
+        let config = ColumnConfig {
+            aggregates: vec!["min".to_string(), "sum".to_string(), "mean".to_string()],
+            ..Default::default()
+        };
+        let strvals: Vec<String> = ["-3", "1", "4"].iter().map(|s| s.to_string()).collect();
+        let c = ColInt::parse(config, &strvals, basetype::BaseType::I8).expect("");
+        let aggregates = c.aggregates();
+        assert!(aggregates.len() == 3);
+        assert!(aggregates[0].value == "-3");
+        assert!(aggregates[1].value == "2" && aggregates[1].typ == basetype::BaseType::I64);
+        assert!(aggregates[2].value == "0.6666666666666666");
+    }
 }
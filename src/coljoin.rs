@@ -4,10 +4,11 @@
 //
 // jointure column
 //
+use crate::closure::Closure;
 use crate::language::Language;
 use crate::table;
 use crate::table::ColumnConfig;
-use crate::{basetype, lint};
+use crate::{basetype, closure, lint};
 use std::collections::HashMap;
 
 pub struct ColJoin {
@@ -30,6 +31,11 @@ impl table::Column for ColJoin {
         }
     }
 
+    fn packed_value(&self, row: usize) -> Option<i64> {
+        let v = self.values[row] as i64;
+        Some(if self.optional() { v } else { v - 1 })
+    }
+
     fn indexes(&self) -> Vec<usize> {
         let values = &self.values;
         let mut indexes = if self.optional() {
@@ -53,19 +59,39 @@ impl table::Column for ColJoin {
                 });
             }
         }
+        if self.info.config.closure.is_some() {
+            for row in closure::cycles(&self.parents()) {
+                linter.row(row, |lt| {
+                    lt.err(false, "cycle in closure join");
+                });
+            }
+        }
     }
 
     fn reverse_name(&self) -> String {
         self.reverse_name.to_string()
     }
+
+    fn closure(&self) -> Option<Closure> {
+        self.info
+            .config
+            .closure
+            .as_ref()
+            .map(|_| Closure::new(&self.parents()))
+    }
 }
 
 impl ColJoin {
+    // joined row for each row
+    fn parents(&self) -> Vec<Option<usize>> {
+        self.values.iter().map(|v| v.checked_sub(1)).collect()
+    }
+
     pub fn new(
         mut config: ColumnConfig,
-        values: &Vec<String>,
+        values: &[String],
         dest_table: &str,
-        dest_keys: &Vec<String>,
+        dest_keys: &[String],
 
         reverse: &str,
     ) -> ColJoin {
@@ -103,8 +103,8 @@ mod tests {
         let c = ColLabel::parse(
             ColumnConfig::default(),
             "",
-            &vec!["hello".to_string()],
-            &vec!["".to_string()],
+            &["hello".to_string()],
+            &["".to_string()],
         )
         .expect("");
         let linter = lint::test_linter();
@@ -117,8 +117,8 @@ mod tests {
         let c = ColLabel::parse(
             ColumnConfig::default(),
             "",
-            &vec!["0hello".to_string()],
-            &vec!["".to_string()],
+            &["0hello".to_string()],
+            &["".to_string()],
         )
         .expect("");
         let linter = lint::test_linter();
@@ -131,8 +131,8 @@ mod tests {
         let c = ColLabel::parse(
             ColumnConfig::default(),
             "",
-            &vec!["hello".to_string(), "HELLO".to_string()],
-            &vec!["".to_string(), "".to_string()],
+            &["hello".to_string(), "HELLO".to_string()],
+            &["".to_string(), "".to_string()],
         )
         .expect("");
         let linter = lint::test_linter();
@@ -145,8 +145,8 @@ mod tests {
         let c = ColLabel::parse(
             ColumnConfig::default(),
             "",
-            &vec!["hello".to_string(), "".to_string()],
-            &vec!["".to_string(), "".to_string()],
+            &["hello".to_string(), "".to_string()],
+            &["".to_string(), "".to_string()],
         )
         .expect("");
         let linter = lint::test_linter();
@@ -162,8 +162,8 @@ mod tests {
                 ..Default::default()
             },
             "",
-            &vec!["hello".to_string(), "".to_string()],
-            &vec!["".to_string(), "".to_string()],
+            &["hello".to_string(), "".to_string()],
+            &["".to_string(), "".to_string()],
         )
         .expect("");
         let linter = lint::test_linter();
@@ -173,13 +173,8 @@ mod tests {
 
     #[test]
     fn label_missing_help() {
-        let c = ColLabel::parse(
-            ColumnConfig::default(),
-            "",
-            &vec!["hello".to_string()],
-            &vec![],
-        )
-        .expect("");
+        let c =
+            ColLabel::parse(ColumnConfig::default(), "", &["hello".to_string()], &[]).expect("");
         let linter = lint::test_linter();
         c.lint(&linter);
         assert!(linter.errors() == 1);
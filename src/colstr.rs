@@ -5,14 +5,20 @@
 // string data type column
 //
 
+use crate::bktree::BkTree;
+use crate::compress::{self, Compressed};
 use crate::language::Language;
 use crate::table::ColumnConfig;
 use crate::{aperror, table};
 use crate::{basetype, lint};
 
+// uncompressed size under which a compression that does not save space is only noted
+const SMALL_COMPRESSION_BYTES: usize = 1024;
+
 pub struct ColStr {
     info: table::ColumnInfo,
     values: Vec<String>,
+    compressed: Option<Compressed>,
 }
 
 impl table::Column for ColStr {
@@ -20,10 +26,8 @@ impl table::Column for ColStr {
         &self.info
     }
 
-    fn emit_table_cell(&self, row: usize, _lang: &dyn Language) -> String {
-        // TODO : stability of String.Debug trait implementation is not guaranteed
-        let v = &self.values[row];
-        format!("{:?}", v)
+    fn emit_table_cell(&self, row: usize, lang: &dyn Language) -> String {
+        lang.emit_str(&self.values[row])
     }
 
     fn indexes(&self) -> Vec<usize> {
@@ -34,7 +38,51 @@ impl table::Column for ColStr {
         indexes
     }
 
-    fn lint(&self, _linter: &lint::Linter) {}
+    fn lint(&self, linter: &lint::Linter) {
+        let config = &self.info.config;
+        let Some(codec) = &config.compress else {
+            return;
+        };
+        linter.err(
+            compress::CODECS.contains(&codec.as_str()),
+            &format!("unknown compression {}", codec),
+        );
+        linter.err(
+            !config.fuzzy && !config.bitmap && !config.group,
+            "compressed column can not be fuzzy, bitmap or group",
+        );
+        if let Some(compressed) = &self.compressed {
+            let message = format!(
+                "{} compression uses {} bytes instead of {}",
+                codec,
+                compressed.bytes(),
+                compressed.unpacked_bytes
+            );
+            // symbol tables outweigh the savings on a few strings : not worth a warning
+            let small = compressed.unpacked_bytes < SMALL_COMPRESSION_BYTES;
+            if compressed.bytes() < compressed.unpacked_bytes || small {
+                linter.note(&message);
+            } else {
+                linter.warn(false, &format!("{message}, strings are larger compressed"));
+            }
+        }
+    }
+
+    fn bktree(&self) -> Option<BkTree> {
+        if self.info.config.fuzzy {
+            Some(BkTree::new(&self.values))
+        } else {
+            None
+        }
+    }
+
+    fn compressed(&self) -> Option<&Compressed> {
+        self.compressed.as_ref()
+    }
+
+    fn blob_value(&self, row: usize) -> Option<Vec<u8>> {
+        Some(self.values[row].as_bytes().to_vec())
+    }
 }
 
 impl ColStr {
@@ -42,6 +90,10 @@ impl ColStr {
         config: ColumnConfig,
         values: &Vec<String>,
     ) -> aperror::Result<Box<dyn table::Column>> {
+        let compressed = config
+            .compress
+            .as_ref()
+            .and_then(|codec| Compressed::new(codec, values));
         Ok(Box::new(ColStr {
             info: table::ColumnInfo {
                 config,
@@ -50,6 +102,7 @@ impl ColStr {
                 table_type: basetype::BaseType::Str,
             },
             values: values.to_owned(),
+            compressed,
         }))
     }
 }
@@ -10,13 +10,15 @@ pub struct Dest<'a> {
     table: String,
     /// generate accessor for reverse join
     reverse: String,
+    /// column of table matched by values
+    key: String,
 
     max: usize,
 
     toindex: HashMap<&'a String, usize>,
 }
 impl<'a> Dest<'a> {
-    pub fn new(values: &'a [String], table: String, reverse: String) -> Dest {
+    pub fn new(values: &'a [String], table: String, reverse: String, key: String) -> Dest<'a> {
         let mut keyindexes = HashMap::<&String, usize>::new();
         for (i, x) in values.iter().enumerate() {
             keyindexes.insert(x, i);
@@ -24,6 +26,7 @@ impl<'a> Dest<'a> {
         Dest {
             table,
             reverse,
+            key,
             max: 0,
             toindex: keyindexes,
         }
@@ -52,6 +55,10 @@ impl table::Column for ColVariant {
         v.to_string()
     }
 
+    fn packed_value(&self, row: usize) -> Option<i64> {
+        Some(self.values[row] as i64)
+    }
+
     fn indexes(&self) -> Vec<usize> {
         if self.optional() {
             index::filter_index(&self.values, 0)
@@ -132,6 +139,7 @@ fn col_offsets(dests: &Vec<Dest>, optional: bool) -> (Vec<Variant>, usize) {
             index: 0,
             count: 1,
             reverse: "".to_string(),
+            key: "".to_string(),
             is_none: true,
         });
         index = 1;
@@ -143,6 +151,7 @@ fn col_offsets(dests: &Vec<Dest>, optional: bool) -> (Vec<Variant>, usize) {
             index,
             count: dest.max,
             reverse: dest.reverse.to_string(),
+            key: dest.key.to_string(),
             is_none: false,
         });
         index += dest.max;
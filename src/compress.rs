@@ -0,0 +1,359 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Compression of str columns with a dictionary built at generation time
+//
+
+use crate::basetype;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+pub const CODECS: [&str; 2] = ["huffman", "fsst"];
+
+// huffman symbol ending each value
+pub const HUFFMAN_END: usize = 256;
+// longest huffman code, decoders accumulate codes in 32 bits
+const HUFFMAN_MAX_BITS: usize = 24;
+
+// fsst codes that are not symbols
+pub const FSST_END: usize = 254;
+pub const FSST_ESCAPE: usize = 255;
+const FSST_MAX_SYMBOL: usize = 8;
+const FSST_GENERATIONS: usize = 5;
+
+pub enum Dictionary {
+    // canonical code : number of codes of each bit length and symbols in code order
+    Huffman {
+        counts: Vec<usize>,
+        symbols: Vec<usize>,
+    },
+    // byte strings of the codes below FSST_END
+    Fsst {
+        symbols: Vec<Vec<u8>>,
+    },
+}
+
+pub struct Compressed {
+    pub dictionary: Dictionary,
+    pub blob: Vec<u8>,         // encoded values, each one ended by an end code
+    pub offsets: Vec<usize>,   // start of each row value in blob
+    pub max_len: usize,        // longest decoded value in bytes
+    pub unpacked_bytes: usize, // size of distinct values without compression
+}
+
+impl Compressed {
+    pub fn new(codec: &str, values: &[String]) -> Option<Compressed> {
+        // identical values share their encoding
+        let mut distinct: Vec<&[u8]> = vec![];
+        let mut positions = HashMap::<&str, usize>::new();
+        let rows: Vec<usize> = values
+            .iter()
+            .map(|v| {
+                *positions.entry(v).or_insert_with(|| {
+                    distinct.push(v.as_bytes());
+                    distinct.len() - 1
+                })
+            })
+            .collect();
+
+        let (dictionary, encoded) = match codec {
+            "huffman" => huffman(&distinct),
+            "fsst" => fsst(&distinct),
+            _ => return None,
+        };
+
+        let mut blob = vec![];
+        let mut starts = vec![];
+        for code in encoded {
+            starts.push(blob.len());
+            blob.extend(code);
+        }
+        Some(Compressed {
+            dictionary,
+            blob,
+            offsets: rows.iter().map(|row| starts[*row]).collect(),
+            max_len: values.iter().map(|v| v.len()).max().unwrap_or(0),
+            unpacked_bytes: distinct.iter().map(|v| v.len()).sum(),
+        })
+    }
+
+    pub fn codec(&self) -> &'static str {
+        match self.dictionary {
+            Dictionary::Huffman { .. } => "huffman",
+            Dictionary::Fsst { .. } => "fsst",
+        }
+    }
+
+    // compressed values and dictionary
+    pub fn bytes(&self) -> usize {
+        self.blob.len()
+            + match &self.dictionary {
+                Dictionary::Huffman { counts, symbols } => 2 * (counts.len() + symbols.len()),
+                Dictionary::Fsst { symbols } => {
+                    symbols.iter().map(|s| s.len()).sum::<usize>() + 2 * (symbols.len() + 1)
+                }
+            }
+    }
+
+    // integer type large enough for offsets in blob
+    pub fn offset_type(&self) -> basetype::BaseType {
+        basetype::int_type_for_range(0..=self.blob.len() as i64)
+    }
+}
+
+// ================================================================================================
+// Huffman
+// ================================================================================================
+// bit length of the code of each symbol, 0 for unused symbols
+fn huffman_lengths(freqs: &[usize]) -> Vec<usize> {
+    let mut freqs = freqs.to_vec();
+    loop {
+        let mut lengths = vec![0; freqs.len()];
+        let leaves: Vec<usize> = (0..freqs.len()).filter(|s| freqs[*s] > 0).collect();
+        if leaves.len() == 1 {
+            lengths[leaves[0]] = 1;
+            return lengths;
+        }
+
+        let mut parents = vec![usize::MAX; leaves.len()];
+        let mut heap: BinaryHeap<Reverse<(usize, usize)>> = leaves
+            .iter()
+            .enumerate()
+            .map(|(node, s)| Reverse((freqs[*s], node)))
+            .collect();
+        while heap.len() > 1 {
+            let Reverse((wa, a)) = heap.pop().expect("heap has two nodes");
+            let Reverse((wb, b)) = heap.pop().expect("heap has two nodes");
+            let node = parents.len();
+            parents.push(usize::MAX);
+            parents[a] = node;
+            parents[b] = node;
+            heap.push(Reverse((wa + wb, node)));
+        }
+
+        for (leaf, s) in leaves.iter().enumerate() {
+            let mut node = leaf;
+            while parents[node] != usize::MAX {
+                lengths[*s] += 1;
+                node = parents[node];
+            }
+        }
+        if lengths.iter().all(|len| *len <= HUFFMAN_MAX_BITS) {
+            return lengths;
+        }
+        // flatten the frequencies until the longest code fit
+        for f in freqs.iter_mut().filter(|f| **f > 0) {
+            *f = (*f).div_ceil(2);
+        }
+    }
+}
+
+fn huffman(values: &[&[u8]]) -> (Dictionary, Vec<Vec<u8>>) {
+    let mut freqs = vec![0; HUFFMAN_END + 1];
+    for v in values {
+        for b in v.iter() {
+            freqs[*b as usize] += 1;
+        }
+        freqs[HUFFMAN_END] += 1;
+    }
+    let lengths = huffman_lengths(&freqs);
+
+    // canonical code : shorter codes first, then by symbol
+    let mut symbols: Vec<usize> = (0..lengths.len()).filter(|s| lengths[*s] > 0).collect();
+    symbols.sort_by_key(|s| (lengths[*s], *s));
+    let max_bits = lengths.iter().copied().max().unwrap_or(0);
+    let mut counts = vec![0; max_bits + 1];
+    let mut codes = vec![(0u32, 0usize); lengths.len()];
+    let mut code = 0u32;
+    let mut len = 0;
+    for s in &symbols {
+        while len < lengths[*s] {
+            code <<= 1;
+            len += 1;
+        }
+        codes[*s] = (code, len);
+        counts[len] += 1;
+        code += 1;
+    }
+
+    let encoded = values
+        .iter()
+        .map(|v| {
+            let mut bytes = vec![];
+            let mut bit = 0;
+            let symbols = v.iter().map(|b| *b as usize).chain([HUFFMAN_END]);
+            for s in symbols {
+                let (code, len) = codes[s];
+                for i in (0..len).rev() {
+                    if bit % 8 == 0 {
+                        bytes.push(0);
+                    }
+                    if (code >> i) & 1 != 0 {
+                        *bytes.last_mut().expect("byte pushed") |= 0x80 >> (bit % 8);
+                    }
+                    bit += 1;
+                }
+            }
+            bytes
+        })
+        .collect();
+    (Dictionary::Huffman { counts, symbols }, encoded)
+}
+
+// ================================================================================================
+// FSST : Fast Static Symbol Table
+// ================================================================================================
+// greedy split of a value in longest symbols, None for escaped bytes
+fn fsst_tokens<'a>(index: &HashMap<&[u8], usize>, v: &'a [u8]) -> Vec<(&'a [u8], Option<usize>)> {
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < v.len() {
+        let longest = FSST_MAX_SYMBOL.min(v.len() - pos);
+        let token = (1..=longest)
+            .rev()
+            .find_map(|len| index.get(&v[pos..pos + len]).map(|code| (len, *code)));
+        match token {
+            Some((len, code)) => {
+                tokens.push((&v[pos..pos + len], Some(code)));
+                pos += len;
+            }
+            None => {
+                tokens.push((&v[pos..pos + 1], None));
+                pos += 1;
+            }
+        }
+    }
+    tokens
+}
+
+// symbol table refined over generations : keep symbols and concatenations with best gain
+fn fsst_symbols(values: &[&[u8]]) -> Vec<Vec<u8>> {
+    let mut symbols: Vec<Vec<u8>> = vec![];
+    for _ in 0..FSST_GENERATIONS {
+        let index: HashMap<&[u8], usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(code, s)| (s.as_slice(), code))
+            .collect();
+        let mut counts = HashMap::<Vec<u8>, usize>::new();
+        for v in values {
+            let tokens = fsst_tokens(&index, v);
+            for (i, (token, _)) in tokens.iter().enumerate() {
+                *counts.entry(token.to_vec()).or_default() += 1;
+                if let Some((next, _)) = tokens.get(i + 1) {
+                    if token.len() + next.len() <= FSST_MAX_SYMBOL {
+                        *counts.entry([*token, *next].concat()).or_default() += 1;
+                    }
+                }
+            }
+        }
+        let mut candidates: Vec<(usize, Vec<u8>)> = counts
+            .into_iter()
+            .map(|(s, count)| (count * s.len(), s))
+            .collect();
+        candidates.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        symbols = candidates
+            .into_iter()
+            .take(FSST_END)
+            .map(|(_, s)| s)
+            .collect();
+    }
+    symbols
+}
+
+fn fsst(values: &[&[u8]]) -> (Dictionary, Vec<Vec<u8>>) {
+    let symbols = fsst_symbols(values);
+    let index: HashMap<&[u8], usize> = symbols
+        .iter()
+        .enumerate()
+        .map(|(code, s)| (s.as_slice(), code))
+        .collect();
+    let encoded = values
+        .iter()
+        .map(|v| {
+            let mut bytes = vec![];
+            for (token, code) in fsst_tokens(&index, v) {
+                match code {
+                    Some(code) => bytes.push(code as u8),
+                    None => bytes.extend([FSST_ESCAPE as u8, token[0]]),
+                }
+            }
+            bytes.push(FSST_END as u8);
+            bytes
+        })
+        .collect();
+    (Dictionary::Fsst { symbols }, encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // same algorithms as generated decoders
+    fn decode(c: &Compressed, row: usize) -> String {
+        let mut out = vec![];
+        let mut pos = c.offsets[row];
+        match &c.dictionary {
+            Dictionary::Huffman { counts, symbols } => {
+                let mut bit = pos * 8;
+                loop {
+                    let (mut code, mut first, mut index) = (0, 0, 0);
+                    let mut symbol = None;
+                    for count in &counts[1..] {
+                        code |= ((c.blob[bit / 8] >> (7 - bit % 8)) & 1) as usize;
+                        bit += 1;
+                        if code < first + count {
+                            symbol = Some(symbols[index + code - first]);
+                            break;
+                        }
+                        index += count;
+                        first = (first + count) << 1;
+                        code <<= 1;
+                    }
+                    match symbol.expect("valid code") {
+                        HUFFMAN_END => break,
+                        s => out.push(s as u8),
+                    }
+                }
+            }
+            Dictionary::Fsst { symbols } => loop {
+                let code = c.blob[pos] as usize;
+                pos += 1;
+                match code {
+                    FSST_END => break,
+                    FSST_ESCAPE => {
+                        out.push(c.blob[pos]);
+                        pos += 1;
+                    }
+                    _ => out.extend(&symbols[code]),
+                }
+            },
+        }
+        String::from_utf8(out).expect("utf8")
+    }
+
+    #[test]
+    fn roundtrip() {
+        let values: Vec<String> = [
+            "Premier ministre",
+            "",
+            "Ministre de l'Économie",
+            "Premier ministre",
+            "Ministre des Armées",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        for codec in CODECS {
+            let c = Compressed::new(codec, &values).unwrap();
+            assert!(c.codec() == codec);
+            assert!(c.offsets[0] == c.offsets[3]);
+            assert!(c.max_len == "Ministre de l'Économie".len());
+            for (row, v) in values.iter().enumerate() {
+                assert!(&decode(&c, row) == v);
+            }
+        }
+        assert!(Compressed::new("zip", &values).is_none());
+    }
+}
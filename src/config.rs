@@ -164,11 +164,26 @@ struct Col {
     as_label: Option<String>,
     /// column containing documentation for each label
     label_helps: Option<String>,
+    /// generate accessor for approximate string lookup
+    fuzzy: Option<bool>,
+    /// doc comment for approximate lookup method
+    fuzzy_help: Option<String>,
+    /// generate bitmap index for low cardinality column
+    bitmap: Option<bool>,
+    /// doc comment for bitmap lookup method
+    bitmap_help: Option<String>,
+    /// statistics computed at generation time : min, max, sum, mean
+    aggregates: Option<Vec<String>>,
+    /// generate iterator on distinct values and their rows
+    group: Option<bool>,
+    /// doc comment for groups iterator
+    group_help: Option<String>,
+    /// codec of compressed str values : huffman or fsst
+    compress: Option<String>,
 }
 static EMPTY_TARGET: Vec<Target> = vec![];
 impl Col {
-    /// generate column for integer type value
-
+    /// target language definition for object column
     fn target(&self, lang: &str) -> Option<&Target> {
         self.target
             .as_ref()
@@ -185,6 +200,16 @@ impl Col {
     ) -> aperror::Result<Box<dyn table::Column>> {
         let lang = ctx.table_context.lang.extension();
         let Some(target) = self.target(&lang) else {
+            if !ctx.table_context.lang.native_objects() {
+                // raw values are enough for languages that only describe the data
+                return Ok(Box::new(colobject::ColObject::new(
+                    config,
+                    strvals.to_owned(),
+                    "object",
+                    "{}",
+                    "",
+                )));
+            }
             return Err(aperror::Error::new(&format!(
                 "target language {} not defined for column {}",
                 lang, self.name
@@ -250,6 +275,18 @@ impl Col {
             iterable: self.range.unwrap_or(false),
             iter_help: self.range_help.to_owned(),
             optional: false,
+            fuzzy: self.fuzzy.unwrap_or(false),
+            fuzzy_help: self.fuzzy_help.to_owned(),
+            bitmap: self.bitmap.unwrap_or(false),
+            bitmap_help: self.bitmap_help.to_owned(),
+            aggregates: self.aggregates.to_owned().unwrap_or_default(),
+            group: self.group.unwrap_or(false),
+            group_help: self.group_help.to_owned(),
+            closure: None,
+            closure_help: None,
+            compress: self.compress.to_owned(),
+            src: src.to_owned(),
+            key: String::new(),
         };
 
         // generate column from field type
@@ -304,6 +341,10 @@ struct Join {
     reverse: Option<String>,
     /// doc comment for reverse join
     reverse_help: Option<String>,
+    /// transitive closure of self join : ancestors or descendants
+    closure: Option<String>,
+    /// doc comment for closure iterator
+    closure_help: Option<String>,
 }
 
 impl Join {
@@ -334,6 +375,11 @@ impl Join {
             iterable: false, // will be computed from reverse
             iter_help: self.reverse_help.to_owned(),
             optional: self.optional.unwrap_or_default(),
+            closure: self.closure.to_owned(),
+            closure_help: self.closure_help.to_owned(),
+            src: src.to_owned(),
+            key: self.to.to_owned(),
+            ..Default::default()
         };
 
         Ok(Box::new(coljoin::ColJoin::new(
@@ -369,6 +415,7 @@ impl Either {
             values,
             dest_table,
             self.reverse.as_deref().unwrap_or_default().to_string(),
+            self.to.to_string(),
         )
     }
 }
@@ -425,6 +472,8 @@ impl Variant {
             iterable: false,
             iter_help: None,
             optional: self.optional.unwrap_or_default(),
+            src: src.to_owned(),
+            ..Default::default()
         };
 
         ColVariant::parse(config, values, &mut dests)
@@ -458,6 +507,13 @@ struct Table {
     sorted: Option<bool>,
     /// set this flag to true if a row can not be added between major releases
     exhaustive: Option<bool>,
+    /// column used to implement Display for rows
+    display: Option<String>,
+    /// memory layout : "aos" for array of row structs, "soa" for one array per column,
+    /// "blob" for rows in a binary file
+    layout: Option<String>,
+    /// "bits" pack small integer, bool and join columns into words
+    packing: Option<String>,
     /// list of struct field that will implement a column in the table
     col: Option<Vec<Col>>,
     /// list of the struct field that will implement a join in the table
@@ -625,13 +681,43 @@ impl Table {
 
         let get_array = self.array.unwrap_or(false);
         let exhaustive = self.exhaustive.unwrap_or(false);
-        table::Table::new(
+        let layout = self.layout.as_deref().unwrap_or("aos");
+        let soa = match layout {
+            "aos" => false,
+            "soa" | "blob" => true,
+            other => {
+                let err = aperror::Error::new(&format!("unknown layout '{}'", other));
+                runtime.linter.check_result::<()>(&self.name, Err(err));
+                false
+            }
+        };
+        let mut table = table::Table::new(
             &self.name,
             self.help.to_owned(),
             columns,
             get_array,
             exhaustive,
-        )
+            self.display.to_owned(),
+            soa,
+        );
+        match self.packing.as_deref().unwrap_or("none") {
+            "none" => {}
+            "bits" => {
+                if !table.pack() {
+                    runtime.linter.context(&self.name, |lt| {
+                        lt.note("bits packing skipped as it would not reduce the row size")
+                    });
+                }
+            }
+            other => {
+                let err = aperror::Error::new(&format!("unknown packing '{}'", other));
+                runtime.linter.check_result::<()>(&self.name, Err(err));
+            }
+        }
+        if layout == "blob" {
+            table.store_blob();
+        }
+        table
     }
 }
 
@@ -643,6 +729,20 @@ impl Table {
 struct Config {
     dest: Option<String>,
     help: Option<String>,
+    /// generated code must not depend on std library
+    no_std: Option<bool>,
+    /// generated Rust code use const fn for joins, label conversions and counts (Rust 1.83 or later)
+    const_fn: Option<bool>,
+    /// generated Rust code implement serde Serialize for rows and labels
+    derive_serde: Option<bool>,
+    /// serialize joins as row index ("key") or as nested object ("nested")
+    serde_joins: Option<String>,
+    /// generated Rust code is a directory with one module file per table
+    split: Option<bool>,
+    /// generated C code is a single header, implemented where INSRCDATA_IMPLEMENTATION is defined
+    single_header: Option<bool>,
+    /// generated C functions and tables are static
+    static_linkage: Option<bool>,
     table: Vec<Table>,
 }
 struct ConfigContext {
@@ -672,6 +772,11 @@ impl Config {
         } else {
             dst.to_string()
         };
+        // split module directory take the place of the module file
+        let dst = match self.split.unwrap_or(false) {
+            true => dst.strip_suffix(".rs").unwrap_or(&dst).to_string(),
+            false => dst,
+        };
 
         let confpath = Path::new(&dst);
         if confpath.is_absolute() {
@@ -697,9 +802,16 @@ impl Config {
     /// create project object
     fn project(&self, ctx: &ConfigContext) -> aperror::Result<Project> {
         let dst_path = self.dst_path(&ctx.runtime);
+        // split output is a Rust module directory without extension
+        let lang = match dst_path.extension() {
+            None if self.split.unwrap_or(false) => {
+                language::language_for_dest(&dst_path.with_extension("rs"))?
+            }
+            _ => language::language_for_dest(&dst_path)?,
+        };
         let table_context = TableContext {
             config_context: ctx,
-            lang: language::language_for_dest(&dst_path),
+            lang,
             col_values: self.read_values(&ctx.runtime),
         };
 
@@ -709,10 +821,28 @@ impl Config {
             tables.push(t);
         }
 
+        let serde_nested = match self.serde_joins.as_deref().unwrap_or("key") {
+            "key" => false,
+            "nested" => true,
+            other => {
+                return Err(aperror::Error::new(&format!(
+                    "unknown serde_joins '{}'",
+                    other
+                )))
+            }
+        };
+
         let project = Project {
             dst_path,
             help: self.help.to_owned(),
             lang: table_context.lang,
+            no_std: self.no_std.unwrap_or(false),
+            const_fn: self.const_fn.unwrap_or(false),
+            derive_serde: self.derive_serde.unwrap_or(false),
+            serde_nested,
+            split: self.split.unwrap_or(false),
+            single_header: self.single_header.unwrap_or(false),
+            static_linkage: self.static_linkage.unwrap_or(false),
             tables,
             src_paths: self.src_paths(ctx),
         };
@@ -5,14 +5,55 @@
 // Index generator
 //
 
-pub fn index<T: Ord>(values: &Vec<T>) -> Vec<usize> {
+use crate::language::Language;
+use crate::table;
+
+pub fn index<T: Ord>(values: &[T]) -> Vec<usize> {
     let mut indexes = Vec::from_iter(0..values.len());
     indexes.sort_by(|a, b| values[*a].cmp(&values[*b]));
     indexes
 }
 
-pub fn filter_index<T: Ord>(values: &Vec<T>, exclude: T) -> Vec<usize> {
+pub fn filter_index<T: Ord>(values: &[T], exclude: T) -> Vec<usize> {
     let mut indexes = Vec::from_iter((0..values.len()).filter(|a| values[*a] != exclude));
     indexes.sort_by(|a, b| values[*a].cmp(&values[*b]));
     indexes
 }
+
+// position in sorted index where each run of equal values starts, followed by index length
+pub fn group_bounds(col: &dyn table::Column, lang: &dyn Language) -> Vec<usize> {
+    let indexes = col.indexes();
+    let mut bounds = vec![];
+    let mut last: Option<String> = None;
+    for (pos, row) in indexes.iter().enumerate() {
+        let value = col.emit_table_cell(*row, lang);
+        if last.as_ref() != Some(&value) {
+            bounds.push(pos);
+            last = Some(value);
+        }
+    }
+    bounds.push(indexes.len());
+    bounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basetype::BaseType;
+    use crate::colint::ColInt;
+    use crate::langrust;
+    use crate::table::ColumnConfig;
+
+    #[test]
+    fn int_group_bounds() {
+        let strvals: Vec<String> = ["3", "1", "3", "2", "1", "3"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let c = ColInt::parse(ColumnConfig::default(), &strvals, BaseType::U8).expect("");
+        assert!(group_bounds(c.as_ref(), langrust::RUST) == vec![0, 2, 3, 6]);
+
+        let c = ColInt::parse(ColumnConfig::default(), &[], BaseType::U8).expect("");
+        assert!(group_bounds(c.as_ref(), langrust::RUST) == vec![0]);
+    }
+}
@@ -6,9 +6,11 @@
 //
 
 use crate::basetype::BaseType;
+use crate::blob::Blob;
+use crate::compress::{self, Dictionary};
 use crate::language::Language;
 use crate::table::JoinTo;
-use crate::{aperror, basetype, language, log, table};
+use crate::{aperror, basetype, bitmap, index, language, log, packing, table};
 use heck::{ToShoutySnakeCase, ToSnakeCase};
 use language::write_help;
 use std::path::PathBuf;
@@ -97,41 +99,257 @@ pub fn table_name(table_name: &str) -> String {
     table_name.to_shouty_snake_case()
 }
 
+// ================================================================================================
+// Linkage
+// ================================================================================================
+// languages using the C binding need external symbols
+fn static_linkage(project: &table::Project) -> bool {
+    project.static_linkage && project.lang.extension() == "c"
+}
+
+// storage class of functions declared in header, inline avoid warnings for unused static functions
+// definitions without storage class get the internal linkage of their declaration
+fn linkage(project: &table::Project) -> &'static str {
+    if static_linkage(project) {
+        "static inline "
+    } else {
+        "extern "
+    }
+}
+
+// prefix of function declarations without storage class
+fn internal(project: &table::Project) -> &'static str {
+    if static_linkage(project) {
+        "static inline "
+    } else {
+        ""
+    }
+}
+
+// ================================================================================================
+// Stored values
+// ================================================================================================
+fn packed_field<'a>(
+    table: &'a table::Table,
+    col: &dyn table::Column,
+) -> Option<&'a packing::Field> {
+    table.packing.as_ref().and_then(|p| p.field(col))
+}
+
+fn word_type(table: &table::Table) -> String {
+    let bits = table.packing.as_ref().map_or(64, |p| p.word_bits);
+    format!("uint{bits}_t")
+}
+
+// value of a packed column extracted from its word
+fn unpack(col: &dyn table::Column, field: &packing::Field, word: &str) -> String {
+    let typ = &col.info().table_type;
+    if field.bits == 0 {
+        return match typ {
+            BaseType::Bool => (field.min != 0).to_string(),
+            _ => format!("(({}){})", strtype(typ), field.min),
+        };
+    }
+    let shifted = match field.shift {
+        0 => word.to_string(),
+        shift => format!("({word} >> {shift})"),
+    };
+    let masked = format!("({shifted} & {:#x}ULL)", field.mask());
+    let ctype = strtype(typ);
+    match field.min {
+        _ if *typ == BaseType::Bool => format!("({masked} != 0)"),
+        0 => format!("(({ctype}){masked})"),
+        min if min > 0 => format!("(({ctype})({masked} + {min}ULL))"),
+        min => format!("(({ctype})({masked} - {}ULL))", min.unsigned_abs()),
+    }
+}
+
+// row holding a stored value : pointer to the row struct or index in the table
+#[derive(Clone, Copy)]
+enum Row<'a> {
+    Ptr(&'a str),
+    Index(&'a str),
+}
+
+impl Row<'_> {
+    // expression accessing the struct fields like "s->"
+    fn fields(&self, table: &table::Table) -> String {
+        match self {
+            Row::Ptr(ptr) => format!("{ptr}->"),
+            Row::Index(index) => format!("{}_TABLE[{index}].", table_name(&table.name)),
+        }
+    }
+
+    // pointer to the row record in blob
+    fn record(&self, table: &table::Table, offset: usize) -> String {
+        let tablename = table_name(&table.name);
+        let index = match self {
+            Row::Ptr(ptr) => format!("({ptr} - {tablename}_TABLE)"),
+            Row::Index(index) => index.to_string(),
+        };
+        let offset = match offset {
+            0 => String::new(),
+            offset => format!(" + {offset}"),
+        };
+        format!("({tablename}_BLOB + (size_t){index} * {tablename}_ROW_SIZE{offset})")
+    }
+}
+
+// value of type typ read in blob at the record pointer
+fn blob_read(table: &table::Table, typ: &BaseType, record: &str) -> String {
+    match typ {
+        BaseType::Bool => format!("({record}[0] != 0)"),
+        BaseType::F32 => format!("insrcdata_f32((uint32_t)insrcdata_read({record}, 4))"),
+        BaseType::F64 => format!("insrcdata_f64(insrcdata_read({record}, 8))"),
+        BaseType::Str => format!(
+            "((const char*){}_BLOB + insrcdata_read({record}, 4))",
+            table_name(&table.name)
+        ),
+        typ => format!(
+            "(({})insrcdata_read({record}, {}))",
+            strtype(typ),
+            typ.byte_size().expect("blob field size")
+        ),
+    }
+}
+
+// stored value of a column
+fn stored(table: &table::Table, col: &dyn table::Column, row: Row) -> String {
+    if let Some(blob) = &table.blob {
+        if let Some(field) = packed_field(table, col) {
+            let size = table
+                .packing
+                .as_ref()
+                .map_or(8, |p| p.word_bits as usize / 8);
+            let word = format!(
+                "(({})insrcdata_read({}, {size}))",
+                word_type(table),
+                row.record(table, blob.words[field.word])
+            );
+            return unpack(col, field, &word);
+        }
+        if let Some(field) = blob.field(col) {
+            return blob_read(table, &field.typ, &row.record(table, field.offset));
+        }
+    }
+    let fields = row.fields(table);
+    match packed_field(table, col) {
+        Some(field) => unpack(col, field, &format!("{fields}packed_[{}]", field.word)),
+        None => format!("{fields}{}_", col.name()),
+    }
+}
+
+// column value is read in blob, that is not visible from the header
+fn in_blob(table: &table::Table, col: &dyn table::Column) -> bool {
+    table
+        .blob
+        .as_ref()
+        .is_some_and(|blob| blob.field(col).is_some() || packed_field(table, col).is_some())
+}
+
+// column value is a field of the row struct
+fn in_struct(table: &table::Table, col: &dyn table::Column) -> bool {
+    packed_field(table, col).is_none() && !in_blob(table, col)
+}
+
+// packing words stored in the row struct
+fn struct_words(table: &table::Table) -> Option<&packing::Packing> {
+    match table.blob {
+        Some(_) => None,
+        None => table.packing.as_ref().filter(|p| p.words > 0),
+    }
+}
+
+// type of the value stored in table, compressed columns store an offset
+fn stored_type(col: &dyn table::Column) -> BaseType {
+    match col.compressed() {
+        Some(compressed) => compressed.offset_type(),
+        None => col.info().table_type.clone(),
+    }
+}
+
+// decoding of a compressed value in buf of size bytes
+fn decoded(
+    table: &table::Table,
+    col: &dyn table::Column,
+    offset: &str,
+    buf: &str,
+    size: &str,
+) -> String {
+    let name = format!("{}_{}", table_name(&table.name), table_name(col.name()));
+    match col.compressed().map(|c| &c.dictionary) {
+        Some(Dictionary::Huffman { counts, .. }) => format!(
+            "insrcdata_huffman_decode({name}_BLOB, {name}_COUNTS, {}, {name}_SYMBOLS, {offset}, {buf}, {size})",
+            counts.len() - 1
+        ),
+        Some(Dictionary::Fsst { .. }) => format!(
+            "insrcdata_fsst_decode({name}_BLOB, {name}_SYMBOLS, {name}_SYMBOL_STARTS, {offset}, {buf}, {size})"
+        ),
+        None => offset.to_string(),
+    }
+}
+
 // ================================================================================================
 // Getters
 // ================================================================================================
 fn header_getter_col(
+    project: &table::Project,
     table: &table::Table,
     col: &dyn table::Column,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
+    let linkage = linkage(project);
     let info = col.info();
     let strname = struct_name(&table.name);
     let field = col.name();
+    if let Some(compressed) = col.compressed() {
+        let tablename = table_name(&table.name);
+        let uperfield = table_name(field);
+        let max_len = compressed.max_len;
+        writeln!(
+            output,
+            "// Length of the longest {field} value, buffers of {tablename}_{uperfield}_MAX_LEN+1 bytes hold any value
+static unsigned const {tablename}_{uperfield}_MAX_LEN = {max_len};"
+        )?;
+        write_help(output, "//", &info.config.help)?;
+        return writeln!(
+            output,
+            "// Decode {field} in buf of size bytes, truncated if buf is too small
+{linkage}const char* {strname}_{field}_decode(const {strname}_t* s, char* buf, size_t size);",
+        );
+    }
     write_help(output, "//", &info.config.help)?;
     match &info.type_impl() {
         table::TypeImpl::Label => {
             log::verbose("unexpected getter_col for Label type");
         }
+        table::TypeImpl::Scalar if in_blob(table, col) => {
+            let outtype = strtype(&info.interface_type);
+            writeln!(
+                output,
+                "{linkage}{outtype} {strname}_{field}(const {strname}_t* s);",
+            )?;
+        }
         table::TypeImpl::Scalar => {
             let outtype = strtype(&info.interface_type);
+            let value = stored(table, col, Row::Ptr("s"));
             writeln!(
                 output,
-                "static inline {outtype} {strname}_{field}(const {strname}_t* s) {{ return s->{field}_; }}",
+                "static inline {outtype} {strname}_{field}(const {strname}_t* s) {{ return {value}; }}",
             )?;
         }
         table::TypeImpl::Join => {
             let outtype = struct_name(&info.join_table());
             writeln!(
                 output,
-                "extern const {outtype}_t* {strname}_{field}(const {strname}_t* s);",
+                "{linkage}const {outtype}_t* {strname}_{field}(const {strname}_t* s);",
             )?;
         }
         table::TypeImpl::JoinOptional => {
             let outtype = struct_name(&info.join_table());
             writeln!(
                 output,
-                "extern bool {strname}_{field}(const {strname}_t* s, const {outtype}_t** ptr);",
+                "{linkage}bool {strname}_{field}(const {strname}_t* s, const {outtype}_t** ptr);",
             )?;
         }
 
@@ -139,7 +357,7 @@ fn header_getter_col(
             //let outtype = strtype(&info.interface_type);
             writeln!(
                 output,
-                "extern {strname}_{field}_t {strname}_{field}(const {strname}_t* s);",
+                "{linkage}{strname}_{field}_t {strname}_{field}(const {strname}_t* s);",
             )?;
         }
     }
@@ -155,9 +373,10 @@ fn impl_getter_join(
     let field = col.name();
     let outtype = struct_name(&col.info().join_table());
     let jointable = table_name(&outtype);
+    let value = stored(table, col, Row::Ptr("s"));
     writeln!(
         output,
-        "const {outtype}_t* {strname}_{field}(const {strname}_t* s) {{ return &{jointable}_TABLE[s->{field}_];}}",
+        "const {outtype}_t* {strname}_{field}(const {strname}_t* s) {{ return &{jointable}_TABLE[{value}];}}",
     )
 }
 
@@ -167,62 +386,809 @@ fn impl_getter_join_optional(
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
     let strname = struct_name(&table.name);
-    let field = col.name();
-    let outtype = struct_name(&col.info().join_table());
-    let jointable = table_name(&outtype);
+    let field = col.name();
+    let outtype = struct_name(&col.info().join_table());
+    let jointable = table_name(&outtype);
+    let value = stored(table, col, Row::Ptr("s"));
+    writeln!(
+        output,
+        "bool {strname}_{field}(const {strname}_t* s, const {outtype}_t** ptr) {{
+    if( {value}) {{
+        *ptr = &{jointable}_TABLE[{value}-1];
+        return true;
+    }}
+    return false;
+}}",
+    )
+}
+
+fn impl_getter_compressed(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let field = col.name();
+    let value = decoded(
+        table,
+        col,
+        &stored(table, col, Row::Ptr("s")),
+        "buf",
+        "size",
+    );
+    writeln!(
+        output,
+        "const char* {strname}_{field}_decode(const {strname}_t* s, char* buf, size_t size) {{ return {value}; }}",
+    )
+}
+
+fn impl_getter_blob(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let field = col.name();
+    let outtype = strtype(&col.info().interface_type);
+    let value = stored(table, col, Row::Ptr("s"));
+    writeln!(
+        output,
+        "{outtype} {strname}_{field}(const {strname}_t* s) {{ return {value}; }}",
+    )
+}
+
+fn impl_getter_col(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    match &col.info().type_impl() {
+        table::TypeImpl::Label => {}
+        table::TypeImpl::Scalar if col.compressed().is_some() => {
+            impl_getter_compressed(table, col, output)?;
+        }
+        table::TypeImpl::Scalar if in_blob(table, col) => {
+            impl_getter_blob(table, col, output)?;
+        }
+        table::TypeImpl::Scalar => {}
+        table::TypeImpl::Join => {
+            impl_getter_join(table, col, output)?;
+        }
+        table::TypeImpl::JoinOptional => {
+            impl_getter_join_optional(table, col, output)?;
+        }
+        table::TypeImpl::Variant => {
+            impl_getter_variant(table, col, output)?;
+        }
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn header_iter_range(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
+    let info = col.info();
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    write_help(output, "//", &info.config.iter_help)?;
+    writeln!(
+        output,
+        "{linkage}{strname}_iter_t  {strname}_{colname}_range( {argtype} start, {argtype} stop);
+// Number of rows returned by {strname}_{colname}_range
+{linkage}size_t  {strname}_{colname}_count( {argtype} start, {argtype} stop);"
+    )
+}
+
+fn impl_iter_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let strtable = table_name(&table.name);
+    let indextyp = strtype(&table.index_type());
+    let field = table_name(col.name());
+    let mut right = stored(table, col, Row::Index("*mid")) + " ";
+    if let Some(compressed) = col.compressed() {
+        // values are decoded in a buffer during comparison
+        let size = compressed.max_len + 1;
+        right = decoded(
+            table,
+            col,
+            &right,
+            &format!("(char[{size}]){{0}}"),
+            &size.to_string(),
+        );
+    }
+    let gt = gt(&col.info().table_type, "start", &right);
+    let lt = lt(&col.info().table_type, "stop", &right);
+
+    write!(
+        output,
+        "{strname}_iter_t  {strname}_{colname}_range( {argtype} start, {argtype} stop) {{
+    {indextyp}* lo = {strtable}_{field}_INDEX;
+    {indextyp}*  hi = {strtable}_{field}_INDEX + {strtable}_{field}_INDEX_COUNT;
+    while( lo < hi ){{
+        {indextyp}*  mid = lo + ( hi-lo)/2;
+        if( {gt} ){{
+             lo = mid + 1;
+        }} else {{
+             hi = mid;
+        }}
+    }}
+
+    {indextyp}*  begin = lo;
+    hi = {strtable}_{field}_INDEX + {strtable}_{field}_INDEX_COUNT;
+    while( lo < hi ){{
+         {indextyp}* mid = lo + ( hi-lo)/2;
+        if( {lt} ){{
+            hi = mid;
+        }} else {{
+            lo = mid + 1;
+        }}
+    }}
+
+    {strname}_iter_t res = {{  begin,  lo }};
+    return res;
+}}
+
+size_t  {strname}_{colname}_count( {argtype} start, {argtype} stop) {{
+    {strname}_iter_t iter = {strname}_{colname}_range(start, stop);
+    return iter.end - iter.ptr;
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Groups iterator
+// ================================================================================================
+fn header_groups(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
+    let info = col.info();
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    let valtype = strtype(&info.interface_type);
+    write_help(output, "//", &info.config.group_help)?;
+    writeln!(
+        output,
+        "{linkage}{strname}_groups_t  {strname}_{colname}_groups(void);
+// Next distinct value of {colname} with its rows, return false after last group
+{linkage}bool  {strname}_{colname}_groups_next({strname}_groups_t* groups, {valtype}* value, {strname}_iter_t* rows);"
+    )
+}
+
+fn impl_groups(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    let valtype = strtype(&col.info().interface_type);
+    let strtable = table_name(&table.name);
+    let field = table_name(col.name());
+
+    writeln!(
+        output,
+        "{strname}_groups_t  {strname}_{colname}_groups(void) {{
+    {strname}_groups_t res = {{ {strtable}_{field}_GROUPS, {strtable}_{field}_GROUPS + {strtable}_{field}_GROUPS_COUNT - 1, {strtable}_{field}_INDEX }};
+    return res;
+}}
+
+bool  {strname}_{colname}_groups_next({strname}_groups_t* groups, {valtype}* value, {strname}_iter_t* rows) {{
+    if( groups->bound >= groups->end ) {{
+        return false;
+    }}
+    rows->ptr = groups->index + groups->bound[0];
+    rows->end = groups->index + groups->bound[1];
+    groups->bound++;
+    *value = {strname}_{colname}(&{strtable}_TABLE[*rows->ptr]);
+    return true;
+}}
+"
+    )
+}
+
+fn impl_group_bounds(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !col.info().config.group {
+        return Ok(());
+    }
+    let indextyp = strtype(&table.index_type());
+    let name = format!(
+        "{}_{}_GROUPS",
+        table_name(&table.name),
+        table_name(col.name())
+    );
+    let bounds = index::group_bounds(col, project.lang);
+    writeln!(
+        output,
+        "static unsigned const {name}_COUNT = {};",
+        bounds.len()
+    )?;
+    impl_array(&name, &indextyp, &bounds, output)
+}
+
+// ================================================================================================
+// Closure of self join
+// ================================================================================================
+fn header_closure_types(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
+    let strname = struct_name(&table.name);
+    if table.has_closure("ancestors") {
+        writeln!(
+            output,
+            "typedef struct {{ const {strname}_t* row; unsigned depth; }} {strname}_ancestors_t;"
+        )?;
+    }
+    if table.has_closure("descendants") {
+        let indextyp = strtype(&table.index_type());
+        writeln!(
+            output,
+            "typedef struct {{ const {indextyp}* ptr; const {indextyp}* end; const {indextyp}* depths; unsigned base; }} {strname}_descendants_t;
+{linkage}const {strname}_t* {strname}_descendants_next({strname}_descendants_t* iter, unsigned* depth);"
+        )?;
+    }
+    Ok(())
+}
+
+fn header_closure(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
+    let info = col.info();
+    let Some(kind) = &info.config.closure else {
+        return Ok(());
+    };
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    write_help(output, "//", &info.config.closure_help)?;
+    if kind == "ancestors" {
+        writeln!(
+            output,
+            "{linkage}{strname}_ancestors_t {strname}_{colname}_ancestors(const {strname}_t* s);
+// Next ancestor with its distance, return NULL after the root
+{linkage}const {strname}_t* {strname}_{colname}_ancestors_next({strname}_ancestors_t* iter, unsigned* depth);"
+        )?;
+    } else {
+        writeln!(
+            output,
+            "{linkage}{strname}_descendants_t {strname}_{colname}_descendants(const {strname}_t* s);"
+        )?;
+    }
+    writeln!(
+        output,
+        "// Check if s is reached by following {colname} links from other row
+{linkage}bool {strname}_{colname}_is_ancestor_of(const {strname}_t* s, const {strname}_t* other);"
+    )
+}
+
+fn impl_closure(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let Some(kind) = &info.config.closure else {
+        return Ok(());
+    };
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    let field = col.name();
+    let strtable = table_name(&table.name);
+    let closurename = format!("{strtable}_{}", table_name(col.name()));
+
+    if kind == "ancestors" {
+        let parent = if info.config.optional {
+            format!(
+                "const {strname}_t* parent = NULL;
+    if( !{strname}_{field}(iter->row, &parent) ) {{
+        parent = NULL;
+    }}"
+            )
+        } else {
+            format!("const {strname}_t* parent = {strname}_{field}(iter->row);")
+        };
+        writeln!(
+            output,
+            "{strname}_ancestors_t {strname}_{colname}_ancestors(const {strname}_t* s) {{
+    {strname}_ancestors_t res = {{ s, 0 }};
+    return res;
+}}
+
+const {strname}_t* {strname}_{colname}_ancestors_next({strname}_ancestors_t* iter, unsigned* depth) {{
+    if( iter->row == NULL ) {{
+        return NULL;
+    }}
+    {parent}
+    if( parent == iter->row ) {{
+        parent = NULL;
+    }}
+    iter->row = parent;
+    *depth = ++iter->depth;
+    return parent;
+}}
+"
+        )?;
+    } else {
+        writeln!(
+            output,
+            "{strname}_descendants_t {strname}_{colname}_descendants(const {strname}_t* s) {{
+    size_t row = s - {strtable}_TABLE;
+    {strname}_descendants_t res = {{ {closurename}_PREORDER + {closurename}_ENTER[row] + 1, {closurename}_PREORDER + {closurename}_EXIT[row], {closurename}_DEPTH, {closurename}_DEPTH[row] }};
+    return res;
+}}
+"
+        )?;
+    }
+    writeln!(
+        output,
+        "bool {strname}_{colname}_is_ancestor_of(const {strname}_t* s, const {strname}_t* other) {{
+    size_t row = s - {strtable}_TABLE;
+    size_t orow = other - {strtable}_TABLE;
+    return {closurename}_ENTER[row] < {closurename}_ENTER[orow] && {closurename}_ENTER[orow] < {closurename}_EXIT[row];
+}}
+"
+    )
+}
+
+fn impl_closure_functions(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+    if !table.has_closure("descendants") {
+        return Ok(());
+    }
+    let strname = struct_name(&table.name);
+    let strtable = table_name(&table.name);
+    writeln!(
+        output,
+        "const {strname}_t* {strname}_descendants_next({strname}_descendants_t* iter, unsigned* depth) {{
+    if( iter->ptr >= iter->end ) {{
+        return NULL;
+    }}
+    *depth = iter->depths[*iter->ptr] - iter->base;
+    return &{strtable}_TABLE[*iter->ptr++];
+}}
+"
+    )
+}
+
+fn impl_closure_index(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(closure) = col.closure() else {
+        return Ok(());
+    };
+    let indextyp = strtype(&table.index_type());
+    let name = format!("{}_{}", table_name(&table.name), table_name(col.name()));
+
+    impl_array(&format!("{name}_ENTER"), &indextyp, &closure.enter, output)?;
+    impl_array(&format!("{name}_EXIT"), &indextyp, &closure.exit, output)?;
+    if col.info().config.closure.as_deref() == Some("descendants") {
+        impl_array(
+            &format!("{name}_PREORDER"),
+            &indextyp,
+            &closure.preorder,
+            output,
+        )?;
+        impl_array(&format!("{name}_DEPTH"), &indextyp, &closure.depth, output)?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn header_aggregates(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let tablename = table_name(&table.name);
+    for aggregate in col.aggregates() {
+        let name = table_name(&format!("{}_{}", col.name(), aggregate.name));
+        let typ = strtype(&aggregate.typ);
+        let value = &aggregate.value;
+        writeln!(
+            output,
+            "// {} of {} column values\nstatic {typ} const {tablename}_{name} = {value};",
+            aggregate.name,
+            col.name()
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Fuzzy lookup
+// ================================================================================================
+fn header_fuzzy(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
+    let info = col.info();
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    write_help(output, "//", &info.config.fuzzy_help)?;
+    writeln!(
+        output,
+        "{linkage}size_t {strname}_{colname}_fuzzy(const char* query, unsigned max_distance, {strname}_fuzzy_t* results, size_t capacity);"
+    )
+}
+
+fn impl_fuzzy(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(tree) = col.bktree() else {
+        return Ok(());
+    };
+    let strname = struct_name(&table.name);
+    let colname = struct_name(col.name());
+    let value = stored(table, col, Row::Ptr("s"));
+    let strtable = table_name(&table.name);
+    let indextyp = strtype(&table.index_type());
+    let fuzzy = format!("{strtable}_{}_FUZZY", table_name(col.name()));
+    let rowsize = tree.max_len + 1;
+    let stacksize = tree.stack_size;
+
+    writeln!(
+        output,
+        "size_t {strname}_{colname}_fuzzy(const char* query, unsigned max_distance, {strname}_fuzzy_t* results, size_t capacity) {{
+    unsigned row[{rowsize}];
+    {indextyp} stack[{stacksize}];
+    size_t top = 0;
+    size_t count = 0;
+    if( {fuzzy}_COUNT ) {{
+        stack[top++] = 0;
+    }}
+    while( top ) {{
+        {indextyp} node = stack[--top];
+        const {strname}_t* s = &{strtable}_TABLE[{fuzzy}_NODES[node]];
+        unsigned distance = insrcdata_levenshtein(query, {value}, row);
+        if( distance <= max_distance ) {{
+            // insert in ranked results
+            size_t i = count < capacity ? count++ : capacity;
+            while( i > 0 && ( results[i-1].distance > distance || ( results[i-1].distance == distance && results[i-1].row > s ))) {{
+                if( i < capacity ) {{
+                    results[i] = results[i-1];
+                }}
+                i--;
+            }}
+            if( i < capacity ) {{
+                results[i].row = s;
+                results[i].distance = distance;
+            }}
+        }}
+        for( {indextyp} child = {fuzzy}_CHILDREN[node]; child < {fuzzy}_CHILDREN[node+1]; child++ ) {{
+            unsigned edge = {fuzzy}_DISTANCES[child];
+            if( edge + max_distance >= distance && edge <= distance + max_distance ) {{
+                stack[top++] = child;
+            }}
+        }}
+    }}
+    return count;
+}}
+"
+    )
+}
+
+fn impl_fuzzy_index(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(tree) = col.bktree() else {
+        return Ok(());
+    };
+    let indextyp = strtype(&table.index_type());
+    let fuzzy = format!(
+        "{}_{}_FUZZY",
+        table_name(&table.name),
+        table_name(col.name())
+    );
+    let maxdist = tree.distances.iter().max().copied().unwrap_or(0);
+    let disttyp = strtype(&basetype::int_type_for_range(0..=maxdist as i64));
+    let count = tree.nodes.len();
+
+    writeln!(output, "static unsigned const {fuzzy}_COUNT  =  {count};")?;
+    impl_array(&format!("{fuzzy}_NODES"), &indextyp, &tree.nodes, output)?;
+    impl_array(
+        &format!("{fuzzy}_DISTANCES"),
+        &disttyp,
+        &tree.distances,
+        output,
+    )?;
+    impl_array(
+        &format!("{fuzzy}_CHILDREN"),
+        &indextyp,
+        &tree.children,
+        output,
+    )
+}
+
+// edit distance counted in utf-8 code points, row must hold strlen(s)+1 values
+fn impl_levenshtein(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "static uint32_t insrcdata_utf8_next(const char** s) {{
+    const unsigned char* p = (const unsigned char*)*s;
+    uint32_t c = *p++;
+    int extra = c >= 0xF0 ? 3 : c >= 0xE0 ? 2 : c >= 0xC0 ? 1 : 0;
+    if( extra ) {{
+        c &= 0x3F >> extra;
+    }}
+    while( extra-- && ( *p & 0xC0 ) == 0x80 ) {{
+        c = ( c << 6 ) | ( *p++ & 0x3F );
+    }}
+    *s = (const char*)p;
+    return c;
+}}
+
+static unsigned insrcdata_levenshtein(const char* query, const char* s, unsigned* row) {{
+    unsigned len = 0;
+    for( const char* p = s; *p; insrcdata_utf8_next(&p) ) {{
+        row[len] = len;
+        len++;
+    }}
+    row[len] = len;
+
+    unsigned i = 0;
+    while( *query ) {{
+        uint32_t cq = insrcdata_utf8_next(&query);
+        unsigned diag = row[0];
+        row[0] = ++i;
+        const char* p = s;
+        for( unsigned j = 0; j < len; j++ ) {{
+            uint32_t cs = insrcdata_utf8_next(&p);
+            unsigned above = row[j+1];
+            unsigned best = diag;
+            if( cq != cs ) {{
+                best = diag < above ? diag : above;
+                best = row[j] < best ? row[j] : best;
+                best++;
+            }}
+            row[j+1] = best;
+            diag = above;
+        }}
+    }}
+    return row[len];
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Compression
+// ================================================================================================
+fn impl_compressed_data(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(compressed) = col.compressed() else {
+        return Ok(());
+    };
+    let name = format!("{}_{}", table_name(&table.name), table_name(col.name()));
+    let blob: Vec<usize> = compressed.blob.iter().map(|b| *b as usize).collect();
+    impl_array(&format!("{name}_BLOB"), "uint8_t", &blob, output)?;
+    match &compressed.dictionary {
+        Dictionary::Huffman { counts, symbols } => {
+            impl_array(&format!("{name}_COUNTS"), "uint16_t", counts, output)?;
+            impl_array(&format!("{name}_SYMBOLS"), "uint16_t", symbols, output)
+        }
+        Dictionary::Fsst { symbols } => {
+            let bytes: Vec<usize> = symbols.concat().iter().map(|b| *b as usize).collect();
+            let mut starts = vec![0];
+            for s in symbols {
+                starts.push(starts[starts.len() - 1] + s.len());
+            }
+            impl_array(&format!("{name}_SYMBOLS"), "uint8_t", &bytes, output)?;
+            impl_array(
+                &format!("{name}_SYMBOL_STARTS"),
+                "uint16_t",
+                &starts,
+                output,
+            )
+        }
+    }
+}
+
+// decoders write at most size-1 bytes and a null terminator in buf
+fn impl_huffman_decode(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "static const char* insrcdata_huffman_decode(const uint8_t* blob, const uint16_t* counts, unsigned maxbits, const uint16_t* symbols, size_t offset, char* buf, size_t size) {{
+    size_t bit = offset * 8;
+    size_t len = 0;
+    for(;;) {{
+        // canonical code : codes of each length follow the codes of shorter length
+        unsigned code = 0, first = 0, index = 0;
+        unsigned symbol = {end};
+        for( unsigned bits = 1; bits <= maxbits; bits++ ) {{
+            code |= ( blob[bit / 8] >> ( 7 - bit % 8 ) ) & 1;
+            bit++;
+            if( code < first + counts[bits] ) {{
+                symbol = symbols[index + code - first];
+                break;
+            }}
+            index += counts[bits];
+            first = ( first + counts[bits] ) << 1;
+            code <<= 1;
+        }}
+        if( symbol == {end} ) {{
+            break;
+        }}
+        if( len + 1 < size ) {{
+            buf[len++] = (char)symbol;
+        }}
+    }}
+    if( size ) {{
+        buf[len] = 0;
+    }}
+    return buf;
+}}
+",
+        end = compress::HUFFMAN_END
+    )
+}
+
+fn impl_fsst_decode(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "static const char* insrcdata_fsst_decode(const uint8_t* blob, const uint8_t* symbols, const uint16_t* starts, size_t offset, char* buf, size_t size) {{
+    size_t pos = offset;
+    size_t len = 0;
+    for(;;) {{
+        unsigned code = blob[pos++];
+        if( code == {end} ) {{
+            break;
+        }}
+        const uint8_t* bytes = code == {escape} ? blob + pos++ : symbols + starts[code];
+        const uint8_t* end = code == {escape} ? bytes + 1 : symbols + starts[code + 1];
+        while( bytes < end && len + 1 < size ) {{
+            buf[len++] = (char)*bytes++;
+        }}
+    }}
+    if( size ) {{
+        buf[len] = 0;
+    }}
+    return buf;
+}}
+",
+        end = compress::FSST_END,
+        escape = compress::FSST_ESCAPE
+    )
+}
+
+// ================================================================================================
+// Bitmap index
+// ================================================================================================
+fn header_bitmap_types(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
+    let strname = struct_name(&table.name);
+    let words = bitmap::word_count(table.len);
     writeln!(
         output,
-        "bool {strname}_{field}(const {strname}_t* s, const {outtype}_t** ptr) {{
-    if( s->{field}_) {{
-        *ptr = &{jointable}_TABLE[s->{field}_-1];
-        return true;
-    }}
-    return false;
-}}",
+        "typedef struct {{ uint64_t words[{words}]; }} {strname}_bitmap_t;
+typedef struct {{ {strname}_bitmap_t bitmap; unsigned word; }} {strname}_bitmap_iter_t;
+{linkage}{strname}_bitmap_t {strname}_bitmap_and({strname}_bitmap_t a, {strname}_bitmap_t b);
+{linkage}{strname}_bitmap_t {strname}_bitmap_or({strname}_bitmap_t a, {strname}_bitmap_t b);
+{linkage}size_t {strname}_bitmap_count({strname}_bitmap_t bitmap);
+{linkage}{strname}_bitmap_iter_t {strname}_bitmap_iter({strname}_bitmap_t bitmap);
+{linkage}const {strname}_t* {strname}_bitmap_next({strname}_bitmap_iter_t* iter);"
     )
 }
 
-fn impl_getter_col(
-    table: &table::Table,
-    col: &dyn table::Column,
-    output: &mut dyn io::Write,
-) -> io::Result<()> {
-    match &col.info().type_impl() {
-        table::TypeImpl::Label => {}
-        table::TypeImpl::Scalar => {}
-        table::TypeImpl::Join => {
-            impl_getter_join(table, col, output)?;
-        }
-        table::TypeImpl::JoinOptional => {
-            impl_getter_join_optional(table, col, output)?;
-        }
-        table::TypeImpl::Variant => {
-            impl_getter_variant(table, col, output)?;
-        }
-    }
-    Ok(())
+fn impl_bitmap_functions(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let tablename = table_name(&table.name);
+    let words = bitmap::word_count(table.len);
+    writeln!(
+        output,
+        "{strname}_bitmap_t {strname}_bitmap_and({strname}_bitmap_t a, {strname}_bitmap_t b) {{
+    for( unsigned i = 0; i < {words}; i++ ) {{
+        a.words[i] &= b.words[i];
+    }}
+    return a;
+}}
+
+{strname}_bitmap_t {strname}_bitmap_or({strname}_bitmap_t a, {strname}_bitmap_t b) {{
+    for( unsigned i = 0; i < {words}; i++ ) {{
+        a.words[i] |= b.words[i];
+    }}
+    return a;
+}}
+
+size_t {strname}_bitmap_count({strname}_bitmap_t bitmap) {{
+    size_t count = 0;
+    for( unsigned i = 0; i < {words}; i++ ) {{
+        for( uint64_t w = bitmap.words[i]; w; w &= w - 1 ) {{
+            count++;
+        }}
+    }}
+    return count;
+}}
+
+{strname}_bitmap_iter_t {strname}_bitmap_iter({strname}_bitmap_t bitmap) {{
+    {strname}_bitmap_iter_t iter = {{ bitmap, 0 }};
+    return iter;
+}}
+
+const {strname}_t* {strname}_bitmap_next({strname}_bitmap_iter_t* iter) {{
+    while( iter->word < {words} ) {{
+        uint64_t w = iter->bitmap.words[iter->word];
+        if( w ) {{
+            unsigned bit = 0;
+            while( !( ( w >> bit ) & 1 ) ) {{
+                bit++;
+            }}
+            iter->bitmap.words[iter->word] = w & ( w - 1 );
+            return &{tablename}_TABLE[iter->word * 64 + bit];
+        }}
+        iter->word++;
+    }}
+    return NULL;
+}}
+"
+    )
 }
 
-// ================================================================================================
-// Range iterator
-// ================================================================================================
-fn header_iter_range(
+fn header_bitmap(
+    project: &table::Project,
     table: &table::Table,
     col: &dyn table::Column,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
+    let linkage = linkage(project);
     let info = col.info();
     let strname = struct_name(&table.name);
     let colname = struct_name(col.name());
     let argtype = strtype(&info.interface_type);
-    write_help(output, "//", &info.config.iter_help)?;
+    write_help(output, "//", &info.config.bitmap_help)?;
     writeln!(
         output,
-        "extern {strname}_iter_t  {strname}_{colname}_range( {argtype} start, {argtype} stop);"
+        "{linkage}{strname}_bitmap_t {strname}_where_{colname}({argtype} value);"
     )
 }
 
-fn impl_iter_range(
+fn impl_bitmap(
     table: &table::Table,
     col: &dyn table::Column,
     output: &mut dyn io::Write,
@@ -231,53 +1197,100 @@ fn impl_iter_range(
     let strname = struct_name(&table.name);
     let colname = struct_name(col.name());
     let argtype = strtype(&info.interface_type);
-    let strtable = table_name(&table.name);
-    let indextyp = strtype(&table.index_type());
-    let field = table_name(col.name());
-    let right = format!("{strtable}_TABLE[*mid].{colname}_ ");
-    let gt = gt(&col.info().table_type, "start", &right);
-    let lt = lt(&col.info().table_type, "stop", &right);
+    let bitmapname = format!(
+        "{}_{}_BITMAP",
+        table_name(&table.name),
+        table_name(col.name())
+    );
+    let gt = gt(
+        &info.interface_type,
+        "value",
+        &format!("{bitmapname}_VALUES[mid]"),
+    );
+    let lt = lt(
+        &info.interface_type,
+        "value",
+        &format!("{bitmapname}_VALUES[lo]"),
+    );
 
-    write!(
+    writeln!(
         output,
-        "{strname}_iter_t  {strname}_{colname}_range( {argtype} start, {argtype} stop) {{
-    {indextyp}* lo = {strtable}_{field}_INDEX;
-    {indextyp}*  hi = {strtable}_{field}_INDEX + {strtable}_{field}_INDEX_COUNT;
-    while( lo < hi ){{
-        {indextyp}*  mid = lo + ( hi-lo)/2;
-        if( {gt} ){{
-             lo = mid + 1;
+        "{strname}_bitmap_t {strname}_where_{colname}({argtype} value) {{
+    unsigned lo = 0;
+    unsigned hi = {bitmapname}_COUNT;
+    while( lo < hi ) {{
+        unsigned mid = lo + ( hi-lo)/2;
+        if( {gt} ) {{
+            lo = mid + 1;
         }} else {{
-             hi = mid;
-        }}
-    }}
-
-    {indextyp}*  begin = lo;
-    hi = {strtable}_{field}_INDEX + {strtable}_{field}_INDEX_COUNT;
-    while( lo < hi ){{
-         {indextyp}* mid = lo + ( hi-lo)/2;
-        if( {lt} ){{
             hi = mid;
-        }} else {{
-            lo = mid + 1;
         }}
     }}
-
-    {strname}_iter_t res = {{  begin,  lo }};
-    return res;
+    if( lo < {bitmapname}_COUNT && !( {lt} ) ) {{
+        return {bitmapname}S[lo];
+    }}
+    {strname}_bitmap_t empty = {{ {{ 0 }} }};
+    return empty;
 }}
 "
     )
 }
 
+fn impl_bitmap_index(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    if !info.config.bitmap {
+        return Ok(());
+    }
+    let bitmaps = bitmap::bitmaps(col, project.lang);
+    let strname = struct_name(&table.name);
+    let bitmapname = format!(
+        "{}_{}_BITMAP",
+        table_name(&table.name),
+        table_name(col.name())
+    );
+    let valtyp = strtype(&info.interface_type);
+    let count = bitmaps.len();
+
+    write!(
+        output,
+        "static unsigned const {bitmapname}_COUNT  =  {count};
+static {valtyp} const {bitmapname}_VALUES[{count}] = {{"
+    )?;
+    for (i, bitmap) in bitmaps.iter().enumerate() {
+        if i % 20 == 0 {
+            write!(output, "\n    ")?;
+        }
+        write!(output, "{}, ", bitmap.value)?;
+    }
+    writeln!(
+        output,
+        "\n}};\nstatic const {strname}_bitmap_t {bitmapname}S[{count}] = {{"
+    )?;
+    for bitmap in &bitmaps {
+        write!(output, "    {{ {{ ")?;
+        for word in &bitmap.words {
+            write!(output, "{:#018x}ULL, ", word)?;
+        }
+        writeln!(output, "}} }},")?;
+    }
+    writeln!(output, "}};\n")
+}
+
 // ================================================================================================
 // Reverse join
 // ================================================================================================
 fn header_reverse_join(
+    project: &table::Project,
     table: &table::Table,
     rj: &JoinTo,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
+    let linkage = linkage(project);
     let info = rj.col.info();
     let strname = struct_name(&table.name);
     let reverse = &rj.reverse_name;
@@ -285,7 +1298,7 @@ fn header_reverse_join(
     write_help(output, "//", &info.config.iter_help)?;
     writeln!(
         output,
-        "extern {strsrc}_iter_t {strname}_{reverse}(const {strname}_t* s);"
+        "{linkage}{strsrc}_iter_t {strname}_{reverse}(const {strname}_t* s);"
     )
 }
 
@@ -306,8 +1319,8 @@ fn impl_reverse_join(
     let tablesrc = table_name(&rj.table.name);
     let strtable = table_name(&table.name);
 
-    let colname = rj.col.name();
-    let field = table_name(colname);
+    let field = table_name(rj.col.name());
+    let right = stored(rj.table, rj.col, Row::Index("*mid"));
     let offset = rj.offset;
     let offset = if offset > 0 {
         format!(" + {offset}")
@@ -325,7 +1338,7 @@ fn impl_reverse_join(
    
     while( lo < hi ){{
         {indextyp}*  mid =  lo + ( hi-lo)/2;
-        if ( cons > {tablesrc}_TABLE[*mid].{colname}_ ) {{
+        if ( cons > {right} ) {{
              lo = mid + 1;
         }} else {{
              hi = mid;
@@ -337,7 +1350,7 @@ fn impl_reverse_join(
     hi = {tablesrc}_{field}_INDEX +  {tablesrc}_{field}_INDEX_COUNT;
     while( lo < hi ){{
         {indextyp}*  mid =  lo + ( hi-lo)/2;
-        if( cons < {tablesrc}_TABLE[*mid].{colname}_ )  {{
+        if( cons < {right} )  {{
             hi = mid;
         }} else {{
             lo = mid + 1;
@@ -358,11 +1371,13 @@ pub fn enum_name(s: &str) -> String {
 // Labels
 // ================================================================================================
 fn header_col_labels(
+    project: &table::Project,
     table: &table::Table,
     col: &dyn table::Column,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
     let info = col.info();
+    let internal = internal(project);
     write_help(output, "//", &info.config.help)?;
     writeln!(output, "typedef enum {{")?;
 
@@ -383,8 +1398,8 @@ fn header_col_labels(
         let strname = struct_name(&table.name);
         writeln!(
             output,
-            "const {strname}_t* {strname}_from_{enumname}({enumname}_t label);
-{enumname}_t {strname}_{enumname}(const {strname}_t *s);
+            "{internal}const {strname}_t* {strname}_from_{enumname}({enumname}_t label);
+{internal}{enumname}_t {strname}_{enumname}(const {strname}_t *s);
             "
         )?;
     }
@@ -418,14 +1433,19 @@ fn impl_col_labels(
 // ================================================================================================
 // Indexes
 // ================================================================================================
-fn header_index(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+fn header_index(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let linkage = linkage(project);
     let strname = struct_name(&table.name);
     let indextyp = strtype(&table.index_type());
 
     writeln!(
         output,
         "typedef struct {{ {indextyp}* ptr; {indextyp}* end; }} {strname}_iter_t;
-extern const {strname}_t* {strname}_next({strname}_iter_t* idx);"
+{linkage}const {strname}_t* {strname}_next({strname}_iter_t* idx);"
     )
 }
 
@@ -450,9 +1470,23 @@ fn impl_col_index(
         "static unsigned const {tablename}_{field}_INDEX_COUNT  =  {count};
 static {indextyp} {tablename}_{field}_INDEX   [{tablename}_{field}_INDEX_COUNT] = {{",
     )?;
+    impl_array_values(&indexes, output)
+}
+
+fn impl_array(
+    name: &str,
+    typ: &str,
+    values: &[usize],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let count = values.len();
+    write!(output, "static const {typ} {name}[{count}] = {{")?;
+    impl_array_values(values, output)
+}
 
-    let width = language::digits(indexes.len());
-    for (i, v) in indexes.iter().enumerate() {
+fn impl_array_values(values: &[usize], output: &mut dyn io::Write) -> io::Result<()> {
+    let width = language::digits(values.len());
+    for (i, v) in values.iter().enumerate() {
         if i % 20 == 0 {
             write!(output, "\n    ")?;
         }
@@ -517,10 +1551,11 @@ fn impl_getter_variant(
     let field = col.name();
     let strname = struct_name(&table.name);
     let outyp = format!("{strname}_{field}_t");
+    let value = stored(table, col, Row::Ptr("s"));
     writeln!(
         output,
         "{outyp} {strname}_{field}(const {strname}_t* s){{
-    int v = s->{field}_ ;"
+    int v = {value} ;"
     )?;
 
     for vrn in variants {
@@ -548,6 +1583,54 @@ fn impl_getter_variant(
     )
 }
 
+// ================================================================================================
+// Blob
+// ================================================================================================
+fn impl_blob(table: &table::Table, blob: &Blob, output: &mut dyn io::Write) -> io::Result<()> {
+    let tablename = table_name(&table.name);
+    let row_size = blob.row_size;
+    let size = blob.bytes.len();
+    write!(
+        output,
+        "static unsigned const {tablename}_ROW_SIZE = {row_size};
+static const uint8_t {tablename}_BLOB[{size}] = {{"
+    )?;
+    for (i, b) in blob.bytes.iter().enumerate() {
+        if i % 16 == 0 {
+            write!(output, "\n    ")?;
+        }
+        write!(output, "{b:#04x}, ")?;
+    }
+    writeln!(output, "\n}};\n")
+}
+
+fn impl_blob_read(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "// little endian value of size bytes
+static inline uint64_t insrcdata_read(const uint8_t* p, unsigned size) {{
+    uint64_t v = 0;
+    while( size ) {{
+        v = ( v << 8 ) | p[--size];
+    }}
+    return v;
+}}
+
+static inline float insrcdata_f32(uint32_t bits) {{
+    float f;
+    memcpy(&f, &bits, sizeof f);
+    return f;
+}}
+
+static inline double insrcdata_f64(uint64_t bits) {{
+    double f;
+    memcpy(&f, &bits, sizeof f);
+    return f;
+}}
+"
+    )
+}
+
 // ================================================================================================
 // Table
 // ================================================================================================
@@ -563,24 +1646,57 @@ fn header_table_types(
         let datacols: Vec<&dyn table::Column> = table.data_columns();
         write_help(output, "//", &table.help)?;
         writeln!(output, "typedef struct  {{")?;
-        for col in &datacols {
-            let info = col.info();
-            let fieldtype = strtype(&info.table_type);
+        let fields: Vec<&&dyn table::Column> = datacols
+            .iter()
+            .filter(|col| in_struct(table, **col))
+            .collect();
+        for col in &fields {
+            let fieldtype = strtype(&stored_type(**col));
             writeln!(output, "    {} {}_;", fieldtype, col.name())?;
         }
+        if let Some(packing) = struct_words(table) {
+            // small columns packed in words
+            let typ = word_type(table);
+            writeln!(output, "    {typ} packed_[{}];", packing.words)?;
+        } else if fields.is_empty() {
+            // rows are read in blob, C does not allow empty struct
+            writeln!(output, "    uint8_t unused_;")?;
+        }
         writeln!(output, "}} {strname}_t;")?;
 
         if table.get_array {
             let count = table.len;
+            let linkage = if static_linkage(project) {
+                "static "
+            } else {
+                "extern "
+            };
             writeln!(
                 output,
                 "static unsigned const {tablename}_TABLE_COUNT = {count};
-extern const {strname}_t {tablename}_TABLE[{tablename}_TABLE_COUNT];"
+{linkage}const {strname}_t {tablename}_TABLE[{tablename}_TABLE_COUNT];"
             )?;
         }
 
         if project.table_need_iter(table) {
-            header_index(table, output)?;
+            header_index(project, table, output)?;
+        }
+        if table.has_group() {
+            let indextyp = strtype(&table.index_type());
+            writeln!(
+                output,
+                "typedef struct {{ const {indextyp}* bound; const {indextyp}* end; {indextyp}* index; }} {strname}_groups_t;"
+            )?;
+        }
+        header_closure_types(project, table, output)?;
+        if table.has_fuzzy() {
+            writeln!(
+                output,
+                "typedef struct {{ const {strname}_t* row; unsigned distance; }} {strname}_fuzzy_t;"
+            )?;
+        }
+        if table.has_bitmap() {
+            header_bitmap_types(project, table, output)?;
         }
         writeln!(output)?;
 
@@ -602,21 +1718,41 @@ fn header_table_methods(
     // Labels
     let labelcols: Vec<&dyn table::Column> = table.label_columns();
     for col in labelcols {
-        header_col_labels(table, col, output)?;
+        header_col_labels(project, table, col, output)?;
+    }
+
+    if table.has_data() {
+        let strname = struct_name(&table.name);
+        let count = table.len;
+        writeln!(
+            output,
+            "// Number of rows in the table\nstatic inline size_t {strname}_len(void) {{ return {count}; }}"
+        )?;
     }
 
     //methods for data column
     let datacols: Vec<&dyn table::Column> = table.data_columns();
     for col in &datacols {
-        header_getter_col(table, *col, output)?;
+        header_getter_col(project, table, *col, output)?;
         if col.info().has_iter_range() {
-            header_iter_range(table, *col, output)?;
+            header_iter_range(project, table, *col, output)?;
+        }
+        if col.info().config.group {
+            header_groups(project, table, *col, output)?;
+        }
+        header_closure(project, table, *col, output)?;
+        header_aggregates(table, *col, output)?;
+        if col.info().config.fuzzy {
+            header_fuzzy(project, table, *col, output)?;
+        }
+        if col.info().config.bitmap {
+            header_bitmap(project, table, *col, output)?;
         }
     }
 
     let reverse_join = project.join_to_columns(table);
     for rj in reverse_join {
-        header_reverse_join(table, &rj, output)?;
+        header_reverse_join(project, table, &rj, output)?;
     }
     Ok(())
 }
@@ -630,6 +1766,9 @@ fn impl_table_data(
     let tablename = table_name(&table.name);
 
     let datacols: Vec<&dyn table::Column> = table.data_columns();
+    if let Some(blob) = &table.blob {
+        impl_blob(table, blob, output)?;
+    }
 
     if !table.get_array {
         let count = table.len;
@@ -638,21 +1777,44 @@ fn impl_table_data(
             "static unsigned const {tablename}_TABLE_COUNT = {count};
 static "
         )?;
+    } else if static_linkage(project) {
+        write!(output, "static ")?;
     }
 
-    writeln!(
-        output,
-        "const {strname}_t {tablename}_TABLE[{tablename}_TABLE_COUNT] = {{"
-    )?;
-
-    for row in 0..table.len {
-        write!(output, "   {{")?;
-        for col in &datacols {
-            write!(output, "{}, ", col.emit_table_cell(row, project.lang))?;
+    let packing = struct_words(table);
+    if packing.is_none() && !datacols.iter().any(|col| in_struct(table, *col)) {
+        // rows are read in blob, the table only give their index
+        write!(
+            output,
+            "const {strname}_t {tablename}_TABLE[{tablename}_TABLE_COUNT] = {{{{0}}}};\n\n"
+        )?;
+    } else {
+        writeln!(
+            output,
+            "const {strname}_t {tablename}_TABLE[{tablename}_TABLE_COUNT] = {{"
+        )?;
+        for row in 0..table.len {
+            write!(output, "   {{")?;
+            for col in &datacols {
+                if !in_struct(table, *col) {
+                    continue;
+                }
+                match col.compressed() {
+                    Some(compressed) => write!(output, "{}, ", compressed.offsets[row])?,
+                    None => write!(output, "{}, ", col.emit_table_cell(row, project.lang))?,
+                }
+            }
+            if let Some(packing) = packing {
+                write!(output, "{{")?;
+                for word in packing.row(&datacols, row) {
+                    write!(output, "{word:#x}u, ")?;
+                }
+                write!(output, "}}, ")?;
+            }
+            writeln!(output, "}},")?;
         }
-        writeln!(output, "}},")?;
+        write!(output, "}};\n\n")?;
     }
-    write!(output, "}};\n\n")?;
 
     // indexes
     if project.table_need_iter(table) {
@@ -662,6 +1824,11 @@ static "
         if col.iterable() {
             impl_col_index(table, *col, output)?;
         }
+        impl_group_bounds(project, table, *col, output)?;
+        impl_closure_index(table, *col, output)?;
+        impl_fuzzy_index(table, *col, output)?;
+        impl_compressed_data(table, *col, output)?;
+        impl_bitmap_index(project, table, *col, output)?;
     }
 
     // Labels
@@ -686,6 +1853,14 @@ fn impl_table_methods(
         if col.info().has_iter_range() {
             impl_iter_range(table, *col, output)?;
         }
+        if col.info().config.group {
+            impl_groups(table, *col, output)?;
+        }
+        impl_closure(table, *col, output)?;
+        impl_fuzzy(table, *col, output)?;
+        if col.info().config.bitmap {
+            impl_bitmap(table, *col, output)?;
+        }
     }
 
     let reverse_join = project.join_to_columns(table);
@@ -693,6 +1868,11 @@ fn impl_table_methods(
         impl_reverse_join(table, &rj, output)?;
     }
 
+    if table.has_bitmap() {
+        impl_bitmap_functions(table, output)?;
+    }
+    impl_closure_functions(table, output)?;
+
     Ok(())
 }
 
@@ -705,17 +1885,15 @@ fn header_path(project: &table::Project) -> PathBuf {
     path
 }
 
-pub fn header_project(lang: &dyn Language, project: &table::Project) -> aperror::Result<()> {
-    let mut outfile = fs::File::create(&header_path(project))?;
-    let output = (&mut outfile) as &mut dyn io::Write;
-
+fn write_header(
+    lang: &dyn Language,
+    project: &table::Project,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
     let include_guard = project.name().to_shouty_snake_case();
-    let notice = language::file_notice();
     writeln!(
         output,
-        "// {notice}
-
-#ifndef INSRCDATA_{include_guard}_H
+        "#ifndef INSRCDATA_{include_guard}_H
 #define INSRCDATA_{include_guard}_H
 #include <stddef.h>
 #include <stdint.h>
@@ -738,7 +1916,16 @@ pub fn header_project(lang: &dyn Language, project: &table::Project) -> aperror:
     // for language that use C binding
     lang.c_binding_header(project, output)?;
 
-    writeln!(output, "\n#endif //  {include_guard}_H ")?;
+    writeln!(output, "\n#endif //  {include_guard}_H ")
+}
+
+pub fn header_project(lang: &dyn Language, project: &table::Project) -> aperror::Result<()> {
+    let mut outfile = fs::File::create(header_path(project))?;
+    let output = (&mut outfile) as &mut dyn io::Write;
+
+    let notice = language::file_notice();
+    writeln!(output, "// {notice}\n")?;
+    write_header(lang, project, output)?;
     Ok(())
 }
 
@@ -747,6 +1934,54 @@ fn impl_path(project: &table::Project) -> PathBuf {
     path.set_extension("c");
     path
 }
+
+// static helper shared by single headers implemented in the same translation unit
+fn impl_helper(
+    project: &table::Project,
+    name: &str,
+    helper: fn(&mut dyn io::Write) -> io::Result<()>,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !project.single_header {
+        return helper(output);
+    }
+    writeln!(output, "#ifndef INSRCDATA_{name}\n#define INSRCDATA_{name}")?;
+    helper(output)?;
+    writeln!(output, "#endif // INSRCDATA_{name}\n")
+}
+
+fn write_impl(
+    lang: &dyn Language,
+    project: &table::Project,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if project.tables.iter().any(|t| t.has_fuzzy()) {
+        impl_helper(project, "LEVENSHTEIN", impl_levenshtein, output)?;
+    }
+    if project.tables.iter().any(|t| t.blob.is_some()) {
+        impl_helper(project, "BLOB_READ", impl_blob_read, output)?;
+    }
+    if project.tables.iter().any(|t| t.has_compression("huffman")) {
+        impl_helper(project, "HUFFMAN_DECODE", impl_huffman_decode, output)?;
+    }
+    if project.tables.iter().any(|t| t.has_compression("fsst")) {
+        impl_helper(project, "FSST_DECODE", impl_fsst_decode, output)?;
+    }
+
+    for table in &project.tables {
+        if table.has_data() {
+            impl_table_data(project, table, output)?;
+        }
+    }
+
+    for table in &project.tables {
+        impl_table_methods(project, table, output)?;
+    }
+
+    // for language that use C binding
+    lang.c_binding_impl(project, output)
+}
+
 pub fn impl_project(lang: &dyn Language, project: &table::Project) -> aperror::Result<()> {
     let filename = project.name();
 
@@ -765,20 +2000,36 @@ pub fn impl_project(lang: &dyn Language, project: &table::Project) -> aperror::R
 #include <stdlib.h>
 "
     )?;
+    write_impl(lang, project, output)?;
+    Ok(())
+}
 
-    for table in &project.tables {
-        if table.has_data() {
-            impl_table_data(project, table, output)?;
-        }
-    }
-
-    for table in &project.tables {
-        impl_table_methods(project, table, output)?;
-    }
-
-    // for language that use C binding
-    lang.c_binding_impl(project, output)?;
+// stb style header : the implementation is compiled where INSRCDATA_IMPLEMENTATION is defined
+fn single_header_project(lang: &dyn Language, project: &table::Project) -> aperror::Result<()> {
+    let filepath = header_path(project);
+    let mut outfile = aperror::io_error_result(fs::File::create(&filepath), &filepath)?;
+    let output = (&mut outfile) as &mut dyn io::Write;
 
+    let include_guard = project.name().to_shouty_snake_case();
+    let notice = language::file_notice();
+    writeln!(output, "// {notice}\n")?;
+    write_header(lang, project, output)?;
+    writeln!(
+        output,
+        "
+#ifdef INSRCDATA_IMPLEMENTATION
+#ifndef INSRCDATA_{include_guard}_IMPLEMENTATION
+#define INSRCDATA_{include_guard}_IMPLEMENTATION
+#include <string.h>
+#include <stdio.h>
+#include <stdlib.h>
+"
+    )?;
+    write_impl(lang, project, output)?;
+    writeln!(
+        output,
+        "#endif // INSRCDATA_{include_guard}_IMPLEMENTATION\n#endif // INSRCDATA_IMPLEMENTATION"
+    )?;
     Ok(())
 }
 
@@ -787,6 +2038,9 @@ pub fn impl_project(lang: &dyn Language, project: &table::Project) -> aperror::R
 // ================================================================================================
 impl language::Language for LangC {
     fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        if project.single_header {
+            return single_header_project(self, project);
+        }
         header_project(self, project)?;
         impl_project(self, project)
     }
@@ -794,6 +2048,21 @@ impl language::Language for LangC {
     fn extension(&self) -> String {
         "c".to_string()
     }
+
+    fn supports(&self, _feature: &str) -> bool {
+        true
+    }
+
+    // a single header project has no .c file
+    fn dst_modified(&self, project: &table::Project) -> aperror::Result<std::time::SystemTime> {
+        let path = match project.single_header {
+            true => header_path(project),
+            false => project.dst_path.clone(),
+        };
+        let metadata = fs::metadata(path)?;
+        let modified = metadata.modified()?;
+        Ok(modified)
+    }
 }
 
 const LANG_C_: LangC = LangC {};
@@ -0,0 +1,716 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for C++ language
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, log, table};
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use language::write_help;
+use std::{fs, io};
+
+struct LangCpp {}
+
+// c++ data types
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return struct_name(name),
+        BaseType::I8 => "int8_t",
+        BaseType::I16 => "int16_t",
+        BaseType::I32 => "int32_t",
+        BaseType::I64 => "int64_t",
+        BaseType::U8 => "uint8_t",
+        BaseType::U16 => "uint16_t",
+        BaseType::U32 => "uint32_t",
+        BaseType::U64 => "uint64_t",
+        BaseType::Str => "std::string_view",
+        BaseType::Join { .. } => "TODO",
+        BaseType::Object { objtype } => objtype,
+
+        BaseType::Bool => "bool",
+        BaseType::F32 => "float",
+        BaseType::F64 => "double",
+        BaseType::Variant => "TODO",
+    })
+}
+
+// ================================================================================================
+// format name to C++ conventions
+// ================================================================================================
+fn namespace_name(project_name: &str) -> String {
+    project_name.to_snake_case()
+}
+fn struct_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn field_name(col_name: &str) -> String {
+    col_name.to_snake_case()
+}
+fn const_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+fn table_name(table_name: &str) -> String {
+    format!("detail::{}_TABLE", const_name(table_name))
+}
+fn index_name(table_name: &str, col_name: &str) -> String {
+    format!(
+        "detail::{}_{}_INDEX",
+        const_name(table_name),
+        const_name(col_name)
+    )
+}
+fn range_name(table_name: &str) -> String {
+    format!("{}Range", struct_name(table_name))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    let strname = struct_name(&table.name);
+    let varname = struct_name(col.name());
+    format!("{strname}{varname}")
+}
+
+// ================================================================================================
+// Helpers shared by all tables
+// ================================================================================================
+fn write_helpers(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "namespace detail {{
+
+// rows of a table selected by a slice of index
+template <typename Row, typename Index>
+class RowRange {{
+public:
+    class iterator {{
+    public:
+        using iterator_category = std::forward_iterator_tag;
+        using value_type = Row;
+        using difference_type = std::ptrdiff_t;
+        using pointer = const Row*;
+        using reference = const Row&;
+
+        constexpr iterator(const Row* table, const Index* ptr) : table_(table), ptr_(ptr) {{}}
+        constexpr const Row& operator*() const {{ return table_[*ptr_]; }}
+        constexpr const Row* operator->() const {{ return &table_[*ptr_]; }}
+        constexpr iterator& operator++() {{ ++ptr_; return *this; }}
+        constexpr iterator operator++(int) {{ iterator it = *this; ++ptr_; return it; }}
+        constexpr bool operator==(const iterator& other) const {{ return ptr_ == other.ptr_; }}
+        constexpr bool operator!=(const iterator& other) const {{ return ptr_ != other.ptr_; }}
+
+    private:
+        const Row* table_;
+        const Index* ptr_;
+    }};
+
+    constexpr RowRange(const Row* table, const Index* first, const Index* last)
+        : table_(table), first_(first), last_(last) {{}}
+    constexpr iterator begin() const {{ return iterator(table_, first_); }}
+    constexpr iterator end() const {{ return iterator(table_, last_); }}
+    constexpr std::size_t size() const {{ return static_cast<std::size_t>(last_ - first_); }}
+    constexpr bool empty() const {{ return first_ == last_; }}
+
+private:
+    const Row* table_;
+    const Index* first_;
+    const Index* last_;
+}};
+
+// rows with proj(row) in [start..=stop], index must be sorted by proj(row)
+template <typename Row, std::size_t M, typename Index, std::size_t N, typename Key, typename Proj>
+constexpr RowRange<Row, Index> bisect(const std::array<Row, M>& table, const std::array<Index, N>& index,
+                                      Key start, Key stop, Proj proj) {{
+    const Index* lo = index.data();
+    const Index* hi = index.data() + N;
+    while (lo < hi) {{
+        const Index* mid = lo + (hi - lo) / 2;
+        if (proj(table[*mid]) < start) {{
+            lo = mid + 1;
+        }} else {{
+            hi = mid;
+        }}
+    }}
+
+    const Index* begin = lo;
+    hi = index.data() + N;
+    while (lo < hi) {{
+        const Index* mid = lo + (hi - lo) / 2;
+        if (stop < proj(table[*mid])) {{
+            hi = mid;
+        }} else {{
+            lo = mid + 1;
+        }}
+    }}
+    return RowRange<Row, Index>(table.data(), begin, lo);
+}}
+
+}} // namespace detail
+"
+    )
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    write_help(output, "/// ", &info.config.help)?;
+    writeln!(output, "enum class {enumname} {{")?;
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            let camel = label.to_upper_camel_case();
+            let help = col.emit_label_help(row);
+            write_help(output, "    /// ", &help)?;
+            writeln!(output, "    {camel} = {row},")?;
+        }
+    }
+    writeln!(output, "}};\n")
+}
+
+fn decl_labels(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    let method = field_name(&enumname);
+    writeln!(
+        output,
+        "    /// Row corresponding to label
+    static constexpr const {strname}& from({enumname} label);
+    /// Label of the current row
+    constexpr {enumname} {method}() const;"
+    )
+}
+
+fn impl_labels(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let tablename = table_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    let method = field_name(&enumname);
+    writeln!(
+        output,
+        "constexpr const {strname}& {strname}::from({enumname} label) {{ return {tablename}[static_cast<std::size_t>(label)]; }}
+constexpr {enumname} {strname}::{method}() const {{ return static_cast<{enumname}>(as_index()); }}"
+    )
+}
+
+// ================================================================================================
+// Getters
+// ================================================================================================
+fn decl_getter(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let field = field_name(col.name());
+    write_help(output, "    /// ", &info.config.help)?;
+    match &info.type_impl() {
+        table::TypeImpl::Label => {
+            let outtype = strtype(&info.interface_type);
+            writeln!(
+                output,
+                "    constexpr {outtype} {field}() const {{ return {field}_; }}"
+            )?;
+        }
+        table::TypeImpl::Scalar => {
+            let outtype = strtype(&info.interface_type);
+            writeln!(
+                output,
+                "    constexpr {outtype} {field}() const {{ return {field}_; }}"
+            )?;
+        }
+        table::TypeImpl::Join => {
+            let outtype = struct_name(&info.join_table());
+            writeln!(output, "    constexpr const {outtype}& {field}() const;")?;
+        }
+        table::TypeImpl::JoinOptional => {
+            let outtype = struct_name(&info.join_table());
+            writeln!(
+                output,
+                "    std::optional<std::reference_wrapper<const {outtype}>> {field}() const;"
+            )?;
+        }
+        table::TypeImpl::Variant => {
+            let vartypname = variant_type_name(table, col);
+            writeln!(output, "    constexpr {vartypname} {field}() const;")?;
+        }
+    }
+    Ok(())
+}
+
+fn impl_getter(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let strname = struct_name(&table.name);
+    let field = field_name(col.name());
+    match &info.type_impl() {
+        table::TypeImpl::Join => {
+            let outtype = struct_name(&info.join_table());
+            let jointable = table_name(&info.join_table());
+            writeln!(
+                output,
+                "constexpr const {outtype}& {strname}::{field}() const {{ return {jointable}[{field}_]; }}"
+            )?;
+        }
+        table::TypeImpl::JoinOptional => {
+            let outtype = struct_name(&info.join_table());
+            let jointable = table_name(&info.join_table());
+            writeln!(
+                output,
+                "inline std::optional<std::reference_wrapper<const {outtype}>> {strname}::{field}() const {{
+    if ({field}_ == 0) {{
+        return std::nullopt;
+    }}
+    return std::cref({jointable}[{field}_ - 1]);
+}}"
+            )?;
+        }
+        table::TypeImpl::Variant => {
+            impl_getter_variant(table, col, output)?;
+        }
+        table::TypeImpl::Label | table::TypeImpl::Scalar => {}
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Variants
+// ================================================================================================
+fn write_variant(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(variants) = col.variants() else {
+        return Ok(());
+    };
+    let vartypname = variant_type_name(table, col);
+    let alternatives: Vec<String> = variants
+        .iter()
+        .map(|vrn| {
+            if vrn.is_none {
+                "std::monostate".to_string()
+            } else {
+                format!("const {}*", struct_name(&vrn.name))
+            }
+        })
+        .collect();
+    writeln!(
+        output,
+        "using {vartypname} = std::variant<{}>;",
+        alternatives.join(", ")
+    )
+}
+
+fn impl_getter_variant(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let variants = col.variants().expect("variant must have variant");
+    let vartypname = variant_type_name(table, col);
+    let strname = struct_name(&table.name);
+    let field = field_name(col.name());
+
+    writeln!(
+        output,
+        "constexpr {vartypname} {strname}::{field}() const {{
+    const std::size_t v = {field}_;"
+    )?;
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let start = vrn.index;
+        let end = start + vrn.count - 1;
+        if vrn.is_none {
+            writeln!(
+                output,
+                "    if (v <= {end}) {{ return {vartypname}(std::monostate{{}}); }}"
+            )?;
+        } else {
+            let jointable = table_name(&vrn.name);
+            let offset = stroffset(-(start as isize));
+            writeln!(
+                output,
+                "    if (v <= {end}) {{ return {vartypname}(&{jointable}[v{offset}]); }}"
+            )?;
+        }
+    }
+    writeln!(
+        output,
+        "    std::abort(); // insrcdata variant index overflow\n}}"
+    )
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn decl_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let field = field_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let range = range_name(&table.name);
+    write_help(output, "    /// ", &info.config.iter_help)?;
+    writeln!(
+        output,
+        "    static constexpr {range} {field}_range({argtype} start, {argtype} stop);
+    /// Number of rows returned by {field}_range
+    static constexpr std::size_t {field}_count({argtype} start, {argtype} stop) {{ return {field}_range(start, stop).size(); }}"
+    )
+}
+
+fn impl_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let strname = struct_name(&table.name);
+    let field = field_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let range = range_name(&table.name);
+    let tablename = table_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    writeln!(
+        output,
+        "constexpr {range} {strname}::{field}_range({argtype} start, {argtype} stop) {{
+    return detail::bisect({tablename}, {indexname}, start, stop, [](const {strname}& r) {{ return r.{field}(); }});
+}}"
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn decl_aggregates(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        let name = const_name(&format!("{}_{}", col.name(), aggregate.name));
+        let typ = strtype(&aggregate.typ);
+        let value = &aggregate.value;
+        writeln!(
+            output,
+            "    /// {} of {} column values
+    static constexpr {typ} {name} = {value};",
+            aggregate.name,
+            col.name()
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn decl_reverse_join(rj: &JoinTo, output: &mut dyn io::Write) -> io::Result<()> {
+    let info = rj.col.info();
+    let reverse = &rj.reverse_name;
+    let range = range_name(&rj.table.name);
+    write_help(output, "    /// ", &info.config.iter_help)?;
+    writeln!(output, "    constexpr {range} {reverse}() const;")
+}
+
+fn impl_reverse_join(
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let strname = struct_name(&table.name);
+    let reverse = &rj.reverse_name;
+    let range = range_name(&rj.table.name);
+    let srcstruct = struct_name(&rj.table.name);
+    let srctable = table_name(&rj.table.name);
+    let indexname = index_name(&rj.table.name, rj.col.name());
+    let field = field_name(rj.col.name());
+    let tabletype = strtype(&info.table_type);
+    let offset = stroffset(rj.offset as isize);
+    writeln!(
+        output,
+        "constexpr {range} {strname}::{reverse}() const {{
+    const auto cons = static_cast<{tabletype}>(as_index(){offset});
+    return detail::bisect({srctable}, {indexname}, cons, cons, [](const {srcstruct}& r) {{ return r.{field}_; }});
+}}"
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn write_struct(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    write_help(output, "/// ", &table.help)?;
+    writeln!(output, "struct {strname} {{")?;
+    for col in &datacols {
+        let fieldtype = strtype(&col.info().table_type);
+        writeln!(output, "    {fieldtype} {}_;", field_name(col.name()))?;
+    }
+    writeln!(output)?;
+
+    for col in &datacols {
+        decl_getter(table, *col, output)?;
+        if col.info().has_iter_range() {
+            decl_range(table, *col, output)?;
+        }
+        decl_aggregates(*col, output)?;
+    }
+    for rj in project.join_to_columns(table) {
+        decl_reverse_join(&rj, output)?;
+    }
+    for col in table.label_columns() {
+        decl_labels(table, col, output)?;
+    }
+
+    let len = table.len;
+    writeln!(
+        output,
+        "    /// Number of rows in the table
+    static constexpr std::size_t len() {{ return {len}; }}
+    /// Index of the current record in the table
+    constexpr std::size_t as_index() const;"
+    )?;
+    if table.get_array {
+        writeln!(
+            output,
+            "    /// Reference to the table containing all the values
+    static constexpr const std::array<{strname}, {len}>& array();"
+        )?;
+    }
+    writeln!(
+        output,
+        "
+    constexpr bool operator==(const {strname}& other) const {{ return this == &other; }}
+    constexpr bool operator!=(const {strname}& other) const {{ return this != &other; }}
+}};
+"
+    )
+}
+
+fn write_array(
+    name: &str,
+    typ: &str,
+    values: &[usize],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let len = values.len();
+    write!(
+        output,
+        "inline constexpr std::array<{typ}, {len}> {name} = {{{{"
+    )?;
+    let width = language::digits(values.len());
+    for (i, v) in values.iter().enumerate() {
+        if i % 20 == 0 {
+            write!(output, "\n    ")?;
+        }
+        write!(output, "{:width$}, ", v)?;
+    }
+    writeln!(output, "\n}}}};")
+}
+
+fn write_table_data(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let constname = const_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    writeln!(
+        output,
+        "inline constexpr std::array<{strname}, {}> {constname}_TABLE = {{{{",
+        table.len
+    )?;
+    for row in 0..table.len {
+        write!(output, "    {{")?;
+        for col in &datacols {
+            write!(output, "{}, ", col.emit_table_cell(row, project.lang))?;
+        }
+        writeln!(output, "}},")?;
+    }
+    writeln!(output, "}}}};")?;
+
+    // indexes
+    let indextype = strtype(&table.index_type());
+    for col in &datacols {
+        if col.iterable() {
+            let name = format!("{constname}_{}_INDEX", const_name(col.name()));
+            write_array(&name, &indextype, &col.indexes(), output)?;
+        }
+    }
+    writeln!(output)
+}
+
+fn write_table_methods(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strname = struct_name(&table.name);
+    let tablename = table_name(&table.name);
+
+    for col in table.data_columns() {
+        impl_getter(table, col, output)?;
+        if col.info().has_iter_range() {
+            impl_range(table, col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        impl_reverse_join(table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        impl_labels(table, col, output)?;
+    }
+    writeln!(
+        output,
+        "constexpr std::size_t {strname}::as_index() const {{ return static_cast<std::size_t>(this - {tablename}.data()); }}"
+    )?;
+    if table.get_array {
+        let len = table.len;
+        writeln!(
+            output,
+            "constexpr const std::array<{strname}, {len}>& {strname}::array() {{ return {tablename}; }}"
+        )?;
+    }
+    writeln!(output)
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangCpp {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        let include_guard = project.name().to_shouty_snake_case();
+        let namespace = namespace_name(&project.name());
+        let notice = language::file_notice();
+        writeln!(
+            output,
+            "// {notice}
+
+#ifndef INSRCDATA_{include_guard}_HPP
+#define INSRCDATA_{include_guard}_HPP
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <functional>
+#include <iterator>
+#include <optional>
+#include <string_view>
+#include <variant>"
+        )?;
+        write_help(output, "/// ", &project.help)?;
+        for import in project.imports() {
+            writeln!(output, "#include \"{import}\"")?;
+        }
+        writeln!(output, "\nnamespace {namespace} {{\n")?;
+        write_helpers(output)?;
+
+        // labels
+        for table in &project.tables {
+            for col in table.label_columns() {
+                write_labels(col, output)?;
+            }
+        }
+
+        // forward declarations
+        let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+        for table in &tables {
+            writeln!(output, "struct {};", struct_name(&table.name))?;
+        }
+        for table in &tables {
+            if project.table_need_iter(table) {
+                let strname = struct_name(&table.name);
+                let indextype = strtype(&table.index_type());
+                writeln!(
+                    output,
+                    "using {strname}Range = detail::RowRange<{strname}, {indextype}>;"
+                )?;
+            }
+            for col in table.data_columns() {
+                write_variant(table, col, output)?;
+            }
+        }
+        writeln!(output)?;
+
+        // structures
+        for table in &tables {
+            write_struct(project, table, output)?;
+        }
+
+        // data
+        writeln!(output, "namespace detail {{\n")?;
+        for table in &tables {
+            write_table_data(project, table, output)?;
+        }
+        writeln!(output, "}} // namespace detail\n")?;
+
+        // methods
+        for table in &tables {
+            write_table_methods(project, table, output)?;
+        }
+
+        writeln!(
+            output,
+            "}} // namespace {namespace}
+
+#endif // INSRCDATA_{include_guard}_HPP"
+        )?;
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "hpp".to_string()
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        let enumstr = strtype(typ);
+        let camel = label.to_upper_camel_case();
+        format!("{enumstr}::{camel}")
+    }
+}
+
+const LANG_CPP_: LangCpp = LangCpp {};
+pub const LANG_CPP: &'static dyn language::Language = &LANG_CPP_;
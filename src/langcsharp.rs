@@ -0,0 +1,717 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for C# language
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, log, table};
+use heck::ToUpperCamelCase;
+use std::collections::BTreeSet;
+use std::{fs, io};
+
+struct LangCSharp {}
+
+// c# data types
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return type_name(name),
+        BaseType::I8 => "sbyte",
+        BaseType::I16 => "short",
+        BaseType::I32 => "int",
+        BaseType::I64 => "long",
+        BaseType::U8 => "byte",
+        BaseType::U16 => "ushort",
+        BaseType::U32 => "uint",
+        BaseType::U64 => "ulong",
+        BaseType::Str => "string",
+        BaseType::Join { strname } => return type_name(strname),
+        BaseType::Object { objtype } => objtype,
+
+        BaseType::Bool => "bool",
+        BaseType::F32 => "float",
+        BaseType::F64 => "double",
+        BaseType::Variant => "TODO",
+    })
+}
+
+// primitive values are stored in ReadOnlySpan properties that the compiler emit in the data section
+fn is_primitive(typ: &basetype::BaseType) -> bool {
+    !matches!(
+        typ,
+        BaseType::Str | BaseType::Label { .. } | BaseType::Object { .. } | BaseType::Variant
+    )
+}
+
+// c# literal for a value emitted by a column, integer literals are implicitly converted
+fn literal(typ: &basetype::BaseType, v: &str) -> String {
+    match (typ, v) {
+        (BaseType::F32, "inf") => "float.PositiveInfinity".to_string(),
+        (BaseType::F32, "-inf") => "float.NegativeInfinity".to_string(),
+        (BaseType::F32, "NaN") => "float.NaN".to_string(),
+        (BaseType::F32, _) => format!("{v}f"),
+        (BaseType::F64, "inf") => "double.PositiveInfinity".to_string(),
+        (BaseType::F64, "-inf") => "double.NegativeInfinity".to_string(),
+        (BaseType::F64, "NaN") => "double.NaN".to_string(),
+        _ => v.to_string(),
+    }
+}
+
+// conversion of a stored integer to int, used for span indexes and row constructors
+fn to_int(typ: &basetype::BaseType, expr: &str) -> String {
+    match typ {
+        BaseType::U32 | BaseType::I64 | BaseType::U64 => format!("(int){expr}"),
+        _ => expr.to_string(),
+    }
+}
+
+// comparison of two values with the ordering used by insrcdata to sort indexes
+fn compare(typ: &basetype::BaseType, a: &str, b: &str) -> String {
+    match typ {
+        BaseType::Str => format!("CompareCodePoints({a}, {b})"),
+        _ => format!("{a}.CompareTo({b})"),
+    }
+}
+
+// write xml doc comment
+fn write_doc(output: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    if let Some(doc) = doc {
+        let doc = doc
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        let rows: Vec<&str> = doc.split('\n').collect();
+        if rows.len() == 1 {
+            writeln!(output, "{indent}/// <summary>{}</summary>", rows[0])?;
+        } else {
+            writeln!(output, "{indent}/// <summary>")?;
+            for row in rows {
+                writeln!(output, "{indent}/// {row}")?;
+            }
+            writeln!(output, "{indent}/// </summary>")?;
+        }
+    }
+    Ok(())
+}
+fn write_doc_or(
+    output: &mut dyn io::Write,
+    indent: &str,
+    doc: &Option<String>,
+    default: String,
+) -> io::Result<()> {
+    match doc {
+        Some(_) => write_doc(output, indent, doc),
+        None => write_doc(output, indent, &Some(default)),
+    }
+}
+
+// ================================================================================================
+// format name to C# conventions
+// ================================================================================================
+fn type_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn member_name(name: &str) -> String {
+    name.to_upper_camel_case()
+}
+fn column_name(col_name: &str) -> String {
+    format!("{}Column", member_name(col_name))
+}
+fn index_name(col_name: &str) -> String {
+    format!("{}Index", member_name(col_name))
+}
+fn bound_name(col_name: &str) -> String {
+    format!("{}Bound", member_name(col_name))
+}
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    format!("I{}{}", type_name(&table.name), type_name(col.name()))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+
+// ================================================================================================
+// Helpers
+// ================================================================================================
+fn write_helpers(project: &table::Project, output: &mut dyn io::Write) -> io::Result<()> {
+    let str_range = project.tables.iter().filter(|t| t.has_data()).any(|t| {
+        t.data_columns()
+            .iter()
+            .any(|c| c.info().has_iter_range() && c.info().interface_type == BaseType::Str)
+    });
+    if str_range {
+        writeln!(
+            output,
+            "    // compare strings by unicode code points, string.CompareOrdinal use UTF-16 code units
+    private static int CompareCodePoints(string a, string b)
+    {{
+        // surrogates encode code points above the basic plane
+        static int Fix(int c) => c >= 0xe000 ? c - 0x800 : c >= 0xd800 ? c + 0x2000 : c;
+        int n = Math.Min(a.Length, b.Length);
+        for (int i = 0; i < n; i++)
+        {{
+            if (a[i] != b[i])
+            {{
+                return Fix(a[i]).CompareTo(Fix(b[i]));
+            }}
+        }}
+        return a.Length.CompareTo(b.Length);
+    }}
+"
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    let inttype = strtype(&table.index_type());
+    write_doc(output, "    ", &info.config.help)?;
+    writeln!(output, "    public enum {enumname} : {inttype}\n    {{")?;
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            write_doc(output, "        ", &col.emit_label_help(row))?;
+            writeln!(output, "        {} = {row},", member_name(&label))?;
+        }
+    }
+    writeln!(output, "    }}\n")
+}
+
+fn write_label_methods(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    writeln!(
+        output,
+        "
+        /// <summary>Returns the row corresponding to label</summary>
+        public static {typname} From{enumname}({enumname} label) => new {typname}((int)label);
+
+        /// <summary>Returns the label of the row</summary>
+        public {enumname} {enumname} => ({enumname})index;"
+    )
+}
+
+// ================================================================================================
+// Getters
+// ================================================================================================
+fn write_getter(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let name = member_name(col.name());
+    let column = column_name(col.name());
+    let outtype = strtype(&info.interface_type);
+    writeln!(output)?;
+    write_doc(output, "        ", &info.config.help)?;
+    match &info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => writeln!(
+            output,
+            "        public {outtype} {name} => {column}[index];"
+        ),
+        table::TypeImpl::Join => writeln!(
+            output,
+            "        public {outtype} {name} => new {outtype}({});",
+            to_int(&info.table_type, &format!("{column}[index]"))
+        ),
+        table::TypeImpl::JoinOptional => {
+            let value = to_int(&info.table_type, &format!("{column}[index]"));
+            writeln!(
+                output,
+                "        public {outtype}? {name}
+        {{
+            get
+            {{
+                int v = {value};
+                return v == 0 ? null : new {outtype}(v - 1);
+            }}
+        }}"
+            )
+        }
+        table::TypeImpl::Variant => {
+            let nullable = if info.config.optional { "?" } else { "" };
+            writeln!(
+                output,
+                "        public {}{nullable} {name}
+        {{
+            get
+            {{
+{}
+            }}
+        }}",
+                variant_type_name(table, col),
+                variant_body(table, col)
+            )
+        }
+    }
+}
+
+fn write_variant_type(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if col.variants().is_none() {
+        return Ok(());
+    }
+    let nullnote = if col.info().config.optional {
+        ", null for rows without match"
+    } else {
+        ""
+    };
+    writeln!(
+        output,
+        "    /// <summary>One of the rows that can be referenced by {}.{}{nullnote}</summary>
+    public interface {} {{ }}
+",
+        type_name(&table.name),
+        member_name(col.name()),
+        variant_type_name(table, col)
+    )
+}
+
+// variant interfaces implemented by the rows of a table
+fn variant_interfaces(project: &table::Project, table: &table::Table) -> Vec<String> {
+    let mut interfaces = vec![];
+    for src in &project.tables {
+        if !src.has_data() {
+            continue;
+        }
+        for col in src.data_columns() {
+            if let Some(variants) = col.variants() {
+                if variants.iter().any(|v| !v.is_none && v.name == table.name) {
+                    interfaces.push(variant_type_name(src, col));
+                }
+            }
+        }
+    }
+    interfaces
+}
+
+fn variant_body(table: &table::Table, col: &dyn table::Column) -> String {
+    let variants = col.variants().expect("variant must have variant");
+    let info = col.info();
+    let value = to_int(
+        &info.table_type,
+        &format!("{}[index]", column_name(col.name())),
+    );
+    let mut body = format!("                int v = {value};\n");
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let end = vrn.index + vrn.count - 1;
+        let ret = if vrn.is_none {
+            "null".to_string()
+        } else {
+            let offset = stroffset(-(vrn.index as isize));
+            format!("new {}(v{offset})", type_name(&vrn.name))
+        };
+        body.push_str(&format!(
+            "                if (v <= {end})
+                {{
+                    return {ret};
+                }}
+"
+        ));
+    }
+    body.push_str(&format!(
+        "                throw new InvalidOperationException(\"insrcdata variant index overflow in {}\");",
+        type_name(&table.name)
+    ));
+    body
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn write_bound(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let indexname = index_name(col.name());
+    let position = to_int(&table.index_type(), &format!("{indexname}[mid]"));
+    let value = format!("{}[{position}]", column_name(col.name()));
+    writeln!(
+        output,
+        "
+        // binary search of the first position in {indexname} with a value greater (or equal) than value
+        internal static int {}({} value, bool upper)
+        {{
+            int lo = 0;
+            int hi = {indexname}.Length;
+            while (lo < hi)
+            {{
+                int mid = (lo + hi) >> 1;
+                int c = {};
+                if (c < 0 || (upper && c == 0))
+                {{
+                    lo = mid + 1;
+                }}
+                else
+                {{
+                    hi = mid;
+                }}
+            }}
+            return lo;
+        }}",
+        bound_name(col.name()),
+        stored_type(col),
+        compare(&stored_basetype(col), &value, "value")
+    )
+}
+
+fn write_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let name = member_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let row = to_int(
+        &table.index_type(),
+        &format!("{}[i]", index_name(col.name())),
+    );
+    let bound = bound_name(col.name());
+    writeln!(output)?;
+    write_doc_or(
+        output,
+        "        ",
+        &info.config.iter_help,
+        format!("Returns the rows with {name} in [start..=stop]"),
+    )?;
+    writeln!(
+        output,
+        "        public static IEnumerable<{typname}> {name}Range({argtype} start, {argtype} stop)
+        {{
+            int lo = {bound}(start, false);
+            int hi = Math.Max(lo, {bound}(stop, true));
+            return Enumerable.Range(lo, hi - lo).Select(i => new {typname}({row}));
+        }}
+
+        /// <summary>Returns the number of rows returned by {name}Range</summary>
+        public static int {name}Count({argtype} start, {argtype} stop)
+        {{
+            int lo = {bound}(start, false);
+            return Math.Max(lo, {bound}(stop, true)) - lo;
+        }}"
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn write_aggregates(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        writeln!(
+            output,
+            "
+        /// <summary>The {} of {} column values</summary>
+        public const {} {} = {};",
+            aggregate.name,
+            col.name(),
+            strtype(&aggregate.typ),
+            member_name(&format!("{}_{}", col.name(), aggregate.name)),
+            literal(&aggregate.typ, &aggregate.value)
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn write_reverse_join(
+    project: &table::Project,
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let method = member_name(&rj.reverse_name);
+    let srctype = type_name(&rj.table.name);
+    // qualified name, the method may hide the type when reverse name match the source table
+    let qualified = format!("{}.{srctype}", type_name(&project.name()));
+    let bound = bound_name(rj.col.name());
+    let tabletype = strtype(&info.table_type);
+    let cons = match rj.offset {
+        0 => format!("({tabletype})index"),
+        offset => format!("({tabletype})(index{})", stroffset(offset as isize)),
+    };
+    let row = to_int(
+        &rj.table.index_type(),
+        &format!("{qualified}.{}[i]", index_name(rj.col.name())),
+    );
+    writeln!(output)?;
+    write_doc_or(
+        output,
+        "        ",
+        &info.config.iter_help,
+        format!("Returns the {srctype} rows that reference this row"),
+    )?;
+    writeln!(
+        output,
+        "        public IEnumerable<{srctype}> {method}()
+        {{
+            var cons = {cons};
+            int lo = {qualified}.{bound}(cons, false);
+            int hi = {qualified}.{bound}(cons, true);
+            return Enumerable.Range(lo, hi - lo).Select(i => new {srctype}({row}));
+        }}"
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn stored_basetype(col: &dyn table::Column) -> BaseType {
+    let info = col.info();
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => info.interface_type.clone(),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            info.table_type.clone()
+        }
+    }
+}
+fn stored_type(col: &dyn table::Column) -> String {
+    strtype(&stored_basetype(col))
+}
+
+// static data of a column : a span over data section for primitives, an array otherwise
+fn write_values(
+    visibility: &str,
+    name: &str,
+    typ: &basetype::BaseType,
+    values: &[String],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let strtype = strtype(typ);
+    let (decl, per_line) = if is_primitive(typ) {
+        (
+            format!("{visibility} static ReadOnlySpan<{strtype}> {name} => new {strtype}[]"),
+            20,
+        )
+    } else {
+        (
+            format!("{visibility} static readonly {strtype}[] {name} = new {strtype}[]"),
+            1,
+        )
+    };
+    if values.len() <= per_line {
+        return writeln!(output, "        {decl} {{ {} }};", values.join(", "));
+    }
+    writeln!(output, "        {decl}\n        {{")?;
+    for chunk in values.chunks(per_line) {
+        writeln!(output, "            {},", chunk.join(", "))?;
+    }
+    writeln!(output, "        }};")
+}
+
+fn write_struct(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+    let interfaces = variant_interfaces(project, table);
+    let implements = if interfaces.is_empty() {
+        "".to_string()
+    } else {
+        format!(" : {}", interfaces.join(", "))
+    };
+
+    write_doc(output, "    ", &table.help)?;
+    writeln!(
+        output,
+        "    public readonly record struct {typname}{implements}
+    {{
+        private readonly int index;
+
+        internal {typname}(int index)
+        {{
+            this.index = index;
+        }}
+
+        // getters are not printed, joins may be cyclic
+        private bool PrintMembers(StringBuilder builder)
+        {{
+            builder.Append(\"Index = \").Append(index);
+            return true;
+        }}
+
+        /// <summary>The number of rows in the table</summary>
+        public const int Len = {};
+",
+        table.len
+    )?;
+
+    // data
+    for col in &datacols {
+        let typ = stored_basetype(*col);
+        let values: Vec<String> = (0..table.len)
+            .map(|row| literal(&typ, &col.emit_table_cell(row, project.lang)))
+            .collect();
+        write_values("private", &column_name(col.name()), &typ, &values, output)?;
+    }
+    let indextype = table.index_type();
+    for col in &datacols {
+        if col.iterable() {
+            let values: Vec<String> = col.indexes().iter().map(|v| v.to_string()).collect();
+            write_values(
+                "internal",
+                &index_name(col.name()),
+                &indextype,
+                &values,
+                output,
+            )?;
+        }
+    }
+
+    // methods
+    for col in &datacols {
+        write_aggregates(*col, output)?;
+        write_getter(table, *col, output)?;
+        if col.iterable() {
+            write_bound(table, *col, output)?;
+        }
+        if col.info().has_iter_range() {
+            write_range(table, *col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        write_reverse_join(project, table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        write_label_methods(table, col, output)?;
+    }
+    writeln!(
+        output,
+        "
+        /// <summary>Returns the position of the row in the table</summary>
+        public int AsIndex => index;"
+    )?;
+    if table.get_array {
+        writeln!(
+            output,
+            "
+        /// <summary>Returns all the rows of the table</summary>
+        public static IReadOnlyList<{typname}> Array {{ get; }} = Enumerable.Range(0, Len).Select(i => new {typname}(i)).ToList().AsReadOnly();"
+        )?;
+    }
+    writeln!(output, "    }}\n")
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangCSharp {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        writeln!(output, "// {}\n", language::file_notice())?;
+        writeln!(output, "#nullable enable\n")?;
+
+        let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+        let mut imports: BTreeSet<String> = project.imports().into_iter().collect();
+        if !tables.is_empty() {
+            imports.insert("System".to_string());
+            imports.insert("System.Text".to_string());
+        }
+        if tables
+            .iter()
+            .any(|t| project.table_need_iter(t) || t.get_array)
+        {
+            imports.insert("System.Collections.Generic".to_string());
+            imports.insert("System.Linq".to_string());
+        }
+        for import in &imports {
+            writeln!(output, "using {import};")?;
+        }
+        if !imports.is_empty() {
+            writeln!(output)?;
+        }
+
+        write_doc(output, "", &project.help)?;
+        writeln!(
+            output,
+            "public static partial class {}\n{{",
+            type_name(&project.name())
+        )?;
+        write_helpers(project, output)?;
+
+        // labels
+        for table in &project.tables {
+            for col in table.label_columns() {
+                write_labels(table, col, output)?;
+            }
+        }
+
+        // variants
+        for table in &tables {
+            for col in table.data_columns() {
+                write_variant_type(table, col, output)?;
+            }
+        }
+
+        // tables, the blank line that follow the last one must not precede the closing brace
+        let mut structs: Vec<u8> = vec![];
+        for table in &tables {
+            write_struct(project, table, &mut structs)?;
+        }
+        output.write_all(structs.trim_ascii_end())?;
+        if !structs.is_empty() {
+            writeln!(output)?;
+        }
+        writeln!(output, "}}")?;
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "cs".to_string()
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        format!("{}.{}", strtype(typ), member_name(label))
+    }
+
+    fn emit_str(&self, v: &str) -> String {
+        language::quote_str(v)
+    }
+}
+
+const LANG_CSHARP_: LangCSharp = LangCSharp {};
+pub const LANG_CSHARP: &'static dyn language::Language = &LANG_CSHARP_;
@@ -0,0 +1,338 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// documentation of embedded data as Markdown or HTML
+//
+
+use crate::basetype::BaseType;
+use crate::{aperror, language, table};
+use heck::ToSnakeCase;
+use std::{fs, io};
+
+// the same generator write Markdown or HTML documents
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Markdown,
+    Html,
+}
+
+struct LangDoc {
+    format: Format,
+}
+
+// type name displayed in column schema
+fn strtype(typ: &BaseType) -> String {
+    match typ {
+        BaseType::Str => "str".to_string(),
+        BaseType::Object { .. } => "object".to_string(),
+        typ => typ.to_string(),
+    }
+}
+
+fn flag(v: bool) -> String {
+    if v { "yes" } else { "" }.to_string()
+}
+
+// ================================================================================================
+// Markup primitives
+// ================================================================================================
+fn escape_html(v: &str) -> String {
+    v.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl LangDoc {
+    fn text(&self, v: &str) -> String {
+        match self.format {
+            Format::Markdown => v.to_string(),
+            Format::Html => escape_html(v),
+        }
+    }
+
+    // text inside a table cell
+    fn cell(&self, v: &str) -> String {
+        match self.format {
+            Format::Markdown => v.replace('|', "\\|").replace('\n', "<br>"),
+            Format::Html => escape_html(v).replace('\n', "<br>"),
+        }
+    }
+
+    fn heading(&self, output: &mut dyn io::Write, level: usize, v: &str) -> io::Result<()> {
+        match self.format {
+            Format::Markdown => writeln!(output, "{} {}\n", "#".repeat(level), v),
+            Format::Html => writeln!(output, "<h{level}>{}</h{level}>", escape_html(v)),
+        }
+    }
+
+    fn paragraph(&self, output: &mut dyn io::Write, v: &str) -> io::Result<()> {
+        match self.format {
+            Format::Markdown => writeln!(output, "{v}\n"),
+            Format::Html => writeln!(output, "<p>{}</p>", escape_html(v).replace('\n', "<br>")),
+        }
+    }
+
+    fn help(&self, output: &mut dyn io::Write, doc: &Option<String>) -> io::Result<()> {
+        match doc {
+            Some(doc) => self.paragraph(output, doc),
+            None => Ok(()),
+        }
+    }
+
+    fn list(&self, output: &mut dyn io::Write, items: &[String]) -> io::Result<()> {
+        match self.format {
+            Format::Markdown => {
+                for item in items {
+                    writeln!(output, "* {item}")?;
+                }
+                writeln!(output)
+            }
+            Format::Html => {
+                writeln!(output, "<ul>")?;
+                for item in items {
+                    writeln!(output, "<li>{item}</li>")?;
+                }
+                writeln!(output, "</ul>")
+            }
+        }
+    }
+
+    // header and rows are expected to be already escaped with cell()
+    fn table(
+        &self,
+        output: &mut dyn io::Write,
+        header: &[&str],
+        rows: &[Vec<String>],
+    ) -> io::Result<()> {
+        match self.format {
+            Format::Markdown => {
+                writeln!(output, "| {} |", header.join(" | "))?;
+                writeln!(output, "|{}", "---|".repeat(header.len()))?;
+                for row in rows {
+                    writeln!(output, "| {} |", row.join(" | "))?;
+                }
+                writeln!(output)
+            }
+            Format::Html => {
+                writeln!(output, "<table>")?;
+                writeln!(output, "<tr><th>{}</th></tr>", header.join("</th><th>"))?;
+                for row in rows {
+                    writeln!(output, "<tr><td>{}</td></tr>", row.join("</td><td>"))?;
+                }
+                writeln!(output, "</table>")
+            }
+        }
+    }
+}
+
+// ================================================================================================
+// Project content
+// ================================================================================================
+impl LangDoc {
+    fn write_summary(
+        &self,
+        project: &table::Project,
+        output: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        self.heading(output, 2, "Tables")?;
+        let rows: Vec<Vec<String>> = project
+            .tables
+            .iter()
+            .map(|table| {
+                vec![
+                    self.cell(&table.name),
+                    table.len.to_string(),
+                    self.cell(table.help.as_deref().unwrap_or("")),
+                ]
+            })
+            .collect();
+        self.table(output, &["Table", "Rows", "Description"], &rows)
+    }
+
+    fn write_join_graph(
+        &self,
+        project: &table::Project,
+        output: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        let mut items = vec![];
+        for table in &project.tables {
+            for col in table.data_columns() {
+                let info = col.info();
+                let source = self.text(&format!("{}.{}", table.name, col.name()));
+                match info.type_impl() {
+                    table::TypeImpl::Join | table::TypeImpl::JoinOptional => {
+                        let mut item = format!("{source} → {}", self.text(&info.join_table()));
+                        if info.config.optional {
+                            item.push_str(" (optional)");
+                        }
+                        let reverse = col.reverse_name();
+                        if !reverse.is_empty() {
+                            item.push_str(&format!(", reverse {}", self.text(&reverse)));
+                        }
+                        items.push(item);
+                    }
+                    table::TypeImpl::Variant => {
+                        let variants = col.variants().expect("variant must have variant");
+                        let targets: Vec<String> = variants
+                            .iter()
+                            .filter(|vrn| !vrn.is_none)
+                            .map(|vrn| {
+                                if vrn.reverse.is_empty() {
+                                    self.text(&vrn.name)
+                                } else {
+                                    self.text(&format!("{} (reverse {})", vrn.name, vrn.reverse))
+                                }
+                            })
+                            .collect();
+                        let mut item = format!("{source} → {}", targets.join(" | "));
+                        if info.config.optional {
+                            item.push_str(" (optional)");
+                        }
+                        items.push(item);
+                    }
+                    table::TypeImpl::Label | table::TypeImpl::Scalar => {}
+                }
+            }
+        }
+        if items.is_empty() {
+            return Ok(());
+        }
+        self.heading(output, 2, "Joins")?;
+        self.list(output, &items)
+    }
+
+    fn write_columns(&self, table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+        let rows: Vec<Vec<String>> = table
+            .data_columns()
+            .iter()
+            .map(|col| {
+                let info = col.info();
+                vec![
+                    self.cell(col.name()),
+                    self.cell(&strtype(&info.interface_type)),
+                    self.cell(&strtype(&info.table_type)),
+                    flag(info.has_iter_range()),
+                    flag(info.config.optional),
+                    self.cell(info.config.help.as_deref().unwrap_or("")),
+                ]
+            })
+            .collect();
+        if rows.is_empty() {
+            return Ok(());
+        }
+        self.table(
+            output,
+            &[
+                "Column",
+                "Interface type",
+                "Table type",
+                "Range",
+                "Optional",
+                "Description",
+            ],
+            &rows,
+        )
+    }
+
+    fn write_labels(
+        &self,
+        table: &table::Table,
+        col: &dyn table::Column,
+        output: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        let name = match &col.info().interface_type {
+            BaseType::Label { name } => name.to_string(),
+            _ => col.name().to_string(),
+        };
+        self.heading(output, 3, &format!("{name} labels"))?;
+        self.help(output, &col.info().config.help)?;
+        let rows: Vec<Vec<String>> = (0..table.len)
+            .filter(|row| !col.emit_label(*row).is_empty())
+            .map(|row| {
+                vec![
+                    self.cell(&col.emit_label(row).to_snake_case()),
+                    row.to_string(),
+                    self.cell(&col.emit_label_help(row).unwrap_or_default()),
+                ]
+            })
+            .collect();
+        self.table(output, &["Label", "Row", "Description"], &rows)
+    }
+
+    fn write_table(&self, table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+        self.heading(output, 2, &table.name)?;
+        self.help(output, &table.help)?;
+        let exhaustive = if table.exhaustive {
+            ", all rows are labelled"
+        } else {
+            ""
+        };
+        self.paragraph(output, &format!("{} rows{exhaustive}.", table.len))?;
+        self.write_columns(table, output)?;
+        for col in table.label_columns() {
+            self.write_labels(table, col, output)?;
+        }
+        Ok(())
+    }
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangDoc {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        let title = project.name();
+        match self.format {
+            Format::Markdown => {
+                writeln!(output, "<!-- {} -->", language::file_notice())?;
+                write!(output, "# {title}\n\n")?;
+            }
+            Format::Html => {
+                writeln!(output, "<!DOCTYPE html>")?;
+                writeln!(output, "<!-- {} -->", language::file_notice())?;
+                writeln!(output, "<html>\n<head>\n<meta charset=\"utf-8\">")?;
+                writeln!(output, "<title>{}</title>", escape_html(&title))?;
+                writeln!(output, "</head>\n<body>")?;
+                writeln!(output, "<h1>{}</h1>", escape_html(&title))?;
+            }
+        }
+        self.help(output, &project.help)?;
+        self.write_summary(project, output)?;
+        self.write_join_graph(project, output)?;
+        for table in &project.tables {
+            self.write_table(table, output)?;
+        }
+        if self.format == Format::Html {
+            writeln!(output, "</body>\n</html>")?;
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        match self.format {
+            Format::Markdown => "md",
+            Format::Html => "html",
+        }
+        .to_string()
+    }
+
+    fn native_objects(&self) -> bool {
+        false
+    }
+}
+
+const LANG_MD_: LangDoc = LangDoc {
+    format: Format::Markdown,
+};
+pub const LANG_MD: &'static dyn language::Language = &LANG_MD_;
+
+const LANG_HTML_: LangDoc = LangDoc {
+    format: Format::Html,
+};
+pub const LANG_HTML: &'static dyn language::Language = &LANG_HTML_;
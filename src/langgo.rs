@@ -0,0 +1,707 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for Go language
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, lint, log, table};
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+use std::{fs, io};
+
+struct LangGo {}
+
+// go data types
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return type_name(name),
+        BaseType::I8 => "int8",
+        BaseType::I16 => "int16",
+        BaseType::I32 => "int32",
+        BaseType::I64 => "int64",
+        BaseType::U8 => "uint8",
+        BaseType::U16 => "uint16",
+        BaseType::U32 => "uint32",
+        BaseType::U64 => "uint64",
+        BaseType::Str => "string",
+        BaseType::Join { strname } => return format!("*{}", type_name(strname)),
+        BaseType::Object { objtype } => objtype,
+
+        BaseType::Bool => "bool",
+        BaseType::F32 => "float32",
+        BaseType::F64 => "float64",
+        BaseType::Variant => "TODO",
+    })
+}
+
+// write doc comment, go doc comments start with the name of the declared identifier
+fn write_doc(
+    output: &mut dyn io::Write,
+    indent: &str,
+    name: &str,
+    doc: &Option<String>,
+) -> io::Result<()> {
+    if let Some(doc) = doc {
+        for (i, row) in doc.split('\n').enumerate() {
+            if i == 0 && !row.starts_with(name) {
+                writeln!(output, "{indent}// {name} : {row}")?;
+            } else {
+                writeln!(output, "{indent}// {row}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// write lines with cells aligned like gofmt does, a doc comment start a new alignment block
+fn write_aligned(
+    output: &mut dyn io::Write,
+    rows: &[(Option<String>, Vec<String>)],
+) -> io::Result<()> {
+    let mut start = 0;
+    while start < rows.len() {
+        let mut end = start + 1;
+        while end < rows.len() && rows[end].0.is_none() {
+            end += 1;
+        }
+        let block = &rows[start..end];
+        let columns = block.iter().map(|r| r.1.len()).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|c| {
+                block
+                    .iter()
+                    .map(|r| r.1.get(c).map_or(0, |s| s.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for (doc, cells) in block {
+            let name = cells.first().map_or("", |s| s.as_str());
+            write_doc(output, "\t", name, doc)?;
+            let mut line = String::from("\t");
+            for (c, cell) in cells.iter().enumerate() {
+                if c + 1 == cells.len() {
+                    line.push_str(cell);
+                } else {
+                    line.push_str(&format!("{cell:w$} ", w = widths[c]));
+                }
+            }
+            writeln!(output, "{line}")?;
+        }
+        start = end;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// format name to Go conventions
+// ================================================================================================
+fn package_name(project_name: &str) -> String {
+    project_name.to_snake_case().replace('_', "")
+}
+fn type_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn field_name(col_name: &str) -> String {
+    col_name.to_lower_camel_case()
+}
+fn method_name(col_name: &str) -> String {
+    col_name.to_upper_camel_case()
+}
+fn func_name(table_name: &str, name: &str) -> String {
+    format!("{}{}", type_name(table_name), name.to_upper_camel_case())
+}
+fn table_name(table_name: &str) -> String {
+    format!("{}Table", table_name.to_lower_camel_case())
+}
+fn index_name(table_name: &str, col_name: &str) -> String {
+    format!(
+        "{}{}Index",
+        table_name.to_lower_camel_case(),
+        col_name.to_upper_camel_case()
+    )
+}
+fn rows_name(table_name: &str) -> String {
+    format!("{}Rows", table_name.to_lower_camel_case())
+}
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    format!("{}{}", type_name(&table.name), type_name(col.name()))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+
+// comparison of a column value with a bound, go does not order bool values
+fn ge(typ: &BaseType, a: &str, b: &str) -> String {
+    match typ {
+        BaseType::Bool => format!("{a} || !{b}"),
+        _ => format!("{a} >= {b}"),
+    }
+}
+fn gt(typ: &BaseType, a: &str, b: &str) -> String {
+    match typ {
+        BaseType::Bool => format!("{a} && !{b}"),
+        _ => format!("{a} > {b}"),
+    }
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    let inttype = strtype(&table.index_type());
+    write_doc(output, "", &enumname, &info.config.help)?;
+    writeln!(output, "type {enumname} {inttype}\n\nconst (")?;
+    let mut rows = vec![];
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            let name = format!("{enumname}{}", label.to_upper_camel_case());
+            rows.push((
+                col.emit_label_help(row),
+                vec![name, enumname.to_string(), format!("= {row}")],
+            ));
+        }
+    }
+    write_aligned(output, &rows)?;
+    writeln!(output, ")\n")?;
+
+    // tables that may get new rows have a sentinel to iterate on all labels
+    if !table.exhaustive {
+        writeln!(
+            output,
+            "// {enumname}Count is the number of {enumname} labels
+const {enumname}Count = {}
+",
+            info.len
+        )?;
+    }
+    Ok(())
+}
+
+fn write_label_methods(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let tablename = table_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    let from = func_name(&table.name, &format!("from_{enumname}"));
+    writeln!(
+        output,
+        "// {from} returns the row corresponding to label
+func {from}(label {enumname}) *{typname} {{
+	return &{tablename}[label]
+}}
+
+// {enumname} returns the label of the row
+func (r *{typname}) {enumname}() {enumname} {{
+	return {enumname}(r.index_)
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Getters
+// ================================================================================================
+fn write_getter(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let field = field_name(col.name());
+    let method = method_name(col.name());
+    let outtype = strtype(&info.interface_type);
+    let (ret, body) = match &info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => {
+            (outtype, format!("\treturn r.{field}"))
+        }
+        table::TypeImpl::Join => {
+            let jointable = table_name(&info.join_table());
+            (outtype, format!("\treturn &{jointable}[r.{field}]"))
+        }
+        table::TypeImpl::JoinOptional => {
+            let jointable = table_name(&info.join_table());
+            (
+                outtype,
+                format!(
+                    "\tif r.{field} == 0 {{
+		return nil
+	}}
+	return &{jointable}[r.{field}-1]"
+                ),
+            )
+        }
+        table::TypeImpl::Variant => (variant_type_name(table, col), variant_body(table, col)),
+    };
+    write_doc(output, "", &method, &info.config.help)?;
+    writeln!(
+        output,
+        "func (r *{typname}) {method}() {ret} {{\n{body}\n}}\n"
+    )
+}
+
+fn write_variant_type(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(variants) = col.variants() else {
+        return Ok(());
+    };
+    let vartypname = variant_type_name(table, col);
+    let marker = format!("is{vartypname}");
+    let nilnote = if col.info().config.optional {
+        ", nil for rows without match"
+    } else {
+        ""
+    };
+    writeln!(
+        output,
+        "// {vartypname} is one of the rows that can be referenced by {}.{}(){nilnote}
+type {vartypname} interface {{
+	{marker}()
+}}
+",
+        type_name(&table.name),
+        method_name(col.name())
+    )?;
+    for vrn in variants.iter().filter(|vrn| !vrn.is_none) {
+        writeln!(output, "func (*{}) {marker}() {{}}", type_name(&vrn.name))?;
+    }
+    writeln!(output)
+}
+
+fn variant_body(table: &table::Table, col: &dyn table::Column) -> String {
+    let variants = col.variants().expect("variant must have variant");
+    let field = field_name(col.name());
+    let mut body = format!("\tv := int(r.{field})");
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let end = vrn.index + vrn.count - 1;
+        if vrn.is_none {
+            body.push_str(&format!(
+                "
+	if v <= {end} {{
+		return nil
+	}}"
+            ));
+        } else {
+            let jointable = table_name(&vrn.name);
+            // gofmt does not space binary expressions in index
+            let offset = stroffset(-(vrn.index as isize)).replace(' ', "");
+            body.push_str(&format!(
+                "
+	if v <= {end} {{
+		return &{jointable}[v{offset}]
+	}}"
+            ));
+        }
+    }
+    body.push_str(&format!(
+        "
+	panic(\"insrcdata variant index overflow in {}\")",
+        type_name(&table.name)
+    ));
+    body
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn write_rows(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let tablename = table_name(&table.name);
+    let rows = rows_name(&table.name);
+    let indextype = strtype(&table.index_type());
+    writeln!(
+        output,
+        "// {rows} returns the rows selected by a slice of index
+func {rows}(index []{indextype}) []*{typname} {{
+	rows := make([]*{typname}, len(index))
+	for i, v := range index {{
+		rows[i] = &{tablename}[v]
+	}}
+	return rows
+}}
+"
+    )
+}
+
+fn write_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let field = field_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let tablename = table_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    let rows = rows_name(&table.name);
+    let bounds = format!(
+        "{}{}Bounds",
+        table.name.to_lower_camel_case(),
+        method_name(col.name())
+    );
+    let range = func_name(&table.name, &format!("{}_range", col.name()));
+    let count = func_name(&table.name, &format!("{}_count", col.name()));
+    let value = format!("{tablename}[{indexname}[i]].{field}");
+    let lower = ge(&info.interface_type, &value, "start");
+    let upper = gt(&info.interface_type, &value, "stop");
+    writeln!(
+        output,
+        "// {bounds} returns the positions in {indexname} of rows with {field} in [start..=stop]
+func {bounds}(start, stop {argtype}) (int, int) {{
+	lo := sort.Search(len({indexname}), func(i int) bool {{ return {lower} }})
+	hi := sort.Search(len({indexname}), func(i int) bool {{ return {upper} }})
+	if hi < lo {{
+		hi = lo
+	}}
+	return lo, hi
+}}
+"
+    )?;
+    match &info.config.iter_help {
+        Some(_) => write_doc(output, "", &range, &info.config.iter_help)?,
+        None => writeln!(
+            output,
+            "// {range} returns the rows with {field} in [start..=stop]"
+        )?,
+    }
+    writeln!(
+        output,
+        "func {range}(start, stop {argtype}) []*{typname} {{
+	lo, hi := {bounds}(start, stop)
+	return {rows}({indexname}[lo:hi])
+}}
+
+// {count} returns the number of rows returned by {range}
+func {count}(start, stop {argtype}) int {{
+	lo, hi := {bounds}(start, stop)
+	return hi - lo
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn write_aggregates(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        let name = func_name(&table.name, &format!("{}_{}", col.name(), aggregate.name));
+        let typ = strtype(&aggregate.typ);
+        writeln!(
+            output,
+            "// {name} is the {} of {} column values
+const {name} {typ} = {}
+",
+            aggregate.name,
+            col.name(),
+            aggregate.value
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn write_reverse_join(
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let typname = type_name(&table.name);
+    let method = method_name(&rj.reverse_name);
+    let srctype = type_name(&rj.table.name);
+    let srctable = table_name(&rj.table.name);
+    let indexname = index_name(&rj.table.name, rj.col.name());
+    let rows = rows_name(&rj.table.name);
+    let field = field_name(rj.col.name());
+    let tabletype = strtype(&info.table_type);
+    let offset = stroffset(rj.offset as isize);
+    match &info.config.iter_help {
+        Some(_) => write_doc(output, "", &method, &info.config.iter_help)?,
+        None => writeln!(
+            output,
+            "// {method} returns the {srctype} rows that reference this row"
+        )?,
+    }
+    writeln!(
+        output,
+        "func (r *{typname}) {method}() []*{srctype} {{
+	cons := {tabletype}(int(r.index_){offset})
+	lo := sort.Search(len({indexname}), func(i int) bool {{ return {srctable}[{indexname}[i]].{field} >= cons }})
+	hi := sort.Search(len({indexname}), func(i int) bool {{ return {srctable}[{indexname}[i]].{field} > cons }})
+	return {rows}({indexname}[lo:hi])
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn stored_type(col: &dyn table::Column) -> String {
+    let info = col.info();
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => strtype(&info.interface_type),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            strtype(&info.table_type)
+        }
+    }
+}
+
+fn write_struct(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let indextype = strtype(&table.index_type());
+    write_doc(output, "", &typname, &table.help)?;
+    writeln!(output, "type {typname} struct {{")?;
+    // the underscore keeps the position apart from the camel case column fields
+    let mut rows = vec![(None, vec!["index_".to_string(), indextype])];
+    for col in table.data_columns() {
+        rows.push((None, vec![field_name(col.name()), stored_type(col)]));
+    }
+    write_aligned(output, &rows)?;
+    writeln!(output, "}}\n")
+}
+
+fn write_index(
+    name: &str,
+    typ: &str,
+    values: &[usize],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    if values.len() <= 20 {
+        return writeln!(output, "var {name} = []{typ}{{{}}}", values.join(", "));
+    }
+    writeln!(output, "var {name} = []{typ}{{")?;
+    for chunk in values.chunks(20) {
+        writeln!(output, "\t{},", chunk.join(", "))?;
+    }
+    writeln!(output, "}}")
+}
+
+fn write_table_data(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let tablename = table_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    writeln!(output, "var {tablename} = []{typname}{{")?;
+    for row in 0..table.len {
+        let mut cells = vec![row.to_string()];
+        for col in &datacols {
+            cells.push(col.emit_table_cell(row, project.lang));
+        }
+        writeln!(output, "\t{{{}}},", cells.join(", "))?;
+    }
+    writeln!(output, "}}\n")?;
+
+    // indexes
+    let indextype = strtype(&table.index_type());
+    let mut has_index = false;
+    for col in &datacols {
+        if col.iterable() {
+            write_index(
+                &index_name(&table.name, col.name()),
+                &indextype,
+                &col.indexes(),
+                output,
+            )?;
+            has_index = true;
+        }
+    }
+    if has_index {
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+fn write_table_methods(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let tablename = table_name(&table.name);
+    let len = func_name(&table.name, "len");
+    writeln!(
+        output,
+        "// {len} is the number of rows in the table
+const {len} = {}
+",
+        table.len
+    )?;
+    if project.table_need_iter(table) {
+        write_rows(table, output)?;
+    }
+    for col in table.data_columns() {
+        write_aggregates(table, col, output)?;
+        write_getter(table, col, output)?;
+        if col.info().has_iter_range() {
+            write_range(table, col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        write_reverse_join(table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        write_label_methods(table, col, output)?;
+    }
+    writeln!(
+        output,
+        "// Index returns the position of the row in the table
+func (r *{typname}) Index() int {{
+	return int(r.index_)
+}}
+"
+    )?;
+    if table.get_array {
+        let array = func_name(&table.name, "array");
+        writeln!(
+            output,
+            "// {array} returns the slice containing all the rows, it must not be modified
+func {array}() []{typname} {{
+	return {tablename}
+}}
+"
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangGo {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        let package = package_name(&project.name());
+        writeln!(
+            output,
+            "// Code {}. DO NOT EDIT.\n",
+            language::file_notice()
+        )?;
+        write_doc(output, "", &format!("Package {package}"), &project.help)?;
+        writeln!(output, "package {package}\n")?;
+
+        let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+        let mut imports: Vec<String> = project.imports().into_iter().collect();
+        let uses_sort = tables.iter().any(|t| {
+            t.data_columns().iter().any(|c| c.info().has_iter_range())
+                || !project.join_to_columns(t).is_empty()
+        });
+        if uses_sort {
+            imports.push("\"sort\"".to_string());
+        }
+        imports.sort();
+        if !imports.is_empty() {
+            writeln!(output, "import (")?;
+            for import in imports {
+                writeln!(output, "\t{import}")?;
+            }
+            writeln!(output, ")\n")?;
+        }
+
+        // labels
+        for table in &project.tables {
+            for col in table.label_columns() {
+                write_labels(table, col, output)?;
+            }
+        }
+
+        // types
+        for table in &tables {
+            write_struct(table, output)?;
+            for col in table.data_columns() {
+                write_variant_type(table, col, output)?;
+            }
+        }
+
+        // data
+        for table in &tables {
+            write_table_data(project, table, output)?;
+        }
+
+        // methods
+        for table in &tables {
+            write_table_methods(project, table, output)?;
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "go".to_string()
+    }
+
+    // getters share the method set of the row
+    fn lint(&self, project: &table::Project, linter: &lint::Linter) {
+        for table in &project.tables {
+            for col in table.data_columns() {
+                linter.err(
+                    method_name(col.name()) != "Index",
+                    &format!(
+                        "column {} of table {} clash with the Index() method of go rows",
+                        col.name(),
+                        table.name
+                    ),
+                );
+            }
+        }
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        let enumstr = strtype(typ);
+        let camel = label.to_upper_camel_case();
+        format!("{enumstr}{camel}")
+    }
+
+    fn emit_str(&self, v: &str) -> String {
+        language::quote_str(v)
+    }
+}
+
+const LANG_GO_: LangGo = LangGo {};
+pub const LANG_GO: &'static dyn language::Language = &LANG_GO_;
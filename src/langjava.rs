@@ -0,0 +1,844 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for Java language
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, log, table};
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+use std::{fs, io};
+
+struct LangJava {}
+
+// java data types, unsigned values are stored in the next wider signed type
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return type_name(name),
+        BaseType::I8 => "byte",
+        BaseType::I16 => "short",
+        BaseType::I32 => "int",
+        BaseType::I64 => "long",
+        BaseType::U8 => "short",
+        BaseType::U16 => "int",
+        BaseType::U32 => "long",
+        BaseType::U64 => "long",
+        BaseType::Str => "String",
+        BaseType::Join { strname } => return type_name(strname),
+        BaseType::Object { objtype } => objtype,
+
+        BaseType::Bool => "boolean",
+        BaseType::F32 => "float",
+        BaseType::F64 => "double",
+        BaseType::Variant => "TODO",
+    })
+}
+
+// java literal for a value emitted by a column, narrow types need a cast in constructor arguments
+fn literal(typ: &basetype::BaseType, v: &str) -> String {
+    match typ {
+        BaseType::I8 => format!("(byte) {v}"),
+        BaseType::I16 | BaseType::U8 => format!("(short) {v}"),
+        BaseType::I64 | BaseType::U32 | BaseType::U64 => format!("{v}L"),
+        BaseType::F32 => match v {
+            "inf" => "Float.POSITIVE_INFINITY".to_string(),
+            "-inf" => "Float.NEGATIVE_INFINITY".to_string(),
+            "NaN" => "Float.NaN".to_string(),
+            _ => format!("{v}f"),
+        },
+        BaseType::F64 => match v {
+            "inf" => "Double.POSITIVE_INFINITY".to_string(),
+            "-inf" => "Double.NEGATIVE_INFINITY".to_string(),
+            "NaN" => "Double.NaN".to_string(),
+            _ => v.to_string(),
+        },
+        _ => v.to_string(),
+    }
+}
+
+// comparison of two values with the ordering used by insrcdata to sort indexes
+fn compare(typ: &basetype::BaseType, a: &str, b: &str) -> String {
+    match typ {
+        BaseType::Bool => format!("Boolean.compare({a}, {b})"),
+        BaseType::I64 | BaseType::U32 | BaseType::U64 => format!("Long.compare({a}, {b})"),
+        BaseType::F32 => format!("Float.compare({a}, {b})"),
+        BaseType::F64 => format!("Double.compare({a}, {b})"),
+        BaseType::Str => format!("compareCodePoints({a}, {b})"),
+        _ => format!("Integer.compare({a}, {b})"),
+    }
+}
+
+// write javadoc comment
+fn write_doc(output: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    if let Some(doc) = doc {
+        let doc = doc.replace("*/", "*&#47;");
+        let rows: Vec<&str> = doc.split('\n').collect();
+        if rows.len() == 1 {
+            writeln!(output, "{indent}/** {} */", rows[0])?;
+        } else {
+            writeln!(output, "{indent}/**")?;
+            for row in rows {
+                writeln!(output, "{indent} * {row}")?;
+            }
+            writeln!(output, "{indent} */")?;
+        }
+    }
+    Ok(())
+}
+fn write_doc_or(
+    output: &mut dyn io::Write,
+    indent: &str,
+    doc: &Option<String>,
+    default: String,
+) -> io::Result<()> {
+    match doc {
+        Some(_) => write_doc(output, indent, doc),
+        None => write_doc(output, indent, &Some(default)),
+    }
+}
+
+// ================================================================================================
+// format name to Java conventions
+// ================================================================================================
+fn type_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn const_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+fn field_name(col_name: &str) -> String {
+    col_name.to_lower_camel_case()
+}
+fn index_name(col_name: &str) -> String {
+    format!("{}_INDEX", const_name(col_name))
+}
+fn bound_name(col_name: &str) -> String {
+    format!("{}Bound", field_name(col_name))
+}
+fn chunk_name(table_name: &str, chunk: usize) -> String {
+    format!("{}Rows{chunk}", type_name(table_name))
+}
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    format!("{}{}", type_name(&table.name), type_name(col.name()))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+
+// package from maven and gradle source layout : src/main/java/com/example/Data.java
+fn package_name(project: &table::Project) -> Option<String> {
+    let dir = project.dst_path.parent()?;
+    let parts: Vec<String> = dir
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let pos = parts.iter().rposition(|p| p == "java" || p == "kotlin")?;
+    if pos + 1 == parts.len() {
+        None
+    } else {
+        Some(parts[pos + 1..].join("."))
+    }
+}
+
+// ================================================================================================
+// Helpers
+// ================================================================================================
+fn write_helpers(project: &table::Project, output: &mut dyn io::Write) -> io::Result<()> {
+    let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+    if tables.iter().any(|t| project.table_need_iter(t)) {
+        writeln!(
+            output,
+            "    // read only view on the rows selected by a slice of an index
+    private static final class RowSlice<T> extends AbstractList<T> {{
+        private final T[] table;
+        private final int[] index;
+        private final int start;
+        private final int stop;
+
+        RowSlice(T[] table, int[] index, int start, int stop) {{
+            this.table = table;
+            this.index = index;
+            this.start = start;
+            this.stop = stop;
+        }}
+
+        @Override
+        public T get(int i) {{
+            if (i < 0 || i >= stop - start) {{
+                throw new IndexOutOfBoundsException(\"Index: \" + i + \", Size: \" + size());
+            }}
+            return table[index[start + i]];
+        }}
+
+        @Override
+        public int size() {{
+            return stop - start;
+        }}
+    }}
+
+    // decode an index from chunks of comma separated values
+    private static int[] decodeIndex(int len, String... chunks) {{
+        int[] index = new int[len];
+        int i = 0;
+        for (String chunk : chunks) {{
+            for (String v : chunk.split(\",\")) {{
+                index[i++] = Integer.parseInt(v);
+            }}
+        }}
+        return index;
+    }}
+"
+        )?;
+    }
+    let str_range = tables.iter().any(|t| {
+        t.data_columns()
+            .iter()
+            .any(|c| c.info().has_iter_range() && c.info().interface_type == BaseType::Str)
+    });
+    if str_range {
+        writeln!(
+            output,
+            "    // compare strings by unicode code points, String.compareTo use UTF-16 code units
+    private static int compareCodePoints(String a, String b) {{
+        int i = 0;
+        int j = 0;
+        while (i < a.length() && j < b.length()) {{
+            int ca = a.codePointAt(i);
+            int cb = b.codePointAt(j);
+            if (ca != cb) {{
+                return Integer.compare(ca, cb);
+            }}
+            i += Character.charCount(ca);
+            j += Character.charCount(cb);
+        }}
+        return Integer.compare(a.length() - i, b.length() - j);
+    }}
+"
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    write_doc(output, "    ", &info.config.help)?;
+    writeln!(output, "    public enum {enumname} {{")?;
+    let mut labels = vec![];
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            labels.push((row, label, col.emit_label_help(row)));
+        }
+    }
+    for (i, (row, label, help)) in labels.iter().enumerate() {
+        write_doc(output, "        ", help)?;
+        let sep = if i + 1 == labels.len() { ";" } else { "," };
+        writeln!(output, "        {}({row}){sep}", const_name(label))?;
+    }
+    writeln!(
+        output,
+        "
+        private final int index;
+
+        {enumname}(int index) {{
+            this.index = index;
+        }}"
+    )?;
+    if table.has_data() {
+        let typname = type_name(&table.name);
+        writeln!(
+            output,
+            "
+        private static final {enumname}[] BY_INDEX = new {enumname}[{}];
+
+        static {{
+            for ({enumname} label : values()) {{
+                BY_INDEX[label.index] = label;
+            }}
+        }}
+
+        /** Returns the row corresponding to the label */
+        public {typname} row() {{
+            return {typname}.TABLE[index];
+        }}",
+            table.len
+        )?;
+    }
+    writeln!(output, "    }}\n")
+}
+
+fn write_label_methods(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let enumname = strtype(&col.info().interface_type);
+    writeln!(
+        output,
+        "
+        /** Returns the label of the row, null if the row has no label */
+        public {enumname} {}() {{
+            return {enumname}.BY_INDEX[index_];
+        }}",
+        field_name(&enumname)
+    )
+}
+
+// ================================================================================================
+// Getters
+// ================================================================================================
+fn write_getter(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let field = field_name(col.name());
+    let outtype = strtype(&info.interface_type);
+    let (ret, body) = match &info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => {
+            (outtype, format!("            return {field};"))
+        }
+        table::TypeImpl::Join => (
+            outtype.to_string(),
+            format!("            return {outtype}.TABLE[{field}];"),
+        ),
+        table::TypeImpl::JoinOptional => (
+            outtype.to_string(),
+            format!(
+                "            if ({field} == 0) {{
+                return null;
+            }}
+            return {outtype}.TABLE[{field} - 1];"
+            ),
+        ),
+        table::TypeImpl::Variant => (variant_type_name(table, col), variant_body(table, col)),
+    };
+    writeln!(output)?;
+    write_doc(output, "        ", &info.config.help)?;
+    writeln!(
+        output,
+        "        public {ret} {field}() {{\n{body}\n        }}"
+    )
+}
+
+fn write_variant_type(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if col.variants().is_none() {
+        return Ok(());
+    }
+    let nullnote = if col.info().config.optional {
+        ", null for rows without match"
+    } else {
+        ""
+    };
+    writeln!(
+        output,
+        "    /** One of the rows that can be referenced by {}.{}(){nullnote} */
+    public interface {} {{}}
+",
+        type_name(&table.name),
+        field_name(col.name()),
+        variant_type_name(table, col)
+    )
+}
+
+// variant interfaces implemented by the rows of a table
+fn variant_interfaces(project: &table::Project, table: &table::Table) -> Vec<String> {
+    let mut interfaces = vec![];
+    for src in &project.tables {
+        if !src.has_data() {
+            continue;
+        }
+        for col in src.data_columns() {
+            if let Some(variants) = col.variants() {
+                if variants.iter().any(|v| !v.is_none && v.name == table.name) {
+                    interfaces.push(variant_type_name(src, col));
+                }
+            }
+        }
+    }
+    interfaces
+}
+
+fn variant_body(table: &table::Table, col: &dyn table::Column) -> String {
+    let variants = col.variants().expect("variant must have variant");
+    let field = field_name(col.name());
+    let mut body = String::new();
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let end = vrn.index + vrn.count - 1;
+        let ret = if vrn.is_none {
+            "null".to_string()
+        } else {
+            let offset = stroffset(-(vrn.index as isize));
+            format!("{}.TABLE[{field}{offset}]", type_name(&vrn.name))
+        };
+        body.push_str(&format!(
+            "            if ({field} <= {end}) {{
+                return {ret};
+            }}
+"
+        ));
+    }
+    body.push_str(&format!(
+        "            throw new IllegalStateException(\"insrcdata variant index overflow in {}\");",
+        type_name(&table.name)
+    ));
+    body
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn write_bound(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let typ = stored_basetype(col);
+    let indexname = index_name(col.name());
+    let value = format!("TABLE[{indexname}[mid]].{}", field_name(col.name()));
+    writeln!(
+        output,
+        "
+        // binary search of the first position in {indexname} with a value greater (or equal) than value
+        private static int {}({} value, boolean upper) {{
+            int lo = 0;
+            int hi = {indexname}.length;
+            while (lo < hi) {{
+                int mid = (lo + hi) >>> 1;
+                int c = {};
+                if (c < 0 || (upper && c == 0)) {{
+                    lo = mid + 1;
+                }} else {{
+                    hi = mid;
+                }}
+            }}
+            return lo;
+        }}",
+        bound_name(col.name()),
+        stored_type(col),
+        compare(&typ, &value, "value")
+    )
+}
+
+fn write_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let field = field_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let indexname = index_name(col.name());
+    let bound = bound_name(col.name());
+    let range = format!("{field}Range");
+    let count = format!("{field}Count");
+    writeln!(output)?;
+    write_doc_or(
+        output,
+        "        ",
+        &info.config.iter_help,
+        format!("Returns the rows with {field} in [start..=stop]"),
+    )?;
+    writeln!(
+        output,
+        "        public static List<{typname}> {range}({argtype} start, {argtype} stop) {{
+            int lo = {bound}(start, false);
+            int hi = Math.max(lo, {bound}(stop, true));
+            return new RowSlice<>(TABLE, {indexname}, lo, hi);
+        }}
+
+        /** Returns the number of rows returned by {range} */
+        public static int {count}({argtype} start, {argtype} stop) {{
+            int lo = {bound}(start, false);
+            return Math.max(lo, {bound}(stop, true)) - lo;
+        }}"
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn write_aggregates(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        writeln!(
+            output,
+            "
+        /** The {} of {} column values */
+        public static final {} {} = {};",
+            aggregate.name,
+            col.name(),
+            strtype(&aggregate.typ),
+            const_name(&format!("{}_{}", col.name(), aggregate.name)),
+            literal(&aggregate.typ, &aggregate.value)
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn write_reverse_join(
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let method = field_name(&rj.reverse_name);
+    let srctype = type_name(&rj.table.name);
+    let indexname = index_name(rj.col.name());
+    let bound = bound_name(rj.col.name());
+    let offset = stroffset(rj.offset as isize);
+    writeln!(output)?;
+    write_doc_or(
+        output,
+        "        ",
+        &info.config.iter_help,
+        format!("Returns the {srctype} rows that reference this row"),
+    )?;
+    writeln!(
+        output,
+        "        public List<{srctype}> {method}() {{
+            int cons = index_{offset};
+            int lo = {srctype}.{bound}(cons, false);
+            int hi = {srctype}.{bound}(cons, true);
+            return new RowSlice<>({srctype}.TABLE, {srctype}.{indexname}, lo, hi);
+        }}"
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn stored_basetype(col: &dyn table::Column) -> BaseType {
+    let info = col.info();
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => info.interface_type.clone(),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            BaseType::I32
+        }
+    }
+}
+fn stored_type(col: &dyn table::Column) -> String {
+    strtype(&stored_basetype(col))
+}
+
+// count of rows by initializer class, the bytecode of a method must stay under 64KB
+fn chunk_len(table: &table::Table) -> usize {
+    let columns = table.data_columns().len();
+    (32000 / (16 + 8 * columns)).clamp(1, 1000)
+}
+
+fn write_index(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let values: Vec<String> = col.indexes().iter().map(|v| v.to_string()).collect();
+    let chunks: Vec<String> = values
+        .chunks(2000)
+        .map(|chunk| format!("\"{}\"", chunk.join(",")))
+        .collect();
+    let mut args = vec![values.len().to_string()];
+    args.extend(chunks);
+    writeln!(
+        output,
+        "        private static final int[] {} = decodeIndex({});",
+        index_name(col.name()),
+        args.join(", ")
+    )
+}
+
+fn write_class(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+    let interfaces = variant_interfaces(project, table);
+    let implements = if interfaces.is_empty() {
+        "".to_string()
+    } else {
+        format!(" implements {}", interfaces.join(", "))
+    };
+
+    write_doc(output, "    ", &table.help)?;
+    writeln!(
+        output,
+        "    public static final class {typname}{implements} {{"
+    )?;
+
+    // data
+    writeln!(
+        output,
+        "        private static final {typname}[] TABLE = new {typname}[{}];\n",
+        table.len
+    )?;
+    writeln!(output, "        static {{")?;
+    for chunk in 0..table.len.div_ceil(chunk_len(table)) {
+        writeln!(
+            output,
+            "            {}.fill(TABLE);",
+            chunk_name(&table.name, chunk)
+        )?;
+    }
+    writeln!(output, "        }}\n")?;
+    let mut has_index = false;
+    for col in &datacols {
+        if col.iterable() {
+            write_index(*col, output)?;
+            has_index = true;
+        }
+    }
+    if has_index {
+        writeln!(output)?;
+    }
+
+    // fields
+    // camel case column fields never end with an underscore
+    writeln!(output, "        private final int index_;")?;
+    for col in &datacols {
+        writeln!(
+            output,
+            "        private final {} {};",
+            stored_type(*col),
+            field_name(col.name())
+        )?;
+    }
+
+    // constructor
+    let mut params = vec!["int index_".to_string()];
+    let mut assigns = vec!["            this.index_ = index_;".to_string()];
+    for col in &datacols {
+        let field = field_name(col.name());
+        params.push(format!("{} {field}", stored_type(*col)));
+        assigns.push(format!("            this.{field} = {field};"));
+    }
+    writeln!(
+        output,
+        "
+        private {typname}({}) {{
+{}
+        }}",
+        params.join(", "),
+        assigns.join("\n")
+    )?;
+
+    // methods
+    writeln!(
+        output,
+        "
+        /** The number of rows in the table */
+        public static final int LEN = {};",
+        table.len
+    )?;
+    for col in &datacols {
+        write_aggregates(*col, output)?;
+        write_getter(table, *col, output)?;
+        if col.iterable() {
+            write_bound(*col, output)?;
+        }
+        if col.info().has_iter_range() {
+            write_range(table, *col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        write_reverse_join(table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        write_label_methods(col, output)?;
+    }
+    writeln!(
+        output,
+        "
+        /** Returns the position of the row in the table */
+        public int asIndex() {{
+            return index_;
+        }}"
+    )?;
+    if table.get_array {
+        writeln!(
+            output,
+            "
+        /** Returns a read only list of all the rows of the table */
+        public static List<{typname}> array() {{
+            return Collections.unmodifiableList(Arrays.asList(TABLE));
+        }}"
+        )?;
+    }
+    writeln!(output, "    }}\n")
+}
+
+// rows are filled by initializer classes so that large tables fit in JVM method and constant pool limits
+fn write_chunks(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+    let len = chunk_len(table);
+    for chunk in 0..table.len.div_ceil(len) {
+        let start = chunk * len;
+        let stop = (start + len).min(table.len);
+        writeln!(
+            output,
+            "    // rows {start} to {} of {typname}
+    private static final class {} {{
+        static void fill({typname}[] t) {{",
+            stop - 1,
+            chunk_name(&table.name, chunk)
+        )?;
+        for row in start..stop {
+            let mut cells = vec![row.to_string()];
+            for col in &datacols {
+                let cell = col.emit_table_cell(row, project.lang);
+                cells.push(literal(&stored_basetype(*col), &cell));
+            }
+            writeln!(
+                output,
+                "            t[{row}] = new {typname}({});",
+                cells.join(", ")
+            )?;
+        }
+        writeln!(output, "        }}\n    }}\n")?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangJava {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        writeln!(output, "// {}\n", language::file_notice())?;
+        if let Some(package) = package_name(project) {
+            writeln!(output, "package {package};\n")?;
+        }
+
+        let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+        let mut imports: Vec<String> = project.imports().into_iter().collect();
+        if tables.iter().any(|t| project.table_need_iter(t)) {
+            imports.push("java.util.AbstractList".to_string());
+        }
+        if tables
+            .iter()
+            .any(|t| project.table_need_iter(t) || t.get_array)
+        {
+            imports.push("java.util.List".to_string());
+        }
+        if tables.iter().any(|t| t.get_array) {
+            imports.push("java.util.Arrays".to_string());
+            imports.push("java.util.Collections".to_string());
+        }
+        imports.sort();
+        for import in &imports {
+            writeln!(output, "import {import};")?;
+        }
+        if !imports.is_empty() {
+            writeln!(output)?;
+        }
+
+        write_doc(output, "", &project.help)?;
+        let classname = project.name();
+        writeln!(
+            output,
+            "public final class {classname} {{
+    private {classname}() {{}}
+"
+        )?;
+        write_helpers(project, output)?;
+
+        // labels
+        for table in &project.tables {
+            for col in table.label_columns() {
+                write_labels(table, col, output)?;
+            }
+        }
+
+        // variants
+        for table in &tables {
+            for col in table.data_columns() {
+                write_variant_type(table, col, output)?;
+            }
+        }
+
+        // tables
+        for table in &tables {
+            write_class(project, table, output)?;
+        }
+        // initializer classes end with a blank line that must not precede the closing brace
+        let mut chunks: Vec<u8> = vec![];
+        for table in &tables {
+            write_chunks(project, table, &mut chunks)?;
+        }
+        output.write_all(chunks.trim_ascii_end())?;
+        writeln!(output, "\n}}")?;
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "java".to_string()
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    // enum constants are created by a single static initializer limited to 64KB of bytecode
+    fn max_labels(&self) -> Option<usize> {
+        Some(2500)
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        format!("{}.{}", strtype(typ), const_name(label))
+    }
+
+    // ascii literal that does not depend on source encoding
+    // java translate \u escapes before parsing, so control characters use octal escapes
+    fn emit_str(&self, v: &str) -> String {
+        let mut s = String::from("\"");
+        for c in v.chars() {
+            match c {
+                '\\' => s.push_str("\\\\"),
+                '"' => s.push_str("\\\""),
+                '\n' => s.push_str("\\n"),
+                '\r' => s.push_str("\\r"),
+                '\t' => s.push_str("\\t"),
+                c if c.is_control() => s.push_str(&format!("\\{:03o}", c as u32)),
+                c if c.is_ascii() => s.push(c),
+                c => {
+                    let mut units = [0; 2];
+                    for unit in c.encode_utf16(&mut units) {
+                        s.push_str(&format!("\\u{:04x}", unit));
+                    }
+                }
+            }
+        }
+        s.push('"');
+        s
+    }
+}
+
+const LANG_JAVA_: LangJava = LangJava {};
+pub const LANG_JAVA: &'static dyn language::Language = &LANG_JAVA_;
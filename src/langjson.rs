@@ -0,0 +1,264 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// export of validated tables as JSON document
+//
+
+use crate::basetype::BaseType;
+use crate::{aperror, language, table};
+use heck::ToSnakeCase;
+use std::{fs, io};
+
+struct LangJson {}
+
+// type name in schema section
+fn strtype(typ: &BaseType) -> &str {
+    match typ {
+        BaseType::Label { .. } => "label",
+        BaseType::Bool => "bool",
+        BaseType::I8 => "i8",
+        BaseType::I16 => "i16",
+        BaseType::I32 => "i32",
+        BaseType::I64 => "i64",
+        BaseType::U8 => "u8",
+        BaseType::U16 => "u16",
+        BaseType::U32 => "u32",
+        BaseType::U64 => "u64",
+        BaseType::F32 => "f32",
+        BaseType::F64 => "f64",
+        BaseType::Str => "str",
+        BaseType::Object { .. } => "object",
+        BaseType::Join { .. } => "join",
+        BaseType::Variant => "variant",
+    }
+}
+
+fn quote(v: &str) -> String {
+    language::quote_str(v)
+}
+
+fn optional_str(v: &Option<String>) -> String {
+    match v {
+        Some(s) => quote(s),
+        None => "null".to_string(),
+    }
+}
+
+// JSON has no literal for NaN and infinities
+fn number(v: String) -> String {
+    match v.as_str() {
+        "NaN" | "inf" | "-inf" => "null".to_string(),
+        _ => v,
+    }
+}
+
+// multiline list of table members
+fn block(open: &str, close: &str, items: &[String]) -> String {
+    if items.is_empty() {
+        format!("{open}{close}")
+    } else {
+        format!("{open}\n{}\n      {close}", items.join(",\n"))
+    }
+}
+
+fn int_list(values: &[usize]) -> String {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", values.join(", "))
+}
+
+// ================================================================================================
+// Schema
+// ================================================================================================
+fn column_schema(col: &dyn table::Column) -> String {
+    let info = col.info();
+    let mut fields = vec![
+        format!("\"name\": {}", quote(col.name())),
+        format!("\"type\": \"{}\"", strtype(&info.interface_type)),
+    ];
+    match &info.interface_type {
+        BaseType::Object { objtype } => fields.push(format!("\"objtype\": {}", quote(objtype))),
+        BaseType::Join { strname } => {
+            fields.push(format!("\"table\": {}", quote(strname)));
+            fields.push(format!("\"optional\": {}", info.config.optional));
+        }
+        BaseType::Variant => {
+            let tables: Vec<String> = variant_tables(col).iter().map(|t| quote(t)).collect();
+            fields.push(format!("\"tables\": [{}]", tables.join(", ")));
+            fields.push(format!("\"optional\": {}", info.config.optional));
+        }
+        _ => {}
+    }
+    if info.config.help.is_some() {
+        fields.push(format!("\"help\": {}", optional_str(&info.config.help)));
+    }
+    let aggregates: Vec<String> = col
+        .aggregates()
+        .into_iter()
+        .map(|a| format!("{}: {}", quote(&a.name), number(a.value)))
+        .collect();
+    if !aggregates.is_empty() {
+        fields.push(format!("\"aggregates\": {{{}}}", aggregates.join(", ")));
+    }
+    format!("{{{}}}", fields.join(", "))
+}
+
+fn variant_tables(col: &dyn table::Column) -> Vec<String> {
+    col.variants()
+        .expect("variant must have variant")
+        .iter()
+        .filter(|vrn| !vrn.is_none)
+        .map(|vrn| vrn.name.to_string())
+        .collect()
+}
+
+// ================================================================================================
+// Cell values
+// ================================================================================================
+fn cell_value(project: &table::Project, col: &dyn table::Column, row: usize) -> String {
+    let cell = col.emit_table_cell(row, project.lang);
+    match col.info().type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Join => cell,
+        table::TypeImpl::JoinOptional => {
+            // stored with an offset of one, zero is the null value
+            let v: usize = cell.parse().expect("join index must be an integer");
+            match v {
+                0 => "null".to_string(),
+                v => (v - 1).to_string(),
+            }
+        }
+        table::TypeImpl::Variant => {
+            let v: usize = cell.parse().expect("variant index must be an integer");
+            let variants = col.variants().expect("variant must have variant");
+            match variants
+                .iter()
+                .find(|vrn| v >= vrn.index && v < vrn.index + vrn.count)
+            {
+                Some(vrn) if !vrn.is_none => format!(
+                    "{{\"table\": {}, \"row\": {}}}",
+                    quote(&vrn.name),
+                    v - vrn.index
+                ),
+                _ => "null".to_string(),
+            }
+        }
+        table::TypeImpl::Scalar => match col.info().interface_type {
+            BaseType::F32 | BaseType::F64 => number(cell),
+            // object template is written as a string
+            BaseType::Object { .. } => quote(&cell),
+            _ => cell,
+        },
+    }
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn write_table(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let datacols = table.data_columns();
+
+    writeln!(output, "    {{")?;
+    writeln!(output, "      \"name\": {},", quote(&table.name))?;
+    if table.help.is_some() {
+        writeln!(output, "      \"help\": {},", optional_str(&table.help))?;
+    }
+    writeln!(output, "      \"len\": {},", table.len)?;
+    writeln!(output, "      \"exhaustive\": {},", table.exhaustive)?;
+
+    // schema
+    let columns: Vec<String> = datacols
+        .iter()
+        .map(|col| format!("        {}", column_schema(*col)))
+        .collect();
+    writeln!(output, "      \"columns\": {},", block("[", "]", &columns))?;
+
+    // labels name to row index
+    let labels: Vec<String> = table
+        .label_columns()
+        .iter()
+        .map(|col| {
+            let name = match &col.info().interface_type {
+                BaseType::Label { name } => name.to_string(),
+                _ => col.name().to_string(),
+            };
+            let rows: Vec<String> = (0..table.len)
+                .filter(|row| !col.emit_label(*row).is_empty())
+                .map(|row| format!("{}: {row}", quote(&col.emit_label(row).to_snake_case())))
+                .collect();
+            format!("        {}: {{{}}}", quote(&name), rows.join(", "))
+        })
+        .collect();
+    writeln!(output, "      \"labels\": {},", block("{", "}", &labels))?;
+
+    // indexes : rows sorted by column value
+    let indexes: Vec<String> = datacols
+        .iter()
+        .filter(|col| col.iterable())
+        .map(|col| {
+            format!(
+                "        {}: {}",
+                quote(col.name()),
+                int_list(&col.indexes())
+            )
+        })
+        .collect();
+    writeln!(output, "      \"indexes\": {},", block("{", "}", &indexes))?;
+
+    // data
+    let rows: Vec<String> = (0..table.len)
+        .map(|row| {
+            let cells: Vec<String> = datacols
+                .iter()
+                .map(|col| cell_value(project, *col, row))
+                .collect();
+            format!("        [{}]", cells.join(", "))
+        })
+        .collect();
+    writeln!(output, "      \"rows\": {}", block("[", "]", &rows))?;
+    write!(output, "    }}")
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangJson {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        writeln!(output, "{{")?;
+        writeln!(
+            output,
+            "  \"generator\": {},",
+            quote(&language::file_notice())
+        )?;
+        if project.help.is_some() {
+            writeln!(output, "  \"help\": {},", optional_str(&project.help))?;
+        }
+        writeln!(output, "  \"tables\": [")?;
+        for (i, table) in project.tables.iter().enumerate() {
+            if i > 0 {
+                writeln!(output, ",")?;
+            }
+            write_table(project, table, output)?;
+        }
+        writeln!(output, "\n  ]\n}}")?;
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "json".to_string()
+    }
+
+    fn emit_str(&self, v: &str) -> String {
+        language::quote_str(v)
+    }
+}
+
+const LANG_JSON_: LangJson = LangJson {};
+pub const LANG_JSON: &'static dyn language::Language = &LANG_JSON_;
@@ -0,0 +1,512 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for Python language
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, log, table};
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use std::{fs, io};
+
+struct LangPython {}
+
+// python type annotations
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return class_name(name),
+        BaseType::I8
+        | BaseType::I16
+        | BaseType::I32
+        | BaseType::I64
+        | BaseType::U8
+        | BaseType::U16
+        | BaseType::U32
+        | BaseType::U64 => "int",
+        BaseType::Str => "str",
+        BaseType::Join { strname } => return class_name(strname),
+        BaseType::Object { objtype } => objtype,
+
+        BaseType::Bool => "bool",
+        BaseType::F32 | BaseType::F64 => "float",
+        BaseType::Variant => "int",
+    })
+}
+
+// write docstring
+fn write_doc(output: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    if let Some(doc) = doc {
+        let doc = doc.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+        if doc.contains('\n') {
+            let doc = doc.replace('\n', &format!("\n{indent}"));
+            writeln!(output, "{indent}\"\"\"{doc}\n{indent}\"\"\"")?;
+        } else {
+            writeln!(output, "{indent}\"\"\"{doc}\"\"\"")?;
+        }
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// format name to Python conventions
+// ================================================================================================
+fn class_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn field_name(col_name: &str) -> String {
+    col_name.to_snake_case()
+}
+fn const_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+fn table_name(table_name: &str) -> String {
+    format!("{}_TABLE", const_name(table_name))
+}
+fn index_name(table_name: &str, col_name: &str) -> String {
+    format!("_{}_{}_INDEX", const_name(table_name), const_name(col_name))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+
+// ================================================================================================
+// Helpers shared by all tables
+// ================================================================================================
+fn write_helpers(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "
+def _bounds(table, index, start, stop, key):
+    # positions in index of rows with key(row) in [start..=stop], index must be sorted by key(row)
+    lo = bisect.bisect_left(index, start, key=lambda i: key(table[i]))
+    hi = bisect.bisect_right(index, stop, lo, key=lambda i: key(table[i]))
+    return lo, hi
+
+
+def _rows(table, index, start, stop, key):
+    # rows of table with key(row) in [start..=stop]
+    lo, hi = _bounds(table, index, start, stop, key)
+    return (table[i] for i in index[lo:hi])
+"
+    )
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    writeln!(output, "\nclass {enumname}(enum.IntEnum):")?;
+    write_doc(output, "    ", &info.config.help)?;
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            let constant = const_name(&label);
+            writeln!(output, "    {constant} = {row}")?;
+            write_doc(output, "    ", &col.emit_label_help(row))?;
+        }
+    }
+    writeln!(output)
+}
+
+fn write_label_methods(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let clsname = class_name(&table.name);
+    let tablename = table_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    let method = field_name(&enumname);
+    writeln!(
+        output,
+        "
+    @staticmethod
+    def from_{method}(label: {enumname}) -> {clsname}:
+        \"\"\"Row corresponding to label\"\"\"
+        return {tablename}[label]
+
+    @property
+    def {method}(self) -> {enumname}:
+        \"\"\"Label of the current row\"\"\"
+        return {enumname}(self._index)"
+    )
+}
+
+// ================================================================================================
+// Getters
+// ================================================================================================
+fn write_getter(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let field = field_name(col.name());
+    let outtype = strtype(&info.interface_type);
+    let body = match &info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => return Ok(()),
+        table::TypeImpl::Join => {
+            let jointable = table_name(&info.join_table());
+            format!(
+                "    def {field}(self) -> {outtype}:
+        return {jointable}[self._{field}]"
+            )
+        }
+        table::TypeImpl::JoinOptional => {
+            let jointable = table_name(&info.join_table());
+            format!(
+                "    def {field}(self) -> Optional[{outtype}]:
+        return {jointable}[self._{field} - 1] if self._{field} else None"
+            )
+        }
+        table::TypeImpl::Variant => variant_getter(table, col),
+    };
+    writeln!(output, "\n    @property\n{body}")?;
+    write_doc(output, "        ", &info.config.help)?;
+    Ok(())
+}
+
+fn variant_getter(table: &table::Table, col: &dyn table::Column) -> String {
+    let variants = col.variants().expect("variant must have variant");
+    let field = field_name(col.name());
+    let alternatives: Vec<String> = variants
+        .iter()
+        .map(|vrn| {
+            if vrn.is_none {
+                "None".to_string()
+            } else {
+                class_name(&vrn.name)
+            }
+        })
+        .collect();
+    let mut body = format!(
+        "    def {field}(self) -> Union[{}]:
+        v = self._{field}",
+        alternatives.join(", ")
+    );
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let end = vrn.index + vrn.count - 1;
+        if vrn.is_none {
+            body.push_str(&format!(
+                "
+        if v <= {end}:
+            return None"
+            ));
+        } else {
+            let jointable = table_name(&vrn.name);
+            let offset = stroffset(-(vrn.index as isize));
+            body.push_str(&format!(
+                "
+        if v <= {end}:
+            return {jointable}[v{offset}]"
+            ));
+        }
+    }
+    body.push_str(&format!(
+        "
+        raise IndexError(\"insrcdata variant index overflow in {}\")",
+        class_name(&table.name)
+    ));
+    body
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn write_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let clsname = class_name(&table.name);
+    let field = field_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let tablename = table_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    writeln!(
+        output,
+        "
+    @staticmethod
+    def {field}_range(start: {argtype}, stop: {argtype}) -> Iterator[{clsname}]:"
+    )?;
+    write_doc(output, "        ", &info.config.iter_help)?;
+    writeln!(
+        output,
+        "        return _rows({tablename}, {indexname}, start, stop, lambda r: r.{field})
+
+    @staticmethod
+    def {field}_count(start: {argtype}, stop: {argtype}) -> int:
+        \"\"\"Number of rows returned by {field}_range\"\"\"
+        lo, hi = _bounds({tablename}, {indexname}, start, stop, lambda r: r.{field})
+        return hi - lo"
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn write_aggregates(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        let name = const_name(&format!("{}_{}", col.name(), aggregate.name));
+        writeln!(
+            output,
+            "    {name} = {}\n    \"\"\"{} of {} column values\"\"\"",
+            aggregate.value,
+            aggregate.name,
+            col.name()
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn write_reverse_join(
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let reverse = &rj.reverse_name;
+    let srcclass = class_name(&rj.table.name);
+    let srctable = table_name(&rj.table.name);
+    let indexname = index_name(&rj.table.name, rj.col.name());
+    let field = field_name(rj.col.name());
+    let offset = stroffset(rj.offset as isize);
+    writeln!(
+        output,
+        "
+    @property
+    def {reverse}(self) -> Iterator[{srcclass}]:"
+    )?;
+    write_doc(output, "        ", &info.config.iter_help)?;
+    writeln!(
+        output,
+        "        cons = self._index{offset}
+        return _rows({srctable}, {indexname}, cons, cons, lambda r: r._{field})"
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+
+// getter are properties for joins and variants, the raw value is kept in a private field
+fn stored_name(col: &dyn table::Column) -> String {
+    let field = field_name(col.name());
+    match col.info().type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => field,
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            format!("_{field}")
+        }
+    }
+}
+
+fn stored_type(col: &dyn table::Column) -> String {
+    let info = col.info();
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => strtype(&info.interface_type),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            "int".to_string()
+        }
+    }
+}
+
+fn write_class(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let clsname = class_name(&table.name);
+    let tablename = table_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    writeln!(
+        output,
+        "\n@dataclass(frozen=True, eq=False, slots=True)\nclass {clsname}:"
+    )?;
+    write_doc(output, "    ", &table.help)?;
+    writeln!(output, "    _index: int = field(repr=False)")?;
+    for col in &datacols {
+        writeln!(output, "    {}: {}", stored_name(*col), stored_type(*col))?;
+        if matches!(
+            col.info().type_impl(),
+            table::TypeImpl::Label | table::TypeImpl::Scalar
+        ) {
+            write_doc(output, "    ", &col.info().config.help)?;
+        }
+    }
+    for col in &datacols {
+        write_aggregates(*col, output)?;
+    }
+
+    for col in &datacols {
+        write_getter(table, *col, output)?;
+        if col.info().has_iter_range() {
+            write_range(table, *col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        write_reverse_join(table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        write_label_methods(table, col, output)?;
+    }
+
+    writeln!(
+        output,
+        "
+    @staticmethod
+    def len() -> int:
+        \"\"\"Number of rows in the table\"\"\"
+        return len({tablename})
+
+    def as_index(self) -> int:
+        \"\"\"Index of the current record in the table\"\"\"
+        return self._index"
+    )?;
+    if table.get_array {
+        writeln!(
+            output,
+            "
+    @staticmethod
+    def array() -> Tuple[{clsname}, ...]:
+        \"\"\"Tuple containing all the values\"\"\"
+        return {tablename}"
+        )?;
+    }
+    writeln!(output)
+}
+
+fn write_tuple(name: &str, values: &[usize], output: &mut dyn io::Write) -> io::Result<()> {
+    write!(output, "{name}: Tuple[int, ...] = (")?;
+    let width = language::digits(values.len());
+    for (i, v) in values.iter().enumerate() {
+        if i % 20 == 0 {
+            write!(output, "\n    ")?;
+        }
+        write!(output, "{:width$}, ", v)?;
+    }
+    writeln!(output, "\n)")
+}
+
+fn write_table_data(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let clsname = class_name(&table.name);
+    let tablename = table_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    writeln!(output, "\n{tablename}: Tuple[{clsname}, ...] = (")?;
+    for row in 0..table.len {
+        write!(output, "    {clsname}({row}, ")?;
+        let cells: Vec<String> = datacols
+            .iter()
+            .map(|col| col.emit_table_cell(row, project.lang))
+            .collect();
+        writeln!(output, "{}),", cells.join(", "))?;
+    }
+    writeln!(output, ")")?;
+
+    // indexes
+    for col in &datacols {
+        if col.iterable() {
+            write_tuple(&index_name(&table.name, col.name()), &col.indexes(), output)?;
+        }
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangPython {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        writeln!(output, "# {}", language::file_notice())?;
+        write_doc(output, "", &project.help)?;
+        writeln!(
+            output,
+            "
+from __future__ import annotations
+
+import bisect
+import enum
+from dataclasses import dataclass, field
+from typing import Iterator, Optional, Tuple, Union"
+        )?;
+        let mut imports: Vec<String> = project.imports().into_iter().collect();
+        imports.sort();
+        for import in imports {
+            writeln!(output, "import {import}")?;
+        }
+        writeln!(output)?;
+        write_helpers(output)?;
+
+        // labels
+        for table in &project.tables {
+            for col in table.label_columns() {
+                write_labels(col, output)?;
+            }
+        }
+
+        // classes
+        let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+        for table in &tables {
+            write_class(project, table, output)?;
+        }
+
+        // data
+        for table in &tables {
+            write_table_data(project, table, output)?;
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "py".to_string()
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        let enumstr = strtype(typ);
+        let constant = const_name(label);
+        format!("{enumstr}.{constant}")
+    }
+
+    fn emit_bool(&self, v: bool) -> String {
+        if v { "True" } else { "False" }.to_string()
+    }
+
+    fn emit_str(&self, v: &str) -> String {
+        language::quote_str(v)
+    }
+}
+
+const LANG_PYTHON_: LangPython = LangPython {};
+pub const LANG_PYTHON: &'static dyn language::Language = &LANG_PYTHON_;
@@ -6,10 +6,13 @@
 //
 
 use crate::basetype::BaseType;
+use crate::blob::Blob;
+use crate::compress::{self, Compressed, Dictionary};
 use crate::table::JoinTo;
-use crate::{aperror, basetype, language, log, table};
+use crate::{aperror, basetype, bitmap, index, language, log, packing, table};
 use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use language::write_help;
+use std::path::PathBuf;
 use std::{fs, io};
 
 struct Rust {}
@@ -66,10 +69,214 @@ fn table_name(strname: &str) -> String {
 fn index_name(strname: &str, field: &str) -> String {
     format!("{}::{}_INDEX", mod_name(strname), const_name(field))
 }
+fn bitmap_name(strname: &str, field: &str) -> String {
+    format!("{}::{}_BITMAP", mod_name(strname), const_name(field))
+}
+fn groups_name(strname: &str, field: &str) -> String {
+    format!("{}::{}_GROUPS", mod_name(strname), const_name(field))
+}
+fn closure_name(strname: &str, field: &str) -> String {
+    format!("{}::{}", mod_name(strname), const_name(field))
+}
+fn fuzzy_name(strname: &str, field: &str) -> String {
+    format!("{}::{}_FUZZY", mod_name(strname), const_name(field))
+}
 
 // ================================================================================================
 // Getters
 // ================================================================================================
+// qualifier for functions that need const access to statics (Rust 1.83)
+fn const_fn(project: &table::Project) -> &'static str {
+    if project.const_fn {
+        "const "
+    } else {
+        ""
+    }
+}
+
+// qualifier for private items that other table files use in split output
+fn split_visibility(project: &table::Project) -> &'static str {
+    if project.split {
+        "pub(super) "
+    } else {
+        ""
+    }
+}
+
+fn packed_field<'a>(
+    table: &'a table::Table,
+    col: &dyn table::Column,
+) -> Option<&'a packing::Field> {
+    table.packing.as_ref().and_then(|p| p.field(col))
+}
+
+// columns stored as struct fields or column arrays
+fn unpacked_columns<'a>(
+    table: &table::Table,
+    datacols: &[&'a dyn table::Column],
+) -> Vec<&'a dyn table::Column> {
+    datacols
+        .iter()
+        .filter(|col| packed_field(table, **col).is_none())
+        .filter(|col| table.blob.as_ref().and_then(|b| b.field(**col)).is_none())
+        .copied()
+        .collect()
+}
+
+fn word_type(table: &table::Table) -> String {
+    let bits = table.packing.as_ref().map_or(64, |p| p.word_bits);
+    format!("u{bits}")
+}
+
+// value of a packed column extracted from its word
+fn unpack(
+    table: &table::Table,
+    col: &dyn table::Column,
+    field: &packing::Field,
+    word: &str,
+) -> String {
+    let typ = &col.info().table_type;
+    if field.bits == 0 {
+        return match typ {
+            BaseType::Bool => (field.min != 0).to_string(),
+            _ => format!("{}{}", field.min, strtype(typ)),
+        };
+    }
+    let shifted = match field.shift {
+        0 => word.to_string(),
+        shift => format!("({word} >> {shift})"),
+    };
+    let masked = format!("({shifted} & {:#x})", field.mask());
+    let typ = strtype(typ);
+    match field.min {
+        _ if typ == "bool" => format!("{masked} != 0"),
+        0 if typ == word_type(table) => masked,
+        0 => format!("{masked} as {typ}"),
+        min if min > 0 => format!("({masked} as u64 + {min}) as {typ}"),
+        min => format!(
+            "({masked} as u64).wrapping_sub({}) as {typ}",
+            min.unsigned_abs()
+        ),
+    }
+}
+
+// value read from the binary blob of table at offset in row
+fn blob_read(modname: &str, typ: &BaseType, index: &str, offset: usize) -> String {
+    let pos = match offset {
+        0 => format!("{index} * {modname}::ROW_SIZE"),
+        _ => format!("{index} * {modname}::ROW_SIZE + {offset}"),
+    };
+    match typ {
+        BaseType::Bool => format!("{modname}::BLOB[{pos}] != 0"),
+        BaseType::U8 => format!("{modname}::BLOB[{pos}]"),
+        BaseType::Str => format!("{modname}::str_at({pos})"),
+        _ => format!(
+            "{}::from_le_bytes({modname}::bytes::<{}>({pos}))",
+            strtype(typ),
+            typ.byte_size().unwrap_or(0)
+        ),
+    }
+}
+
+// stored value of a column, the row is given by its index in table
+fn stored_at(table: &table::Table, col: &dyn table::Column, index: &str) -> String {
+    let modname = mod_name(&table.name);
+    if let Some(field) = packed_field(table, col) {
+        let word = if let Some(blob) = &table.blob {
+            let typ = match table.packing.as_ref().map_or(64, |p| p.word_bits) {
+                8 => BaseType::U8,
+                16 => BaseType::U16,
+                32 => BaseType::U32,
+                _ => BaseType::U64,
+            };
+            blob_read(&modname, &typ, index, blob.words[field.word])
+        } else if table.soa {
+            format!("{modname}::PACKED_VALUES[{index}][{}]", field.word)
+        } else {
+            format!("{modname}::TABLE[{index}].packed_[{}]", field.word)
+        };
+        return unpack(table, col, field, &word);
+    }
+    if let Some(field) = table.blob.as_ref().and_then(|blob| blob.field(col)) {
+        return blob_read(&modname, &field.typ, index, field.offset);
+    }
+    if table.soa {
+        format!("{modname}::{}_VALUES[{index}]", const_name(col.name()))
+    } else {
+        format!("{modname}::TABLE[{index}].{}_", field_name(col.name()))
+    }
+}
+
+// expression without its enclosing parentheses, for the right side of let statements
+fn unparen(expr: &str) -> &str {
+    let Some(inner) = expr.strip_prefix('(').and_then(|e| e.strip_suffix(')')) else {
+        return expr;
+    };
+    let mut depth = 0;
+    for c in inner.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return expr,
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    inner
+}
+
+// stored value of a column for the current row
+fn stored_self(table: &table::Table, col: &dyn table::Column) -> String {
+    if table.soa {
+        return stored_at(table, col, "self.index_ as usize");
+    }
+    match packed_field(table, col) {
+        Some(field) => unpack(table, col, field, &format!("self.packed_[{}]", field.word)),
+        None => format!("self.{}_", field_name(col.name())),
+    }
+}
+
+// type of the value stored in table, compressed columns store an offset
+fn stored_type(col: &dyn table::Column) -> BaseType {
+    match col.compressed() {
+        Some(compressed) => compressed.offset_type(),
+        None => col.info().table_type.clone(),
+    }
+}
+
+fn table_cell(project: &table::Project, col: &dyn table::Column, row: usize) -> String {
+    match col.compressed() {
+        Some(compressed) => compressed.offsets[row].to_string(),
+        None => col.emit_table_cell(row, project.lang),
+    }
+}
+
+// decoding of a compressed value in buf
+fn decoded(table: &table::Table, col: &dyn table::Column, offset: &str, buf: &str) -> String {
+    let modname = mod_name(&table.name);
+    let name = format!("{modname}::{}", const_name(col.name()));
+    match col.compressed().map(|c| &c.dictionary) {
+        Some(Dictionary::Huffman { .. }) => format!(
+            "huffman_decode(&{name}_BLOB, &{name}_COUNTS, &{name}_SYMBOLS, {offset} as usize, {buf})"
+        ),
+        Some(Dictionary::Fsst { .. }) => format!(
+            "fsst_decode(&{name}_BLOB, &{name}_SYMBOLS, &{name}_SYMBOL_STARTS, {offset} as usize, {buf})"
+        ),
+        None => offset.to_string(),
+    }
+}
+
+// value of a column read through the getters, compressed values are decoded in a stack buffer
+fn getter_value(col: &dyn table::Column) -> String {
+    let field = field_name(col.name());
+    match col.compressed() {
+        Some(_) => format!(
+            "self.{field}_decode(&mut [0u8; Self::{}_MAX_LEN]).unwrap_or_default()",
+            const_name(col.name())
+        ),
+        None => format!("self.{field}()"),
+    }
+}
+
 fn cast_to_interface_type(info: &table::ColumnInfo) -> String {
     if info.interface_type == info.table_type {
         String::from("")
@@ -79,19 +286,27 @@ fn cast_to_interface_type(info: &table::ColumnInfo) -> String {
 }
 
 fn getter_col(
+    project: &table::Project,
     table: &table::Table,
     col: &dyn table::Column,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
     let info = col.info();
     let field = field_name(col.name());
+    let constfn = const_fn(project);
+    // struct of arrays read statics
+    let scalarfn = if table.soa { constfn } else { "const " };
+    let stored = stored_self(table, col);
+    if let Some(compressed) = col.compressed() {
+        return getter_compressed(project, table, col, compressed, output);
+    }
     write_help(output, "    ///", &info.config.help)?;
     match &info.type_impl() {
         table::TypeImpl::Label => {
             let outtype = strtype(&info.interface_type);
             writeln!(
                 output,
-                "    pub fn {field}(&self) -> &{outtype} {{ &self.{field}_}}",
+                "    pub {scalarfn}fn {field}(&self) -> &{outtype} {{ &{stored}}}",
             )?;
         }
         table::TypeImpl::Scalar => {
@@ -99,7 +314,7 @@ fn getter_col(
             let cast = cast_to_interface_type(info);
             writeln!(
                 output,
-                "    pub fn {field}(&self) -> {outtype} {{ self.{field}_{cast} }}",
+                "    pub {scalarfn}fn {field}(&self) -> {outtype} {{ {stored}{cast} }}",
             )?;
         }
         table::TypeImpl::Join => {
@@ -107,27 +322,64 @@ fn getter_col(
             let jointable = table_name(&outtype);
             writeln!(
                 output,
-                "    pub fn {field}(&self) -> &'static {outtype} {{ &{jointable}[self.{field}_ as usize]}}"
+                "    pub {constfn}fn {field}(&self) -> &'static {outtype} {{ &{jointable}[{stored} as usize]}}"
             )?;
         }
         table::TypeImpl::JoinOptional => {
             let outtype = struct_name(&info.join_table());
             let jointable = table_name(&outtype);
+            let value = unparen(&stored);
             writeln!(
                 output,
-                "    pub fn {field}(&self) -> Option<&'static {outtype}> {{
-        let index = self.{field}_;
+                "    pub {constfn}fn {field}(&self) -> Option<&'static {outtype}> {{
+        let index = {value};
         if index==0 {{ None }} else {{ Some(&{jointable}[index as usize -1]) }}
     }}"
             )?;
         }
         table::TypeImpl::Variant => {
-            getter_variant(table, col, output)?;
+            getter_variant(project, table, col, output)?;
         }
     }
     Ok(())
 }
 
+fn getter_compressed(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    compressed: &Compressed,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let field = field_name(col.name());
+    let uperfield = const_name(col.name());
+    let max_len = compressed.max_len;
+    let decoded = decoded(table, col, &stored_self(table, col), "buf");
+    writeln!(
+        output,
+        "    /// Size of a buffer large enough to decode any {field} value
+    pub const {uperfield}_MAX_LEN: usize = {max_len};"
+    )?;
+    write_help(output, "    ///", &info.config.help)?;
+    writeln!(
+        output,
+        "    /// Decode {field} in buf, None if buf is smaller than the value
+    pub fn {field}_decode<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> {{ {decoded} }}"
+    )?;
+    if !project.no_std {
+        write_help(output, "    ///", &info.config.help)?;
+        writeln!(
+            output,
+            "    pub fn {field}(&self) -> String {{
+        let mut buf = [0u8; Self::{uperfield}_MAX_LEN];
+        String::from(self.{field}_decode(&mut buf).unwrap_or_default())
+    }}"
+        )?;
+    }
+    Ok(())
+}
+
 // ================================================================================================
 // Iterator definition
 // ================================================================================================
@@ -141,7 +393,7 @@ fn write_iter_index_struct(
     writeln!(
         output,
         "pub struct IndexIter {{
-    pub indexes : Box<dyn Iterator<Item=&'static {indextype}>>,
+    pub indexes : core::slice::Iter<'static, {indextype}>,
 }}
 
 impl Iterator for IndexIter {{
@@ -154,8 +406,14 @@ impl Iterator for IndexIter {{
             None => None,
         }}
     }}
+
+    fn size_hint(&self) -> (usize, Option<usize>) {{
+        self.indexes.size_hint()
+    }}
 }}
 
+impl ExactSizeIterator for IndexIter {{}}
+
 "
     )
 }
@@ -163,29 +421,25 @@ impl Iterator for IndexIter {{
 // ================================================================================================
 // Range iterator
 // ================================================================================================
-fn iter_col(
-    table: &table::Table,
-    col: &dyn table::Column,
-    output: &mut dyn io::Write,
-) -> io::Result<()> {
+// bisection computing index bounds [begin..lo] for values in [start..=stop]
+fn bisect_range(table: &table::Table, col: &dyn table::Column) -> String {
     let info = col.info();
 
-    let field = field_name(col.name());
-    let argtype = argtype(&info.interface_type);
-    let modname = mod_name(&table.name);
-    let tablename = table_name(&table.name);
     let indexname = index_name(&table.name, col.name());
     let cast = cast_to_interface_type(info);
+    let mut stored = stored_at(table, col, &format!("{indexname}[mid] as usize"));
+    if let Some(compressed) = col.compressed() {
+        // values are decoded in a buffer during comparison
+        let buf = format!("&mut [0u8; {}]", compressed.max_len);
+        stored = decoded(table, col, &stored, &buf) + ".unwrap_or_default()";
+    }
 
-    write_help(output, "    ///", &info.config.iter_help)?;
-    writeln!(
-        output,
-        "    pub fn {field}_range(start:{argtype}, stop:{argtype}) -> {modname}::IndexIter {{
-        let mut lo = 0;
+    format!(
+        "        let mut lo = 0;
         let mut hi = {indexname}.len();
         while lo < hi {{
             let mid = (lo + hi) / 2;
-            if start > {tablename}[{indexname}[mid] as usize].{field}_{cast} {{
+            if start > {stored}{cast} {{
                  lo = mid + 1;
             }} else {{
                  hi = mid;
@@ -196,240 +450,1382 @@ fn iter_col(
         hi = {indexname}.len();
         while lo < hi {{
             let mid = (lo + hi) / 2;
-            if stop < {tablename}[{indexname}[mid] as usize].{field}_{cast} {{
+            if stop < {stored}{cast} {{
                 hi = mid;
             }} else {{
                 lo = mid + 1;
             }}
-        }}
+        }}"
+    )
+}
+
+fn iter_col(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+
+    let field = field_name(col.name());
+    let argtype = argtype(&info.interface_type);
+    let modname = mod_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    let bisect = bisect_range(table, col);
+
+    write_help(output, "    ///", &info.config.iter_help)?;
+    writeln!(
+        output,
+        "    pub fn {field}_range(start:{argtype}, stop:{argtype}) -> {modname}::IndexIter {{
+{bisect}
         {modname}::IndexIter {{
-            indexes: Box::new({indexname}[begin..lo].iter()),
+            indexes: {indexname}[begin..lo].iter(),
         }}
     }}"
     )
 }
 
-// ================================================================================================
-// Reverse join
-// ================================================================================================
-fn reverse_join(table: &table::Table, rj: &JoinTo, output: &mut dyn io::Write) -> io::Result<()> {
-    if !table.has_data() {
-        log::warning(&format!("{} will crash if used", &rj.reverse_name));
-    }
+fn count_col(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
 
-    let info = rj.col.info();
-    let field = field_name(rj.col.name());
-    let reverse = &rj.reverse_name;
-    let srcmod = mod_name(&rj.table.name);
-    let srcstruct = struct_name(&rj.table.name);
-    let joinmod = mod_name(&table.name);
-    let tabletype = strtype(&info.table_type);
-    let offset = stroffset(rj.offset as isize);
-    let indexname = index_name(&rj.table.name, rj.col.name());
+    let field = field_name(col.name());
+    let argtype = argtype(&info.interface_type);
+    let bisect = bisect_range(table, col);
+    // str comparison is not available in const context
+    let constfn = match info.interface_type {
+        BaseType::Str => "",
+        _ => const_fn(project),
+    };
 
-    write_help(output, "    ///", &info.config.iter_help)?;
     writeln!(
         output,
-        "    pub fn {reverse}(&self) -> {srcstruct}Iter {{
-        let cons = {joinmod}::index_of(self) as {tabletype}{offset};
+        "    /// Number of rows returned by {field}_range
+    pub {constfn}fn {field}_count(start:{argtype}, stop:{argtype}) -> usize {{
+{bisect}
+        lo - begin
+    }}"
+    )
+}
 
-        // bissect left
-        let mut lo = 0;
-        let mut hi = {indexname}.len();
-        while lo < hi {{
-            let mid = (lo + hi) / 2;
-            if cons > {srcmod}::TABLE[{indexname}[mid] as usize].{field}_ {{
-                lo = mid + 1;
-            }} else {{
-                hi = mid;
-            }}
-        }}
-        let start = lo;
+// ================================================================================================
+// Groups iterator
+// ================================================================================================
+fn groups_col(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
 
-        // bissect-right
-        hi = {indexname}.len();
-        while lo < hi {{
-            let mid = (lo + hi) / 2;
-            if cons < {srcmod}::TABLE[{indexname}[mid] as usize].{field}_  {{
-                hi = mid;
-            }} else {{
-                lo = mid + 1;
-            }}
-        }}
+    let field = field_name(col.name());
+    let outtype = strtype(&info.interface_type);
+    let strname = struct_name(&table.name);
+    let modname = mod_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    let groupsname = groups_name(&table.name, col.name());
 
-        {srcmod}::IndexIter {{
-            indexes: Box::new({indexname}[start..lo].iter()),
+    write_help(output, "    ///", &info.config.group_help)?;
+    writeln!(
+        output,
+        "    pub fn {field}_groups() -> {modname}::GroupIter<{outtype}> {{
+        {modname}::GroupIter {{
+            index: &{indexname},
+            bounds: {groupsname}.windows(2),
+            value: {strname}::{field},
         }}
     }}"
     )
 }
 
+fn write_group_iter_struct(
+    table: &table::Table,
+    strname: &String,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let indextype = strtype(&table.index_type());
+
+    writeln!(
+        output,
+        "pub struct GroupIter<T> {{
+    pub index : &'static [{indextype}],
+    pub bounds : core::slice::Windows<'static, {indextype}>,
+    pub value : fn(&'static {strname}) -> T,
+}}
+
+impl<T> Iterator for GroupIter<T> {{
+    type Item = (T, IndexIter);
+
+    fn next(&mut self) -> Option<(T, IndexIter)> {{
+        let bounds = self.bounds.next()?;
+        let rows = &self.index[bounds[0] as usize..bounds[1] as usize];
+        let value = (self.value)(&TABLE[rows[0] as usize]);
+        Some((value, IndexIter {{ indexes: rows.iter() }}))
+    }}
+}}
+"
+    )
+}
+
+fn write_group_bounds(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !col.info().config.group {
+        return Ok(());
+    }
+    let bounds = index::group_bounds(col, project.lang);
+    let uperfield = const_name(col.name());
+    let indextype = strtype(&table.index_type());
+
+    write_array(&format!("{uperfield}_GROUPS"), &indextype, &bounds, output)
+}
+
 // ================================================================================================
-// Labels
+// Closure of self join
 // ================================================================================================
-fn col_labels(
+fn closure_col(
     table: &table::Table,
     col: &dyn table::Column,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
     let info = col.info();
-    let enumname = strtype(&info.interface_type);
-    let non_exaustive = if table.exhaustive {
-        ""
-    } else {
-        "#[non_exhaustive]\n"
+    let Some(kind) = &info.config.closure else {
+        return Ok(());
     };
-    write_help(output, "///", &info.config.help)?;
-    write!(
-        output,
-        "{non_exaustive}#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\npub enum {enumname} {{\n"
-    )?;
-
-    for row in 0..info.len {
-        let label = col.emit_label(row);
-        if !label.is_empty() {
-            let upper_label = label.to_upper_camel_case();
-            let help = col.emit_label_help(row);
-            write_help(output, "     ///", &help)?;
-            writeln!(output, "    {upper_label} = {row},")?;
-        }
-    }
-    writeln!(output, "}}")?;
 
-    let strname = struct_name(&table.name);
+    let field = field_name(col.name());
     let modname = mod_name(&table.name);
-    if table.has_data() {
+    let closurename = closure_name(&table.name, col.name());
+
+    write_help(output, "    ///", &info.config.closure_help)?;
+    if kind == "ancestors" {
+        let parent = if info.config.optional {
+            format!("s.{field}()")
+        } else {
+            format!("Some(s.{field}())")
+        };
         writeln!(
             output,
-            "impl From<{enumname}> for  &'static {strname}{{
-    fn from(value:{enumname}) -> Self {{
-        &{modname}::TABLE[value as usize]
-    }}
-}}
-impl From<&{enumname}> for  &'static {strname}{{
-    fn from(value: &{enumname}) -> Self {{
+            "    pub fn {field}_ancestors(&self) -> {modname}::AncestorIter {{
+        {modname}::AncestorIter {{
+            row: Some(&{modname}::TABLE[{modname}::index_of(self)]),
+            depth: 0,
+            parent: |s| {parent}.filter(|p| !core::ptr::eq(*p, s)),
+        }}
+    }}"
+        )?;
+    } else {
+        writeln!(
+            output,
+            "    pub fn {field}_descendants(&self) -> {modname}::DescendantIter {{
+        let row = {modname}::index_of(self);
+        {modname}::DescendantIter {{
+            rows: {closurename}_PREORDER[{closurename}_ENTER[row] as usize + 1..{closurename}_EXIT[row] as usize].iter(),
+            depths: &{closurename}_DEPTH,
+            base: {closurename}_DEPTH[row] as usize,
+        }}
+    }}"
+        )?;
+    }
+    writeln!(
+        output,
+        "    /// Check if this row is reached by following {field} links from other row
+    pub fn {field}_is_ancestor_of(&self, other: &Self) -> bool {{
+        let (row, other) = ({modname}::index_of(self), {modname}::index_of(other));
+        {closurename}_ENTER[row] < {closurename}_ENTER[other] && {closurename}_ENTER[other] < {closurename}_EXIT[row]
+    }}"
+    )
+}
+
+fn write_closure_structs(
+    table: &table::Table,
+    strname: &String,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if table.has_closure("ancestors") {
+        writeln!(
+            output,
+            "pub struct AncestorIter {{
+    pub row : Option<&'static {strname}>,
+    pub depth : usize,
+    pub parent : fn(&'static {strname}) -> Option<&'static {strname}>,
+}}
+
+impl Iterator for AncestorIter {{
+    type Item = (&'static {strname}, usize);
+
+    fn next(&mut self) -> Option<(&'static {strname}, usize)> {{
+        self.row = (self.parent)(self.row?);
+        self.depth += 1;
+        self.row.map(|row| (row, self.depth))
+    }}
+}}
+"
+        )?;
+    }
+    if table.has_closure("descendants") {
+        let indextype = strtype(&table.index_type());
+        writeln!(
+            output,
+            "pub struct DescendantIter {{
+    pub rows : core::slice::Iter<'static, {indextype}>,
+    pub depths : &'static [{indextype}],
+    pub base : usize,
+}}
+
+impl Iterator for DescendantIter {{
+    type Item = (&'static {strname}, usize);
+
+    fn next(&mut self) -> Option<(&'static {strname}, usize)> {{
+        let row = *self.rows.next()? as usize;
+        Some((&TABLE[row], self.depths[row] as usize - self.base))
+    }}
+}}
+"
+        )?;
+    }
+    Ok(())
+}
+
+fn write_closure_index(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(closure) = col.closure() else {
+        return Ok(());
+    };
+    let uperfield = const_name(col.name());
+    let indextype = strtype(&table.index_type());
+
+    write_array(
+        &format!("{uperfield}_ENTER"),
+        &indextype,
+        &closure.enter,
+        output,
+    )?;
+    write_array(
+        &format!("{uperfield}_EXIT"),
+        &indextype,
+        &closure.exit,
+        output,
+    )?;
+    if col.info().config.closure.as_deref() == Some("descendants") {
+        write_array(
+            &format!("{uperfield}_PREORDER"),
+            &indextype,
+            &closure.preorder,
+            output,
+        )?;
+        write_array(
+            &format!("{uperfield}_DEPTH"),
+            &indextype,
+            &closure.depth,
+            output,
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn aggregates_col(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        let name = const_name(&format!("{}_{}", col.name(), aggregate.name));
+        let typ = strtype(&aggregate.typ);
+        let value = &aggregate.value;
+        writeln!(
+            output,
+            "    /// {} of {} column values
+    pub const {name}: {typ} = {value};",
+            aggregate.name,
+            col.name()
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Fuzzy lookup
+// ================================================================================================
+fn fuzzy_col(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+
+    let field = field_name(col.name());
+    let strname = struct_name(&table.name);
+    let modname = mod_name(&table.name);
+    let tablename = table_name(&table.name);
+    let fuzzyname = fuzzy_name(&table.name, col.name());
+    let stored = stored_at(table, col, &format!("{fuzzyname}_NODES[node] as usize"));
+
+    write_help(output, "    ///", &info.config.fuzzy_help)?;
+    writeln!(
+        output,
+        "    pub fn {field}_fuzzy(query:&str, max_distance:usize) -> Vec<(&'static {strname}, usize)> {{
+        let mut found = Vec::new();
+        if {fuzzyname}_NODES.is_empty() {{
+            return found;
+        }}
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {{
+            let row = &{tablename}[{fuzzyname}_NODES[node] as usize];
+            let distance = {modname}::levenshtein(query, {stored});
+            if distance <= max_distance {{
+                found.push((row, distance));
+            }}
+            for child in {fuzzyname}_CHILDREN[node] as usize..{fuzzyname}_CHILDREN[node + 1] as usize {{
+                let edge = {fuzzyname}_DISTANCES[child] as usize;
+                if edge + max_distance >= distance && edge <= distance + max_distance {{
+                    stack.push(child);
+                }}
+            }}
+        }}
+        found.sort_by_key(|(row, distance)| (*distance, {modname}::index_of(row)));
+        found
+    }}"
+    )
+}
+
+fn write_levenshtein(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "pub fn levenshtein(a: &str, b: &str) -> usize {{
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {{
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {{
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {{ diag }} else {{ 1 + diag.min(above).min(row[j]) }};
+            diag = above;
+        }}
+    }}
+    row[b.len()]
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Compression
+// ================================================================================================
+fn write_huffman_decode(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "fn huffman_decode<'a>(blob: &[u8], counts: &[u16], symbols: &[u16], offset: usize, buf: &'a mut [u8]) -> Option<&'a str> {{
+    let mut bit = offset * 8;
+    let mut len = 0;
+    loop {{
+        // canonical code : codes of each length follow the codes of shorter length
+        let (mut code, mut first, mut index) = (0, 0, 0);
+        let mut symbol = {end};
+        for count in &counts[1..] {{
+            code |= ((blob[bit / 8] >> (7 - bit % 8)) & 1) as usize;
+            bit += 1;
+            let count = *count as usize;
+            if code < first + count {{
+                symbol = symbols[index + code - first] as usize;
+                break;
+            }}
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }}
+        if symbol == {end} {{
+            break;
+        }}
+        *buf.get_mut(len)? = symbol as u8;
+        len += 1;
+    }}
+    core::str::from_utf8(&buf[..len]).ok()
+}}
+",
+        end = compress::HUFFMAN_END
+    )
+}
+
+fn write_fsst_decode(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "fn fsst_decode<'a>(blob: &[u8], symbols: &[u8], starts: &[u16], offset: usize, buf: &'a mut [u8]) -> Option<&'a str> {{
+    let mut pos = offset;
+    let mut len = 0;
+    loop {{
+        let code = blob[pos] as usize;
+        pos += 1;
+        let bytes = match code {{
+            {end} => break,
+            {escape} => {{
+                pos += 1;
+                &blob[pos - 1..pos]
+            }}
+            _ => &symbols[starts[code] as usize..starts[code + 1] as usize],
+        }};
+        buf.get_mut(len..len + bytes.len())?.copy_from_slice(bytes);
+        len += bytes.len();
+    }}
+    core::str::from_utf8(&buf[..len]).ok()
+}}
+",
+        end = compress::FSST_END,
+        escape = compress::FSST_ESCAPE
+    )
+}
+
+fn write_compressed_data(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let Some(compressed) = col.compressed() else {
+        return Ok(());
+    };
+    let uperfield = const_name(col.name());
+    let blob: Vec<usize> = compressed.blob.iter().map(|b| *b as usize).collect();
+    write_array(&format!("{uperfield}_BLOB"), "u8", &blob, output)?;
+    match &compressed.dictionary {
+        Dictionary::Huffman { counts, symbols } => {
+            write_array(&format!("{uperfield}_COUNTS"), "u16", counts, output)?;
+            write_array(&format!("{uperfield}_SYMBOLS"), "u16", symbols, output)
+        }
+        Dictionary::Fsst { symbols } => {
+            let bytes: Vec<usize> = symbols.concat().iter().map(|b| *b as usize).collect();
+            let mut starts = vec![0];
+            for s in symbols {
+                starts.push(starts[starts.len() - 1] + s.len());
+            }
+            write_array(&format!("{uperfield}_SYMBOLS"), "u8", &bytes, output)?;
+            write_array(
+                &format!("{uperfield}_SYMBOL_STARTS"),
+                "u16",
+                &starts,
+                output,
+            )
+        }
+    }
+}
+
+fn write_fuzzy_index(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(tree) = col.bktree() else {
+        return Ok(());
+    };
+    let uperfield = const_name(col.name());
+    let indextype = strtype(&table.index_type());
+    let maxdist = tree.distances.iter().max().copied().unwrap_or(0);
+    let disttype = strtype(&basetype::int_type_for_range(0..=maxdist as i64));
+
+    write_array(
+        &format!("{uperfield}_FUZZY_NODES"),
+        &indextype,
+        &tree.nodes,
+        output,
+    )?;
+    write_array(
+        &format!("{uperfield}_FUZZY_DISTANCES"),
+        &disttype,
+        &tree.distances,
+        output,
+    )?;
+    write_array(
+        &format!("{uperfield}_FUZZY_CHILDREN"),
+        &indextype,
+        &tree.children,
+        output,
+    )
+}
+
+// ================================================================================================
+// Bitmap index
+// ================================================================================================
+fn bitmap_col(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+
+    let field = field_name(col.name());
+    let argtype = argtype(&info.interface_type);
+    let strname = struct_name(&table.name);
+    let bitmapname = bitmap_name(&table.name, col.name());
+    let compare = match info.interface_type {
+        BaseType::Str => "(*v).cmp(value)",
+        _ => "v.cmp(&value)",
+    };
+
+    write_help(output, "    ///", &info.config.bitmap_help)?;
+    writeln!(
+        output,
+        "    pub fn where_{field}(value:{argtype}) -> {strname}Bitmap {{
+        match {bitmapname}_VALUES.binary_search_by(|v| {compare}) {{
+            Ok(i) => {bitmapname}S[i],
+            Err(_) => {strname}Bitmap::EMPTY,
+        }}
+    }}"
+    )
+}
+
+fn write_bitmap_struct(
+    table: &table::Table,
+    strname: &String,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let words = bitmap::word_count(table.len);
+
+    writeln!(
+        output,
+        "#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bitmap {{
+    words : [u64; {words}],
+}}
+
+impl Bitmap {{
+    /// Bitmap without any row
+    pub const EMPTY: Bitmap = Bitmap {{ words: [0; {words}] }};
+
+    /// Rows present in both bitmaps
+    pub fn and(&self, other: &Bitmap) -> Bitmap {{
+        let mut words = self.words;
+        for (word, o) in words.iter_mut().zip(other.words.iter()) {{
+            *word &= o;
+        }}
+        Bitmap {{ words }}
+    }}
+
+    /// Rows present in at least one of the bitmaps
+    pub fn or(&self, other: &Bitmap) -> Bitmap {{
+        let mut words = self.words;
+        for (word, o) in words.iter_mut().zip(other.words.iter()) {{
+            *word |= o;
+        }}
+        Bitmap {{ words }}
+    }}
+
+    /// Number of rows in bitmap
+    pub fn count(&self) -> usize {{
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }}
+
+    /// Check if bitmap has no row
+    pub fn is_empty(&self) -> bool {{
+        self.words.iter().all(|w| *w == 0)
+    }}
+
+    /// Iterate the rows in table order
+    pub fn iter(&self) -> BitmapIter {{
+        BitmapIter {{ words: self.words, word: 0 }}
+    }}
+}}
+
+impl IntoIterator for Bitmap {{
+    type Item = &'static {strname};
+    type IntoIter = BitmapIter;
+
+    fn into_iter(self) -> BitmapIter {{
+        self.iter()
+    }}
+}}
+
+pub struct BitmapIter {{
+    words : [u64; {words}],
+    word : usize,
+}}
+
+impl Iterator for BitmapIter {{
+    type Item = &'static {strname};
+
+    fn next(&mut self) -> Option<&'static {strname}> {{
+        while self.word < {words} {{
+            let w = self.words[self.word];
+            if w != 0 {{
+                self.words[self.word] = w & (w - 1);
+                return Some(&TABLE[self.word * 64 + w.trailing_zeros() as usize]);
+            }}
+            self.word += 1;
+        }}
+        None
+    }}
+}}
+"
+    )
+}
+
+fn write_bitmap_index(
+    project: &table::Project,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !col.info().config.bitmap {
+        return Ok(());
+    }
+    let bitmaps = bitmap::bitmaps(col, project.lang);
+    let uperfield = const_name(col.name());
+    let valtype = strtype(&col.info().interface_type);
+    let len = bitmaps.len();
+
+    write!(
+        output,
+        "pub static {uperfield}_BITMAP_VALUES : [ {valtype} ; {len} ] = ["
+    )?;
+    for (i, bitmap) in bitmaps.iter().enumerate() {
+        if i % 20 == 0 {
+            write!(output, "\n    ")?;
+        }
+        write!(output, "{}, ", bitmap.value)?;
+    }
+    write!(output, "\n];\n")?;
+
+    writeln!(
+        output,
+        "pub static {uperfield}_BITMAPS : [ Bitmap ; {len} ] = ["
+    )?;
+    for bitmap in &bitmaps {
+        write!(output, "    Bitmap {{ words: [")?;
+        for word in &bitmap.words {
+            write!(output, "{:#018x}, ", word)?;
+        }
+        writeln!(output, "] }},")?;
+    }
+    writeln!(output, "];")
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn reverse_join(table: &table::Table, rj: &JoinTo, output: &mut dyn io::Write) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+
+    let info = rj.col.info();
+    let reverse = &rj.reverse_name;
+    let srcmod = mod_name(&rj.table.name);
+    let srcstruct = struct_name(&rj.table.name);
+    let joinmod = mod_name(&table.name);
+    let tabletype = strtype(&info.table_type);
+    let offset = stroffset(rj.offset as isize);
+    let indexname = index_name(&rj.table.name, rj.col.name());
+    let stored = stored_at(rj.table, rj.col, &format!("{indexname}[mid] as usize"));
+
+    write_help(output, "    ///", &info.config.iter_help)?;
+    writeln!(
+        output,
+        "    pub fn {reverse}(&self) -> {srcstruct}Iter {{
+        let cons = {joinmod}::index_of(self) as {tabletype}{offset};
+
+        // bissect left
+        let mut lo = 0;
+        let mut hi = {indexname}.len();
+        while lo < hi {{
+            let mid = (lo + hi) / 2;
+            if cons > {stored} {{
+                lo = mid + 1;
+            }} else {{
+                hi = mid;
+            }}
+        }}
+        let start = lo;
+
+        // bissect-right
+        hi = {indexname}.len();
+        while lo < hi {{
+            let mid = (lo + hi) / 2;
+            if cons < {stored}  {{
+                hi = mid;
+            }} else {{
+                lo = mid + 1;
+            }}
+        }}
+
+        {srcmod}::IndexIter {{
+            indexes: {indexname}[start..lo].iter(),
+        }}
+    }}"
+    )
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn col_labels(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    let non_exaustive = if table.exhaustive {
+        ""
+    } else {
+        "#[non_exhaustive]\n"
+    };
+    write_help(output, "///", &info.config.help)?;
+    let serde = if project.derive_serde {
+        ", serde::Serialize, serde::Deserialize"
+    } else {
+        ""
+    };
+    write!(
+        output,
+        "{non_exaustive}#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash{serde})]\npub enum {enumname} {{\n"
+    )?;
+
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            let upper_label = label.to_upper_camel_case();
+            let help = col.emit_label_help(row);
+            write_help(output, "     ///", &help)?;
+            writeln!(output, "    {upper_label} = {row},")?;
+        }
+    }
+    writeln!(output, "}}")?;
+
+    let strname = struct_name(&table.name);
+    let modname = mod_name(&table.name);
+    if table.has_data() {
+        writeln!(
+            output,
+            "impl From<{enumname}> for  &'static {strname}{{
+    fn from(value:{enumname}) -> Self {{
+        &{modname}::TABLE[value as usize]
+    }}
+}}
+impl From<&{enumname}> for  &'static {strname}{{
+    fn from(value: &{enumname}) -> Self {{
         &{modname}::TABLE[*value as usize]
     }}
 }}
 impl PartialEq<{enumname}> for &{strname} {{
     fn eq(&self, other: &{enumname}) -> bool {{
-        std::ptr::eq(<&{strname}>::from(other), *self)
+        core::ptr::eq(<&{strname}>::from(other), *self)
+    }}
+}}
+"
+        )?;
+        if project.const_fn {
+            let method = field_name(&enumname);
+            writeln!(
+                output,
+                "impl {strname} {{
+    /// Row corresponding to label, usable in const context
+    pub const fn from_{method}(value: {enumname}) -> &'static {strname} {{
+        &{modname}::TABLE[value as usize]
     }}
 }}
 "
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Indexes
+// ================================================================================================
+fn write_index(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let indexes = col.indexes();
+    let uperfield = const_name(col.name());
+    let indextype = strtype(&table.index_type());
+
+    write_array(&format!("{uperfield}_INDEX"), &indextype, &indexes, output)
+}
+
+fn write_array(
+    name: &str,
+    typ: &str,
+    values: &[usize],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let len = values.len();
+    write!(output, "pub static {name} : [ {typ} ; {len} ] = [")?;
+
+    let width = language::digits(values.len());
+    for (i, v) in values.iter().enumerate() {
+        if i % 20 == 0 {
+            write!(output, "\n    ")?;
+        }
+        write!(output, "{:width$}, ", v)?;
+    }
+    write!(output, "\n];\n")
+}
+
+// ================================================================================================
+// Variants
+// ================================================================================================
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    let strname = struct_name(&table.name);
+    let varname = struct_name(col.name());
+    format!("{strname}{varname}")
+}
+fn write_variant(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(variants) = col.variants() else {
+        return Ok(());
+    };
+    let vartypname = variant_type_name(table, col);
+
+    writeln!(
+        output,
+        "#[derive(Clone, Copy, PartialEq, Eq, Hash)]\npub enum  {vartypname} {{"
+    )?;
+    for vrn in variants {
+        let joinstruct = struct_name(&vrn.name);
+        if vrn.is_none {
+            writeln!(output, "     {joinstruct},")?;
+        } else {
+            writeln!(output, "     {joinstruct}(&'static {joinstruct}),")?;
+        }
+    }
+
+    writeln!(output, "}}\n")?;
+
+    write_variant_debug(&vartypname, variants, output)?;
+    if project.derive_serde {
+        write_variant_serialize(project, &vartypname, variants, output)?;
+    }
+    Ok(())
+}
+
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+
+fn getter_variant(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let variants = col.variants().expect("variant must have variant");
+    let vartypname = variant_type_name(table, col);
+    let field = field_name(col.name());
+    let constfn = const_fn(project);
+    let stored = stored_self(table, col);
+    let value = unparen(&stored);
+
+    writeln!(
+        output,
+        "    pub {constfn}fn {field}(&self) -> {vartypname} {{ 
+        let v = {value} ;
+        match v {{"
+    )?;
+
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let start = vrn.index;
+        let end = start + vrn.count - 1;
+        let jointable = table_name(&vrn.name);
+        let joinstruct = struct_name(&vrn.name);
+        if vrn.is_none {
+            writeln!(
+                output,
+                "             {start}..={end} => {vartypname}::{joinstruct},"
+            )?;
+        } else {
+            let offset = stroffset(-(start as isize));
+            writeln!(output, "             {start}..={end} => {vartypname}::{joinstruct}(&{jointable}[v as usize {offset}]),")?;
+        }
+    }
+
+    writeln!(
+        output,
+        "             _ => panic!(\"insrcdata variant index overflow\"),\n        }}\n    }}"
+    )
+}
+
+// ================================================================================================
+// Debug, Display and fields
+// ================================================================================================
+fn write_field_value(project: &table::Project, output: &mut dyn io::Write) -> io::Result<()> {
+    // an owned decoded string prevents the enum from being Copy
+    let compressed = project
+        .tables
+        .iter()
+        .any(|t| t.columns.iter().any(|c| c.compressed().is_some()));
+    let (derive, decoded) = match (compressed, project.no_std) {
+        (false, _) => ("Clone, Copy, Debug", ""),
+        (true, false) => (
+            "Clone, Debug",
+            "    /// compressed string column, decoded\n    String(String),\n",
+        ),
+        (true, true) => (
+            "Clone, Copy, Debug",
+            "    /// compressed string column, use its decode getter to read the value\n    Compressed,\n",
+        ),
+    };
+    writeln!(
+        output,
+        "/// Dynamic value of a column returned by fields()
+#[derive({derive})]
+pub enum FieldValue {{
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Float(f64),
+    Str(&'static str),
+{decoded}    Label(&'static dyn core::fmt::Debug),
+    /// joined table name and row index
+    Join(&'static str, usize),
+    /// object column, its type is only known by the application
+    Object,
+    /// optional join or variant without value
+    Null,
+}}
+"
+    )
+}
+
+fn join_value(strname: &str, value: &str) -> String {
+    format!(
+        "FieldValue::Join(\"{}\", {}::index_of({value}))",
+        struct_name(strname),
+        mod_name(strname)
+    )
+}
+
+// expression of column value as FieldValue
+fn field_value(project: &table::Project, table: &table::Table, col: &dyn table::Column) -> String {
+    let info = col.info();
+    let field = field_name(col.name());
+    let cast = |typ: &str| {
+        if strtype(&info.interface_type) == typ {
+            format!("self.{field}()")
+        } else {
+            format!("self.{field}() as {typ}")
+        }
+    };
+    match info.type_impl() {
+        table::TypeImpl::Label => format!("FieldValue::Label(self.{field}())"),
+        table::TypeImpl::Scalar => match info.interface_type {
+            BaseType::Bool => format!("FieldValue::Bool(self.{field}())"),
+            BaseType::I8 | BaseType::I16 | BaseType::I32 | BaseType::I64 => {
+                format!("FieldValue::Int({})", cast("i64"))
+            }
+            BaseType::U8 | BaseType::U16 | BaseType::U32 | BaseType::U64 => {
+                format!("FieldValue::Uint({})", cast("u64"))
+            }
+            BaseType::F32 | BaseType::F64 => format!("FieldValue::Float({})", cast("f64")),
+            BaseType::Str if col.compressed().is_some() && project.no_std => {
+                "FieldValue::Compressed".to_string()
+            }
+            BaseType::Str if col.compressed().is_some() => {
+                format!("FieldValue::String(self.{field}())")
+            }
+            BaseType::Str => format!("FieldValue::Str(self.{field}())"),
+            _ => "FieldValue::Object".to_string(),
+        },
+        table::TypeImpl::Join => join_value(&info.join_table(), &format!("self.{field}()")),
+        table::TypeImpl::JoinOptional => format!(
+            "match self.{field}() {{ Some(v) => {}, None => FieldValue::Null }}",
+            join_value(&info.join_table(), "v")
+        ),
+        table::TypeImpl::Variant => {
+            let vartypname = variant_type_name(table, col);
+            let arms: Vec<String> = col
+                .variants()
+                .expect("variant must have variant")
+                .iter()
+                .map(|vrn| {
+                    let joinstruct = struct_name(&vrn.name);
+                    if vrn.is_none {
+                        format!("{vartypname}::{joinstruct} => FieldValue::Null")
+                    } else {
+                        format!(
+                            "{vartypname}::{joinstruct}(v) => {}",
+                            join_value(&vrn.name, "v")
+                        )
+                    }
+                })
+                .collect();
+            format!("match self.{field}() {{ {} }}", arms.join(", "))
+        }
+    }
+}
+
+fn fields_col(
+    project: &table::Project,
+    table: &table::Table,
+    datacols: &[&dyn table::Column],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let len = datacols.len();
+    let names: Vec<String> = datacols
+        .iter()
+        .map(|col| format!("\"{}\"", field_name(col.name())))
+        .collect();
+    writeln!(
+        output,
+        "    /// Name of the columns in the order of fields()
+    pub const COLUMNS: [&'static str; {len}] = [{}];
+    /// Name and value of each column
+    pub fn fields(&'static self) -> [(&'static str, FieldValue); {len}] {{
+        [",
+        names.join(", ")
+    )?;
+    for col in datacols {
+        writeln!(
+            output,
+            "            (\"{}\", {}),",
+            field_name(col.name()),
+            field_value(project, table, *col)
+        )?;
+    }
+    writeln!(output, "        ]\n    }}")
+}
+
+fn write_debug(
+    table: &table::Table,
+    strname: &str,
+    datacols: &[&dyn table::Column],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    writeln!(
+        output,
+        "impl core::fmt::Debug for {strname} {{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+        f.debug_struct(\"{strname}\")"
+    )?;
+    let mut finish = "finish";
+    for col in datacols {
+        let info = col.info();
+        let field = field_name(col.name());
+        let value = match info.type_impl() {
+            table::TypeImpl::Scalar if matches!(info.interface_type, BaseType::Object { .. }) => {
+                // object types are not required to implement Debug
+                finish = "finish_non_exhaustive";
+                continue;
+            }
+            table::TypeImpl::Join => {
+                format!("{}::DebugRef(self.{field}())", mod_name(&info.join_table()))
+            }
+            table::TypeImpl::JoinOptional => format!(
+                "self.{field}().map({}::DebugRef)",
+                mod_name(&info.join_table())
+            ),
+            _ => getter_value(*col),
+        };
+        writeln!(output, "            .field(\"{field}\", &{value})")?;
+    }
+    writeln!(
+        output,
+        "            .{finish}()
+    }}
+}}"
+    )?;
+
+    if let Some(col) = datacols
+        .iter()
+        .find(|col| Some(col.name()) == table.display.as_ref())
+    {
+        let value = getter_value(*col);
+        writeln!(
+            output,
+            "impl core::fmt::Display for {strname} {{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+        core::fmt::Display::fmt(&{value}, f)
+    }}
+}}"
+        )?;
+    }
+    writeln!(output)
+}
+
+// short representation of a joined row : label if any, else index in table
+fn write_debug_ref(
+    project: &table::Project,
+    table: &table::Table,
+    strname: &str,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let mut arms = vec![];
+    if let Some(col) = table.label_columns().first() {
+        let typ = &col.info().interface_type;
+        for row in 0..table.len {
+            let label = col.emit_label(row);
+            if !label.is_empty() {
+                let value = project.lang.emit_enum(typ, &label);
+                arms.push(format!("            {row} => f.write_str(\"{value}\"),"));
+            }
+        }
+    }
+    writeln!(
+        output,
+        "pub struct DebugRef(pub &'static {strname});
+impl core::fmt::Debug for DebugRef {{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+    )?;
+    if arms.is_empty() {
+        writeln!(
+            output,
+            "        write!(f, \"{strname}[{{}}]\", index_of(self.0))"
+        )?;
+    } else {
+        writeln!(output, "        match index_of(self.0) {{")?;
+        for arm in arms {
+            writeln!(output, "{arm}")?;
+        }
+        writeln!(
+            output,
+            "            i => write!(f, \"{strname}[{{i}}]\"),
+        }}"
         )?;
     }
-    Ok(())
+    writeln!(output, "    }}\n}}")
 }
 
-// ================================================================================================
-// Indexes
-// ================================================================================================
-fn write_index(
-    table: &table::Table,
-    col: &dyn table::Column,
+fn write_variant_debug(
+    vartypname: &str,
+    variants: &[table::Variant],
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
-    let indexes = col.indexes();
-    let uperfield = const_name(col.name());
-    let indextype = strtype(&table.index_type());
-    let len = indexes.len();
-
-    write!(
+    writeln!(
         output,
-        "pub static {uperfield}_INDEX : [ {indextype} ; {len} ] = ["
+        "impl core::fmt::Debug for {vartypname} {{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+        match self {{"
     )?;
-
-    let width = language::digits(indexes.len());
-    for (i, v) in indexes.iter().enumerate() {
-        if i % 20 == 0 {
-            write!(output, "\n    ")?;
+    for vrn in variants {
+        let joinstruct = struct_name(&vrn.name);
+        if vrn.is_none {
+            writeln!(
+                output,
+                "            {vartypname}::{joinstruct} => f.write_str(\"{joinstruct}\"),"
+            )?;
+        } else {
+            let modname = mod_name(&vrn.name);
+            writeln!(
+                output,
+                "            {vartypname}::{joinstruct}(v) => f.debug_tuple(\"{joinstruct}\").field(&{modname}::DebugRef(v)).finish(),"
+            )?;
         }
-        write!(output, "{:width$}, ", v)?;
     }
-    write!(output, "\n];\n")
+    writeln!(
+        output,
+        "        }}
+    }}
+}}
+"
+    )
 }
 
 // ================================================================================================
-// Variants
+// Serde
 // ================================================================================================
-fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
-    let strname = struct_name(&table.name);
-    let varname = struct_name(col.name());
-    format!("{strname}{varname}")
+// serialized value of a joined row in key mode
+#[derive(PartialEq)]
+enum SerdeKey {
+    Getter(String), // the key column matched by the join
+    Label,          // the label of the row when the joined table has no such column
+    Index,          // the row index when the joined table has no label either
 }
-fn write_variant(
+
+fn serde_key(project: &table::Project, strname: &str, key: &str) -> SerdeKey {
+    let Some(table) = project.tables.iter().find(|t| t.name == strname) else {
+        return SerdeKey::Index;
+    };
+    let keycol = table.data_columns().into_iter().find(|col| {
+        col.info().config.src == key
+            && col.compressed().is_none()
+            && matches!(
+                col.info().type_impl(),
+                table::TypeImpl::Scalar | table::TypeImpl::Label
+            )
+    });
+    match keycol {
+        Some(col) => SerdeKey::Getter(field_name(col.name())),
+        None if !table.label_columns().is_empty() => SerdeKey::Label,
+        None => SerdeKey::Index,
+    }
+}
+
+// a join of the project is serialized with the label of table rows
+fn need_serde_ref(project: &table::Project, table: &table::Table) -> bool {
+    if !project.derive_serde || project.serde_nested {
+        return false;
+    }
+    let is_label = |key: &str| serde_key(project, &table.name, key) == SerdeKey::Label;
+    project
+        .tables
+        .iter()
+        .flat_map(|t| t.data_columns())
+        .any(|col| {
+            let info = col.info();
+            match info.type_impl() {
+                table::TypeImpl::Join | table::TypeImpl::JoinOptional => {
+                    info.join_table() == table.name && is_label(&info.config.key)
+                }
+                table::TypeImpl::Variant => col.variants().is_some_and(|variants| {
+                    variants
+                        .iter()
+                        .any(|v| !v.is_none && v.name == table.name && is_label(&v.key))
+                }),
+                _ => false,
+            }
+        })
+}
+
+// serialize a joined row as the label enum value, as DebugRef does
+fn write_serde_ref(
+    project: &table::Project,
     table: &table::Table,
-    col: &dyn table::Column,
+    strname: &str,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
-    let Some(variants) = col.variants() else {
-        return Ok(());
-    };
-    let vartypname = variant_type_name(table, col);
-
     writeln!(
         output,
-        "#[derive(Clone, Copy, PartialEq, Eq, Hash)]\npub enum  {vartypname} {{"
+        "pub struct SerdeRef(pub &'static {strname});
+impl serde::Serialize for SerdeRef {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        match index_of(self.0) {{"
     )?;
-    for vrn in variants {
-        let joinstruct = struct_name(&vrn.name);
-        if vrn.is_none {
-            writeln!(output, "     {joinstruct},")?;
-        } else {
-            writeln!(output, "     {joinstruct}(&'static {joinstruct}),")?;
+    if let Some(col) = table.label_columns().first() {
+        let typ = &col.info().interface_type;
+        for row in 0..table.len {
+            let label = col.emit_label(row);
+            if !label.is_empty() {
+                let value = project.lang.emit_enum(typ, &label);
+                writeln!(
+                    output,
+                    "            {row} => serde::Serialize::serialize(&{value}, serializer),"
+                )?;
+            }
         }
     }
+    writeln!(
+        output,
+        "            i => serializer.serialize_u64(i as u64),
+        }}
+    }}
+}}"
+    )
+}
 
-    writeln!(output, "}}\n")
+// serialized value of a joined row
+fn serde_join(project: &table::Project, strname: &str, key: &str, value: &str) -> String {
+    if project.serde_nested {
+        return value.to_string();
+    }
+    let modname = mod_name(strname);
+    match serde_key(project, strname, key) {
+        SerdeKey::Getter(getter) => format!("{value}.{getter}()"),
+        SerdeKey::Label => format!("{modname}::SerdeRef({value})"),
+        SerdeKey::Index => format!("{modname}::index_of({value})"),
+    }
 }
 
-fn stroffset(v: isize) -> String {
-    match { v } {
-        0 => "".to_string(),
-        v if v < 0 => format!(" - {}", -v),
-        _ => format!(" + {}", v),
+fn serde_field(project: &table::Project, col: &dyn table::Column) -> String {
+    let info = col.info();
+    let field = field_name(col.name());
+    match info.type_impl() {
+        table::TypeImpl::Join => {
+            let value = format!("self.{field}()");
+            serde_join(project, &info.join_table(), &info.config.key, &value)
+        }
+        table::TypeImpl::JoinOptional if !project.serde_nested => {
+            let strname = info.join_table();
+            let modname = mod_name(&strname);
+            match serde_key(project, &strname, &info.config.key) {
+                SerdeKey::Getter(getter) => format!("self.{field}().map(|v| v.{getter}())"),
+                SerdeKey::Label => format!("self.{field}().map({modname}::SerdeRef)"),
+                SerdeKey::Index => format!("self.{field}().map({modname}::index_of)"),
+            }
+        }
+        _ => getter_value(col),
     }
 }
 
-fn getter_variant(
-    table: &table::Table,
-    col: &dyn table::Column,
+fn write_serialize(
+    project: &table::Project,
+    strname: &str,
+    datacols: &[&dyn table::Column],
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
-    let variants = col.variants().expect("variant must have variant");
-    let vartypname = variant_type_name(table, col);
-    let field = field_name(col.name());
-
+    let len = datacols.len();
     writeln!(
         output,
-        "    pub fn {field}(&self) -> {vartypname} {{ 
-        let v = self.{field}_ ;
-        match v {{"
+        "impl serde::Serialize for {strname} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct(\"{strname}\", {len})?;"
     )?;
+    for col in datacols {
+        let name = field_name(col.name());
+        let value = serde_field(project, *col);
+        writeln!(
+            output,
+            "        state.serialize_field(\"{name}\", &{value})?;"
+        )?;
+    }
+    writeln!(
+        output,
+        "        state.end()
+    }}
+}}
+"
+    )
+}
 
-    for vrn in variants {
-        if vrn.count == 0 {
-            continue;
-        }
-        let start = vrn.index;
-        let end = start + vrn.count - 1;
-        let jointable = table_name(&vrn.name);
+fn write_variant_serialize(
+    project: &table::Project,
+    vartypname: &str,
+    variants: &[table::Variant],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    writeln!(
+        output,
+        "impl serde::Serialize for {vartypname} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        match *self {{"
+    )?;
+    for (i, vrn) in variants.iter().enumerate() {
         let joinstruct = struct_name(&vrn.name);
         if vrn.is_none {
             writeln!(
                 output,
-                "             {start}..={end} => {vartypname}::{joinstruct},"
+                "            {vartypname}::{joinstruct} => serializer.serialize_unit_variant(\"{vartypname}\", {i}, \"{joinstruct}\"),"
             )?;
         } else {
-            let offset = stroffset(-(start as isize));
-            writeln!(output, "             {start}..={end} => {vartypname}::{joinstruct}(&{jointable}[v as usize {offset}]),")?;
+            let value = serde_join(project, &vrn.name, &vrn.key, "v");
+            writeln!(
+                output,
+                "            {vartypname}::{joinstruct}(v) => serializer.serialize_newtype_variant(\"{vartypname}\", {i}, \"{joinstruct}\", &{value}),"
+            )?;
         }
     }
-
     writeln!(
         output,
-        "             _ => panic!(\"insrcdata variant index overflow\"),\n        }}\n    }}"
+        "        }}
+    }}
+}}
+"
     )
 }
 
@@ -438,17 +1834,22 @@ fn getter_variant(
 // ================================================================================================
 // define ctor fuction  : const fn r(hello:u8, ) -> Table1 { return Table1{hello_:hello, }; }
 fn write_ctor_function(
+    table: &table::Table,
     strname: &String,
     outcols: &Vec<&dyn table::Column>,
     output: &mut dyn io::Write,
 ) -> io::Result<()> {
+    let words = table.packing.as_ref().map_or(0, |p| p.words);
+
     // interface
     write!(output, "const fn r(")?;
     for col in outcols {
-        let info = col.info();
-        let typ = modtype(&info.table_type);
+        let typ = modtype(&stored_type(*col));
         write!(output, "{}:{}, ", field_name(col.name()), typ)?;
     }
+    if words > 0 {
+        write!(output, "packed:[{}; {words}], ", word_type(table))?;
+    }
     write!(output, ") -> {} ", strname)?;
 
     // body
@@ -457,9 +1858,144 @@ fn write_ctor_function(
         let field = field_name(col.name());
         write!(output, "{field}_:{field}, ")?;
     }
+    if words > 0 {
+        write!(output, "packed_:packed, ")?;
+    }
     write!(output, "}}\n}}\n\n")
 }
 
+// packed words of a row as array literal
+fn packed_row(table: &table::Table, datacols: &[&dyn table::Column], row: usize) -> String {
+    let Some(packing) = &table.packing else {
+        return String::new();
+    };
+    let words: Vec<String> = packing
+        .row(datacols, row)
+        .iter()
+        .map(|w| format!("{w:#x}"))
+        .collect();
+    format!("[{}]", words.join(", "))
+}
+
+// binary file holding the rows of table, next to the generated source
+fn blob_path(project: &table::Project, table: &table::Table) -> PathBuf {
+    if project.split {
+        return project
+            .dst_path
+            .join(format!("{}.bin", mod_name(&table.name)));
+    }
+    let stem = project
+        .dst_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+    let filename = format!("{stem}_{}.bin", mod_name(&table.name));
+    project.dst_path.with_file_name(filename)
+}
+
+// row handles are built by a const loop instead of a literal per row
+fn write_blob_data(
+    project: &table::Project,
+    table: &table::Table,
+    strname: &str,
+    blob: &Blob,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let len = table.len;
+    let size = blob.bytes.len();
+    let row_size = blob.row_size;
+    let filename = blob_path(project, table);
+    let filename = filename.file_name().unwrap_or_default().to_string_lossy();
+    let indextype = strtype(&table.index_type());
+    writeln!(
+        output,
+        "pub static BLOB : &[u8; {size}] = include_bytes!(\"{filename}\");
+pub const ROW_SIZE : usize = {row_size};
+
+pub const fn bytes<const N: usize>(pos: usize) -> [u8; N] {{
+    let mut bytes = [0u8; N];
+    let mut i = 0;
+    while i < N {{
+        bytes[i] = BLOB[pos + i];
+        i += 1;
+    }}
+    bytes
+}}
+
+// str are stored after the rows, located by u32 offset and length
+pub const fn str_at(pos: usize) -> &'static str {{
+    let offset = u32::from_le_bytes(bytes::<4>(pos)) as usize;
+    let len = u32::from_le_bytes(bytes::<4>(pos + 4)) as usize;
+    match core::str::from_utf8(BLOB.split_at(offset).1.split_at(len).0) {{
+        Ok(s) => s,
+        Err(_) => \"\",
+    }}
+}}
+
+pub static TABLE : [ {strname} ; {len} ] = {{
+    const ROW : {strname} = {strname}{{index_:0}};
+    let mut table = [ROW; {len}];
+    let mut i = 0;
+    while i < {len} {{
+        table[i].index_ = i as {indextype};
+        i += 1;
+    }}
+    table
+}};"
+    )
+}
+
+// struct of arrays : one static array per column
+fn write_columns_data(
+    project: &table::Project,
+    table: &table::Table,
+    strname: &str,
+    datacols: &[&dyn table::Column],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let len = table.len;
+    if let Some(blob) = &table.blob {
+        write_blob_data(project, table, strname, blob, output)?;
+    } else {
+        write!(output, "pub static TABLE : [ {strname} ; {len} ] = [")?;
+        for row in 0..len {
+            if row % 10 == 0 {
+                write!(output, "\n    ")?;
+            }
+            write!(output, "{strname}{{index_:{row}}}, ")?;
+        }
+        writeln!(output, "\n];")?;
+    }
+
+    for col in unpacked_columns(table, datacols) {
+        let name = const_name(col.name());
+        // references in static items are implicitly 'static
+        let typ = strtype(&stored_type(col)).replace("&'static ", "&");
+        writeln!(output, "pub static {name}_VALUES : [ {typ} ; {len} ] = [")?;
+        for row in 0..len {
+            writeln!(output, "    {},", table_cell(project, col, row))?;
+        }
+        writeln!(output, "];")?;
+    }
+    if let Some(packing) = table
+        .packing
+        .as_ref()
+        .filter(|p| p.words > 0 && table.blob.is_none())
+    {
+        let typ = word_type(table);
+        let words = packing.words;
+        writeln!(
+            output,
+            "pub static PACKED_VALUES : [ [{typ}; {words}] ; {len} ] = ["
+        )?;
+        for row in 0..len {
+            writeln!(output, "    {},", packed_row(table, datacols, row))?;
+        }
+        writeln!(output, "];")?;
+    }
+    Ok(())
+}
+
 fn table_data(
     project: &table::Project,
     table: &table::Table,
@@ -473,22 +2009,38 @@ fn table_data(
 
     write_help(output, "///", &table.help)?;
     writeln!(output, "pub struct {strname} {{")?;
-    for col in &datacols {
-        let info = col.info();
-        let fieldtype = strtype(&info.table_type);
-        writeln!(output, "    {}_ : {},", field_name(col.name()), fieldtype)?;
+    let visibility = split_visibility(project);
+    if table.soa {
+        // values are stored in column arrays, the row only hold its index
+        let indextype = strtype(&table.index_type());
+        writeln!(output, "    {visibility}index_ : {indextype},")?;
+    } else {
+        for col in unpacked_columns(table, &datacols) {
+            let fieldtype = strtype(&stored_type(col));
+            let field = field_name(col.name());
+            writeln!(output, "    {visibility}{field}_ : {fieldtype},")?;
+        }
+        if let Some(packing) = table.packing.as_ref().filter(|p| p.words > 0) {
+            // small columns packed in words
+            let typ = word_type(table);
+            writeln!(
+                output,
+                "    {visibility}packed_ : [{typ}; {}],",
+                packing.words
+            )?;
+        }
     }
     writeln!(
         output,
         "}}
 impl PartialEq<Self> for {strname} {{
     fn eq(&self, other: &Self) -> bool {{
-        std::ptr::eq(self, other)
+        core::ptr::eq(self, other)
     }}
 }}
 impl Eq for {strname} {{}}
-impl std::hash::Hash for {strname} {{
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {{
+impl core::hash::Hash for {strname} {{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {{
         {modname}::index_of(self).hash(state);
     }}
 }}
@@ -500,9 +2052,21 @@ impl std::hash::Hash for {strname} {{
 
     // data column
     for col in &datacols {
-        getter_col(table, *col, output)?;
+        getter_col(project, table, *col, output)?;
         if col.info().has_iter_range() {
             iter_col(table, *col, output)?;
+            count_col(project, table, *col, output)?;
+        }
+        if col.info().config.group {
+            groups_col(table, *col, output)?;
+        }
+        closure_col(table, *col, output)?;
+        aggregates_col(*col, output)?;
+        if col.info().config.fuzzy {
+            fuzzy_col(table, *col, output)?;
+        }
+        if col.info().config.bitmap {
+            bitmap_col(table, *col, output)?;
         }
     }
 
@@ -511,24 +2075,39 @@ impl std::hash::Hash for {strname} {{
         reverse_join(table, &rj, output)?;
     }
 
+    fields_col(project, table, &datacols, output)?;
+
+    let tablelen = table.len;
+    writeln!(
+        output,
+        "    /// Number of rows in the table
+    pub const fn len() -> usize {{ {tablelen} }}",
+    )?;
+
     if table.get_array {
         let srcstruct = struct_name(&table.name);
-        let tablelen = table.len;
         let srcmod = mod_name(&table.name);
+        let constfn = const_fn(project);
         writeln!(
             output,
             "    /// Reference to the table containing all the values
-    pub fn array() -> &'static [{srcstruct}; {tablelen}] {{ &{srcmod}::TABLE }}
+    pub {constfn}fn array() -> &'static [{srcstruct}; {tablelen}] {{ &{srcmod}::TABLE }}
     /// Index of the current record in the table
     pub fn as_index(&self) -> usize {{ {modname}::index_of(self) }}",
         )?;
     }
     write!(output, "}}\n\n")?;
 
+    write_debug(table, &strname, &datacols, output)?;
+    if project.derive_serde {
+        write_serialize(project, &strname, &datacols, output)?;
+    }
+
     // begin module private
+    let visibility = split_visibility(project);
     writeln!(
         output,
-        "mod {modname} {{\
+        "{visibility}mod {modname} {{\
 use super::*;"
     )?;
     for import in table.imports() {
@@ -536,34 +2115,63 @@ use super::*;"
     }
     writeln!(output,"
 pub fn index_of(fic:&{strname}) -> usize {{
-    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / std::mem::size_of::<{strname}>()
+    ((fic  as *const _ as usize) - (&TABLE[0]  as *const _ as usize)) / core::mem::size_of::<{strname}>()
 }}", )?;
 
     if project.table_need_iter(table) {
         write_iter_index_struct(table, &strname, output)?;
     }
-    write_ctor_function(&strname, &datacols, output)?;
-
+    if project.is_join_target(table) {
+        write_debug_ref(project, table, &strname, output)?;
+    }
+    if need_serde_ref(project, table) {
+        write_serde_ref(project, table, &strname, output)?;
+    }
+    if table.has_group() {
+        write_group_iter_struct(table, &strname, output)?;
+    }
+    write_closure_structs(table, &strname, output)?;
+    if table.has_fuzzy() {
+        write_levenshtein(output)?;
+    }
+    if table.has_bitmap() {
+        write_bitmap_struct(table, &strname, output)?;
+    }
     // table data
-    writeln!(
-        output,
-        "pub static TABLE : [ {} ; {} ] = [",
-        strname, table.len
-    )?;
-    for row in 0..table.len {
-        write!(output, "   {{r(")?;
-        for col in &datacols {
-            write!(output, "{}, ", col.emit_table_cell(row, project.lang))?;
+    if table.soa {
+        write_columns_data(project, table, &strname, &datacols, output)?;
+    } else {
+        let outcols = unpacked_columns(table, &datacols);
+        let packed = table.packing.as_ref().is_some_and(|p| p.words > 0);
+        write_ctor_function(table, &strname, &outcols, output)?;
+        writeln!(
+            output,
+            "pub static TABLE : [ {} ; {} ] = [",
+            strname, table.len
+        )?;
+        for row in 0..table.len {
+            write!(output, "   {{r(")?;
+            for col in &outcols {
+                write!(output, "{}, ", table_cell(project, *col, row))?;
+            }
+            if packed {
+                write!(output, "{}, ", packed_row(table, &datacols, row))?;
+            }
+            writeln!(output, ")}},")?;
         }
-        writeln!(output, ")}},")?;
+        writeln!(output, "];")?;
     }
-    writeln!(output, "];")?;
 
     // indexes
     for col in &datacols {
         if col.iterable() {
             write_index(table, *col, output)?;
         }
+        write_group_bounds(project, table, *col, output)?;
+        write_closure_index(table, *col, output)?;
+        write_fuzzy_index(table, *col, output)?;
+        write_bitmap_index(project, *col, output)?;
+        write_compressed_data(*col, output)?;
     }
     writeln!(output, "\n}} // mod {}\n", modname)?;
 
@@ -571,10 +2179,32 @@ pub fn index_of(fic:&{strname}) -> usize {{
     if project.table_need_iter(table) {
         writeln!(output, "pub use {modname}::IndexIter as {strname}Iter;",)?;
     }
+    if table.has_group() {
+        writeln!(output, "pub use {modname}::GroupIter as {strname}Groups;",)?;
+    }
+    if table.has_closure("ancestors") {
+        writeln!(
+            output,
+            "pub use {modname}::AncestorIter as {strname}Ancestors;",
+        )?;
+    }
+    if table.has_closure("descendants") {
+        writeln!(
+            output,
+            "pub use {modname}::DescendantIter as {strname}Descendants;",
+        )?;
+    }
+    if table.has_bitmap() {
+        writeln!(
+            output,
+            "pub use {modname}::Bitmap as {strname}Bitmap;
+pub use {modname}::BitmapIter as {strname}BitmapIter;",
+        )?;
+    }
 
     //
     for col in datacols {
-        write_variant(table, col, output)?;
+        write_variant(project, table, col, output)?;
     }
 
     Ok(())
@@ -588,7 +2218,7 @@ fn emit_table(
     // Labels
     let labelcols: Vec<&dyn table::Column> = table.label_columns();
     for col in labelcols {
-        col_labels(table, col, output)?;
+        col_labels(project, table, col, output)?;
     }
     if table.has_data() {
         table_data(project, table, output)?;
@@ -596,34 +2226,121 @@ fn emit_table(
     Ok(())
 }
 
+// ================================================================================================
+// Split output
+// ================================================================================================
+// split module file holding a table
+fn split_mod_name(table: &table::Table) -> String {
+    format!("{}_table", mod_name(&table.name))
+}
+
+fn split_mod_path(project: &table::Project) -> PathBuf {
+    project.dst_path.join("mod.rs")
+}
+
+fn create_file(path: &PathBuf) -> aperror::Result<fs::File> {
+    aperror::io_error_result(fs::File::create(path), path)
+}
+
+fn write_project_header(project: &table::Project, output: &mut dyn io::Write) -> io::Result<()> {
+    if std::env::var("CARGO_MANIFEST_DIR").is_ok() {
+        // build.rs script limitation
+        // include! cannot include a file that contains inner doc comments  https://github.com/rust-lang/rust/issues/66920
+        write_help(output, "\n//", &project.help)?;
+    } else {
+        write_help(output, "\n//!", &project.help)?;
+    }
+    let notice = language::file_notice();
+    writeln!(output, "// {notice}\n")?;
+
+    if project.tables.iter().any(|table| table.has_data()) {
+        write_field_value(project, output)?;
+    }
+    // decoders shared by the compressed columns of all tables
+    if project.tables.iter().any(|t| t.has_compression("huffman")) {
+        write_huffman_decode(output)?;
+    }
+    if project.tables.iter().any(|t| t.has_compression("fsst")) {
+        write_fsst_decode(output)?;
+    }
+    Ok(())
+}
+
+fn write_blob(project: &table::Project, table: &table::Table) -> aperror::Result<()> {
+    if let Some(blob) = &table.blob {
+        let path = blob_path(project, table);
+        aperror::io_error_result(fs::write(&path, &blob.bytes), &path)?;
+    }
+    Ok(())
+}
+
+// one file per table re-exported by mod.rs
+fn emit_split(project: &table::Project) -> aperror::Result<()> {
+    let dir = &project.dst_path;
+    aperror::io_error_result(fs::create_dir_all(dir), dir)?;
+
+    let modpath = split_mod_path(project);
+    let mut modfile = create_file(&modpath)?;
+    let output = (&mut modfile) as &mut dyn io::Write;
+    write_project_header(project, output)?;
+    for table in &project.tables {
+        let filemod = split_mod_name(table);
+        writeln!(output, "mod {filemod};\npub use {filemod}::*;")?;
+    }
+
+    let notice = language::file_notice();
+    for table in &project.tables {
+        let path = dir.join(format!("{}.rs", split_mod_name(table)));
+        let mut outfile = create_file(&path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+        writeln!(
+            output,
+            "// {notice}\n\n#[allow(unused_imports)]\nuse super::*;\n"
+        )?;
+        emit_table(project, table, output)?;
+        write_blob(project, table)?;
+    }
+    Ok(())
+}
+
 // ================================================================================================
 // Entry point
 // ================================================================================================
 impl language::Language for Rust {
     fn emit(&self, project: &table::Project) -> aperror::Result<()> {
-        let mut outfile =
-            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
-        let output = (&mut outfile) as &mut dyn io::Write;
-        if std::env::var("CARGO_MANIFEST_DIR").is_ok() {
-            // build.rs script limitation
-            // include! cannot include a file that contains inner doc comments  https://github.com/rust-lang/rust/issues/66920
-            write_help(output, "\n//", &project.help)?;
-        } else {
-            write_help(output, "\n//!", &project.help)?;
+        if project.split {
+            return emit_split(project);
         }
-        let notice = language::file_notice();
-        writeln!(output, "// {notice}\n")?;
+        let mut outfile = create_file(&project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+        write_project_header(project, output)?;
 
         for table in &project.tables {
             emit_table(project, table, output)?;
+            write_blob(project, table)?;
         }
         Ok(())
     }
 
+    // the split directory is not modified when its files are rewritten
+    fn dst_modified(&self, project: &table::Project) -> aperror::Result<std::time::SystemTime> {
+        let path = match project.split {
+            true => split_mod_path(project),
+            false => project.dst_path.clone(),
+        };
+        let metadata = fs::metadata(path)?;
+        let modified = metadata.modified()?;
+        Ok(modified)
+    }
+
     fn extension(&self) -> String {
         "rs".to_string()
     }
 
+    fn supports(&self, _feature: &str) -> bool {
+        true
+    }
+
     // support tolabel for label format
     fn to_label(&self) -> bool {
         true
@@ -0,0 +1,470 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for SQL language (SQLite dialect)
+//
+
+use crate::basetype::BaseType;
+use crate::{aperror, language, lint, table};
+use heck::ToSnakeCase;
+use std::collections::HashSet;
+use std::{fs, io};
+
+struct LangSql {}
+
+// synthetic primary key, snake case column names never start with an underscore
+const ROW_ID: &str = "\"_id\"";
+
+// rows per INSERT statement, SQLite limit the number of terms in a compound VALUES to 500
+const INSERT_BATCH: usize = 500;
+
+// column type affinity
+fn strtype(typ: &BaseType) -> String {
+    String::from(match typ {
+        BaseType::I8
+        | BaseType::I16
+        | BaseType::I32
+        | BaseType::I64
+        | BaseType::U8
+        | BaseType::U16
+        | BaseType::U32
+        | BaseType::U64 => "INTEGER",
+        BaseType::Str => "TEXT",
+        BaseType::Object { objtype } => objtype,
+        BaseType::Bool => "BOOLEAN",
+        BaseType::F32 | BaseType::F64 => "REAL",
+        BaseType::Label { .. } | BaseType::Join { .. } | BaseType::Variant => "INTEGER",
+    })
+}
+
+// write comment lines
+fn write_comment(output: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    language::write_help(output, &format!("{indent}-- "), doc)
+}
+
+// ================================================================================================
+// format name to SQL conventions, identifiers are always quoted to avoid clashes with keywords
+// ================================================================================================
+fn ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+fn table_name(table_name: &str) -> String {
+    ident(&table_name.to_snake_case())
+}
+fn column_name(col_name: &str) -> String {
+    ident(&col_name.to_snake_case())
+}
+fn index_name(table_name: &str, col_name: &str) -> String {
+    ident(&format!(
+        "{}_{}",
+        table_name.to_snake_case(),
+        col_name.to_snake_case()
+    ))
+}
+fn label_name(col: &dyn table::Column) -> String {
+    match &col.info().interface_type {
+        BaseType::Label { name } => table_name(name),
+        _ => unreachable!("label column must have label type"),
+    }
+}
+fn foreign_key(column: &str, jointable: &str) -> String {
+    format!(
+        "FOREIGN KEY ({column}) REFERENCES {} ({ROW_ID}) DEFERRABLE INITIALLY DEFERRED",
+        table_name(jointable)
+    )
+}
+
+// variant are stored in one nullable column for each alternative table
+fn variant_columns(col: &dyn table::Column) -> Vec<(String, &table::Variant)> {
+    let variants = col.variants().expect("variant must have variant");
+    variants
+        .iter()
+        .filter(|vrn| !vrn.is_none)
+        .map(|vrn| (column_name(&format!("{}_{}", col.name(), vrn.name)), vrn))
+        .collect()
+}
+
+// ================================================================================================
+// Cell values
+// ================================================================================================
+fn row_values(
+    project: &table::Project,
+    col: &dyn table::Column,
+    row: usize,
+    out: &mut Vec<String>,
+) {
+    let info = col.info();
+    let cell = col.emit_table_cell(row, project.lang);
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Join => out.push(cell),
+        table::TypeImpl::JoinOptional => {
+            // stored with an offset of one, zero is the null value
+            let v: usize = cell.parse().expect("join index must be an integer");
+            out.push(match v {
+                0 => "NULL".to_string(),
+                v => (v - 1).to_string(),
+            });
+        }
+        table::TypeImpl::Variant => {
+            let v: usize = cell.parse().expect("variant index must be an integer");
+            for (_, vrn) in variant_columns(col) {
+                out.push(if v >= vrn.index && v < vrn.index + vrn.count {
+                    (v - vrn.index).to_string()
+                } else {
+                    "NULL".to_string()
+                });
+            }
+        }
+        table::TypeImpl::Scalar => out.push(match cell.as_str() {
+            "NaN" => "NULL".to_string(),
+            "inf" => "9e999".to_string(),
+            "-inf" => "-9e999".to_string(),
+            _ => cell,
+        }),
+    }
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn column_defs(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    columns: &mut Vec<String>,
+    constraints: &mut Vec<String>,
+) {
+    let info = col.info();
+    let name = column_name(col.name());
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => {
+            let mut values = vec![];
+            for row in 0..table.len {
+                row_values(project, col, row, &mut values);
+            }
+            let notnull = if values.iter().any(|v| v == "NULL") {
+                ""
+            } else {
+                " NOT NULL"
+            };
+            columns.push(format!("{name} {}{notnull}", strtype(&info.interface_type)));
+        }
+        table::TypeImpl::Join => {
+            columns.push(format!("{name} INTEGER NOT NULL"));
+            constraints.push(foreign_key(&name, &info.join_table()));
+        }
+        table::TypeImpl::JoinOptional => {
+            columns.push(format!("{name} INTEGER"));
+            constraints.push(foreign_key(&name, &info.join_table()));
+        }
+        table::TypeImpl::Variant => {
+            let mut alternatives = vec![];
+            for (name, vrn) in variant_columns(col) {
+                columns.push(format!("{name} INTEGER"));
+                constraints.push(foreign_key(&name, &vrn.name));
+                alternatives.push(format!("({name} IS NOT NULL)"));
+            }
+            let cmp = if info.config.optional { "<=" } else { "=" };
+            constraints.push(format!("CHECK ({} {cmp} 1)", alternatives.join(" + ")));
+        }
+    }
+}
+
+fn write_create_table(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let tablename = table_name(&table.name);
+    let mut columns = vec![];
+    let mut constraints = vec![];
+
+    writeln!(output)?;
+    write_comment(output, "", &table.help)?;
+    writeln!(output, "CREATE TABLE {tablename} (")?;
+    write!(output, "    {ROW_ID} INTEGER PRIMARY KEY")?;
+    for col in table.data_columns() {
+        column_defs(project, table, col, &mut columns, &mut constraints);
+        for (i, column) in columns.drain(..).enumerate() {
+            writeln!(output, ",")?;
+            if i == 0 {
+                write_comment(output, "    ", &col.info().config.help)?;
+            }
+            write!(output, "    {column}")?;
+        }
+    }
+    for constraint in constraints {
+        write!(output, ",\n    {constraint}")?;
+    }
+    writeln!(output, "\n);")
+}
+
+// labels are exported as a lookup table sharing the id of the labelled row
+fn write_create_label(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let labelname = label_name(col);
+    writeln!(output)?;
+    write_comment(output, "", &info.config.help)?;
+    writeln!(output, "CREATE TABLE {labelname} (")?;
+    writeln!(output, "    {ROW_ID} INTEGER PRIMARY KEY,")?;
+    write!(output, "    \"label\" TEXT NOT NULL UNIQUE")?;
+    if has_label_help(table, col) {
+        write!(output, ",\n    \"help\" TEXT")?;
+    }
+    if table.has_data() {
+        write!(output, ",\n    {}", foreign_key(ROW_ID, &table.name))?;
+    }
+    writeln!(output, "\n);")
+}
+
+fn has_label_help(table: &table::Table, col: &dyn table::Column) -> bool {
+    (0..table.len).any(|row| col.emit_label_help(row).is_some())
+}
+
+// ================================================================================================
+// Data
+// ================================================================================================
+fn write_inserts(
+    tablename: &str,
+    columns: &[String],
+    rows: &[Vec<String>],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    for batch in rows.chunks(INSERT_BATCH) {
+        writeln!(
+            output,
+            "\nINSERT INTO {tablename} ({}) VALUES",
+            columns.join(", ")
+        )?;
+        let values: Vec<String> = batch
+            .iter()
+            .map(|row| format!("    ({})", row.join(", ")))
+            .collect();
+        writeln!(output, "{};", values.join(",\n"))?;
+    }
+    Ok(())
+}
+
+fn write_table_data(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let mut columns = vec![ROW_ID.to_string()];
+    for col in table.data_columns() {
+        match col.info().type_impl() {
+            table::TypeImpl::Variant => {
+                columns.extend(variant_columns(col).into_iter().map(|(name, _)| name))
+            }
+            _ => columns.push(column_name(col.name())),
+        }
+    }
+    let rows: Vec<Vec<String>> = (0..table.len)
+        .map(|row| {
+            let mut values = vec![row.to_string()];
+            for col in table.data_columns() {
+                row_values(project, col, row, &mut values);
+            }
+            values
+        })
+        .collect();
+    write_inserts(&table_name(&table.name), &columns, &rows, output)
+}
+
+fn write_label_data(
+    project: &table::Project,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let labelname = label_name(col);
+    let help = has_label_help(table, col);
+    let mut columns = vec![ROW_ID.to_string(), "\"label\"".to_string()];
+    if help {
+        columns.push("\"help\"".to_string());
+    }
+    let rows: Vec<Vec<String>> = (0..table.len)
+        .filter(|row| !col.emit_label(*row).is_empty())
+        .map(|row| {
+            let mut values = vec![
+                row.to_string(),
+                project.lang.emit_str(&col.emit_label(row).to_snake_case()),
+            ];
+            if help {
+                values.push(match col.emit_label_help(row) {
+                    Some(s) => project.lang.emit_str(&s),
+                    None => "NULL".to_string(),
+                });
+            }
+            values
+        })
+        .collect();
+    write_inserts(&labelname, &columns, &rows, output)
+}
+
+fn write_indexes(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+    let tablename = table_name(&table.name);
+    for col in table.data_columns() {
+        if !col.iterable() {
+            continue;
+        }
+        match col.info().type_impl() {
+            table::TypeImpl::Variant => {
+                for (name, vrn) in variant_columns(col) {
+                    let indexname =
+                        index_name(&table.name, &format!("{}_{}", col.name(), vrn.name));
+                    writeln!(output, "CREATE INDEX {indexname} ON {tablename} ({name});")?;
+                }
+            }
+            _ => {
+                let indexname = index_name(&table.name, col.name());
+                let name = column_name(col.name());
+                writeln!(output, "CREATE INDEX {indexname} ON {tablename} ({name});")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangSql {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        writeln!(output, "-- {}", language::file_notice())?;
+        write_comment(output, "", &project.help)?;
+        let mut imports: Vec<String> = project.imports().into_iter().collect();
+        imports.sort();
+        for import in imports {
+            writeln!(output, "{import}")?;
+        }
+        writeln!(output, "\nBEGIN TRANSACTION;")?;
+
+        // schema
+        for table in &project.tables {
+            if table.has_data() {
+                write_create_table(project, table, output)?;
+            }
+            for col in table.label_columns() {
+                write_create_label(table, col, output)?;
+            }
+        }
+
+        // data
+        for table in &project.tables {
+            if table.has_data() {
+                write_table_data(project, table, output)?;
+            }
+            for col in table.label_columns() {
+                write_label_data(project, table, col, output)?;
+            }
+        }
+
+        // indexes are created after the inserts to build them in one pass
+        writeln!(output)?;
+        for table in project.tables.iter().filter(|t| t.has_data()) {
+            write_indexes(table, output)?;
+        }
+        writeln!(output, "\nCOMMIT;")?;
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "sql".to_string()
+    }
+
+    // label lookup tables share the namespace of data tables
+    fn lint(&self, project: &table::Project, linter: &lint::Linter) {
+        let mut names = HashSet::new();
+        for table in &project.tables {
+            let labels = table.label_columns().into_iter().map(label_name);
+            let data = table.has_data().then(|| table_name(&table.name));
+            for name in data.into_iter().chain(labels) {
+                linter.err(
+                    names.insert(name.to_string()),
+                    &format!("duplicated sql table name {name}"),
+                );
+            }
+        }
+    }
+
+    fn emit_bool(&self, v: bool) -> String {
+        if v { "TRUE" } else { "FALSE" }.to_string()
+    }
+
+    // SQL string literals have no escape sequence : control characters are concatenated
+    fn emit_str(&self, v: &str) -> String {
+        let mut parts: Vec<String> = vec![];
+        let mut literal = String::new();
+        for c in v.chars() {
+            match c {
+                '\'' => literal.push_str("''"),
+                c if c.is_control() => {
+                    if !literal.is_empty() {
+                        parts.push(format!("'{literal}'"));
+                        literal.clear();
+                    }
+                    parts.push(format!("char({})", c as u32));
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() || parts.is_empty() {
+            parts.push(format!("'{literal}'"));
+        }
+        parts.join(" || ")
+    }
+}
+
+const LANG_SQL_: LangSql = LangSql {};
+pub const LANG_SQL: &'static dyn language::Language = &LANG_SQL_;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colstr::ColStr;
+    use crate::table::ColumnConfig;
+    use std::path::PathBuf;
+
+    #[test]
+    fn id_column() {
+        let id = ColStr::parse(
+            ColumnConfig {
+                name: "id".to_string(),
+                ..Default::default()
+            },
+            &vec!["a".to_string(), "b".to_string()],
+        )
+        .unwrap();
+        let table = table::Table::new("table", None, vec![id], false, false, None, false);
+        let project = table::Project {
+            dst_path: PathBuf::from("project.sql"),
+            help: None,
+            lang: LANG_SQL,
+            no_std: false,
+            const_fn: false,
+            derive_serde: false,
+            serde_nested: false,
+            split: false,
+            single_header: false,
+            static_linkage: false,
+            tables: vec![],
+            src_paths: vec![],
+        };
+
+        let mut output = vec![];
+        write_create_table(&project, &table, &mut output).unwrap();
+        let sql = String::from_utf8(output).unwrap();
+        assert!(sql.matches("\"_id\" INTEGER PRIMARY KEY").count() == 1);
+        assert!(sql.matches("\"id\" TEXT NOT NULL").count() == 1);
+    }
+}
@@ -69,6 +69,20 @@ fn getter_col(
         table::TypeImpl::Label => {
             log::verbose("unexpected getter_col for Label type");
         }
+        table::TypeImpl::Scalar if col.compressed().is_some() => {
+            let c_maxlen = format!(
+                "{}_{}_MAX_LEN",
+                langc::table_name(&table.name),
+                langc::table_name(c_field)
+            );
+            writeln!(
+                output,
+                "    var {field}: String {{
+        var buf = [CChar](repeating: 0, count: Int({c_maxlen}) + 1)
+        return String(cString: {c_strname}_{c_field}_decode(cstruct, &buf, buf.count))
+    }}"
+            )?;
+        }
         table::TypeImpl::Scalar => {
             let outtype = strtype(&info.interface_type);
             // const {strname}_t* s) {{ return s->{field}_; }}",
@@ -130,6 +144,41 @@ fn iter_range(
     )
 }
 
+// ================================================================================================
+// Groups iterator
+// ================================================================================================
+fn iter_groups(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let outtype = strtype(&info.interface_type);
+    let field = col.name();
+    write_help(output, "    /// ", &info.config.group_help)?;
+    let c_strname = langc::struct_name(&table.name);
+    let c_colname = langc::struct_name(col.name());
+    let (init, value) = match info.interface_type {
+        BaseType::Str => (
+            "UnsafePointer<CChar>? = nil".to_string(),
+            "String(cString: value!)".to_string(),
+        ),
+        _ => (format!("= {outtype}()"), "value".to_string()),
+    };
+    writeln!(
+        output,
+        "    static func {field}Groups() -> AnySequence<({outtype}, {c_strname}_iter_t)> {{
+        var groups = {c_strname}_{c_colname}_groups()
+        return AnySequence(AnyIterator {{
+            var value {init}
+            var rows = {c_strname}_iter_t()
+            if {c_strname}_{c_colname}_groups_next(&groups, &value, &rows) {{ return ({value}, rows) }}
+            return nil
+        }})
+    }}"
+    )
+}
+
 // ================================================================================================
 // Reverse join
 // ================================================================================================
@@ -305,6 +354,14 @@ fn table_data(
         )?;
     }
 
+    // number of rows
+    if table.has_data() {
+        writeln!(
+            output,
+            "    static var len : Int {{ Int({c_struct}_len()) }}"
+        )?;
+    }
+
     //methods for data column
     let datacols: Vec<&dyn table::Column> = table.data_columns();
     for col in &datacols {
@@ -312,6 +369,9 @@ fn table_data(
         if col.info().has_iter_range() {
             iter_range(table, *col, output)?;
         }
+        if col.info().config.group {
+            iter_groups(table, *col, output)?;
+        }
     }
 
     let reverse_joins = project.join_to_columns(table);
@@ -377,6 +437,11 @@ impl language::Language for LangSwift {
         "swift".to_string()
     }
 
+    // available through the C binding
+    fn supports(&self, _feature: &str) -> bool {
+        true
+    }
+
     // for language that use C binding
     fn c_binding_header(
         &self,
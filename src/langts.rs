@@ -0,0 +1,857 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for TypeScript and JavaScript languages
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, log, table};
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+use std::path::PathBuf;
+use std::{fs, io};
+
+// the same generator write typed TypeScript, or JavaScript with a separate declaration file
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Ts,  // .ts module
+    Js,  // .js module without type annotations
+    Dts, // .d.ts declarations for the .js module
+}
+
+struct LangTs {
+    mode: Mode,
+}
+
+// typescript data types
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return type_name(name),
+        BaseType::I8
+        | BaseType::I16
+        | BaseType::I32
+        | BaseType::I64
+        | BaseType::U8
+        | BaseType::U16
+        | BaseType::U32
+        | BaseType::U64
+        | BaseType::F32
+        | BaseType::F64 => "number",
+        BaseType::Str => "string",
+        BaseType::Join { strname } => return type_name(strname),
+        BaseType::Object { objtype } => objtype,
+        BaseType::Bool => "boolean",
+        BaseType::Variant => "number",
+    })
+}
+
+// write TSDoc comment
+fn write_doc(output: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    if let Some(doc) = doc {
+        let doc = doc.replace("*/", "*\\/");
+        if doc.contains('\n') {
+            writeln!(output, "{indent}/**")?;
+            for row in doc.split('\n') {
+                writeln!(output, "{indent} * {row}")?;
+            }
+            writeln!(output, "{indent} */")?;
+        } else {
+            writeln!(output, "{indent}/** {doc} */")?;
+        }
+    }
+    Ok(())
+}
+
+fn doc(text: &str) -> Option<String> {
+    Some(text.to_string())
+}
+
+// ================================================================================================
+// format name to TypeScript conventions
+// ================================================================================================
+fn type_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn field_name(col_name: &str) -> String {
+    col_name.to_lower_camel_case()
+}
+fn func_name(table_name: &str, name: &str) -> String {
+    format!(
+        "{}{}",
+        table_name.to_lower_camel_case(),
+        name.to_upper_camel_case()
+    )
+}
+fn const_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+fn table_name(table_name: &str) -> String {
+    format!("{}_TABLE", const_name(table_name))
+}
+fn index_name(table_name: &str, col_name: &str) -> String {
+    format!("{}_{}_INDEX", const_name(table_name), const_name(col_name))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    format!("{}{}", type_name(&table.name), type_name(col.name()))
+}
+
+// ================================================================================================
+// Declarations in the three output modes
+// ================================================================================================
+struct Func<'a> {
+    doc: &'a Option<String>,
+    name: String,
+    params: Vec<(String, String)>, // name, type
+    ret: String,
+    body: String,
+}
+
+fn write_func(mode: Mode, func: &Func, output: &mut dyn io::Write) -> io::Result<()> {
+    write_doc(output, "", func.doc)?;
+    let name = &func.name;
+    let params: Vec<String> = func
+        .params
+        .iter()
+        .map(|(p, t)| match mode {
+            Mode::Js => p.to_string(),
+            Mode::Ts | Mode::Dts => format!("{p}: {t}"),
+        })
+        .collect();
+    let params = params.join(", ");
+    let ret = &func.ret;
+    match mode {
+        Mode::Ts => writeln!(
+            output,
+            "export function {name}({params}): {ret} {{\n{}\n}}\n",
+            func.body
+        ),
+        Mode::Js => writeln!(
+            output,
+            "export function {name}({params}) {{\n{}\n}}\n",
+            func.body
+        ),
+        Mode::Dts => writeln!(output, "export declare function {name}({params}): {ret};"),
+    }
+}
+
+fn write_const(
+    mode: Mode,
+    doc: &Option<String>,
+    exported: bool,
+    name: &str,
+    typ: &str,
+    value: &str,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let export = if exported { "export " } else { "" };
+    match mode {
+        Mode::Ts if !typ.is_empty() => {
+            write_doc(output, "", doc)?;
+            writeln!(output, "{export}const {name}: {typ} = {value};")
+        }
+        Mode::Ts | Mode::Js => {
+            write_doc(output, "", doc)?;
+            writeln!(output, "{export}const {name} = {value};")
+        }
+        Mode::Dts if exported => {
+            write_doc(output, "", doc)?;
+            writeln!(output, "export declare const {name}: {typ};")
+        }
+        Mode::Dts => Ok(()),
+    }
+}
+
+// ================================================================================================
+// Helpers shared by all tables
+// ================================================================================================
+fn write_helpers(mode: Mode, output: &mut dyn io::Write) -> io::Result<()> {
+    match mode {
+        Mode::Ts => writeln!(
+            output,
+            "type Key = number | string | boolean;
+
+// compare strings in code point order like the generator sort, not in utf-16 code unit order
+function less(a: Key, b: Key): boolean {{
+    if (typeof a === \"string\" && typeof b === \"string\") {{
+        const len = Math.min(a.length, b.length);
+        for (let i = 0; i < len; i++) {{
+            const x = a.charCodeAt(i);
+            const y = b.charCodeAt(i);
+            if (x !== y) {{
+                // surrogates encode code points above the basic plane
+                const fix = (c: number) => (c >= 0xe000 ? c - 0x800 : c >= 0xd800 ? c + 0x2000 : c);
+                return fix(x) < fix(y);
+            }}
+        }}
+        return a.length < b.length;
+    }}
+    return Number(a) < Number(b);
+}}
+
+// positions in index of rows with key(row) in [start..=stop], index must be sorted by key(row)
+function bounds<R, K extends Key>(
+    table: readonly R[],
+    index: readonly number[],
+    start: K,
+    stop: K,
+    key: (row: R) => K,
+): [number, number] {{
+    let lo = 0;
+    let hi = index.length;
+    while (lo < hi) {{
+        const mid = (lo + hi) >>> 1;
+        if (less(key(table[index[mid]]), start)) {{
+            lo = mid + 1;
+        }} else {{
+            hi = mid;
+        }}
+    }}
+    const begin = lo;
+    hi = index.length;
+    while (lo < hi) {{
+        const mid = (lo + hi) >>> 1;
+        if (less(stop, key(table[index[mid]]))) {{
+            hi = mid;
+        }} else {{
+            lo = mid + 1;
+        }}
+    }}
+    return [begin, lo];
+}}
+
+// rows of table with key(row) in [start..=stop]
+function rows<R, K extends Key>(
+    table: readonly R[],
+    index: readonly number[],
+    start: K,
+    stop: K,
+    key: (row: R) => K,
+): R[] {{
+    const [lo, hi] = bounds(table, index, start, stop, key);
+    return index.slice(lo, hi).map((i) => table[i]);
+}}
+"
+        ),
+        Mode::Js => writeln!(
+            output,
+            "// compare strings in code point order like the generator sort, not in utf-16 code unit order
+function less(a, b) {{
+    if (typeof a === \"string\" && typeof b === \"string\") {{
+        const len = Math.min(a.length, b.length);
+        for (let i = 0; i < len; i++) {{
+            const x = a.charCodeAt(i);
+            const y = b.charCodeAt(i);
+            if (x !== y) {{
+                // surrogates encode code points above the basic plane
+                const fix = (c) => (c >= 0xe000 ? c - 0x800 : c >= 0xd800 ? c + 0x2000 : c);
+                return fix(x) < fix(y);
+            }}
+        }}
+        return a.length < b.length;
+    }}
+    return Number(a) < Number(b);
+}}
+
+// positions in index of rows with key(row) in [start..=stop], index must be sorted by key(row)
+function bounds(table, index, start, stop, key) {{
+    let lo = 0;
+    let hi = index.length;
+    while (lo < hi) {{
+        const mid = (lo + hi) >>> 1;
+        if (less(key(table[index[mid]]), start)) {{
+            lo = mid + 1;
+        }} else {{
+            hi = mid;
+        }}
+    }}
+    const begin = lo;
+    hi = index.length;
+    while (lo < hi) {{
+        const mid = (lo + hi) >>> 1;
+        if (less(stop, key(table[index[mid]]))) {{
+            hi = mid;
+        }} else {{
+            lo = mid + 1;
+        }}
+    }}
+    return [begin, lo];
+}}
+
+// rows of table with key(row) in [start..=stop]
+function rows(table, index, start, stop, key) {{
+    const [lo, hi] = bounds(table, index, start, stop, key);
+    return index.slice(lo, hi).map((i) => table[i]);
+}}
+"
+        ),
+        Mode::Dts => Ok(()),
+    }
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(mode: Mode, col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    write_doc(output, "", &info.config.help)?;
+    match mode {
+        Mode::Ts | Mode::Js => writeln!(output, "export const {enumname} = {{")?,
+        Mode::Dts => writeln!(output, "export declare const {enumname}: {{")?,
+    }
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if !label.is_empty() {
+            let camel = label.to_upper_camel_case();
+            write_doc(output, "    ", &col.emit_label_help(row))?;
+            match mode {
+                Mode::Ts | Mode::Js => writeln!(output, "    {camel}: {row},")?,
+                Mode::Dts => writeln!(output, "    readonly {camel}: {row};")?,
+            }
+        }
+    }
+    match mode {
+        Mode::Ts => writeln!(output, "}} as const;")?,
+        Mode::Js => writeln!(output, "}};")?,
+        Mode::Dts => writeln!(output, "}};")?,
+    }
+    if mode != Mode::Js {
+        writeln!(
+            output,
+            "export type {enumname} = (typeof {enumname})[keyof typeof {enumname}];"
+        )?;
+    }
+    writeln!(output)
+}
+
+fn write_label_funcs(
+    mode: Mode,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let tablename = table_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    write_func(
+        mode,
+        &Func {
+            doc: &doc("Row corresponding to label"),
+            name: func_name(&table.name, &format!("from_{enumname}")),
+            params: vec![("label".to_string(), enumname.to_string())],
+            ret: typname.to_string(),
+            body: format!("    return {tablename}[label];"),
+        },
+        output,
+    )?;
+    let cast = if mode == Mode::Ts {
+        format!(" as {enumname}")
+    } else {
+        "".to_string()
+    };
+    write_func(
+        mode,
+        &Func {
+            doc: &doc("Label of the row"),
+            name: func_name(&table.name, &enumname),
+            params: vec![("row".to_string(), typname)],
+            ret: enumname.to_string(),
+            body: format!("    return row._index{cast};"),
+        },
+        output,
+    )
+}
+
+// ================================================================================================
+// Types
+// ================================================================================================
+
+// joins and variants are read through functions, the raw value is kept in a private field
+fn stored_name(col: &dyn table::Column) -> String {
+    let field = field_name(col.name());
+    match col.info().type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => field,
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            format!("_{field}")
+        }
+    }
+}
+
+fn stored_type(col: &dyn table::Column) -> String {
+    let info = col.info();
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => strtype(&info.interface_type),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            "number".to_string()
+        }
+    }
+}
+
+fn write_interface(table: &table::Table, output: &mut dyn io::Write) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    write_doc(output, "", &table.help)?;
+    writeln!(
+        output,
+        "export interface {typname} {{
+    /** @internal */
+    readonly _index: number;"
+    )?;
+    for col in table.data_columns() {
+        if matches!(
+            col.info().type_impl(),
+            table::TypeImpl::Label | table::TypeImpl::Scalar
+        ) {
+            write_doc(output, "    ", &col.info().config.help)?;
+        } else {
+            writeln!(output, "    /** @internal */")?;
+        }
+        writeln!(
+            output,
+            "    readonly {}: {};",
+            stored_name(col),
+            stored_type(col)
+        )?;
+    }
+    writeln!(output, "}}\n")
+}
+
+fn write_variant_type(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(variants) = col.variants() else {
+        return Ok(());
+    };
+    let vartypname = variant_type_name(table, col);
+    writeln!(output, "export type {vartypname} =")?;
+    let alternatives: Vec<String> = variants
+        .iter()
+        .filter(|vrn| !vrn.is_none)
+        .map(|vrn| {
+            let name = type_name(&vrn.name);
+            format!("    | {{ readonly kind: \"{name}\"; readonly row: {name} }}")
+        })
+        .collect();
+    writeln!(output, "{};\n", alternatives.join("\n"))
+}
+
+// ================================================================================================
+// Getters
+// ================================================================================================
+fn write_getter(
+    mode: Mode,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let field = field_name(col.name());
+    let typname = type_name(&table.name);
+    let outtype = strtype(&info.interface_type);
+    let (ret, body) = match &info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => return Ok(()),
+        table::TypeImpl::Join => {
+            let jointable = table_name(&info.join_table());
+            (outtype, format!("    return {jointable}[row._{field}];"))
+        }
+        table::TypeImpl::JoinOptional => {
+            let jointable = table_name(&info.join_table());
+            (
+                format!("{outtype} | undefined"),
+                format!(
+                    "    return row._{field} === 0 ? undefined : {jointable}[row._{field} - 1];"
+                ),
+            )
+        }
+        table::TypeImpl::Variant => {
+            let mut ret = variant_type_name(table, col);
+            if info.config.optional {
+                ret.push_str(" | undefined");
+            }
+            (ret, variant_body(table, col))
+        }
+    };
+    write_func(
+        mode,
+        &Func {
+            doc: &info.config.help,
+            name: func_name(&table.name, col.name()),
+            params: vec![("row".to_string(), typname)],
+            ret,
+            body,
+        },
+        output,
+    )
+}
+
+fn variant_body(table: &table::Table, col: &dyn table::Column) -> String {
+    let variants = col.variants().expect("variant must have variant");
+    let field = field_name(col.name());
+    let mut body = format!("    const v = row._{field};");
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let end = vrn.index + vrn.count - 1;
+        if vrn.is_none {
+            body.push_str(&format!(
+                "
+    if (v <= {end}) {{
+        return undefined;
+    }}"
+            ));
+        } else {
+            let name = type_name(&vrn.name);
+            let jointable = table_name(&vrn.name);
+            let offset = stroffset(-(vrn.index as isize));
+            body.push_str(&format!(
+                "
+    if (v <= {end}) {{
+        return {{ kind: \"{name}\", row: {jointable}[v{offset}] }};
+    }}"
+            ));
+        }
+    }
+    body.push_str(&format!(
+        "
+    throw new RangeError(\"insrcdata variant index overflow in {}\");",
+        type_name(&table.name)
+    ));
+    body
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn write_range(
+    mode: Mode,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let field = field_name(col.name());
+    let argtype = strtype(&info.interface_type);
+    let tablename = table_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    let params = vec![
+        ("start".to_string(), argtype.to_string()),
+        ("stop".to_string(), argtype.to_string()),
+    ];
+    let rowparam = match mode {
+        Mode::Ts => format!("(r: {typname})"),
+        Mode::Js | Mode::Dts => "(r)".to_string(),
+    };
+    write_func(
+        mode,
+        &Func {
+            doc: &info.config.iter_help,
+            name: func_name(&table.name, &format!("{}_range", col.name())),
+            params: params.clone(),
+            ret: format!("{typname}[]"),
+            body: format!(
+                "    return rows({tablename}, {indexname}, start, stop, {rowparam} => r.{field});"
+            ),
+        },
+        output,
+    )?;
+    let range = func_name(&table.name, &format!("{}_range", col.name()));
+    write_func(
+        mode,
+        &Func {
+            doc: &Some(format!("Number of rows returned by {range}")),
+            name: func_name(&table.name, &format!("{}_count", col.name())),
+            params,
+            ret: "number".to_string(),
+            body: format!(
+                "    const [lo, hi] = bounds({tablename}, {indexname}, start, stop, {rowparam} => r.{field});
+    return hi - lo;"
+            ),
+        },
+        output,
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn write_aggregates(
+    mode: Mode,
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        let name = const_name(&format!("{}_{}_{}", table.name, col.name(), aggregate.name));
+        write_const(
+            mode,
+            &Some(format!(
+                "{} of {} column values",
+                aggregate.name,
+                col.name()
+            )),
+            true,
+            &name,
+            &strtype(&aggregate.typ),
+            &aggregate.value,
+            output,
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn write_reverse_join(
+    mode: Mode,
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let srctype = type_name(&rj.table.name);
+    let srctable = table_name(&rj.table.name);
+    let indexname = index_name(&rj.table.name, rj.col.name());
+    let field = field_name(rj.col.name());
+    let offset = stroffset(rj.offset as isize);
+    let rowparam = match mode {
+        Mode::Ts => format!("(r: {srctype})"),
+        Mode::Js | Mode::Dts => "(r)".to_string(),
+    };
+    write_func(
+        mode,
+        &Func {
+            doc: &info.config.iter_help,
+            name: func_name(&table.name, &rj.reverse_name),
+            params: vec![("row".to_string(), type_name(&table.name))],
+            ret: format!("{srctype}[]"),
+            body: format!(
+                "    const cons = row._index{offset};
+    return rows({srctable}, {indexname}, cons, cons, {rowparam} => r._{field});"
+            ),
+        },
+        output,
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn write_table_data(
+    mode: Mode,
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    let mut value = String::from("[\n");
+    for row in 0..table.len {
+        value.push_str(&format!("    {{ _index: {row}"));
+        for col in &datacols {
+            let cell = col.emit_table_cell(row, project.lang);
+            value.push_str(&format!(", {}: {cell}", stored_name(*col)));
+        }
+        value.push_str(" },\n");
+    }
+    value.push(']');
+    let tabledoc = if table.get_array {
+        doc("All the rows of the table")
+    } else {
+        None
+    };
+    write_const(
+        mode,
+        &tabledoc,
+        table.get_array,
+        &table_name(&table.name),
+        &format!("readonly {typname}[]"),
+        &value,
+        output,
+    )?;
+
+    // indexes
+    for col in &datacols {
+        if col.iterable() {
+            let indexes: Vec<String> = col.indexes().iter().map(|i| i.to_string()).collect();
+            let value = match mode {
+                Mode::Ts => format!("[{}] as const", indexes.join(", ")),
+                Mode::Js | Mode::Dts => format!("[{}]", indexes.join(", ")),
+            };
+            // type is inferred from the const assertion
+            write_const(
+                mode,
+                &None,
+                false,
+                &index_name(&table.name, col.name()),
+                "",
+                &value,
+                output,
+            )?;
+        }
+    }
+    if mode != Mode::Dts {
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+fn write_table_funcs(
+    mode: Mode,
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    write_const(
+        mode,
+        &doc("Number of rows in the table"),
+        true,
+        &format!("{}_LEN", const_name(&table.name)),
+        "number",
+        &table.len.to_string(),
+        output,
+    )?;
+    for col in &datacols {
+        write_aggregates(mode, table, *col, output)?;
+    }
+    writeln!(output)?;
+
+    for col in &datacols {
+        write_getter(mode, table, *col, output)?;
+        if col.info().has_iter_range() {
+            write_range(mode, table, *col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        write_reverse_join(mode, table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        write_label_funcs(mode, table, col, output)?;
+    }
+    write_func(
+        mode,
+        &Func {
+            doc: &doc("Index of the row in the table"),
+            name: func_name(&table.name, "as_index"),
+            params: vec![("row".to_string(), type_name(&table.name))],
+            ret: "number".to_string(),
+            body: "    return row._index;".to_string(),
+        },
+        output,
+    )
+}
+
+fn write_module(
+    mode: Mode,
+    project: &table::Project,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    writeln!(output, "// {}\n", language::file_notice())?;
+    write_doc(output, "", &project.help)?;
+    let mut imports: Vec<String> = project.imports().into_iter().collect();
+    imports.sort();
+    for import in &imports {
+        writeln!(output, "import {import};")?;
+    }
+    if !imports.is_empty() {
+        writeln!(output)?;
+    }
+    let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+    if tables.iter().any(|t| project.table_need_iter(t)) {
+        write_helpers(mode, output)?;
+    }
+
+    // labels
+    for table in &project.tables {
+        for col in table.label_columns() {
+            write_labels(mode, col, output)?;
+        }
+    }
+
+    // types
+    if mode != Mode::Js {
+        for table in &tables {
+            write_interface(table, output)?;
+            for col in table.data_columns() {
+                write_variant_type(table, col, output)?;
+            }
+        }
+    }
+
+    // data
+    for table in &tables {
+        write_table_data(mode, project, table, output)?;
+    }
+
+    // functions
+    for table in &tables {
+        write_table_funcs(mode, project, table, output)?;
+    }
+    Ok(())
+}
+
+fn declaration_path(project: &table::Project) -> PathBuf {
+    let mut path = PathBuf::from(&project.dst_path);
+    path.set_extension("d.ts");
+    path
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangTs {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        write_module(self.mode, project, &mut outfile)?;
+
+        if self.mode == Mode::Js {
+            let path = declaration_path(project);
+            let mut outfile = aperror::io_error_result(fs::File::create(&path), &path)?;
+            write_module(Mode::Dts, project, &mut outfile)?;
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        match self.mode {
+            Mode::Js => "js",
+            Mode::Ts | Mode::Dts => "ts",
+        }
+        .to_string()
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        let enumstr = strtype(typ);
+        let camel = label.to_upper_camel_case();
+        format!("{enumstr}.{camel}")
+    }
+}
+
+const LANG_TS_: LangTs = LangTs { mode: Mode::Ts };
+pub const LANG_TS: &'static dyn language::Language = &LANG_TS_;
+
+const LANG_JS_: LangTs = LangTs { mode: Mode::Js };
+pub const LANG_JS: &'static dyn language::Language = &LANG_JS_;
@@ -6,10 +6,16 @@
 //
 
 use crate::basetype::BaseType;
-use crate::{aperror, langc, langrust, langswift, table};
+use crate::{
+    aperror, langc, langcpp, langcsharp, langdoc, langgo, langjava, langjson, langpython, langrust,
+    langsql, langswift, langts, langzig, lint, table,
+};
 use std::path::Path;
 use std::{fs, io};
 
+// column options that need specific code generation
+pub const FEATURES: [&str; 4] = ["fuzzy", "bitmap", "group", "closure"];
+
 pub trait Language {
     fn emit(&self, project: &table::Project) -> aperror::Result<()>;
 
@@ -25,10 +31,39 @@ pub trait Language {
         false
     }
 
+    // object columns are written as native code and need a target definition
+    fn native_objects(&self) -> bool {
+        true
+    }
+
+    // checks of the project specific to the language
+    fn lint(&self, _project: &table::Project, _linter: &lint::Linter) {}
+
+    // generate the code of a column option listed in FEATURES
+    fn supports(&self, _feature: &str) -> bool {
+        false
+    }
+
+    // maximum count of labels in one label type, None when unbounded
+    fn max_labels(&self) -> Option<usize> {
+        None
+    }
+
     fn emit_enum(&self, _typ: &BaseType, _label: &str) -> String {
         "TO LABEL UNSUPORTED".to_string()
     }
 
+    // literal for bool values
+    fn emit_bool(&self, v: bool) -> String {
+        v.to_string()
+    }
+
+    // literal for str values
+    fn emit_str(&self, v: &str) -> String {
+        // TODO : stability of String.Debug trait implementation is not guaranteed
+        format!("{:?}", v)
+    }
+
     // for language that use C binding
     fn c_binding_header(
         &self,
@@ -47,19 +82,35 @@ pub trait Language {
 }
 
 // compute target language from dest filename extension
-pub fn language_for_dest(filename: &Path) -> &'static dyn Language {
-    match filename
-        // .as_path()
+pub fn language_for_dest(filename: &Path) -> aperror::Result<&'static dyn Language> {
+    let extension = filename
         .extension()
         .unwrap_or_default()
         .to_str()
-        .unwrap_or_default()
-    {
+        .unwrap_or_default();
+    Ok(match extension {
         "rs" => langrust::RUST,
         "c" => langc::LANG_C,
+        "hpp" => langcpp::LANG_CPP,
+        "cs" => langcsharp::LANG_CSHARP,
+        "go" => langgo::LANG_GO,
+        "java" => langjava::LANG_JAVA,
+        "json" => langjson::LANG_JSON,
+        "md" => langdoc::LANG_MD,
+        "html" => langdoc::LANG_HTML,
+        "py" => langpython::LANG_PYTHON,
+        "sql" => langsql::LANG_SQL,
         "swift" => langswift::LANG_SWIFT,
-        _ => langrust::RUST,
-    }
+        "ts" => langts::LANG_TS,
+        "js" => langts::LANG_JS,
+        "zig" => langzig::LANG_ZIG,
+        _ => {
+            return Err(aperror::Error::new(&format!(
+                "unknown language for dest extension '{}'",
+                extension
+            )))
+        }
+    })
 }
 
 pub fn digits(n: usize) -> usize {
@@ -76,6 +127,24 @@ pub fn file_notice() -> String {
     format!("generated by insrcdata version {}", VERSION)
 }
 
+// double quoted string literal with escapes shared by Python, Go and JSON
+pub fn quote_str(v: &str) -> String {
+    let mut s = String::from("\"");
+    for c in v.chars() {
+        match c {
+            '\\' => s.push_str("\\\\"),
+            '"' => s.push_str("\\\""),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if c.is_control() => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
 // write doc comment : prefix each line with comment mark
 pub fn write_help(
     output: &mut dyn io::Write,
@@ -0,0 +1,733 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// target implementation for Zig language
+//
+
+use crate::basetype::BaseType;
+use crate::table::JoinTo;
+use crate::{aperror, basetype, language, log, table};
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+use std::{fs, io};
+
+struct LangZig {}
+
+// zig data types
+fn strtype(typ: &basetype::BaseType) -> String {
+    String::from(match typ {
+        BaseType::Label { name } => return type_name(name),
+        BaseType::I8 => "i8",
+        BaseType::I16 => "i16",
+        BaseType::I32 => "i32",
+        BaseType::I64 => "i64",
+        BaseType::U8 => "u8",
+        BaseType::U16 => "u16",
+        BaseType::U32 => "u32",
+        BaseType::U64 => "u64",
+        BaseType::Str => "[]const u8",
+        BaseType::Join { strname } => return format!("*const {}", type_name(strname)),
+        BaseType::Object { objtype } => objtype,
+
+        BaseType::Bool => "bool",
+        BaseType::F32 => "f32",
+        BaseType::F64 => "f64",
+        BaseType::Variant => "TODO",
+    })
+}
+
+// zig literal for a value emitted by a column
+fn literal(typ: &basetype::BaseType, v: &str) -> String {
+    let float = match typ {
+        BaseType::F32 => "f32",
+        BaseType::F64 => "f64",
+        _ => return v.to_string(),
+    };
+    match v {
+        "inf" => format!("std.math.inf({float})"),
+        "-inf" => format!("-std.math.inf({float})"),
+        "NaN" => format!("std.math.nan({float})"),
+        _ => v.to_string(),
+    }
+}
+
+// identifiers that collide with keywords or primitive types must be quoted
+fn ident(name: String) -> String {
+    const RESERVED: &[&str] = &[
+        "addrspace",
+        "align",
+        "allowzero",
+        "and",
+        "anyframe",
+        "anytype",
+        "asm",
+        "async",
+        "await",
+        "bool",
+        "break",
+        "callconv",
+        "catch",
+        "comptime",
+        "const",
+        "continue",
+        "defer",
+        "else",
+        "enum",
+        "errdefer",
+        "error",
+        "export",
+        "extern",
+        "false",
+        "fn",
+        "for",
+        "if",
+        "inline",
+        "linksection",
+        "noalias",
+        "noinline",
+        "nosuspend",
+        "null",
+        "opaque",
+        "or",
+        "orelse",
+        "packed",
+        "pub",
+        "resume",
+        "return",
+        "struct",
+        "suspend",
+        "switch",
+        "test",
+        "threadlocal",
+        "true",
+        "try",
+        "type",
+        "undefined",
+        "union",
+        "unreachable",
+        "usingnamespace",
+        "var",
+        "void",
+        "volatile",
+        "while",
+    ];
+    if RESERVED.contains(&name.as_str()) {
+        format!("@\"{name}\"")
+    } else {
+        name
+    }
+}
+
+// write doc comment
+fn write_doc(output: &mut dyn io::Write, indent: &str, doc: &Option<String>) -> io::Result<()> {
+    language::write_help(output, &format!("{indent}/// "), doc)
+}
+fn write_doc_or(
+    output: &mut dyn io::Write,
+    indent: &str,
+    doc: &Option<String>,
+    default: String,
+) -> io::Result<()> {
+    match doc {
+        Some(_) => write_doc(output, indent, doc),
+        None => write_doc(output, indent, &Some(default)),
+    }
+}
+
+// ================================================================================================
+// format name to Zig conventions
+// ================================================================================================
+fn type_name(table_name: &str) -> String {
+    table_name.to_upper_camel_case()
+}
+fn field_name(col_name: &str) -> String {
+    ident(col_name.to_snake_case())
+}
+fn func_name(name: &str) -> String {
+    ident(name.to_lower_camel_case())
+}
+// join and variant columns are stored as an index, the field name is used by the accessor method
+fn stored_field_name(col: &dyn table::Column) -> String {
+    match col.info().type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => field_name(col.name()),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            format!("{}_index", col.name().to_snake_case())
+        }
+    }
+}
+fn table_name(table_name: &str) -> String {
+    format!("{}_table", table_name.to_snake_case())
+}
+fn index_name(table_name: &str, col_name: &str) -> String {
+    format!(
+        "{}_{}_index",
+        table_name.to_snake_case(),
+        col_name.to_snake_case()
+    )
+}
+fn variant_type_name(table: &table::Table, col: &dyn table::Column) -> String {
+    format!("{}{}", type_name(&table.name), type_name(col.name()))
+}
+fn stroffset(v: isize) -> String {
+    match v {
+        0 => "".to_string(),
+        v if v < 0 => format!(" - {}", -v),
+        _ => format!(" + {}", v),
+    }
+}
+
+// ================================================================================================
+// Helpers
+// ================================================================================================
+fn write_helpers(output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(
+        output,
+        "/// Iterator on the rows selected by a slice of an index
+pub fn Rows(comptime T: type, comptime I: type) type {{
+    return struct {{
+        table: []const T,
+        index: []const I,
+
+        /// Returns the next row, null at the end of the iteration
+        pub fn next(self: *@This()) ?*const T {{
+            if (self.index.len == 0) return null;
+            const row = &self.table[self.index[0]];
+            self.index = self.index[1..];
+            return row;
+        }}
+
+        /// Returns the number of remaining rows
+        pub fn len(self: @This()) usize {{
+            return self.index.len;
+        }}
+    }};
+}}
+
+// ordering used by insrcdata to sort indexes, utf-8 byte order is unicode code point order
+fn order(a: anytype, b: @TypeOf(a)) std.math.Order {{
+    return switch (@TypeOf(a)) {{
+        []const u8 => std.mem.order(u8, a, b),
+        bool => std.math.order(@intFromBool(a), @intFromBool(b)),
+        else => std.math.order(a, b),
+    }};
+}}
+
+// binary search of the first position in index with a field value greater (or equal) than value
+fn bound(comptime T: type, comptime field: []const u8, table: []const T, index: anytype, value: anytype, upper: bool) usize {{
+    var lo: usize = 0;
+    var hi: usize = index.len;
+    while (lo < hi) {{
+        const mid = lo + (hi - lo) / 2;
+        const o = order(@field(table[index[mid]], field), value);
+        if (o == .lt or (upper and o == .eq)) {{
+            lo = mid + 1;
+        }} else {{
+            hi = mid;
+        }}
+    }}
+    return lo;
+}}
+"
+    )
+}
+
+// ================================================================================================
+// Labels
+// ================================================================================================
+fn write_labels(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let enumname = strtype(&info.interface_type);
+    let inttype = strtype(&table.index_type());
+    write_doc(output, "", &info.config.help)?;
+    writeln!(output, "pub const {enumname} = enum({inttype}) {{")?;
+    let mut complete = true;
+    for row in 0..info.len {
+        let label = col.emit_label(row);
+        if label.is_empty() {
+            complete = false;
+        } else {
+            write_doc(output, "    ", &col.emit_label_help(row))?;
+            writeln!(output, "    {} = {row},", field_name(&label))?;
+        }
+    }
+    // tables that may get new rows have a non exhaustive enum
+    if !table.exhaustive || !complete {
+        writeln!(output, "    _,")?;
+    }
+    if table.has_data() {
+        let typname = type_name(&table.name);
+        writeln!(
+            output,
+            "
+    /// Returns the row corresponding to label
+    pub fn row(self: {enumname}) *const {typname} {{
+        return &{}[@intFromEnum(self)];
+    }}",
+            table_name(&table.name)
+        )?;
+    }
+    writeln!(output, "}};\n")
+}
+
+fn write_label_methods(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let enumname = strtype(&col.info().interface_type);
+    writeln!(
+        output,
+        "
+    /// Returns the label of the row
+    pub fn {}(self: *const {typname}) {enumname} {{
+        return @enumFromInt(self.index_);
+    }}",
+        func_name(&enumname)
+    )
+}
+
+// ================================================================================================
+// Joins
+// ================================================================================================
+fn write_join(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let method = func_name(col.name());
+    let field = stored_field_name(col);
+    let jointable = table_name(&info.join_table());
+    let outtype = strtype(&info.interface_type);
+    let (ret, body) = match &info.type_impl() {
+        table::TypeImpl::Join => (
+            outtype,
+            format!("        return &{jointable}[self.{field}];"),
+        ),
+        table::TypeImpl::JoinOptional => (
+            format!("?{outtype}"),
+            format!(
+                "        if (self.{field} == 0) return null;
+        return &{jointable}[self.{field} - 1];"
+            ),
+        ),
+        table::TypeImpl::Variant => {
+            let optional = if info.config.optional { "?" } else { "" };
+            (
+                format!("{optional}{}", variant_type_name(table, col)),
+                variant_body(col),
+            )
+        }
+        table::TypeImpl::Label | table::TypeImpl::Scalar => return Ok(()),
+    };
+    writeln!(output)?;
+    write_doc(output, "    ", &info.config.help)?;
+    writeln!(
+        output,
+        "    pub fn {method}(self: *const {typname}) {ret} {{\n{body}\n    }}"
+    )
+}
+
+fn write_variant_type(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let Some(variants) = col.variants() else {
+        return Ok(());
+    };
+    let nullnote = if col.info().config.optional {
+        ", null for rows without match"
+    } else {
+        ""
+    };
+    writeln!(
+        output,
+        "/// One of the rows that can be referenced by {}.{}(){nullnote}
+pub const {} = union(enum) {{",
+        type_name(&table.name),
+        func_name(col.name()),
+        variant_type_name(table, col)
+    )?;
+    for vrn in variants.iter().filter(|vrn| !vrn.is_none) {
+        writeln!(
+            output,
+            "    {}: *const {},",
+            field_name(&vrn.name),
+            type_name(&vrn.name)
+        )?;
+    }
+    writeln!(output, "}};\n")
+}
+
+fn variant_body(col: &dyn table::Column) -> String {
+    let variants = col.variants().expect("variant must have variant");
+    let field = stored_field_name(col);
+    let mut body = format!("        const v = self.{field};");
+    for vrn in variants {
+        if vrn.count == 0 {
+            continue;
+        }
+        let end = vrn.index + vrn.count - 1;
+        let ret = if vrn.is_none {
+            "null".to_string()
+        } else {
+            let offset = stroffset(-(vrn.index as isize));
+            format!(
+                ".{{ .{} = &{}[v{offset}] }}",
+                field_name(&vrn.name),
+                table_name(&vrn.name)
+            )
+        };
+        body.push_str(&format!("\n        if (v <= {end}) return {ret};"));
+    }
+    body.push_str("\n        unreachable;");
+    body
+}
+
+// ================================================================================================
+// Range iterator
+// ================================================================================================
+fn write_range(
+    table: &table::Table,
+    col: &dyn table::Column,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let info = col.info();
+    let typname = type_name(&table.name);
+    let field = stored_field_name(col);
+    let argtype = strtype(&info.interface_type);
+    let indextype = strtype(&table.index_type());
+    let tablename = table_name(&table.name);
+    let indexname = index_name(&table.name, col.name());
+    let range = func_name(&format!("{}_range", col.name()));
+    let count = func_name(&format!("{}_count", col.name()));
+    let bound = format!("bound({typname}, \"{field}\", &{tablename}, &{indexname}");
+    writeln!(output)?;
+    write_doc_or(
+        output,
+        "    ",
+        &info.config.iter_help,
+        format!("Returns the rows with {field} in [start..=stop]"),
+    )?;
+    writeln!(
+        output,
+        "    pub fn {range}(start: {argtype}, stop: {argtype}) Rows({typname}, {indextype}) {{
+        const lo = {bound}, start, false);
+        const hi = @max(lo, {bound}, stop, true));
+        return .{{ .table = &{tablename}, .index = {indexname}[lo..hi] }};
+    }}
+
+    /// Returns the number of rows returned by {range}
+    pub fn {count}(start: {argtype}, stop: {argtype}) usize {{
+        const lo = {bound}, start, false);
+        return @max(lo, {bound}, stop, true)) - lo;
+    }}"
+    )
+}
+
+// ================================================================================================
+// Aggregates
+// ================================================================================================
+fn write_aggregates(col: &dyn table::Column, output: &mut dyn io::Write) -> io::Result<()> {
+    for aggregate in col.aggregates() {
+        writeln!(
+            output,
+            "
+    /// The {} of {} column values
+    pub const {}: {} = {};",
+            aggregate.name,
+            col.name(),
+            field_name(&format!("{}_{}", col.name(), aggregate.name)),
+            strtype(&aggregate.typ),
+            literal(&aggregate.typ, &aggregate.value)
+        )?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Reverse join
+// ================================================================================================
+fn write_reverse_join(
+    table: &table::Table,
+    rj: &JoinTo,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    if !table.has_data() {
+        log::warning(&format!("{} will crash if used", &rj.reverse_name));
+    }
+    let info = rj.col.info();
+    let typname = type_name(&table.name);
+    let method = func_name(&rj.reverse_name);
+    let srctype = type_name(&rj.table.name);
+    let srctable = table_name(&rj.table.name);
+    let indexname = index_name(&rj.table.name, rj.col.name());
+    let indextype = strtype(&rj.table.index_type());
+    let field = stored_field_name(rj.col);
+    let tabletype = strtype(&info.table_type);
+    let cons = match rj.offset {
+        0 => "@intCast(self.index_)".to_string(),
+        offset => format!(
+            "@intCast(@as(usize, self.index_){})",
+            stroffset(offset as isize)
+        ),
+    };
+    let bound = format!("bound({srctype}, \"{field}\", &{srctable}, &{indexname}, cons");
+    writeln!(output)?;
+    write_doc_or(
+        output,
+        "    ",
+        &info.config.iter_help,
+        format!("Returns the {srctype} rows that reference this row"),
+    )?;
+    writeln!(
+        output,
+        "    pub fn {method}(self: *const {typname}) Rows({srctype}, {indextype}) {{
+        const cons: {tabletype} = {cons};
+        const lo = {bound}, false);
+        const hi = {bound}, true);
+        return .{{ .table = &{srctable}, .index = {indexname}[lo..hi] }};
+    }}"
+    )
+}
+
+// ================================================================================================
+// Table
+// ================================================================================================
+fn stored_type(col: &dyn table::Column) -> String {
+    let info = col.info();
+    match info.type_impl() {
+        table::TypeImpl::Label | table::TypeImpl::Scalar => strtype(&info.interface_type),
+        table::TypeImpl::Join | table::TypeImpl::JoinOptional | table::TypeImpl::Variant => {
+            strtype(&info.table_type)
+        }
+    }
+}
+
+fn write_struct(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let tablename = table_name(&table.name);
+    write_doc(output, "", &table.help)?;
+    writeln!(output, "pub const {typname} = struct {{")?;
+    // snake case column fields never end with an underscore
+    writeln!(output, "    index_: {},", strtype(&table.index_type()))?;
+    for col in table.data_columns() {
+        // joins help document their accessor method
+        if let table::TypeImpl::Label | table::TypeImpl::Scalar = col.info().type_impl() {
+            write_doc(output, "    ", &col.info().config.help)?;
+        }
+        writeln!(
+            output,
+            "    {}: {},",
+            stored_field_name(col),
+            stored_type(col)
+        )?;
+    }
+    writeln!(
+        output,
+        "
+    /// The number of rows in the table
+    pub const len = {};",
+        table.len
+    )?;
+    if table.get_array {
+        writeln!(
+            output,
+            "
+    /// All the rows of the table
+    pub const array: []const {typname} = &{tablename};"
+        )?;
+    }
+
+    for col in table.data_columns() {
+        write_aggregates(col, output)?;
+        write_join(table, col, output)?;
+        if col.info().has_iter_range() {
+            write_range(table, col, output)?;
+        }
+    }
+    for rj in project.join_to_columns(table) {
+        write_reverse_join(table, &rj, output)?;
+    }
+    for col in table.label_columns() {
+        write_label_methods(table, col, output)?;
+    }
+    writeln!(
+        output,
+        "
+    /// Returns the position of the row in the table
+    pub fn asIndex(self: *const {typname}) usize {{
+        return self.index_;
+    }}
+}};
+"
+    )
+}
+
+fn write_index(
+    name: &str,
+    typ: &str,
+    values: &[usize],
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    if values.len() <= 20 {
+        return writeln!(
+            output,
+            "const {name} = [_]{typ}{{ {} }};",
+            values.join(", ")
+        );
+    }
+    writeln!(output, "const {name} = [_]{typ}{{")?;
+    for chunk in values.chunks(20) {
+        writeln!(output, "    {},", chunk.join(", "))?;
+    }
+    writeln!(output, "}};")
+}
+
+fn write_table_data(
+    project: &table::Project,
+    table: &table::Table,
+    output: &mut dyn io::Write,
+) -> io::Result<()> {
+    let typname = type_name(&table.name);
+    let datacols: Vec<&dyn table::Column> = table.data_columns();
+
+    writeln!(
+        output,
+        "pub const {} = [_]{typname}{{",
+        table_name(&table.name)
+    )?;
+    for row in 0..table.len {
+        let mut cells = vec![format!(".index_ = {row}")];
+        for col in &datacols {
+            let cell = col.emit_table_cell(row, project.lang);
+            cells.push(format!(
+                ".{} = {}",
+                stored_field_name(*col),
+                literal(&col.info().interface_type, &cell)
+            ));
+        }
+        writeln!(output, "    .{{ {} }},", cells.join(", "))?;
+    }
+    writeln!(output, "}};\n")?;
+
+    // indexes
+    let indextype = strtype(&table.index_type());
+    let mut has_index = false;
+    for col in &datacols {
+        if col.iterable() {
+            write_index(
+                &index_name(&table.name, col.name()),
+                &indextype,
+                &col.indexes(),
+                output,
+            )?;
+            has_index = true;
+        }
+    }
+    if has_index {
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+// ================================================================================================
+// Entry point
+// ================================================================================================
+impl language::Language for LangZig {
+    fn emit(&self, project: &table::Project) -> aperror::Result<()> {
+        let mut outfile =
+            aperror::io_error_result(fs::File::create(&project.dst_path), &project.dst_path)?;
+        let output = (&mut outfile) as &mut dyn io::Write;
+
+        writeln!(output, "// {}\n", language::file_notice())?;
+        language::write_help(output, "//! ", &project.help)?;
+        if project.help.is_some() {
+            writeln!(output)?;
+        }
+
+        let tables: Vec<&table::Table> = project.tables.iter().filter(|t| t.has_data()).collect();
+        let mut imports: Vec<String> = project.imports().into_iter().collect();
+        imports.push("const std = @import(\"std\")".to_string());
+        imports.sort();
+        for import in imports {
+            writeln!(output, "{import};")?;
+        }
+        writeln!(output)?;
+
+        if tables.iter().any(|t| project.table_need_iter(t)) {
+            write_helpers(output)?;
+        }
+
+        // labels
+        for table in &project.tables {
+            for col in table.label_columns() {
+                write_labels(table, col, output)?;
+            }
+        }
+
+        // types
+        for table in &tables {
+            for col in table.data_columns() {
+                write_variant_type(table, col, output)?;
+            }
+            write_struct(project, table, output)?;
+        }
+
+        // data, the blank line that follow the last table must not end the file
+        let mut data: Vec<u8> = vec![];
+        for table in &tables {
+            write_table_data(project, table, &mut data)?;
+        }
+        output.write_all(data.trim_ascii_end())?;
+        if !data.is_empty() {
+            writeln!(output)?;
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> String {
+        "zig".to_string()
+    }
+
+    // support tolabel for label format
+    fn to_label(&self) -> bool {
+        true
+    }
+
+    fn emit_enum(&self, typ: &BaseType, label: &str) -> String {
+        format!("{}.{}", strtype(typ), field_name(label))
+    }
+
+    fn emit_str(&self, v: &str) -> String {
+        let mut s = String::from("\"");
+        for c in v.chars() {
+            match c {
+                '\\' => s.push_str("\\\\"),
+                '"' => s.push_str("\\\""),
+                '\n' => s.push_str("\\n"),
+                '\r' => s.push_str("\\r"),
+                '\t' => s.push_str("\\t"),
+                c if c.is_control() => s.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                c => s.push(c),
+            }
+        }
+        s.push('"');
+        s
+    }
+}
+
+const LANG_ZIG_: LangZig = LangZig {};
+pub const LANG_ZIG: &'static dyn language::Language = &LANG_ZIG_;
@@ -8,6 +8,10 @@ use std::path::Path;
 
 mod aperror;
 mod basetype;
+mod bitmap;
+mod bktree;
+mod blob;
+mod closure;
 mod colbool;
 mod colfloat;
 mod colint;
@@ -16,14 +20,26 @@ mod collabel;
 mod colobject;
 mod colstr;
 mod colvariant;
+mod compress;
 mod config;
 mod index;
 mod langc;
+mod langcpp;
+mod langcsharp;
+mod langdoc;
+mod langgo;
+mod langjava;
+mod langjson;
+mod langpython;
 mod langrust;
+mod langsql;
 mod langswift;
+mod langts;
 mod language;
+mod langzig;
 mod lint;
 pub mod log;
+mod packing;
 mod table;
 
 pub use aperror::Result;
@@ -12,6 +12,7 @@ pub struct Linter {
     emit: bool,
     contexts: Vec<String>,
     errors: Cell<usize>,
+    warnings: Cell<usize>,
 }
 
 impl Linter {
@@ -20,6 +21,7 @@ impl Linter {
             emit: true,
             contexts: vec![],
             errors: Cell::new(0),
+            warnings: Cell::new(0),
         }
     }
 
@@ -27,6 +29,11 @@ impl Linter {
         self.errors.get()
     }
 
+    #[cfg(test)]
+    pub fn warnings(&self) -> usize {
+        self.warnings.get()
+    }
+
     pub fn str_context(&self) -> String {
         self.contexts.join(":")
     }
@@ -40,6 +47,23 @@ impl Linter {
         }
     }
 
+    // suspicious configuration that does not prevent generation
+    pub fn warn(&self, condition: bool, message: &str) {
+        if !condition {
+            self.warnings.replace(self.warnings.get() + 1);
+            if self.emit {
+                eprintln!("?{} warning: {}\n", self.str_context(), message);
+            }
+        }
+    }
+
+    // information that is not an error
+    pub fn note(&self, message: &str) {
+        if self.emit {
+            eprintln!("{} {}\n", self.str_context(), message);
+        }
+    }
+
     pub fn check_result<R>(&self, prologue: &str, result: aperror::Result<R>) {
         match result {
             Ok(_) => {}
@@ -60,10 +84,13 @@ impl Linter {
             emit: self.emit,
             contexts,
             errors: Cell::new(0),
+            warnings: Cell::new(0),
         };
 
         block(&linter);
         self.errors.replace(self.errors.get() + linter.errors.get());
+        self.warnings
+            .replace(self.warnings.get() + linter.warnings.get());
     }
 
     pub fn row<F>(&self, row: usize, block: F)
@@ -97,5 +124,6 @@ pub fn test_linter() -> Linter {
         emit: false,
         contexts: vec![],
         errors: Cell::new(0),
+        warnings: Cell::new(0),
     }
 }
@@ -5,6 +5,8 @@
 // log informations to stderr
 //
 
+use std::sync::atomic::{AtomicU8, Ordering};
+
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum Level {
     Off,
@@ -12,15 +14,18 @@ pub enum Level {
     Standard,
     Verbose,
 }
-static mut LEVEL: Level = Level::Standard;
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Standard as u8);
 
 pub fn set_level(level: Level) {
-    unsafe {
-        LEVEL = level;
-    }
+    LEVEL.store(level as u8, Ordering::Relaxed);
 }
 fn level() -> Level {
-    unsafe { LEVEL.clone() }
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Off,
+        1 => Level::Warning,
+        2 => Level::Standard,
+        _ => Level::Verbose,
+    }
 }
 
 pub fn warning(str: &str) {
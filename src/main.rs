@@ -9,6 +9,10 @@ extern crate toml;
 
 mod aperror;
 mod basetype;
+mod bitmap;
+mod bktree;
+mod blob;
+mod closure;
 mod colbool;
 mod colfloat;
 mod colint;
@@ -17,14 +21,26 @@ mod collabel;
 mod colobject;
 mod colstr;
 mod colvariant;
+mod compress;
 mod config;
 mod index;
 mod langc;
+mod langcpp;
+mod langcsharp;
+mod langdoc;
+mod langgo;
+mod langjava;
+mod langjson;
+mod langpython;
 mod langrust;
+mod langsql;
 mod langswift;
+mod langts;
 mod language;
+mod langzig;
 mod lint;
 mod log;
+mod packing;
 mod table;
 use clap::{Parser, ValueEnum};
 use std::path::Path;
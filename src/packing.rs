@@ -0,0 +1,182 @@
+// insrcdata : embed tabular data in source code (https://github.com/sebkeim/insrcdata)
+// Copyright (c)  2023 Sébastien Keim
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Bit packing of small integer, bool and join columns
+//
+
+use crate::table;
+
+// location of a column value in the packed words of a row
+pub struct Field {
+    pub name: String, // column name
+    pub word: usize,
+    pub shift: u32,
+    pub bits: u32,
+    pub min: i64, // subtracted from values before packing
+}
+
+impl Field {
+    pub fn mask(&self) -> u64 {
+        if self.bits == 64 {
+            u64::MAX
+        } else {
+            (1 << self.bits) - 1
+        }
+    }
+}
+
+pub struct Packing {
+    pub word_bits: u32, // 8, 16, 32 or 64
+    pub words: usize,   // number of words for each row
+    pub fields: Vec<Field>,
+    pub unpacked_bytes: usize, // size of packed columns without packing
+}
+
+impl Packing {
+    pub fn new(datacols: &[&dyn table::Column]) -> Packing {
+        let mut packable = vec![];
+        for col in datacols {
+            let values: Option<Vec<i64>> = (0..col.info().len)
+                .map(|row| col.packed_value(row))
+                .collect();
+            if let Some(values) = values {
+                let min = values.iter().copied().min().unwrap_or(0);
+                let max = values.iter().copied().max().unwrap_or(0);
+                let range = (max as i128 - min as i128) as u128;
+                let bits = 128 - range.leading_zeros();
+                packable.push((*col, min, bits));
+            }
+        }
+
+        let total: u32 = packable.iter().map(|(_, _, bits)| bits).sum();
+        // smallest word holding all fields, wider rows use several 64 bits words
+        let word_bits = match total {
+            0..=8 => 8,
+            9..=16 => 16,
+            17..=32 => 32,
+            _ => 64,
+        };
+
+        // first fit : a field never cross word boundary
+        // constant columns have no bits and are not stored
+        let mut fields = vec![];
+        let mut words: usize = 0;
+        let mut used = 0;
+        let mut unpacked_bytes = 0;
+        for (col, min, bits) in packable {
+            if bits > 0 && (words == 0 || used + bits > word_bits) {
+                words += 1;
+                used = 0;
+            }
+            fields.push(Field {
+                name: col.name().to_string(),
+                word: words.saturating_sub(1),
+                shift: used,
+                bits,
+                min,
+            });
+            used += bits;
+            unpacked_bytes += col.info().table_type.byte_size().unwrap_or(1);
+        }
+
+        Packing {
+            word_bits,
+            words,
+            fields,
+            unpacked_bytes,
+        }
+    }
+
+    pub fn field(&self, col: &dyn table::Column) -> Option<&Field> {
+        self.fields.iter().find(|f| &f.name == col.name())
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.words * self.word_bits as usize / 8
+    }
+
+    // packed row is smaller than the unpacked columns
+    pub fn saves(&self) -> bool {
+        self.bytes() < self.unpacked_bytes
+    }
+
+    // packed words of a row
+    pub fn row(&self, datacols: &[&dyn table::Column], row: usize) -> Vec<u64> {
+        let mut words = vec![0; self.words];
+        for col in datacols {
+            if let Some(field) = self.field(*col) {
+                let value = col
+                    .packed_value(row)
+                    .expect("packed column must have value");
+                let raw = (value as i128 - field.min as i128) as u64;
+                words[field.word] |= raw << field.shift;
+            }
+        }
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basetype::BaseType;
+    use crate::colbool::ColBool;
+    use crate::colint::ColInt;
+    use crate::colstr::ColStr;
+    use crate::table::ColumnConfig;
+
+    fn config(name: &str) -> ColumnConfig {
+        ColumnConfig {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pack_columns() {
+        let strvals = |v: &[&str]| -> Vec<String> { v.iter().map(|s| s.to_string()).collect() };
+        let a = ColInt::parse(config("a"), &strvals(&["-2", "1", "0"]), BaseType::I32).unwrap();
+        let b = ColBool::parse(config("b"), &strvals(&["true", "false", "true"])).unwrap();
+        let c = ColStr::parse(config("c"), &strvals(&["x", "y", "z"])).unwrap();
+        let d = ColInt::parse(config("d"), &strvals(&["7", "7", "7"]), BaseType::U8).unwrap();
+        let datacols = vec![a.as_ref(), b.as_ref(), c.as_ref(), d.as_ref()];
+
+        let packing = Packing::new(&datacols);
+        assert!(packing.words == 1);
+        assert!(packing.word_bits == 8);
+        assert!(packing.saves());
+        assert!(packing.fields.len() == 3);
+        assert!(packing.unpacked_bytes == 3);
+
+        let a = packing.field(datacols[0]).unwrap();
+        assert!(a.shift == 0 && a.bits == 2 && a.min == -2);
+        let b = packing.field(datacols[1]).unwrap();
+        assert!(b.shift == 2 && b.bits == 1);
+        assert!(packing.field(datacols[2]).is_none());
+        let d = packing.field(datacols[3]).unwrap();
+        assert!(d.bits == 0 && d.min == 7);
+
+        assert!(packing.row(&datacols, 0) == vec![0b100]);
+        assert!(packing.row(&datacols, 1) == vec![0b011]);
+    }
+
+    #[test]
+    fn pack_word_size() {
+        let strvals = |v: &[&str]| -> Vec<String> { v.iter().map(|s| s.to_string()).collect() };
+        let a = ColInt::parse(config("a"), &strvals(&["0", "1000"]), BaseType::U16).unwrap();
+        let b = ColBool::parse(config("b"), &strvals(&["true", "false"])).unwrap();
+        let datacols = vec![a.as_ref(), b.as_ref()];
+        let packing = Packing::new(&datacols);
+        assert!(packing.word_bits == 16);
+        assert!(packing.bytes() == 2);
+        assert!(packing.unpacked_bytes == 3);
+        assert!(packing.saves());
+
+        let c = ColInt::parse(config("c"), &strvals(&["0", "70000"]), BaseType::U32).unwrap();
+        let datacols = vec![c.as_ref()];
+        let packing = Packing::new(&datacols);
+        assert!(packing.word_bits == 32);
+        assert!(!packing.saves());
+    }
+}
@@ -8,7 +8,12 @@ use std::fs;
 // Abstract type for project
 //
 use crate::basetype::BaseType;
+use crate::bktree::BkTree;
+use crate::blob::Blob;
+use crate::closure::Closure;
+use crate::compress::Compressed;
 use crate::language::Language;
+use crate::packing::Packing;
 use crate::{aperror, basetype, language, lint};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -27,11 +32,23 @@ pub enum TypeImpl {
 
 #[derive(Default)]
 pub struct ColumnConfig {
-    pub name: String,              // field name
-    pub help: Option<String>,      // doc comment for getter
+    pub name: String,                 // field name
+    pub help: Option<String>,         // doc comment for getter
     pub iterable: bool, // implement iter acessor (range search for data or reverse for join)
     pub iter_help: Option<String>, // doc for iter acessor
     pub optional: bool, // for join
+    pub fuzzy: bool,    // implement approximate string lookup
+    pub fuzzy_help: Option<String>, // doc for fuzzy lookup
+    pub bitmap: bool,   // implement bitmap index lookup
+    pub bitmap_help: Option<String>, // doc for bitmap lookup
+    pub aggregates: Vec<String>, // statistics computed at generation time
+    pub group: bool,    // implement iterator on distinct values
+    pub group_help: Option<String>, // doc for groups iterator
+    pub closure: Option<String>, // transitive closure of self join
+    pub closure_help: Option<String>, // doc for closure iterator
+    pub compress: Option<String>, // codec of compressed str values
+    pub src: String,    // csv column of the values
+    pub key: String,    // for join : csv column of the joined table matched by values
 }
 
 pub struct ColumnInfo {
@@ -76,6 +93,42 @@ impl ColumnInfo {
         }
     }
 
+    pub fn has_bitmap_support(&self) -> bool {
+        matches!(
+            self.interface_type,
+            BaseType::Bool
+                | BaseType::I8
+                | BaseType::I16
+                | BaseType::I32
+                | BaseType::I64
+                | BaseType::U8
+                | BaseType::U16
+                | BaseType::U32
+                | BaseType::U64
+                | BaseType::Str
+        )
+    }
+
+    pub fn has_group_support(&self) -> bool {
+        self.has_bitmap_support() || matches!(self.interface_type, BaseType::F32 | BaseType::F64)
+    }
+
+    pub fn has_aggregate_support(&self) -> bool {
+        matches!(
+            self.interface_type,
+            BaseType::I8
+                | BaseType::I16
+                | BaseType::I32
+                | BaseType::I64
+                | BaseType::U8
+                | BaseType::U16
+                | BaseType::U32
+                | BaseType::U64
+                | BaseType::F32
+                | BaseType::F64
+        )
+    }
+
     pub fn has_iter_range(&self) -> bool {
         match self.interface_type {
             basetype::BaseType::Join { .. } | basetype::BaseType::Variant => false, // implemented target Table by col_reverse_join
@@ -89,9 +142,37 @@ pub struct Variant {
     pub index: usize,
     pub count: usize,
     pub reverse: String, // getter name for reverse join
+    pub key: String,     // csv column of the dest table matched by values
     pub is_none: bool,   // null value placeholder for optional vatiants
 }
 
+// statistic on column values computed at generation time
+pub struct Aggregate {
+    pub name: String, // min, max, sum or mean
+    pub typ: BaseType,
+    pub value: String,
+}
+
+impl Aggregate {
+    // floating point statistic, rendered as a literal by languages
+    pub fn float(name: &str, typ: BaseType, value: f64) -> Aggregate {
+        Aggregate {
+            name: name.to_string(),
+            typ,
+            value: format!("{:?}", value),
+        }
+    }
+
+    // NaN and infinite have no literal in most languages
+    pub fn is_finite(&self) -> bool {
+        self.value.parse::<f64>().map_or(true, f64::is_finite)
+    }
+}
+
+pub const AGGREGATES: [&str; 4] = ["min", "max", "sum", "mean"];
+
+pub const CLOSURES: [&str; 2] = ["ancestors", "descendants"];
+
 pub trait Column {
     fn info(&self) -> &ColumnInfo;
 
@@ -99,7 +180,7 @@ pub trait Column {
         &self.info().config.name
     }
     fn iterable(&self) -> bool {
-        self.info().config.iterable
+        self.info().config.iterable || self.info().config.group
     }
     fn optional(&self) -> bool {
         self.info().config.optional
@@ -131,6 +212,38 @@ pub trait Column {
     fn variants(&self) -> Option<&Vec<Variant>> {
         None
     }
+
+    // for approximate string lookup
+    fn bktree(&self) -> Option<BkTree> {
+        None
+    }
+
+    // statistics requested by aggregates option
+    fn aggregates(&self) -> Vec<Aggregate> {
+        vec![]
+    }
+
+    // depth first numbering for transitive closure of self join
+    fn closure(&self) -> Option<Closure> {
+        None
+    }
+
+    // stored integer value for bit packing, None if column can not be packed
+    fn packed_value(&self, _row: usize) -> Option<i64> {
+        None
+    }
+
+    // dictionary compressed values of str column
+    fn compressed(&self) -> Option<&Compressed> {
+        None
+    }
+
+    // stored value as little endian bytes for binary blob, utf-8 bytes for str
+    fn blob_value(&self, row: usize) -> Option<Vec<u8>> {
+        let size = self.info().table_type.byte_size()?;
+        let value = self.packed_value(row)?;
+        Some(value.to_le_bytes()[..size].to_vec())
+    }
 }
 
 // ================================================================================================
@@ -143,7 +256,11 @@ pub struct Table {
     pub len: usize,
     pub columns: Vec<Box<dyn Column>>,
     pub get_array: bool,
-    pub exhaustive: bool, // a row can not be added between major releases
+    pub exhaustive: bool,        // a row can not be added between major releases
+    pub display: Option<String>, // column used for Display implementation
+    pub soa: bool,               // struct of arrays memory layout
+    pub packing: Option<Packing>, // bit packing of small columns
+    pub blob: Option<Blob>,      // rows stored in a binary file
     pub outcol_indexes: Vec<usize>, // value columns
     pub labcol_indexes: Vec<usize>, // label columns
 }
@@ -156,6 +273,8 @@ impl Table {
         columns: Vec<Box<dyn Column>>,
         get_array: bool,
         exhaustive: bool,
+        display: Option<String>,
+        soa: bool,
     ) -> Table {
         let mut outcol_indexes: Vec<usize> = Vec::new();
         let mut labcol_indexes: Vec<usize> = Vec::new();
@@ -187,6 +306,10 @@ impl Table {
             columns,
             get_array,
             exhaustive,
+            display,
+            soa,
+            packing: None,
+            blob: None,
             outcol_indexes,
             labcol_indexes,
         }
@@ -201,6 +324,27 @@ impl Table {
                 self.has_data() || !self.get_array,
                 "unable to use array on table without values",
             );
+            if let Some(display) = &self.display {
+                lt_table.err(
+                    self.data_columns().iter().any(|col| {
+                        col.name() == display
+                            && matches!(col.info().type_impl(), TypeImpl::Scalar)
+                            && !matches!(col.info().interface_type, BaseType::Object { .. })
+                    }),
+                    &format!(
+                        "display column {display} must be a bool, integer, float or str column"
+                    ),
+                );
+            }
+
+            if let Some(packing) = &self.packing {
+                lt_table.note(&format!(
+                    "bits packing uses {} bytes per row instead of {} for {} columns",
+                    packing.bytes(),
+                    packing.unpacked_bytes,
+                    packing.fields.len()
+                ));
+            }
 
             // check columns
             let mut colnames = HashSet::<&String>::new();
@@ -210,6 +354,54 @@ impl Table {
                     lt_col.err(lint::label(col.name()), "invalid column name");
                     lt_col.err(!colnames.contains(col.name()), "duplicated column name");
                     lt_col.err(self.len == info.len, "mismatched number of rows");
+                    lt_col.err(
+                        !info.config.fuzzy || info.interface_type == BaseType::Str,
+                        "fuzzy lookup is only available for str format",
+                    );
+                    lt_col.err(
+                        !info.config.bitmap || info.has_bitmap_support(),
+                        "bitmap index is only available for bool, integer and str formats",
+                    );
+                    lt_col.err(
+                        info.config.compress.is_none() || info.interface_type == BaseType::Str,
+                        "compression is only available for str format",
+                    );
+                    lt_col.err(
+                        !info.config.group || info.has_group_support(),
+                        "groups are only available for bool, integer, float and str formats",
+                    );
+                    for aggregate in &info.config.aggregates {
+                        lt_col.err(
+                            AGGREGATES.contains(&aggregate.as_str()),
+                            &format!("unknown aggregate {}", aggregate),
+                        );
+                    }
+                    lt_col.err(
+                        info.config.aggregates.is_empty() || info.has_aggregate_support(),
+                        "aggregates are only available for integer and float formats",
+                    );
+                    lt_col.err(
+                        info.config.aggregates.is_empty() || self.len > 0,
+                        "unable to compute aggregates on table without rows",
+                    );
+                    if self.len > 0 {
+                        for aggregate in col.aggregates() {
+                            lt_col.err(
+                                aggregate.is_finite(),
+                                &format!("{} aggregate is not a finite number", aggregate.name),
+                            );
+                        }
+                    }
+                    if let Some(closure) = &info.config.closure {
+                        lt_col.err(
+                            CLOSURES.contains(&closure.as_str()),
+                            &format!("unknown closure {}", closure),
+                        );
+                        lt_col.err(
+                            info.join_table() == self.name,
+                            "closure is only available for join to the same table",
+                        );
+                    }
                     col.lint(lt_col)
                 });
                 colnames.insert(col.name());
@@ -217,6 +409,22 @@ impl Table {
         })
     }
 
+    /// Pack small columns into words, return false when packing would not reduce the row size
+    pub fn pack(&mut self) -> bool {
+        let packing = Packing::new(&self.data_columns());
+        let saves = packing.saves();
+        if saves {
+            self.packing = Some(packing);
+        }
+        saves
+    }
+
+    /// Store rows in a binary blob, must be called after pack()
+    pub fn store_blob(&mut self) {
+        let blob = Blob::new(&self.data_columns(), self.packing.as_ref(), self.len);
+        self.blob = Some(blob);
+    }
+
     /// Data type columns
     pub fn data_columns(&self) -> Vec<&dyn Column> {
         self.outcol_indexes
@@ -238,6 +446,53 @@ impl Table {
             .collect()
     }
 
+    /// Has at least one column with approximate string lookup
+    pub fn has_fuzzy(&self) -> bool {
+        self.columns.iter().any(|c| c.info().config.fuzzy)
+    }
+
+    pub fn has_compression(&self, codec: &str) -> bool {
+        self.columns
+            .iter()
+            .any(|c| c.compressed().is_some_and(|c| c.codec() == codec))
+    }
+
+    /// Has at least one column with bitmap index
+    pub fn has_bitmap(&self) -> bool {
+        self.columns.iter().any(|c| c.info().config.bitmap)
+    }
+
+    /// Has at least one column with groups iterator
+    pub fn has_group(&self) -> bool {
+        self.columns.iter().any(|c| c.info().config.group)
+    }
+
+    /// Has at least one self join with closure of this kind
+    pub fn has_closure(&self, kind: &str) -> bool {
+        self.columns
+            .iter()
+            .any(|c| c.info().config.closure.as_deref() == Some(kind))
+    }
+
+    /// Tables referenced by joins and variants
+    pub fn join_targets(&self) -> Vec<String> {
+        let mut targets = vec![];
+        for col in &self.columns {
+            match &col.info().interface_type {
+                BaseType::Join { strname } => targets.push(strname.to_string()),
+                BaseType::Variant => {
+                    for vrt in col.variants().expect("variant expected") {
+                        if !vrt.is_none {
+                            targets.push(vrt.name.to_string());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        targets
+    }
+
     pub fn index_type(&self) -> basetype::BaseType {
         basetype::int_type_for_range(0..=self.len as i64)
     }
@@ -285,6 +540,13 @@ pub struct Project {
     pub dst_path: PathBuf,
     pub help: Option<String>,
     pub lang: &'static dyn language::Language,
+    pub no_std: bool,
+    pub const_fn: bool,
+    pub derive_serde: bool,
+    pub serde_nested: bool,
+    pub split: bool,          // Rust dst_path is a directory with one file per table
+    pub single_header: bool,  // C implementation is in the header
+    pub static_linkage: bool, // C symbols are internal
     pub tables: Vec<Table>,
     pub src_paths: Vec<PathBuf>,
 }
@@ -308,11 +570,84 @@ impl Project {
                         table.name
                     ),
                 );
+                lt.err(
+                    !(self.no_std && table.has_fuzzy()),
+                    &format!(
+                        "fuzzy option allocate and is unavailable with no_std {}",
+                        table.name
+                    ),
+                );
+                for col in &table.columns {
+                    let config = &col.info().config;
+                    let used = [
+                        config.fuzzy,
+                        config.bitmap,
+                        config.group,
+                        config.closure.is_some(),
+                    ];
+                    for (feature, used) in language::FEATURES.iter().zip(used) {
+                        lt.warn(
+                            !used || self.lang.supports(feature),
+                            &format!(
+                                "{feature} option of {}.{} is ignored by {} dest",
+                                table.name,
+                                col.name(),
+                                self.lang.extension()
+                            ),
+                        );
+                    }
+                }
+                if let Some(max) = self.lang.max_labels() {
+                    for col in table.label_columns() {
+                        let info = col.info();
+                        let count = (0..info.len)
+                            .filter(|row| !col.emit_label(*row).is_empty())
+                            .count();
+                        lt.err(
+                            count <= max,
+                            &format!(
+                                "{count} values in {} exceed the {max} labels supported by {}",
+                                info.interface_type,
+                                self.lang.extension()
+                            ),
+                        );
+                    }
+                }
+                lt.err(
+                    !(self.derive_serde && self.serde_nested && self.join_cycle(table)),
+                    &format!(
+                        "nested serde joins would recurse endlessly from table {}",
+                        table.name
+                    ),
+                );
                 tblnames.insert(&table.name);
             }
+            self.lang.lint(self, lt);
+            lt.err(
+                !self.static_linkage || self.single_header,
+                "static_linkage option need single_header to define the static symbols",
+            );
         })
     }
 
+    // check if table can be reached again by following its joins
+    fn join_cycle(&self, table: &Table) -> bool {
+        let mut visited = HashSet::<String>::new();
+        let mut pending = table.join_targets();
+        while let Some(name) = pending.pop() {
+            if name == table.name {
+                return true;
+            }
+            if !visited.insert(name.to_string()) {
+                continue;
+            }
+            if let Some(target) = self.tables.iter().find(|t| t.name == name) {
+                pending.extend(target.join_targets());
+            }
+        }
+        false
+    }
+
     pub fn src_modified(&self) -> SystemTime {
         let mut last_modified = std::time::UNIX_EPOCH;
         for pathbuf in &self.src_paths {
@@ -354,8 +689,15 @@ impl Project {
         !self.join_to_columns(table).is_empty()
     }
 
+    // table is referenced by a join or a variant
+    pub fn is_join_target(&self, table: &Table) -> bool {
+        self.tables
+            .iter()
+            .any(|t| t.join_targets().contains(&table.name))
+    }
+
     // reverse join to table
-    pub fn join_to_columns(&self, table: &Table) -> Vec<JoinTo> {
+    pub fn join_to_columns(&self, table: &Table) -> Vec<JoinTo<'_>> {
         let mut columns = Vec::<JoinTo>::new();
 
         for join in &self.tables {
@@ -365,10 +707,10 @@ impl Project {
             for col in &join.columns {
                 let info = col.info();
                 match &info.interface_type {
-                    basetype::BaseType::Join { strname, .. } => {
-                        if strname == &table.name && col.iterable() {
-                            columns.push(JoinTo::from_join(join, col.as_ref()));
-                        }
+                    basetype::BaseType::Join { strname, .. }
+                        if strname == &table.name && col.iterable() =>
+                    {
+                        columns.push(JoinTo::from_join(join, col.as_ref()));
                     }
                     basetype::BaseType::Variant => {
                         for vrt in col.variants().expect("variant expected") {
@@ -414,19 +756,29 @@ pub fn parse_vec<T: FromStr>(strvals: &[String]) -> aperror::Result<Vec<T>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::colfloat::ColF64;
+    use crate::coljoin::ColJoin;
+    use crate::collabel::ColLabel;
     use crate::colstr::ColStr;
-    use crate::langrust;
     use crate::lint::test_linter;
+    use crate::{langgo, langrust, langsql};
 
     #[test]
     fn duplicate_table_name() {
-        let t1 = Table::new("mytable", None, vec![], false, false);
-        let t2 = Table::new("mytable", None, vec![], false, false);
+        let t1 = Table::new("mytable", None, vec![], false, false, None, false);
+        let t2 = Table::new("mytable", None, vec![], false, false, None, false);
 
         let project = Project {
             dst_path: PathBuf::new(),
             help: None,
             lang: langrust::RUST,
+            no_std: false,
+            const_fn: false,
+            derive_serde: false,
+            serde_nested: false,
+            split: false,
+            single_header: false,
+            static_linkage: false,
             tables: vec![t1, t2],
             src_paths: vec![],
         };
@@ -436,6 +788,125 @@ mod tests {
         assert!(linter.errors() == 1);
     }
 
+    #[test]
+    fn no_std_fuzzy() {
+        let a = ColStr::parse(
+            ColumnConfig {
+                name: "mycol".to_string(),
+                fuzzy: true,
+                ..Default::default()
+            },
+            &vec![],
+        )
+        .unwrap();
+        let t = Table::new("table", None, vec![a], false, false, None, false);
+
+        let project = Project {
+            dst_path: PathBuf::from("project.rs"),
+            help: None,
+            lang: langrust::RUST,
+            no_std: true,
+            const_fn: false,
+            derive_serde: false,
+            serde_nested: false,
+            split: false,
+            single_header: false,
+            static_linkage: false,
+            tables: vec![t],
+            src_paths: vec![],
+        };
+
+        let linter = test_linter();
+        project.lint(&linter);
+        assert!(linter.errors() == 1);
+    }
+
+    #[test]
+    fn serde_nested_cycle() {
+        let keys = vec!["a".to_string(), "b".to_string()];
+        let parent = ColJoin::new(
+            ColumnConfig {
+                name: "parent".to_string(),
+                ..Default::default()
+            },
+            &keys,
+            "table",
+            &keys,
+            "",
+        );
+        let t = Table::new(
+            "table",
+            None,
+            vec![Box::new(parent)],
+            false,
+            false,
+            None,
+            false,
+        );
+
+        let project = Project {
+            dst_path: PathBuf::from("project.rs"),
+            help: None,
+            lang: langrust::RUST,
+            no_std: false,
+            const_fn: false,
+            derive_serde: true,
+            serde_nested: true,
+            split: false,
+            single_header: false,
+            static_linkage: false,
+            tables: vec![t],
+            src_paths: vec![],
+        };
+
+        let linter = test_linter();
+        project.lint(&linter);
+        assert!(linter.errors() == 1);
+    }
+
+    #[test]
+    fn display_column() {
+        let a = ColStr::parse(
+            ColumnConfig {
+                name: "mycol".to_string(),
+                ..Default::default()
+            },
+            &vec![],
+        )
+        .unwrap();
+        let t = Table::new(
+            "table",
+            None,
+            vec![a],
+            false,
+            false,
+            Some("othercol".to_string()),
+            false,
+        );
+
+        let linter = test_linter();
+        t.lint(&linter);
+        assert!(linter.errors() == 1);
+    }
+
+    #[test]
+    fn infinite_aggregate() {
+        let a = ColF64::parse(
+            ColumnConfig {
+                name: "value".to_string(),
+                aggregates: vec!["max".to_string(), "sum".to_string()],
+                ..Default::default()
+            },
+            &["1.0".to_string(), "inf".to_string()],
+        )
+        .unwrap();
+        let t = Table::new("table", None, vec![a], false, false, None, false);
+
+        let linter = test_linter();
+        t.lint(&linter);
+        assert!(linter.errors() == 2);
+    }
+
     #[test]
     fn duplicate_col_name() {
         let a1 = ColStr::parse(
@@ -455,10 +926,117 @@ mod tests {
         )
         .unwrap();
 
-        let t = Table::new("table", None, vec![a1, a2], false, false);
+        let t = Table::new("table", None, vec![a1, a2], false, false, None, false);
 
         let linter = test_linter();
         t.lint(&linter);
         assert!(linter.errors() == 1);
     }
+
+    #[test]
+    fn sql_label_table_name() {
+        let name = ColStr::parse(
+            ColumnConfig {
+                name: "name".to_string(),
+                ..Default::default()
+            },
+            &vec!["a".to_string()],
+        )
+        .unwrap();
+        let label = ColLabel::parse(
+            ColumnConfig::default(),
+            "item",
+            &["a".to_string()],
+            &["".to_string()],
+        )
+        .unwrap();
+        let t = Table::new("item", None, vec![name, label], false, false, None, false);
+
+        let project = Project {
+            dst_path: PathBuf::from("project.sql"),
+            help: None,
+            lang: langsql::LANG_SQL,
+            no_std: false,
+            const_fn: false,
+            derive_serde: false,
+            serde_nested: false,
+            split: false,
+            single_header: false,
+            static_linkage: false,
+            tables: vec![t],
+            src_paths: vec![],
+        };
+
+        let linter = test_linter();
+        project.lint(&linter);
+        assert!(linter.errors() == 1);
+    }
+
+    #[test]
+    fn go_index_column() {
+        let index = ColStr::parse(
+            ColumnConfig {
+                name: "index".to_string(),
+                ..Default::default()
+            },
+            &vec!["a".to_string()],
+        )
+        .unwrap();
+        let t = Table::new("item", None, vec![index], false, false, None, false);
+
+        let project = Project {
+            dst_path: PathBuf::from("project.go"),
+            help: None,
+            lang: langgo::LANG_GO,
+            no_std: false,
+            const_fn: false,
+            derive_serde: false,
+            serde_nested: false,
+            split: false,
+            single_header: false,
+            static_linkage: false,
+            tables: vec![t],
+            src_paths: vec![],
+        };
+
+        let linter = test_linter();
+        project.lint(&linter);
+        assert!(linter.errors() == 1);
+    }
+
+    #[test]
+    fn ignored_feature() {
+        let lint_group = |lang: &'static dyn Language| {
+            let group = ColStr::parse(
+                ColumnConfig {
+                    name: "kind".to_string(),
+                    group: true,
+                    ..Default::default()
+                },
+                &vec!["a".to_string()],
+            )
+            .unwrap();
+            let t = Table::new("item", None, vec![group], false, false, None, false);
+            let project = Project {
+                dst_path: PathBuf::from("project"),
+                help: None,
+                lang,
+                no_std: false,
+                const_fn: false,
+                derive_serde: false,
+                serde_nested: false,
+                split: false,
+                single_header: false,
+                static_linkage: false,
+                tables: vec![t],
+                src_paths: vec![],
+            };
+            let linter = test_linter();
+            project.lint(&linter);
+            assert!(linter.errors() == 0);
+            linter.warnings()
+        };
+        assert!(lint_group(langgo::LANG_GO) == 1);
+        assert!(lint_group(langrust::RUST) == 0);
+    }
 }